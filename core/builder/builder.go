@@ -0,0 +1,158 @@
+// Package builder constructs configuration models in Go code, as an
+// alternative to parsing a .terse file. It is meant for tests and for
+// services that embed the Hyperterse runtime.
+//
+//	model, err := builder.NewModel("my-api").
+//		AddAdapter("main_db", "postgres", "{{ env.DATABASE_URL }}").
+//		AddQuery(builder.NewQuery("get-user").
+//			Use("main_db").
+//			Description("Get a user by ID").
+//			Statement("SELECT id, name FROM users WHERE id = {{ inputs.id }}").
+//			Input("id", "int", "User ID")).
+//		Build()
+package builder
+
+import (
+	"errors"
+	"fmt"
+
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// ModelBuilder assembles a configuration model. Connector and type names are
+// checked as they are added; the complete model is validated by Build with the
+// same rules as a configuration file.
+type ModelBuilder struct {
+	model *hyperterse.Model
+	errs  []error
+}
+
+// NewModel starts a configuration with the given name
+func NewModel(name string) *ModelBuilder {
+	return &ModelBuilder{model: &hyperterse.Model{Name: name, ConfigVersion: parser.CurrentConfigVersion}}
+}
+
+// AddAdapter adds an adapter for a connector, such as "postgres" or "redis"
+func (b *ModelBuilder) AddAdapter(name, connector, connectionString string) *ModelBuilder {
+	connectorEnum, err := types.StringToConnectorEnum(connector)
+	if err != nil {
+		b.errs = append(b.errs, fmt.Errorf("invalid connector '%s' for adapter '%s': %w", connector, name, err))
+	}
+	return b.AddAdapterConfig(&hyperterse.Adapter{Name: name, Connector: connectorEnum, ConnectionString: connectionString})
+}
+
+// AddAdapterConfig adds a fully specified adapter, for settings AddAdapter does not cover
+func (b *ModelBuilder) AddAdapterConfig(adapter *hyperterse.Adapter) *ModelBuilder {
+	b.model.Adapters = append(b.model.Adapters, adapter)
+	return b
+}
+
+// AddQuery adds a query
+func (b *ModelBuilder) AddQuery(query *QueryBuilder) *ModelBuilder {
+	b.errs = append(b.errs, query.errs...)
+	b.model.Queries = append(b.model.Queries, query.query)
+	return b
+}
+
+// AddHook adds a webhook notified of runtime events
+func (b *ModelBuilder) AddHook(hook *hyperterse.Hook) *ModelBuilder {
+	b.model.Hooks = append(b.model.Hooks, hook)
+	return b
+}
+
+// WithServer sets the server configuration
+func (b *ModelBuilder) WithServer(server *hyperterse.ServerConfig) *ModelBuilder {
+	b.model.Server = server
+	return b
+}
+
+// WithVersion sets the service version reported to observability tools
+func (b *ModelBuilder) WithVersion(version string) *ModelBuilder {
+	b.model.Version = version
+	return b
+}
+
+// Build validates and returns the model. Each invalid connector or type name
+// and each validation error is reported.
+func (b *ModelBuilder) Build() (*hyperterse.Model, error) {
+	if len(b.errs) > 0 {
+		return nil, errors.Join(b.errs...)
+	}
+	if err := parser.Validate(b.model); err != nil {
+		return nil, err
+	}
+	return b.model, nil
+}
+
+// QueryBuilder assembles a query for ModelBuilder.AddQuery
+type QueryBuilder struct {
+	query *hyperterse.Query
+	errs  []error
+}
+
+// NewQuery starts a query with the given name
+func NewQuery(name string) *QueryBuilder {
+	return &QueryBuilder{query: &hyperterse.Query{Name: name}}
+}
+
+// Use sets the adapters the query runs on
+func (q *QueryBuilder) Use(adapters ...string) *QueryBuilder {
+	q.query.Use = append(q.query.Use, adapters...)
+	return q
+}
+
+// Description sets the description shown in generated documentation and tool listings
+func (q *QueryBuilder) Description(description string) *QueryBuilder {
+	q.query.Description = description
+	return q
+}
+
+// Statement sets the statement, which may reference {{ inputs.name }} and {{ env.NAME }}
+func (q *QueryBuilder) Statement(statement string) *QueryBuilder {
+	q.query.Statement = statement
+	return q
+}
+
+// Input adds a required input of a primitive type, such as "int" or "string"
+func (q *QueryBuilder) Input(name, typ, description string) *QueryBuilder {
+	return q.addInput(&hyperterse.Input{Name: name, Description: description}, typ)
+}
+
+// OptionalInput adds an optional input. An empty default leaves the input unset
+// when it is not given.
+func (q *QueryBuilder) OptionalInput(name, typ, description, defaultValue string) *QueryBuilder {
+	return q.addInput(&hyperterse.Input{Name: name, Description: description, Optional: true, DefaultValue: defaultValue}, typ)
+}
+
+// InputConfig adds a fully specified input, for settings Input does not cover
+func (q *QueryBuilder) InputConfig(input *hyperterse.Input) *QueryBuilder {
+	q.query.Inputs = append(q.query.Inputs, input)
+	return q
+}
+
+// Data declares a result column of a primitive type
+func (q *QueryBuilder) Data(name, typ, description string) *QueryBuilder {
+	dataType, err := types.StringToPrimitiveEnum(typ)
+	if err != nil {
+		q.errs = append(q.errs, fmt.Errorf("invalid type '%s' for data '%s' in query '%s': %w", typ, name, q.query.Name, err))
+	}
+	q.query.Data = append(q.query.Data, &hyperterse.Data{Name: name, Type: dataType, Description: description})
+	return q
+}
+
+// Cache caches the query's results for ttl seconds
+func (q *QueryBuilder) Cache(ttl int32) *QueryBuilder {
+	q.query.Cache = &hyperterse.CacheConfig{Enabled: true, HasEnabled: true, Ttl: ttl, HasTtl: true}
+	return q
+}
+
+func (q *QueryBuilder) addInput(input *hyperterse.Input, typ string) *QueryBuilder {
+	inputType, err := types.StringToPrimitiveEnum(typ)
+	if err != nil {
+		q.errs = append(q.errs, fmt.Errorf("invalid type '%s' for input '%s' in query '%s': %w", typ, input.Name, q.query.Name, err))
+	}
+	input.Type = inputType
+	return q.InputConfig(input)
+}
@@ -0,0 +1,268 @@
+package cmd
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"slices"
+	"sort"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	"github.com/spf13/cobra"
+)
+
+var (
+	benchConcurrency int
+	benchDuration    time.Duration
+	benchInputs      string
+	benchURL         string
+)
+
+// benchCmd represents the bench command
+var benchCmd = &cobra.Command{
+	Use:   "bench <query>",
+	Short: "Load-test a query and report latency percentiles",
+	Long: `Drive concurrent load against a query and report latency percentiles, throughput and error rate.
+By default queries run through a local executor built from the configuration file.
+With --url, requests are sent to a running server's /query/<name> endpoint instead.`,
+	Args:          cobra.ExactArgs(1),
+	RunE:          runBench,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse bench get-user -f config.terse --concurrency 50 --duration 30s --inputs '{"userId": 1}'
+  hyperterse bench get-user -f config.terse --url http://127.0.0.1:8080`,
+}
+
+func init() {
+	rootCmd.AddCommand(benchCmd)
+
+	benchCmd.Flags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	benchCmd.Flags().IntVarP(&benchConcurrency, "concurrency", "c", 10, "Number of concurrent workers")
+	benchCmd.Flags().DurationVarP(&benchDuration, "duration", "d", 10*time.Second, "How long to run the benchmark")
+	benchCmd.Flags().StringVar(&benchInputs, "inputs", "", "Query inputs as a JSON object")
+	benchCmd.Flags().StringVar(&benchURL, "url", "", "Benchmark a running server at this base URL (e.g. http://127.0.0.1:8080) instead of a local executor")
+	benchCmd.Flags().BoolVar(&verbose, "verbose", false, "Enable verbose logging (sets log level to DEBUG)")
+}
+
+// benchTarget executes a single query invocation
+type benchTarget func(ctx context.Context) error
+
+func runBench(cmd *cobra.Command, args []string) error {
+	log := logger.New("bench")
+	queryName := args[0]
+
+	if benchConcurrency < 1 {
+		return log.Errorf("--concurrency must be at least 1")
+	}
+	if benchDuration <= 0 {
+		return log.Errorf("--duration must be greater than 0")
+	}
+
+	inputs := make(map[string]any)
+	if benchInputs != "" {
+		if err := json.Unmarshal([]byte(benchInputs), &inputs); err != nil {
+			return log.Errorf("invalid --inputs JSON: %w", err)
+		}
+	}
+
+	var (
+		target benchTarget
+		mode   string
+	)
+	if benchURL != "" {
+		body, err := json.Marshal(inputs)
+		if err != nil {
+			return log.Errorf("failed to encode inputs: %w", err)
+		}
+		target = httpBenchTarget(strings.TrimRight(benchURL, "/")+"/query/"+queryName, body)
+		mode = benchURL
+	} else {
+		exec, manager, err := prepareBenchExecutor(queryName)
+		if err != nil {
+			return err
+		}
+		defer manager.CloseAll()
+		target = func(ctx context.Context) error {
+			_, err := exec.ExecuteQuery(ctx, queryName, inputs)
+			return err
+		}
+		mode = "local executor"
+	}
+
+	// Per-query logs would drown the report and skew latencies
+	if !verbose {
+		logger.SetLogLevel(logger.LogLevelWarn)
+	}
+
+	fmt.Printf("Benchmarking '%s' via %s: %d worker(s) for %s\n", queryName, mode, benchConcurrency, benchDuration)
+	report := runBenchWorkers(cmd.Context(), target, benchConcurrency, benchDuration)
+	report.print()
+	return nil
+}
+
+// prepareBenchExecutor loads and validates the configuration, then builds an
+// executor with only the adapters used by the query connected, without
+// starting the HTTP server
+func prepareBenchExecutor(queryName string) (*executor.Executor, *connectors.ConnectorManager, error) {
+	log := logger.New("bench")
+
+	model, err := loadValidatedModel(log)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	var query *hyperterse.Query
+	for _, q := range model.Queries {
+		if q.Name == queryName {
+			query = q
+			break
+		}
+	}
+	if query == nil {
+		return nil, nil, log.Errorf("query '%s' not found", queryName)
+	}
+
+	var adapters []*hyperterse.Adapter
+	for _, adapter := range model.Adapters {
+		if slices.Contains(query.Use, adapter.Name) {
+			adapters = append(adapters, adapter)
+		}
+	}
+
+	manager := connectors.NewConnectorManager()
+	if err := manager.InitializeAll(adapters); err != nil {
+		return nil, nil, err
+	}
+	return executor.NewExecutor(model, manager), manager, nil
+}
+
+func httpBenchTarget(url string, body []byte) benchTarget {
+	client := &http.Client{
+		Transport: &http.Transport{
+			MaxIdleConns:        benchConcurrency,
+			MaxIdleConnsPerHost: benchConcurrency,
+		},
+	}
+	return func(ctx context.Context) error {
+		req, err := http.NewRequestWithContext(ctx, http.MethodPost, url, bytes.NewReader(body))
+		if err != nil {
+			return err
+		}
+		req.Header.Set("Content-Type", "application/json")
+		resp, err := client.Do(req)
+		if err != nil {
+			return err
+		}
+		defer resp.Body.Close()
+		// Drain the body so the connection can be reused
+		io.Copy(io.Discard, resp.Body)
+		if resp.StatusCode != http.StatusOK {
+			return fmt.Errorf("unexpected status %d", resp.StatusCode)
+		}
+		return nil
+	}
+}
+
+type benchReport struct {
+	elapsed   time.Duration
+	latencies []time.Duration
+	errors    int
+	errorKind map[string]int
+}
+
+func runBenchWorkers(ctx context.Context, target benchTarget, concurrency int, duration time.Duration) *benchReport {
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	ctx, cancel := context.WithTimeout(ctx, duration)
+	defer cancel()
+
+	report := &benchReport{errorKind: make(map[string]int)}
+	var mu sync.Mutex
+	var wg sync.WaitGroup
+
+	start := time.Now()
+	for range concurrency {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			// Collect locally to keep lock contention out of the measured path
+			var latencies []time.Duration
+			errorKind := make(map[string]int)
+			for ctx.Err() == nil {
+				callStart := time.Now()
+				err := target(ctx)
+				latency := time.Since(callStart)
+				if ctx.Err() != nil {
+					// Calls cut short by the deadline are not representative
+					break
+				}
+				latencies = append(latencies, latency)
+				if err != nil {
+					errorKind[err.Error()]++
+				}
+			}
+
+			mu.Lock()
+			defer mu.Unlock()
+			report.latencies = append(report.latencies, latencies...)
+			for kind, count := range errorKind {
+				report.errorKind[kind] += count
+				report.errors += count
+			}
+		}()
+	}
+	wg.Wait()
+	report.elapsed = time.Since(start)
+
+	sort.Slice(report.latencies, func(i, j int) bool { return report.latencies[i] < report.latencies[j] })
+	return report
+}
+
+func (r *benchReport) percentile(p float64) time.Duration {
+	if len(r.latencies) == 0 {
+		return 0
+	}
+	idx := int(float64(len(r.latencies)-1) * p)
+	return r.latencies[idx]
+}
+
+func (r *benchReport) print() {
+	total := len(r.latencies)
+	fmt.Println()
+	fmt.Printf("Requests:    %d\n", total)
+	fmt.Printf("Duration:    %s\n", r.elapsed.Round(time.Millisecond))
+	fmt.Printf("Throughput:  %.2f req/s\n", float64(total)/r.elapsed.Seconds())
+	if total > 0 {
+		fmt.Printf("Errors:      %d (%.2f%%)\n", r.errors, float64(r.errors)/float64(total)*100)
+		fmt.Println()
+		fmt.Println("Latency:")
+		fmt.Printf("  min  %s\n", r.latencies[0].Round(time.Microsecond))
+		fmt.Printf("  p50  %s\n", r.percentile(0.50).Round(time.Microsecond))
+		fmt.Printf("  p95  %s\n", r.percentile(0.95).Round(time.Microsecond))
+		fmt.Printf("  p99  %s\n", r.percentile(0.99).Round(time.Microsecond))
+		fmt.Printf("  max  %s\n", r.latencies[total-1].Round(time.Microsecond))
+	}
+
+	if len(r.errorKind) > 0 {
+		fmt.Println()
+		fmt.Println("Errors by message:")
+		kinds := make([]string, 0, len(r.errorKind))
+		for kind := range r.errorKind {
+			kinds = append(kinds, kind)
+		}
+		sort.Slice(kinds, func(i, j int) bool { return r.errorKind[kinds[i]] > r.errorKind[kinds[j]] })
+		for _, kind := range kinds {
+			fmt.Printf("  %6d  %s\n", r.errorKind[kind], kind)
+		}
+	}
+}
@@ -0,0 +1,124 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"slices"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/spf13/cobra"
+)
+
+// exitBreakingChanges is the exit status of check-compat when breaking changes
+// are found, distinct from the status 1 of an unreadable configuration
+const exitBreakingChanges = 2
+
+var (
+	compatBase   string
+	compatHead   string
+	compatOutput string
+)
+
+// checkCompatCmd represents the check-compat command
+var checkCompatCmd = &cobra.Command{
+	Use:   "check-compat",
+	Short: "Fail when a configuration breaks clients of another",
+	Long: `Compare a head configuration against a base and exit with status 2 when the head
+would break existing API clients: a removed query, input or data column, a new
+required input, a changed type or removed enum values. Use it in CI to gate merges
+on API compatibility. See hyperterse diff for the full list of changes.`,
+	Args:          cobra.NoArgs,
+	RunE:          runCheckCompat,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse check-compat --base main.terse --head config.terse
+  git show origin/main:config.terse > base.terse
+  hyperterse check-compat --base base.terse --head config.terse --output json > compat.json`,
+}
+
+func init() {
+	rootCmd.AddCommand(checkCompatCmd)
+
+	checkCompatCmd.Flags().StringVar(&compatBase, "base", "", "Configuration clients currently use (required)")
+	checkCompatCmd.Flags().StringVar(&compatHead, "head", "", "Configuration to check against the base (required)")
+	checkCompatCmd.Flags().StringVarP(&compatOutput, "output", "o", "text", "Report format: text or json")
+	checkCompatCmd.MarkFlagRequired("base")
+	checkCompatCmd.MarkFlagRequired("head")
+}
+
+// compatReport is the machine-readable result of check-compat
+type compatReport struct {
+	Compatible bool           `json:"compatible"`
+	Base       string         `json:"base"`
+	Head       string         `json:"head"`
+	Changes    int            `json:"changes"`
+	Breaking   []configChange `json:"breaking"`
+}
+
+func runCheckCompat(cmd *cobra.Command, args []string) error {
+	log := logger.New("check-compat")
+
+	if !slices.Contains(diffOutputFormats, compatOutput) {
+		return log.Errorf("unknown --output '%s'; must be one of: %s", compatOutput, strings.Join(diffOutputFormats, ", "))
+	}
+
+	baseModel, err := internal.LoadConfigWithFormat(compatBase, configFormat)
+	if err != nil {
+		return err
+	}
+	headModel, err := internal.LoadConfigWithFormat(compatHead, configFormat)
+	if err != nil {
+		return err
+	}
+
+	changes := diffModels(baseModel, headModel)
+	report := compatReport{Base: compatBase, Head: compatHead, Changes: len(changes), Breaking: breakingChanges(changes)}
+	report.Compatible = len(report.Breaking) == 0
+
+	if compatOutput == "json" {
+		encoder := json.NewEncoder(os.Stdout)
+		encoder.SetIndent("", "  ")
+		if err := encoder.Encode(report); err != nil {
+			return log.Errorf("failed to write report: %w", err)
+		}
+	} else if report.Compatible {
+		fmt.Printf("%s is compatible with %s (%d non-breaking change(s))\n", compatHead, compatBase, len(changes))
+	} else {
+		fmt.Printf("%s breaks clients of %s:\n", compatHead, compatBase)
+		for _, change := range report.Breaking {
+			if len(change.Details) == 0 {
+				fmt.Printf("  %s '%s' %s\n", change.Kind, change.Name, change.Change)
+			}
+			for _, detail := range change.Details {
+				fmt.Printf("  %s '%s': %s\n", change.Kind, change.Name, detail.Message)
+			}
+		}
+	}
+
+	if !report.Compatible {
+		os.Exit(exitBreakingChanges)
+	}
+	return nil
+}
+
+// breakingChanges returns the breaking changes, keeping only their breaking details
+func breakingChanges(changes []configChange) []configChange {
+	breaking := []configChange{}
+	for _, change := range changes {
+		if !change.Breaking {
+			continue
+		}
+		var details []configDelta
+		for _, detail := range change.Details {
+			if detail.Breaking {
+				details = append(details, detail)
+			}
+		}
+		change.Details = details
+		breaking = append(breaking, change)
+	}
+	return breaking
+}
@@ -0,0 +1,89 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/codegen"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/spf13/cobra"
+)
+
+var (
+	clientLang   string
+	clientOutput string
+	clientName   string
+)
+
+// clientLanguages are the languages generate client supports
+var clientLanguages = []string{"rust"}
+
+// clientCmd represents the client command
+var clientCmd = &cobra.Command{
+	Use:          "client",
+	Short:        "Generate a typed client for the query endpoints",
+	RunE:         generateClient,
+	SilenceUsage: true,
+	Example: `  hyperterse generate client -f config.terse --lang rust
+  hyperterse generate client -f config.terse --lang rust -o crates/my-api-client --name my-api-client`,
+}
+
+func init() {
+	generateCmd.AddCommand(clientCmd)
+
+	clientCmd.Flags().StringVar(&clientLang, "lang", "", "Client language (rust)")
+	clientCmd.Flags().StringVarP(&clientOutput, "output", "o", "client", "Output directory for the client package")
+	clientCmd.Flags().StringVar(&clientName, "name", "", "Package name (default: <config name>-client)")
+	clientCmd.MarkFlagRequired("lang")
+}
+
+func generateClient(cmd *cobra.Command, args []string) error {
+	log := logger.New("generate")
+
+	if !slices.Contains(clientLanguages, clientLang) {
+		log.PrintError("Unsupported language", fmt.Errorf("'%s' is not supported, use one of: %v", clientLang, clientLanguages))
+		os.Exit(1)
+	}
+
+	// Load config
+	model, err := internal.LoadConfigWithFormat(configFile, configFormat)
+	if err != nil {
+		log.PrintError("Error loading config", err)
+		os.Exit(1)
+	}
+
+	// Validate model
+	if err := parser.Validate(model); err != nil {
+		if validationErr, ok := err.(*parser.ValidationErrors); ok {
+			log.PrintValidationErrors(validationErr.Errors)
+		} else {
+			log.PrintError("Validation Error", err)
+		}
+		os.Exit(1)
+	}
+
+	name := clientName
+	if name == "" {
+		name = model.Name + "-client"
+	}
+
+	files := codegen.RustClient(model, name)
+	for path, content := range files {
+		target := filepath.Join(clientOutput, path)
+		if err := os.MkdirAll(filepath.Dir(target), 0755); err != nil {
+			log.PrintError("Failed to create client directory", err)
+			os.Exit(1)
+		}
+		if err := os.WriteFile(target, []byte(content), 0644); err != nil {
+			log.PrintError("Failed to write client file", err)
+			os.Exit(1)
+		}
+	}
+
+	log.PrintSuccess(fmt.Sprintf("Rust client generated: %s (%d queries)", clientOutput, len(model.Queries)))
+	return nil
+}
@@ -0,0 +1,81 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/codegen"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/spf13/cobra"
+)
+
+var (
+	composeOutput        string
+	composeBundle        string
+	composeWithDatabases bool
+)
+
+// composeCmd represents the compose command
+var composeCmd = &cobra.Command{
+	Use:   "compose",
+	Short: "Generate a Docker Compose file running the exported bundle",
+	Long: `Generate a Docker Compose file that builds an image from the exported bundle
+and runs it with a healthcheck on /heartbeat. Every environment variable the
+configuration reads is passed through from the shell or the .env file next to
+the Compose file.
+
+With --with-databases, each postgres, mysql, redis and mongodb adapter whose
+connection is read from environment variables also gets a database service
+with a named volume and a healthcheck. Those variables are set to reach the
+service, and the server waits for the databases to be healthy, so
+'docker compose up' starts a runnable local stack.`,
+	RunE:         generateCompose,
+	SilenceUsage: true,
+	Example: `  hyperterse generate compose -f config.terse
+  hyperterse generate compose -f config.terse --with-databases
+  hyperterse generate compose -f config.terse --bundle build/my-api`,
+}
+
+func init() {
+	generateCmd.AddCommand(composeCmd)
+
+	composeCmd.Flags().StringVarP(&composeOutput, "output", "o", "", "Output path (default: docker-compose.yml next to the config file)")
+	composeCmd.Flags().StringVar(&composeBundle, "bundle", "", "Exported bundle path, relative to the Compose file (default: dist/<config name>)")
+	composeCmd.Flags().BoolVar(&composeWithDatabases, "with-databases", false, "Add a database service for each postgres, mysql, redis and mongodb adapter")
+}
+
+func generateCompose(cmd *cobra.Command, args []string) error {
+	log := logger.New("generate")
+
+	if configFile == "" {
+		return log.Errorf("please provide a file path using -f or --file")
+	}
+	model, err := internal.LoadConfigWithFormat(configFile, configFormat)
+	if err != nil {
+		return err
+	}
+
+	output := composeOutput
+	if output == "" {
+		output = filepath.Join(filepath.Dir(configFile), "docker-compose.yml")
+	}
+	bundle := composeBundle
+	if bundle == "" {
+		bundle = "dist/" + model.Name
+	}
+	content, warnings := codegen.DockerCompose(model, codegen.ComposeOptions{
+		Bundle:        bundle,
+		WithDatabases: composeWithDatabases,
+	})
+	for _, warning := range warnings {
+		log.Warnf("%s", warning)
+	}
+
+	if err := os.WriteFile(output, []byte(content), 0644); err != nil {
+		return log.Errorf("failed to write %s: %w", output, err)
+	}
+	log.PrintSuccess(fmt.Sprintf("Compose file generated: %s", output))
+	return nil
+}
@@ -0,0 +1,53 @@
+package cmd
+
+import (
+	"os"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/schema"
+	"github.com/spf13/cobra"
+)
+
+var (
+	configSchemaOutput string
+)
+
+// configSchemaCmd represents the config-schema command
+var configSchemaCmd = &cobra.Command{
+	Use:   "config-schema",
+	Short: "Generate the JSON Schema for .terse configuration files",
+	Long: `Generate the JSON Schema describing the .terse configuration format.
+Point yaml-language-server (used by the VSCode YAML extension and others) at the
+generated file to get validation and autocompletion while editing configs:
+
+  # yaml-language-server: $schema=./terse.schema.json`,
+	RunE:         generateConfigSchema,
+	SilenceUsage: true,
+	Example: `  hyperterse generate config-schema > terse.schema.json
+  hyperterse generate config-schema -o .vscode/terse.schema.json`,
+}
+
+func init() {
+	generateCmd.AddCommand(configSchemaCmd)
+
+	configSchemaCmd.Flags().StringVarP(&configSchemaOutput, "output", "o", "", "Output path for the schema file (default: stdout)")
+}
+
+func generateConfigSchema(cmd *cobra.Command, args []string) error {
+	log := logger.New("generate")
+
+	schemaJSON := schema.TerseSchema()
+
+	if configSchemaOutput == "" {
+		_, err := os.Stdout.Write(schemaJSON)
+		return err
+	}
+
+	if err := os.WriteFile(configSchemaOutput, schemaJSON, 0644); err != nil {
+		log.PrintError("Failed to write config schema", err)
+		os.Exit(1)
+	}
+
+	log.PrintSuccess("Config schema generated: " + configSchemaOutput)
+	return nil
+}
@@ -8,26 +8,36 @@ import (
 
 	"github.com/fsnotify/fsnotify"
 	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/runtime/dashboard"
 	"github.com/spf13/cobra"
 )
 
 var devCmd = &cobra.Command{
 	Use:          "dev",
 	Short:        "Run the Hyperterse server in development mode",
-	Long:         `Run the Hyperterse server and restart it when the config file changes.`,
+	Long:         `Run the Hyperterse server and restart it when the config file changes.
+A development dashboard for browsing and executing queries is served at /_dev.`,
 	RunE:         runDevServer,
 	SilenceUsage: true,
+	Example: `  hyperterse dev -f config.terse
+  hyperterse dev -f config.terse -p 3000 --verbose`,
 }
 
 func init() {
 	rootCmd.AddCommand(devCmd)
-	devCmd.Flags().StringVarP(&port, "port", "p", "", "Server port (overrides config file and PORT env var)")
-	devCmd.Flags().IntVar(&logLevel, "log-level", 0, "Log level: 1=ERROR, 2=WARN, 3=INFO, 4=DEBUG (overrides config file)")
+	devCmd.Flags().StringVarP(&port, "port", "p", "", "Server port (overrides config file and HYPERTERSE_PORT and PORT env vars)")
+	devCmd.Flags().BoolVar(&portAuto, "port-auto", false, "Use the next free port when the port is in use")
+	devCmd.Flags().IntVar(&logLevel, "log-level", 0, "Log level: 1=ERROR, 2=WARN, 3=INFO, 4=DEBUG (overrides config file and HYPERTERSE_LOG_LEVEL env var)")
+	devCmd.Flags().StringVar(&logFormat, "log-format", "", "Log format: text or json (overrides config file and HYPERTERSE_LOG_FORMAT env var)")
 	devCmd.Flags().BoolVar(&verbose, "verbose", false, "Enable verbose logging (sets log level to DEBUG)")
+	devCmd.Flags().IntVar(&workers, "workers", 0, "Number of OS threads executing Go code simultaneously (overrides config file, default: number of CPUs)")
+	devCmd.Flags().IntVar(&maxThreads, "max-threads", 0, "Maximum number of OS threads, including blocked ones (overrides config file)")
+	devCmd.Flags().BoolVar(&ignoreMissingEnv, "ignore-missing-env", false, "Start even when environment variables referenced by the config are not set")
 }
 
 func runDevServer(cmd *cobra.Command, args []string) error {
 	log := logger.New("dev")
+	lintOnLoad = true
 
 	watcher, err := fsnotify.NewWatcher()
 	if err != nil {
@@ -73,15 +83,21 @@ func runDevServer(cmd *cobra.Command, args []string) error {
 
 	log.Infof("Watching %s for changes", configFile)
 
+	// The status broker outlives individual runtimes so the dashboard can
+	// report reload failures while the previous server keeps running
+	devStatus := dashboard.NewStatusBroker()
+
 	// Start initial runtime - fail immediately if this doesn't work
 	rt, err := PrepareRuntime()
 	if err != nil {
 		return err
 	}
 
+	rt.EnableDevDashboard(devStatus)
 	if err := rt.StartAsync(); err != nil {
 		return err
 	}
+	log.Infof("Dashboard available at http://127.0.0.1:%s%s/_dev", rt.Port(), rt.BasePath())
 
 	for {
 		select {
@@ -89,12 +105,14 @@ func runDevServer(cmd *cobra.Command, args []string) error {
 			return rt.Stop()
 		case <-restart:
 			log.Infof("Config changed, reloading")
+			devStatus.Publish(dashboard.StateReloading, "Config changed, reloading")
 
 			// Try to prepare new runtime first (before stopping old one)
 			// This allows the old server to keep running if config is invalid
 			newRt, err := PrepareRuntime()
 			if err != nil {
 				log.Warnf("Failed to load new config, keeping current server running: %v", err)
+				devStatus.Publish(dashboard.StateError, err.Error())
 				continue
 			}
 
@@ -106,12 +124,14 @@ func runDevServer(cmd *cobra.Command, args []string) error {
 			}
 
 			// Start new runtime
+			newRt.EnableDevDashboard(devStatus)
 			if err := newRt.StartAsync(); err != nil {
 				return log.Errorf("failed to start new server: %w", err)
 			}
 
 			rt = newRt
 			log.Infof("Server reloaded successfully")
+			devStatus.Publish(dashboard.StateOK, "Config reloaded")
 		}
 	}
 }
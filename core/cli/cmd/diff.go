@@ -0,0 +1,352 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"slices"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/spf13/cobra"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+var (
+	diffOutput         string
+	diffFailOnBreaking bool
+)
+
+// diffOutputFormats are the accepted values of diff --output
+var diffOutputFormats = []string{"text", "json"}
+
+// diffRedactedFields hold connection details or secrets, so a change to them
+// is reported without their values
+var diffRedactedFields = []string{"connection_string", "connection", "credentials", "iam_auth", "tls", "admin_token", "url", "secret"}
+
+// diffCmd represents the diff command
+var diffCmd = &cobra.Command{
+	Use:   "diff <old> <new>",
+	Short: "Report the semantic differences between two configuration files",
+	Long: `Parse two configuration files and report the adapters, queries, hooks and server
+settings that were added, removed or changed. Changes that can break API clients,
+such as a removed query, a new required input or a changed input type, are flagged
+as breaking. Connection details and secrets are never printed.`,
+	Args:          cobra.ExactArgs(2),
+	RunE:          runDiff,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse diff old.terse new.terse
+  hyperterse diff <(git show main:config.terse) config.terse --fail-on-breaking
+  hyperterse diff v1.terse v2.terse --output json`,
+}
+
+func init() {
+	rootCmd.AddCommand(diffCmd)
+
+	diffCmd.Flags().StringVarP(&diffOutput, "output", "o", "text", "Output format: text or json")
+	diffCmd.Flags().BoolVar(&diffFailOnBreaking, "fail-on-breaking", false, "Exit with status 1 when any change is breaking")
+}
+
+// configChange is an adapter, query, hook or server setting that differs
+// between two configurations
+type configChange struct {
+	Kind     string        `json:"kind"`
+	Name     string        `json:"name"`
+	Change   string        `json:"change"`
+	Details  []configDelta `json:"details,omitempty"`
+	Breaking bool          `json:"breaking"`
+}
+
+// configDelta is one difference within a changed item
+type configDelta struct {
+	Message  string `json:"message"`
+	Breaking bool   `json:"breaking,omitempty"`
+}
+
+func runDiff(cmd *cobra.Command, args []string) error {
+	log := logger.New("diff")
+
+	if !slices.Contains(diffOutputFormats, diffOutput) {
+		return log.Errorf("unknown --output '%s'; must be one of: %s", diffOutput, strings.Join(diffOutputFormats, ", "))
+	}
+
+	oldModel, err := internal.LoadConfigWithFormat(args[0], configFormat)
+	if err != nil {
+		return err
+	}
+	newModel, err := internal.LoadConfigWithFormat(args[1], configFormat)
+	if err != nil {
+		return err
+	}
+
+	changes := diffModels(oldModel, newModel)
+	if diffOutput == "json" {
+		if changes == nil {
+			changes = []configChange{}
+		}
+		encoder := json.NewEncoder(os.Stdout)
+		encoder.SetIndent("", "  ")
+		if err := encoder.Encode(changes); err != nil {
+			return log.Errorf("failed to write diff: %w", err)
+		}
+	} else {
+		printDiff(changes)
+	}
+
+	if diffFailOnBreaking && slices.ContainsFunc(changes, func(c configChange) bool { return c.Breaking }) {
+		return log.Errorf("breaking changes found")
+	}
+	return nil
+}
+
+// diffModels compares two configurations, listing adapters, then queries, then
+// hooks, then server settings
+func diffModels(oldModel, newModel *hyperterse.Model) []configChange {
+	var changes []configChange
+
+	changes = append(changes, diffNamed("adapter", oldModel.Adapters, newModel.Adapters,
+		func(a *hyperterse.Adapter) string { return a.Name },
+		func(a, b *hyperterse.Adapter) []configDelta { return diffFields(a, b, nil) },
+		func(*hyperterse.Adapter) bool { return false })...)
+
+	changes = append(changes, diffNamed("query", oldModel.Queries, newModel.Queries,
+		func(q *hyperterse.Query) string { return q.Name },
+		diffQuery,
+		func(*hyperterse.Query) bool { return true })...)
+
+	changes = append(changes, diffNamed("hook", oldModel.Hooks, newModel.Hooks,
+		func(h *hyperterse.Hook) string { return h.Name },
+		func(a, b *hyperterse.Hook) []configDelta { return diffFields(a, b, nil) },
+		func(*hyperterse.Hook) bool { return false })...)
+
+	if details := diffFields(serverOrEmpty(oldModel), serverOrEmpty(newModel), nil); len(details) > 0 {
+		changes = append(changes, configChange{Kind: "server", Name: "server", Change: "changed", Details: details})
+	}
+	return changes
+}
+
+func serverOrEmpty(model *hyperterse.Model) *hyperterse.ServerConfig {
+	if model.Server == nil {
+		return &hyperterse.ServerConfig{}
+	}
+	return model.Server
+}
+
+// diffNamed pairs the items of two lists by name and reports those added,
+// removed or changed. Removing an item is breaking when removalBreaks says so;
+// a change is breaking when any of its details is.
+func diffNamed[T proto.Message](kind string, oldItems, newItems []T, name func(T) string, diff func(T, T) []configDelta, removalBreaks func(T) bool) []configChange {
+	var changes []configChange
+	for _, oldItem := range oldItems {
+		index := slices.IndexFunc(newItems, func(item T) bool { return name(item) == name(oldItem) })
+		if index < 0 {
+			changes = append(changes, configChange{Kind: kind, Name: name(oldItem), Change: "removed", Breaking: removalBreaks(oldItem)})
+			continue
+		}
+		if details := diff(oldItem, newItems[index]); len(details) > 0 {
+			change := configChange{Kind: kind, Name: name(oldItem), Change: "changed", Details: details}
+			change.Breaking = slices.ContainsFunc(details, func(d configDelta) bool { return d.Breaking })
+			changes = append(changes, change)
+		}
+	}
+	for _, newItem := range newItems {
+		if !slices.ContainsFunc(oldItems, func(item T) bool { return name(item) == name(newItem) }) {
+			changes = append(changes, configChange{Kind: kind, Name: name(newItem), Change: "added"})
+		}
+	}
+	return changes
+}
+
+// diffQuery compares two versions of a query. Inputs and data columns are
+// compared one by one to flag the changes that break clients.
+func diffQuery(oldQuery, newQuery *hyperterse.Query) []configDelta {
+	details := diffFields(oldQuery, newQuery, []string{"inputs", "data"})
+
+	for _, change := range diffNamed("input", oldQuery.Inputs, newQuery.Inputs,
+		func(i *hyperterse.Input) string { return i.Name }, diffInput,
+		func(*hyperterse.Input) bool { return true }) {
+		switch change.Change {
+		case "removed":
+			details = append(details, configDelta{Message: fmt.Sprintf("input '%s' removed", change.Name), Breaking: true})
+		case "added":
+			input := newQuery.Inputs[slices.IndexFunc(newQuery.Inputs, func(i *hyperterse.Input) bool { return i.Name == change.Name })]
+			required := !input.Optional && input.DefaultValue == ""
+			kind := "optional"
+			if required {
+				kind = "required"
+			}
+			details = append(details, configDelta{Message: fmt.Sprintf("%s input '%s' added", kind, change.Name), Breaking: required})
+		default:
+			for _, detail := range change.Details {
+				details = append(details, configDelta{Message: fmt.Sprintf("input '%s': %s", change.Name, detail.Message), Breaking: detail.Breaking})
+			}
+		}
+	}
+
+	for _, change := range diffNamed("data", oldQuery.Data, newQuery.Data,
+		func(d *hyperterse.Data) string { return d.Name },
+		func(a, b *hyperterse.Data) []configDelta {
+			details := diffFields(a, b, nil)
+			for i := range details {
+				details[i].Breaking = a.Type != b.Type && strings.HasPrefix(details[i].Message, "type ")
+			}
+			return details
+		},
+		func(*hyperterse.Data) bool { return true }) {
+		switch change.Change {
+		case "removed":
+			details = append(details, configDelta{Message: fmt.Sprintf("data column '%s' removed", change.Name), Breaking: true})
+		case "added":
+			details = append(details, configDelta{Message: fmt.Sprintf("data column '%s' added", change.Name)})
+		default:
+			for _, detail := range change.Details {
+				details = append(details, configDelta{Message: fmt.Sprintf("data column '%s': %s", change.Name, detail.Message), Breaking: detail.Breaking})
+			}
+		}
+	}
+	return details
+}
+
+// diffInput compares two versions of an input, flagging changes that reject
+// requests which used to be accepted
+func diffInput(oldInput, newInput *hyperterse.Input) []configDelta {
+	details := diffFields(oldInput, newInput, []string{"optional", "enum"})
+	for i, detail := range details {
+		if strings.HasPrefix(detail.Message, "type ") {
+			details[i].Breaking = true
+		}
+	}
+
+	wasRequired := !oldInput.Optional && oldInput.DefaultValue == ""
+	isRequired := !newInput.Optional && newInput.DefaultValue == ""
+	switch {
+	case isRequired && !wasRequired:
+		details = append(details, configDelta{Message: "now required", Breaking: true})
+	case wasRequired && !isRequired:
+		details = append(details, configDelta{Message: "now optional"})
+	}
+
+	var removed, added []string
+	for _, value := range oldInput.Enum {
+		if !slices.Contains(newInput.Enum, value) {
+			removed = append(removed, value)
+		}
+	}
+	for _, value := range newInput.Enum {
+		if !slices.Contains(oldInput.Enum, value) {
+			added = append(added, value)
+		}
+	}
+	switch {
+	case len(oldInput.Enum) > 0 && len(newInput.Enum) == 0:
+		details = append(details, configDelta{Message: "enum removed"})
+	case len(oldInput.Enum) == 0 && len(newInput.Enum) > 0:
+		details = append(details, configDelta{Message: fmt.Sprintf("values restricted to %s", strings.Join(newInput.Enum, ", ")), Breaking: true})
+	default:
+		if len(removed) > 0 {
+			details = append(details, configDelta{Message: fmt.Sprintf("enum values removed: %s", strings.Join(removed, ", ")), Breaking: true})
+		}
+		if len(added) > 0 {
+			details = append(details, configDelta{Message: fmt.Sprintf("enum values added: %s", strings.Join(added, ", "))})
+		}
+	}
+	return details
+}
+
+// diffFields reports each field that differs between two messages of the same
+// type, other than the skipped ones. Scalar changes show the old and new
+// values; nested messages, lists and redacted fields are only named.
+func diffFields(a, b proto.Message, skip []string) []configDelta {
+	ra, rb := a.ProtoReflect(), b.ProtoReflect()
+	fields := ra.Descriptor().Fields()
+
+	var details []configDelta
+	for i := 0; i < fields.Len(); i++ {
+		field := fields.Get(i)
+		name := string(field.Name())
+		if slices.Contains(skip, name) {
+			continue
+		}
+
+		// Compare the field alone by copying it into otherwise empty messages
+		fa, fb := ra.Type().New(), rb.Type().New()
+		if ra.Has(field) {
+			fa.Set(field, ra.Get(field))
+		}
+		if rb.Has(field) {
+			fb.Set(field, rb.Get(field))
+		}
+		if proto.Equal(fa.Interface(), fb.Interface()) {
+			continue
+		}
+
+		message := name + " changed"
+		if !slices.Contains(diffRedactedFields, name) && !field.IsList() && !field.IsMap() && field.Message() == nil {
+			message = fmt.Sprintf("%s changed from %s to %s", name, formatDiffValue(field, ra.Get(field)), formatDiffValue(field, rb.Get(field)))
+		}
+		details = append(details, configDelta{Message: message})
+	}
+	return details
+}
+
+// formatDiffValue renders a scalar field value, naming enum values by their
+// config spelling, e.g. PRIMITIVE_INT as int
+func formatDiffValue(field protoreflect.FieldDescriptor, value protoreflect.Value) string {
+	if field.Kind() == protoreflect.EnumKind {
+		if enumValue := field.Enum().Values().ByNumber(value.Enum()); enumValue != nil {
+			name := string(enumValue.Name())
+			if _, spelling, found := strings.Cut(name, "_"); found {
+				name = spelling
+			}
+			return strings.ToLower(name)
+		}
+	}
+	if field.Kind() == protoreflect.StringKind {
+		if value.String() == "" {
+			return "(unset)"
+		}
+		return fmt.Sprintf("'%s'", value.String())
+	}
+	return value.String()
+}
+
+func printDiff(changes []configChange) {
+	if len(changes) == 0 {
+		fmt.Println("No differences")
+		return
+	}
+
+	symbols := map[string]string{"added": "+", "removed": "-", "changed": "~"}
+	headings := map[string]string{"adapter": "Adapters", "query": "Queries", "hook": "Hooks", "server": "Server"}
+	breaking := 0
+	kind := ""
+	for _, change := range changes {
+		if change.Kind != kind {
+			if kind != "" {
+				fmt.Println()
+			}
+			kind = change.Kind
+			fmt.Println(headings[kind])
+		}
+		marker := ""
+		if change.Breaking {
+			marker = "  [breaking]"
+			breaking++
+		}
+		fmt.Printf("  %s %s%s\n", symbols[change.Change], change.Name, marker)
+		for _, detail := range change.Details {
+			marker := ""
+			if detail.Breaking {
+				marker = "  [breaking]"
+			}
+			fmt.Printf("      %s%s\n", detail.Message, marker)
+		}
+	}
+
+	fmt.Println()
+	fmt.Printf("%d change(s), %d breaking\n", len(changes), breaking)
+}
@@ -0,0 +1,141 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/spf13/cobra"
+)
+
+var (
+	envExampleOutput string
+	envExampleCheck  bool
+)
+
+// envExampleHints describes the value expected by the fields that commonly
+// hold placeholders, keyed by field name
+var envExampleHints = map[string]string{
+	"connection_string": "connection string",
+	"url":               "URL",
+	"host":              "hostname",
+	"port":              "port number",
+	"user":              "username",
+	"password":          "secret",
+	"database":          "database name",
+	"ssl":               "true or false",
+	"admin_token":       "secret",
+	"secret":            "secret",
+	"region":            "AWS region",
+	"statement":         "substituted into the statement",
+}
+
+// envExampleCmd represents the env-example command
+var envExampleCmd = &cobra.Command{
+	Use:   "env-example",
+	Short: "Generate a .env.example listing the environment variables a config reads",
+	Long: `Generate a .env.example file listing every {{ env.NAME }} placeholder in a
+configuration, with a comment naming the fields that use it and the kind of value
+expected. Running it again keeps the file in sync: values already written for a
+variable are kept, new variables are added and variables the configuration no
+longer reads are removed. Use --check in CI to fail when the file is out of date.`,
+	RunE:         generateEnvExample,
+	SilenceUsage: true,
+	Example: `  hyperterse generate env-example -f config.terse
+  hyperterse generate env-example -f config.terse -o deploy/.env.example
+  hyperterse generate env-example -f config.terse --check`,
+}
+
+func init() {
+	generateCmd.AddCommand(envExampleCmd)
+
+	envExampleCmd.Flags().StringVarP(&envExampleOutput, "output", "o", "", "Output path (default: .env.example next to the config file)")
+	envExampleCmd.Flags().BoolVar(&envExampleCheck, "check", false, "Exit with status 1 if the file is missing or out of date, without writing it")
+}
+
+func generateEnvExample(cmd *cobra.Command, args []string) error {
+	log := logger.New("generate")
+
+	if configFile == "" {
+		return log.Errorf("please provide a file path using -f or --file")
+	}
+	model, err := internal.LoadConfigWithFormat(configFile, configFormat)
+	if err != nil {
+		return err
+	}
+
+	output := envExampleOutput
+	if output == "" {
+		output = filepath.Join(filepath.Dir(configFile), ".env.example")
+	}
+
+	existing, err := os.ReadFile(output)
+	if err != nil && !os.IsNotExist(err) {
+		return log.Errorf("failed to read %s: %w", output, err)
+	}
+	values := envFileValues(string(existing))
+
+	references := parser.EnvReferences(model)
+	var sb strings.Builder
+	fmt.Fprintf(&sb, "# Environment variables read by %s.\n", filepath.Base(configFile))
+	fmt.Fprintf(&sb, "# Generated by 'hyperterse generate env-example'; values are kept when it runs again.\n")
+	added := 0
+	for _, reference := range references {
+		sb.WriteString("\n")
+		fmt.Fprintf(&sb, "# %s\n", envExampleComment(reference))
+		value, ok := values[reference.Name]
+		if !ok {
+			added++
+		}
+		fmt.Fprintf(&sb, "%s=%s\n", reference.Name, value)
+	}
+	// Variables of the existing file that were not kept
+	removed := len(values) - (len(references) - added)
+	content := sb.String()
+
+	if envExampleCheck {
+		if string(existing) != content {
+			return log.Errorf("%s is out of date: run 'hyperterse generate env-example -f %s'", output, configFile)
+		}
+		log.PrintSuccess(fmt.Sprintf("%s is up to date (%d variable(s))", output, len(references)))
+		return nil
+	}
+
+	if err := os.WriteFile(output, []byte(content), 0644); err != nil {
+		return log.Errorf("failed to write %s: %w", output, err)
+	}
+	log.PrintSuccess(fmt.Sprintf("Env example generated: %s (%d variable(s), %d added, %d removed)", output, len(references), added, removed))
+	return nil
+}
+
+// envExampleComment names the fields reading a variable, with the kind of
+// value the first of them expects
+func envExampleComment(reference parser.EnvReference) string {
+	comment := strings.Join(reference.Fields, ", ")
+	field := reference.Fields[0]
+	field = field[strings.LastIndex(field, ".")+1:]
+	if i := strings.Index(field, "["); i != -1 {
+		field = field[:i]
+	}
+	if hint, ok := envExampleHints[field]; ok {
+		comment += " (" + hint + ")"
+	}
+	return comment
+}
+
+// envFileValues returns the variables set by the lines of a .env file
+func envFileValues(content string) map[string]string {
+	values := make(map[string]string)
+	for _, line := range strings.Split(content, "\n") {
+		key, value, ok := strings.Cut(strings.TrimSpace(line), "=")
+		if !ok || strings.HasPrefix(key, "#") {
+			continue
+		}
+		values[strings.TrimSpace(strings.TrimPrefix(key, "export "))] = value
+	}
+	return values
+}
@@ -1,20 +1,50 @@
 package cmd
 
 import (
+	"bytes"
 	"encoding/base64"
+	"maps"
 	"os"
 	"path/filepath"
+	"slices"
+	"strings"
+	"time"
 
 	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/codegen"
 	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
 	"github.com/spf13/cobra"
 )
 
 var (
 	exportOutputDir string
 	exportCleanDir  bool
+	exportLauncher  string
+	exportTarget    string
+	exportSigned    bool
+	exportSignKey   string
 )
 
+// Targets export can write
+const (
+	exportTargetScript = "script"
+	exportTargetHelm   = "helm"
+)
+
+// exportTargets lists every target accepted by --target
+var exportTargets = []string{exportTargetScript, exportTargetHelm}
+
+// Launcher scripts export can write
+const (
+	launcherBash       = "bash"
+	launcherPowerShell = "powershell"
+)
+
+// exportLaunchers lists every launcher accepted by --launcher
+var exportLaunchers = []string{launcherBash, launcherPowerShell}
+
 // exportCmd represents the export command
 var exportCmd = &cobra.Command{
 	Use:           "export",
@@ -22,6 +52,11 @@ var exportCmd = &cobra.Command{
 	RunE:          exportBundle,
 	SilenceUsage:  true,
 	SilenceErrors: true, // Errors are already logged, suppress Cobra's error output
+	Example: `  hyperterse export -f config.terse
+  hyperterse export -f config.terse -o build --clean-dir
+  hyperterse export -f config.terse --launcher powershell
+  hyperterse export -f config.terse --target helm
+  hyperterse export -f config.terse --bundle --sign-key signing.pem`,
 }
 
 func init() {
@@ -30,6 +65,10 @@ func init() {
 	exportCmd.Flags().StringVarP(&configFile, "file", "f", "", "Path to the configuration file (.terse)")
 	exportCmd.Flags().StringVarP(&exportOutputDir, "out", "o", "", "Output directory for the script file (default: dist)")
 	exportCmd.Flags().BoolVar(&exportCleanDir, "clean-dir", false, "Clean output directory before exporting")
+	exportCmd.Flags().StringVar(&exportLauncher, "launcher", "", "Launcher script: bash or powershell (default: detected from the binary)")
+	exportCmd.Flags().StringVar(&exportTarget, "target", exportTargetScript, "What to export: script (launcher script) or helm (Helm chart)")
+	exportCmd.Flags().BoolVar(&exportSigned, "bundle", false, "Export a signed configuration bundle (.htb) for 'hyperterse run --bundle' instead")
+	exportCmd.Flags().StringVar(&exportSignKey, "sign-key", "", "Ed25519 private key (PKCS #8 PEM) signing the bundle, required with --bundle")
 	exportCmd.MarkFlagRequired("file")
 }
 
@@ -38,6 +77,18 @@ func exportBundle(cmd *cobra.Command, args []string) error {
 	if configFile == "" {
 		return log.Errorf("please provide a file path using -f or --file")
 	}
+	if exportLauncher != "" && !slices.Contains(exportLaunchers, exportLauncher) {
+		return log.Errorf("unknown --launcher '%s'; must be one of: %s", exportLauncher, strings.Join(exportLaunchers, ", "))
+	}
+	if !slices.Contains(exportTargets, exportTarget) {
+		return log.Errorf("unknown --target '%s'; must be one of: %s", exportTarget, strings.Join(exportTargets, ", "))
+	}
+	if exportSigned && exportSignKey == "" {
+		return log.Errorf("--bundle requires --sign-key")
+	}
+	if exportSigned && exportTarget != exportTargetScript {
+		return log.Errorf("--bundle cannot be combined with --target %s", exportTarget)
+	}
 
 	// Read config file
 	configContent, err := os.ReadFile(configFile)
@@ -46,7 +97,7 @@ func exportBundle(cmd *cobra.Command, args []string) error {
 	}
 
 	// Load and validate config to get name and export settings
-	model, err := internal.LoadConfig(configFile)
+	model, err := internal.LoadConfigWithFormat(configFile, configFormat)
 	if err != nil {
 		return log.Errorf("error loading config: %w", err)
 	}
@@ -87,8 +138,18 @@ func exportBundle(cmd *cobra.Command, args []string) error {
 		return log.Errorf("error creating output directory: %w", err)
 	}
 
-	// Script filename always uses config name
-	scriptPath := filepath.Join(outputDir, model.Name)
+	// Keep the original extension so the embedded config is parsed with the same format
+	embeddedConfigName := "config" + filepath.Ext(configFile)
+	if filepath.Ext(configFile) == "" {
+		embeddedConfigName = "config.terse"
+	}
+
+	if exportTarget == exportTargetHelm {
+		return exportHelmChart(log, model, outputDir, embeddedConfigName, configContent)
+	}
+	if exportSigned {
+		return exportConfigBundle(log, model, outputDir, embeddedConfigName, configContent)
+	}
 
 	// Find the hyperterse binary
 	binaryPath, err := findBinary(log)
@@ -101,8 +162,24 @@ func exportBundle(cmd *cobra.Command, args []string) error {
 		return log.Errorf("error reading binary: %w", err)
 	}
 
-	// Generate bash script content
-	scriptContent := generateBashScript(configContent, binaryContent)
+	// The launcher must run on the OS the embedded binary was built for
+	launcher := exportLauncher
+	if launcher == "" {
+		launcher = launcherBash
+		if isWindowsBinary(binaryContent) {
+			launcher = launcherPowerShell
+		}
+	}
+
+	// Script filename always uses config name
+	scriptPath := filepath.Join(outputDir, model.Name)
+	var scriptContent string
+	if launcher == launcherPowerShell {
+		scriptPath += ".ps1"
+		scriptContent = generatePowerShellScript(configContent, binaryContent, embeddedConfigName, configFormat)
+	} else {
+		scriptContent = generateBashScript(configContent, binaryContent, embeddedConfigName, configFormat)
+	}
 
 	// Write script to file
 	if err := os.WriteFile(scriptPath, []byte(scriptContent), 0755); err != nil {
@@ -110,16 +187,85 @@ func exportBundle(cmd *cobra.Command, args []string) error {
 	}
 
 	log.Successf("Exported script to ./%s", scriptPath)
-	log.Successf("Run: ./%s", scriptPath)
+	if launcher == launcherPowerShell {
+		log.Successf("Run: powershell -ExecutionPolicy Bypass -File .\\%s", strings.ReplaceAll(scriptPath, "/", `\`))
+	} else {
+		log.Successf("Run: ./%s", scriptPath)
+	}
 
 	return nil
 }
 
+// exportHelmChart writes a Helm chart for the bundle image to
+// {outputDir}/helm/{chart name}, with the configuration mounted from a
+// ConfigMap or Secret
+func exportHelmChart(log *logger.Logger, model *hyperterse.Model, outputDir, configName string, configContent []byte) error {
+	chartDir := filepath.Join(outputDir, "helm", codegen.HelmChartName(model))
+	files := codegen.HelmChart(model, configName, configContent)
+	for _, name := range slices.Sorted(maps.Keys(files)) {
+		path := filepath.Join(chartDir, name)
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			return log.Errorf("error creating directory: %w", err)
+		}
+		if err := os.WriteFile(path, []byte(files[name]), 0644); err != nil {
+			return log.Errorf("error writing %s: %w", path, err)
+		}
+	}
+
+	log.Successf("Exported Helm chart to ./%s", chartDir)
+	log.Successf("Install: helm install %s ./%s --set image.repository=<registry>/%s", codegen.HelmChartName(model), chartDir, model.Name)
+	return nil
+}
+
+// exportConfigBundle writes a signed configuration bundle named after the
+// config and the start of its checksum, so each configuration gets its own file
+func exportConfigBundle(log *logger.Logger, model *hyperterse.Model, outputDir, configName string, configContent []byte) error {
+	if err := parser.Validate(model); err != nil {
+		if validationErr, ok := err.(*parser.ValidationErrors); ok {
+			log.PrintValidationErrors(validationErr.Errors)
+			return log.Errorf("configuration is invalid, bundle not written")
+		}
+		return log.Errorf("%w", err)
+	}
+	key, err := internal.LoadSigningKey(exportSignKey)
+	if err != nil {
+		return log.Errorf("%w", err)
+	}
+
+	format := configFormat
+	if format == "" {
+		format = string(parser.DetectFormat(configFile))
+	}
+	var buf bytes.Buffer
+	manifest, err := internal.WriteBundle(&buf, internal.BundleManifest{
+		Name:              model.Name,
+		Version:           model.Version,
+		ConfigFile:        configName,
+		Format:            format,
+		HyperterseVersion: GetVersion(),
+		CreatedAt:         time.Now().UTC().Truncate(time.Second),
+	}, configContent, key)
+	if err != nil {
+		return log.Errorf("error writing bundle: %w", err)
+	}
+
+	bundlePath := filepath.Join(outputDir, model.Name+"-"+manifest.SHA256[:12]+internal.BundleExtension)
+	if err := os.WriteFile(bundlePath, buf.Bytes(), 0644); err != nil {
+		return log.Errorf("error writing bundle: %w", err)
+	}
+
+	log.Successf("Exported signed bundle to ./%s (sha256 %s)", bundlePath, manifest.SHA256)
+	log.Successf("Run: hyperterse run --bundle %s --verify-key <public key>", bundlePath)
+	return nil
+}
+
 func findBinary(log *logger.Logger) (string, error) {
-	// First, try to find the binary in dist/hyperterse
-	distPath := "dist/hyperterse"
-	if _, err := os.Stat(distPath); err == nil {
-		return distPath, nil
+	// First, try to find the binary in dist/hyperterse, or dist/hyperterse.exe
+	// in Windows builds
+	for _, distPath := range []string{"dist/hyperterse", "dist/hyperterse.exe"} {
+		if _, err := os.Stat(distPath); err == nil {
+			return distPath, nil
+		}
 	}
 
 	// Try to get the current executable path
@@ -174,13 +320,17 @@ func cleanDirectory(log *logger.Logger, dirPath string) error {
 	return nil
 }
 
-func generateBashScript(configContent []byte, binaryContent []byte) string {
+func generateBashScript(configContent []byte, binaryContent []byte, configName string, format string) string {
 	// Base64 encode the config and binary
 	configB64 := base64.StdEncoding.EncodeToString(configContent)
 	binaryB64 := base64.StdEncoding.EncodeToString(binaryContent)
 
 	// Generate bash script that extracts and runs
 	// Base64 strings are safe to embed in double quotes (only contain A-Z, a-z, 0-9, +, /, =)
+	formatFlag := ""
+	if format != "" {
+		formatFlag = " --format " + format
+	}
 	script := `#!/bin/bash
 set -e
 
@@ -194,17 +344,63 @@ trap "rm -rf $TMPDIR" EXIT
 
 # Decode config (try -d first, fallback to -D for older macOS)
 if echo "dGVzdA==" | base64 -d >/dev/null 2>&1; then
-	echo "$CONFIG_B64" | base64 -d > "$TMPDIR/config.terse"
+	echo "$CONFIG_B64" | base64 -d > "$TMPDIR/` + configName + `"
 	echo "$BINARY_B64" | base64 -d > "$TMPDIR/hyperterse"
 else
-	echo "$CONFIG_B64" | base64 -D > "$TMPDIR/config.terse"
+	echo "$CONFIG_B64" | base64 -D > "$TMPDIR/` + configName + `"
 	echo "$BINARY_B64" | base64 -D > "$TMPDIR/hyperterse"
 fi
 
 chmod +x "$TMPDIR/hyperterse"
 
 # Run hyperterse with the embedded config
-"$TMPDIR/hyperterse" run --file "$TMPDIR/config.terse" "$@"
+"$TMPDIR/hyperterse" run --file "$TMPDIR/` + configName + `"` + formatFlag + ` "$@"
+`
+
+	return script
+}
+
+// isWindowsBinary reports whether binary is a Windows executable, which starts
+// with the "MZ" signature of the PE format
+func isWindowsBinary(binary []byte) bool {
+	return len(binary) >= 2 && binary[0] == 'M' && binary[1] == 'Z'
+}
+
+func generatePowerShellScript(configContent []byte, binaryContent []byte, configName string, format string) string {
+	// Base64 encode the config and binary
+	configB64 := base64.StdEncoding.EncodeToString(configContent)
+	binaryB64 := base64.StdEncoding.EncodeToString(binaryContent)
+
+	// Generate a PowerShell script that extracts and runs, the Windows
+	// counterpart of the bash launcher.
+	// Base64 strings are safe to embed in single quotes (no quote characters)
+	formatArgs := ""
+	if format != "" {
+		formatArgs = " --format " + format
+	}
+	script := `$ErrorActionPreference = 'Stop'
+
+# Extract embedded config and binary
+$ConfigB64 = '` + configB64 + `'
+$BinaryB64 = '` + binaryB64 + `'
+
+# Create temporary directory
+$TmpDir = Join-Path ([System.IO.Path]::GetTempPath()) ("hyperterse-" + [System.Guid]::NewGuid())
+New-Item -ItemType Directory -Path $TmpDir | Out-Null
+
+try {
+	$ConfigPath = Join-Path $TmpDir '` + configName + `'
+	$BinaryPath = Join-Path $TmpDir 'hyperterse.exe'
+	[System.IO.File]::WriteAllBytes($ConfigPath, [System.Convert]::FromBase64String($ConfigB64))
+	[System.IO.File]::WriteAllBytes($BinaryPath, [System.Convert]::FromBase64String($BinaryB64))
+
+	# Run hyperterse with the embedded config
+	& $BinaryPath run --file $ConfigPath` + formatArgs + ` @args
+	$ExitCode = $LASTEXITCODE
+} finally {
+	Remove-Item -Recurse -Force $TmpDir -ErrorAction SilentlyContinue
+}
+exit $ExitCode
 `
 
 	return script
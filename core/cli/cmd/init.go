@@ -1,31 +1,84 @@
 package cmd
 
 import (
+	"embed"
 	"fmt"
 	"os"
 	"path/filepath"
+	"slices"
+	"strings"
+	"text/tabwriter"
 
 	"github.com/spf13/cobra"
 )
 
 var (
-	initOutputFile string
+	initOutputFile    string
+	initTemplate      string
+	initListTemplates bool
 )
 
+// initTemplateFiles holds the configuration and .env entries of each init template
+//
+//go:embed templates/*.terse templates/*.env
+var initTemplateFiles embed.FS
+
+// configTemplate is a starting configuration for a common stack
+type configTemplate struct {
+	Name        string
+	Description string
+}
+
+// initTemplates lists the templates available to init --template, in the order they are listed
+var initTemplates = []configTemplate{
+	{Name: "supabase", Description: "Supabase Postgres through the connection pooler"},
+	{Name: "planetscale", Description: "PlanetScale MySQL over TLS"},
+	{Name: "mongo-atlas", Description: "MongoDB Atlas cluster with find and count queries"},
+	{Name: "redis-cache", Description: "Redis key-value cache with get, set and TTL queries"},
+	{Name: "multi-db", Description: "Postgres for data alongside Redis for sessions"},
+}
+
 // initCmd represents the init command
 var initCmd = &cobra.Command{
-	Use:          "init",
-	Short:        "Initialize a new Hyperterse configuration file",
+	Use:   "init",
+	Short: "Initialize a new Hyperterse configuration file",
+	Long: `Create a starter configuration file. With --template, the configuration is
+tailored to a common stack, and the environment variables it reads are added to a
+.env file next to it. Run with --list-templates to see the templates.`,
 	RunE:         runInit,
 	SilenceUsage: true,
+	Example: `  hyperterse init
+  hyperterse init -o api.terse
+  hyperterse init --template supabase
+  hyperterse init --list-templates`,
 }
 
 func init() {
 	rootCmd.AddCommand(initCmd)
 	initCmd.Flags().StringVarP(&initOutputFile, "output", "o", "config.terse", "Output file path for the configuration")
+	initCmd.Flags().StringVarP(&initTemplate, "template", "t", "", "Start from a template for a common stack (see --list-templates)")
+	initCmd.Flags().BoolVar(&initListTemplates, "list-templates", false, "List the available templates and exit")
 }
 
 func runInit(cmd *cobra.Command, args []string) error {
+	if initListTemplates {
+		w := tabwriter.NewWriter(os.Stdout, 0, 0, 2, ' ', 0)
+		for _, template := range initTemplates {
+			fmt.Fprintf(w, "%s\t%s\n", template.Name, template.Description)
+		}
+		return w.Flush()
+	}
+
+	// Generate the config content
+	configContent := generateConfigTemplate()
+	var envContent string
+	if initTemplate != "" {
+		var err error
+		if configContent, envContent, err = loadInitTemplate(initTemplate); err != nil {
+			return err
+		}
+	}
+
 	// Check if file already exists
 	if _, err := os.Stat(initOutputFile); err == nil {
 		return fmt.Errorf("file '%s' already exists. Use a different filename or remove the existing file", initOutputFile)
@@ -39,22 +92,106 @@ func runInit(cmd *cobra.Command, args []string) error {
 		}
 	}
 
-	// Generate the config content
-	configContent := generateConfigTemplate()
-
 	// Write the file
 	if err := os.WriteFile(initOutputFile, []byte(configContent), 0644); err != nil {
 		return fmt.Errorf("failed to write config file: %w", err)
 	}
 
 	fmt.Printf("✓ Created configuration file: %s\n", initOutputFile)
+
+	envFile := filepath.Join(dir, ".env")
+	if envContent != "" {
+		added, err := appendEnvEntries(envFile, envContent)
+		if err != nil {
+			return fmt.Errorf("failed to write .env file: %w", err)
+		}
+		if added > 0 {
+			fmt.Printf("✓ Added %d variable(s) to %s\n", added, envFile)
+		}
+	}
+
 	fmt.Println("\nNext steps:")
-	fmt.Printf("  1. Edit %s to configure your adapters and queries\n", initOutputFile)
+	if envContent != "" {
+		fmt.Printf("  1. Set the connection details in %s\n", envFile)
+	} else {
+		fmt.Printf("  1. Edit %s to configure your adapters and queries\n", initOutputFile)
+	}
 	fmt.Printf("  2. Run: hyperterse -f %s\n", initOutputFile)
 
 	return nil
 }
 
+// loadInitTemplate returns the configuration and .env entries of a template
+func loadInitTemplate(name string) (string, string, error) {
+	if !slices.ContainsFunc(initTemplates, func(t configTemplate) bool { return t.Name == name }) {
+		names := make([]string, len(initTemplates))
+		for i, template := range initTemplates {
+			names[i] = template.Name
+		}
+		return "", "", fmt.Errorf("unknown template '%s'; must be one of: %s", name, strings.Join(names, ", "))
+	}
+
+	config, err := initTemplateFiles.ReadFile("templates/" + name + ".terse")
+	if err != nil {
+		return "", "", err
+	}
+	env, err := initTemplateFiles.ReadFile("templates/" + name + ".env")
+	if err != nil {
+		return "", "", err
+	}
+	return string(config), string(env), nil
+}
+
+// appendEnvEntries adds the entries of a template's .env content to a .env
+// file, creating it if needed. Variables the file already sets are left as
+// they are. It returns the number of variables added.
+func appendEnvEntries(path, content string) (int, error) {
+	existing, err := os.ReadFile(path)
+	if err != nil && !os.IsNotExist(err) {
+		return 0, err
+	}
+
+	set := map[string]bool{}
+	for _, line := range strings.Split(string(existing), "\n") {
+		if key, _, ok := strings.Cut(strings.TrimSpace(line), "="); ok && !strings.HasPrefix(key, "#") {
+			set[strings.TrimSpace(strings.TrimPrefix(key, "export "))] = true
+		}
+	}
+
+	var additions []string
+	added := 0
+	for _, line := range strings.Split(strings.TrimRight(content, "\n"), "\n") {
+		key, _, ok := strings.Cut(line, "=")
+		if ok && set[key] {
+			continue
+		}
+		if ok {
+			added++
+		}
+		additions = append(additions, line)
+	}
+	if added == 0 {
+		return 0, nil
+	}
+
+	text := strings.Join(additions, "\n") + "\n"
+	if len(existing) > 0 {
+		if !strings.HasSuffix(string(existing), "\n") {
+			text = "\n" + text
+		}
+		text = "\n" + text
+	}
+	file, err := os.OpenFile(path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0600)
+	if err != nil {
+		return 0, err
+	}
+	defer file.Close()
+	if _, err := file.WriteString(text); err != nil {
+		return 0, err
+	}
+	return added, nil
+}
+
 func generateConfigTemplate() string {
 	return `name: myconfig
 
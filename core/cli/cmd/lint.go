@@ -0,0 +1,130 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"slices"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/spf13/cobra"
+)
+
+var (
+	lintOutput         string
+	lintFailOnWarnings bool
+	// lintOnLoad logs a summary of lint warnings each time PrepareRuntime loads
+	// the configuration. The dev command sets it.
+	lintOnLoad bool
+)
+
+// lintCmd represents the lint command
+var lintCmd = &cobra.Command{
+	Use:   "lint",
+	Short: "Report likely mistakes in a valid configuration",
+	Long: `Validate a configuration, then report warnings for likely mistakes: unused
+adapters, queries without descriptions, inputs never referenced in the statement,
+SELECT *, list queries without LIMIT or max_rows, and names that differ only in
+case. Warnings do not fail the command unless --fail-on-warnings is set.`,
+	RunE:          runLint,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse lint -f config.terse
+  hyperterse lint -f config.terse --output json
+  hyperterse lint -f config.terse --fail-on-warnings`,
+}
+
+func init() {
+	rootCmd.AddCommand(lintCmd)
+
+	lintCmd.Flags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	lintCmd.Flags().StringVarP(&lintOutput, "output", "o", "text", "Report format: text or json")
+	lintCmd.Flags().BoolVar(&lintFailOnWarnings, "fail-on-warnings", false, "Exit with status 1 when there are warnings")
+}
+
+func runLint(cmd *cobra.Command, args []string) error {
+	log := logger.New("lint")
+
+	if !slices.Contains(diffOutputFormats, lintOutput) {
+		return log.Errorf("unknown --output '%s'; must be one of: %s", lintOutput, strings.Join(diffOutputFormats, ", "))
+	}
+
+	var (
+		model    *hyperterse.Model
+		err      error
+		loadFrom string
+	)
+	if source != "" {
+		if configFile != "" {
+			return log.Errorf("cannot specify both --file and --source flags")
+		}
+		model, err = internal.LoadConfigFromStringWithFormat(source, configFormat)
+		loadFrom = "source"
+	} else {
+		if configFile == "" {
+			return log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
+		}
+		model, err = internal.LoadConfigWithFormat(configFile, configFormat)
+		loadFrom = configFile
+	}
+	if err != nil {
+		return err
+	}
+	if err := parser.Validate(model); err != nil {
+		return log.Errorf("validation failed: %w", err)
+	}
+
+	warnings := parser.Lint(model)
+	if lintOutput == "json" {
+		if warnings == nil {
+			warnings = []parser.LintWarning{}
+		}
+		encoder := json.NewEncoder(os.Stdout)
+		encoder.SetIndent("", "  ")
+		if err := encoder.Encode(warnings); err != nil {
+			return log.Errorf("failed to write report: %w", err)
+		}
+	} else if len(warnings) == 0 {
+		log.Successf("No lint warnings: %s", loadFrom)
+	} else {
+		for _, warning := range warnings {
+			fmt.Println(warning)
+		}
+		fmt.Printf("\n%d warning(s)\n", len(warnings))
+	}
+
+	if lintFailOnWarnings && len(warnings) > 0 {
+		return log.Errorf("%d lint warning(s)", len(warnings))
+	}
+	return nil
+}
+
+// logLintSummary logs the number of lint warnings by rule, pointing at the
+// lint command for details
+func logLintSummary(model *hyperterse.Model) {
+	warnings := parser.Lint(model)
+	if len(warnings) == 0 {
+		return
+	}
+
+	counts := make(map[string]int)
+	var rules []string
+	for _, warning := range warnings {
+		if counts[warning.Rule] == 0 {
+			rules = append(rules, warning.Rule)
+		}
+		counts[warning.Rule]++
+	}
+	slices.Sort(rules)
+	summary := make([]string, 0, len(rules))
+	for _, rule := range rules {
+		summary = append(summary, fmt.Sprintf("%d %s", counts[rule], rule))
+	}
+
+	log := logger.New("lint")
+	log.Warnf("%d lint warning(s): %s. Run 'hyperterse lint -f %s' for details", len(warnings), strings.Join(summary, ", "), configFile)
+}
@@ -21,6 +21,8 @@ var llmsCmd = &cobra.Command{
 	Short:        "Generate llms.txt documentation file",
 	RunE:         generateLLMs,
 	SilenceUsage: true,
+	Example: `  hyperterse generate llms -f config.terse
+  hyperterse generate llms -f config.terse -o docs/llms.txt --base-url https://api.example.com`,
 }
 
 func init() {
@@ -34,7 +36,7 @@ func generateLLMs(cmd *cobra.Command, args []string) error {
 	log := logger.New("generate")
 
 	// Load config
-	model, err := internal.LoadConfig(configFile)
+	model, err := internal.LoadConfigWithFormat(configFile, configFormat)
 	if err != nil {
 		log.PrintError("Error loading config", err)
 		os.Exit(1)
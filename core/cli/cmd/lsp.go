@@ -0,0 +1,41 @@
+package cmd
+
+import (
+	"os"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/lsp"
+	"github.com/spf13/cobra"
+)
+
+// lspCmd represents the lsp command
+var lspCmd = &cobra.Command{
+	Use:   "lsp",
+	Short: "Run the language server for .terse files",
+	Long: `Run a Language Server Protocol server over stdio for Hyperterse configuration files.
+Provides diagnostics from config validation, completion for connectors, primitive
+types and adapter names, hover docs for query inputs, and go-to-definition from
+"use:" to the referenced adapter.`,
+	RunE:          runLanguageServer,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse lsp
+  hyperterse lsp --verbose 2> lsp.log`,
+}
+
+func init() {
+	rootCmd.AddCommand(lspCmd)
+	lspCmd.Flags().BoolVar(&verbose, "verbose", false, "Enable verbose logging to stderr (sets log level to DEBUG)")
+}
+
+func runLanguageServer(cmd *cobra.Command, args []string) error {
+	// stdout carries the protocol stream, so logs must go elsewhere
+	logger.SetOutput(os.Stderr)
+	if verbose {
+		logger.SetLogLevel(logger.LogLevelDebug)
+	} else {
+		logger.SetLogLevel(logger.LogLevelWarn)
+	}
+
+	return lsp.NewServer(os.Stdin, os.Stdout).Run()
+}
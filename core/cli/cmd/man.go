@@ -0,0 +1,200 @@
+package cmd
+
+import (
+	"bytes"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/spf13/cobra"
+	"github.com/spf13/pflag"
+)
+
+var manOutputDir string
+
+// manCmd represents the man command
+var manCmd = &cobra.Command{
+	Use:   "man",
+	Short: "Generate man pages for every command",
+	Long: `Generate a section 1 man page for hyperterse and each of its subcommands,
+built from the same names, descriptions, flags and examples printed by --help.`,
+	Args:         cobra.NoArgs,
+	RunE:         generateManPages,
+	SilenceUsage: true,
+	Example: `  hyperterse generate man -o man
+  man ./man/hyperterse-run.1
+  sudo cp man/*.1 /usr/local/share/man/man1/`,
+}
+
+func init() {
+	generateCmd.AddCommand(manCmd)
+
+	manCmd.Flags().StringVarP(&manOutputDir, "output", "o", "man", "Output directory for the man pages")
+}
+
+func generateManPages(cmd *cobra.Command, args []string) error {
+	log := logger.New("generate")
+
+	if err := os.MkdirAll(manOutputDir, 0755); err != nil {
+		log.PrintError("Failed to create output directory", err)
+		os.Exit(1)
+	}
+
+	count, err := writeManTree(cmd.Root(), manOutputDir, time.Now())
+	if err != nil {
+		log.PrintError("Failed to write man pages", err)
+		os.Exit(1)
+	}
+
+	log.PrintSuccess(fmt.Sprintf("Generated %d man pages in %s", count, manOutputDir))
+	return nil
+}
+
+// writeManTree writes the man page of cmd and of each available subcommand to
+// dir, returning the number of pages written
+func writeManTree(cmd *cobra.Command, dir string, date time.Time) (int, error) {
+	if !cmd.IsAvailableCommand() && cmd.HasParent() {
+		return 0, nil
+	}
+
+	page := manPageName(cmd)
+	if err := os.WriteFile(filepath.Join(dir, page+".1"), renderManPage(cmd, date), 0644); err != nil {
+		return 0, err
+	}
+
+	count := 1
+	for _, sub := range cmd.Commands() {
+		n, err := writeManTree(sub, dir, date)
+		if err != nil {
+			return count, err
+		}
+		count += n
+	}
+	return count, nil
+}
+
+// manPageName names the page of a command after its path, e.g. hyperterse-generate-llms
+func manPageName(cmd *cobra.Command) string {
+	return strings.ReplaceAll(cmd.CommandPath(), " ", "-")
+}
+
+// renderManPage renders the roff source of a command's man page
+func renderManPage(cmd *cobra.Command, date time.Time) []byte {
+	var buf bytes.Buffer
+	page := manPageName(cmd)
+	short := strings.ReplaceAll(cmd.Short, "\n", ": ")
+
+	fmt.Fprintf(&buf, ".TH %q \"1\" %q \"Hyperterse %s\" \"Hyperterse Manual\"\n", strings.ToUpper(page), date.Format("Jan 2006"), GetVersion())
+	fmt.Fprintf(&buf, ".SH NAME\n%s \\- %s\n", page, roffEscape(short))
+
+	buf.WriteString(".SH SYNOPSIS\n")
+	if cmd.Runnable() {
+		fmt.Fprintf(&buf, "\\fB%s\\fP\n", roffEscape(cmd.UseLine()))
+	} else {
+		fmt.Fprintf(&buf, "\\fB%s\\fP <command> [flags]\n", roffEscape(cmd.CommandPath()))
+	}
+
+	buf.WriteString(".SH DESCRIPTION\n")
+	description := cmd.Long
+	if description == "" {
+		description = short
+	}
+	writeRoffText(&buf, description)
+
+	writeManFlags(&buf, "OPTIONS", cmd.NonInheritedFlags())
+	writeManFlags(&buf, "OPTIONS INHERITED FROM PARENT COMMANDS", cmd.InheritedFlags())
+
+	if cmd.Example != "" {
+		buf.WriteString(".SH EXAMPLES\n.PP\n.RS\n.nf\n")
+		for _, line := range strings.Split(cmd.Example, "\n") {
+			buf.WriteString(roffEscape(strings.TrimPrefix(line, "  ")) + "\n")
+		}
+		buf.WriteString(".fi\n.RE\n")
+	}
+
+	var related []string
+	if cmd.HasParent() {
+		related = append(related, manPageName(cmd.Parent()))
+	}
+	for _, sub := range cmd.Commands() {
+		if sub.IsAvailableCommand() {
+			related = append(related, manPageName(sub))
+		}
+	}
+	if len(related) > 0 {
+		buf.WriteString(".SH SEE ALSO\n")
+		for i, name := range related {
+			separator := ","
+			if i == len(related)-1 {
+				separator = ""
+			}
+			fmt.Fprintf(&buf, "\\fB%s\\fP(1)%s\n", name, separator)
+		}
+	}
+	return buf.Bytes()
+}
+
+// writeManFlags writes a section listing the visible flags of a set, if any
+func writeManFlags(buf *bytes.Buffer, section string, flags *pflag.FlagSet) {
+	var entries []*pflag.Flag
+	flags.VisitAll(func(flag *pflag.Flag) {
+		if !flag.Hidden {
+			entries = append(entries, flag)
+		}
+	})
+	if len(entries) == 0 {
+		return
+	}
+
+	fmt.Fprintf(buf, ".SH %s\n", section)
+	for _, flag := range entries {
+		name := "\\fB\\-\\-" + roffEscape(flag.Name) + "\\fP"
+		if flag.Shorthand != "" {
+			name = "\\fB\\-" + flag.Shorthand + "\\fP, " + name
+		}
+		if flag.Value.Type() != "bool" {
+			name += "=" + roffEscape(fmt.Sprintf("%q", flag.DefValue))
+		}
+		fmt.Fprintf(buf, ".TP\n%s\n", name)
+		writeRoffText(buf, flag.Usage)
+	}
+}
+
+// writeRoffText writes paragraphs of plain text, starting a new paragraph at
+// each blank line and keeping indented lines, such as commands, verbatim
+func writeRoffText(buf *bytes.Buffer, text string) {
+	buf.WriteString(".PP\n")
+	verbatim := false
+	for _, line := range strings.Split(strings.TrimSpace(text), "\n") {
+		indented := strings.HasPrefix(line, "  ")
+		switch {
+		case indented && !verbatim:
+			buf.WriteString(".RS\n.nf\n")
+			verbatim = true
+		case !indented && verbatim:
+			buf.WriteString(".fi\n.RE\n")
+			verbatim = false
+		}
+		if strings.TrimSpace(line) == "" {
+			buf.WriteString(".PP\n")
+			continue
+		}
+		buf.WriteString(roffEscape(strings.TrimSpace(line)) + "\n")
+	}
+	if verbatim {
+		buf.WriteString(".fi\n.RE\n")
+	}
+}
+
+// roffEscape escapes backslashes and hyphens, and keeps a line starting with
+// a period or apostrophe from being read as a roff request
+func roffEscape(s string) string {
+	s = strings.NewReplacer(`\`, `\e`, "-", `\-`).Replace(s)
+	if strings.HasPrefix(s, ".") || strings.HasPrefix(s, "'") {
+		s = `\&` + s
+	}
+	return s
+}
@@ -0,0 +1,220 @@
+package cmd
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"text/tabwriter"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/migrations"
+	"github.com/spf13/cobra"
+)
+
+var (
+	migrateAdapter   string
+	migrateUpSteps   int
+	migrateDownSteps int
+)
+
+// migrateCmd represents the migrate command
+var migrateCmd = &cobra.Command{
+	Use:   "migrate",
+	Short: "Apply, revert and list SQL migrations of adapters",
+	Long: `Run the ordered SQL files in the migrations directory of each adapter.
+Applied migrations are recorded in the hyperterse_migrations table of the adapter's database.`,
+}
+
+var migrateUpCmd = &cobra.Command{
+	Use:           "up",
+	Short:         "Apply pending migrations",
+	Args:          cobra.NoArgs,
+	RunE:          runMigrateUp,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse migrate up -f config.terse
+  hyperterse migrate up -f config.terse --adapter main_db --steps 1`,
+}
+
+var migrateDownCmd = &cobra.Command{
+	Use:           "down",
+	Short:         "Revert the most recently applied migrations",
+	Args:          cobra.NoArgs,
+	RunE:          runMigrateDown,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse migrate down -f config.terse --adapter main_db
+  hyperterse migrate down -f config.terse --adapter main_db --steps 2`,
+}
+
+var migrateStatusCmd = &cobra.Command{
+	Use:           "status",
+	Short:         "List migrations and whether they are applied",
+	Args:          cobra.NoArgs,
+	RunE:          runMigrateStatus,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse migrate status -f config.terse`,
+}
+
+func init() {
+	rootCmd.AddCommand(migrateCmd)
+	migrateCmd.AddCommand(migrateUpCmd, migrateDownCmd, migrateStatusCmd)
+
+	migrateCmd.PersistentFlags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	migrateCmd.PersistentFlags().StringVarP(&migrateAdapter, "adapter", "a", "", "Adapter to migrate (default: every adapter with migrations)")
+	migrateUpCmd.Flags().IntVar(&migrateUpSteps, "steps", 0, "Number of migrations to apply (default: all pending)")
+	migrateDownCmd.Flags().IntVar(&migrateDownSteps, "steps", 1, "Number of migrations to revert")
+}
+
+func runMigrateUp(cmd *cobra.Command, args []string) error {
+	log := logger.New("migrate")
+	if migrateUpSteps < 0 {
+		return log.Errorf("--steps must be non-negative")
+	}
+	return forEachMigrationRunner(false, func(adapter *hyperterse.Adapter, runner *migrations.Runner) error {
+		applied, err := runner.Up(commandContext(cmd), migrateUpSteps)
+		for _, migration := range applied {
+			log.Infof("%s: applied %d_%s", adapter.Name, migration.Version, migration.Name)
+		}
+		if err != nil {
+			return log.Errorf("adapter '%s': %w", adapter.Name, err)
+		}
+		if len(applied) == 0 {
+			log.Successf("%s: already up to date", adapter.Name)
+		} else {
+			log.Successf("%s: applied %d migration(s)", adapter.Name, len(applied))
+		}
+		return nil
+	})
+}
+
+func runMigrateDown(cmd *cobra.Command, args []string) error {
+	log := logger.New("migrate")
+	if migrateDownSteps < 1 {
+		return log.Errorf("--steps must be at least 1")
+	}
+	return forEachMigrationRunner(true, func(adapter *hyperterse.Adapter, runner *migrations.Runner) error {
+		reverted, err := runner.Down(commandContext(cmd), migrateDownSteps)
+		for _, migration := range reverted {
+			log.Infof("%s: reverted %d_%s", adapter.Name, migration.Version, migration.Name)
+		}
+		if err != nil {
+			return log.Errorf("adapter '%s': %w", adapter.Name, err)
+		}
+		log.Successf("%s: reverted %d migration(s)", adapter.Name, len(reverted))
+		return nil
+	})
+}
+
+func runMigrateStatus(cmd *cobra.Command, args []string) error {
+	log := logger.New("migrate")
+	return forEachMigrationRunner(false, func(adapter *hyperterse.Adapter, runner *migrations.Runner) error {
+		statuses, err := runner.Status(commandContext(cmd))
+		if err != nil {
+			return log.Errorf("adapter '%s': %w", adapter.Name, err)
+		}
+
+		fmt.Printf("%s (%s)\n", adapter.Name, adapter.Migrations)
+		w := tabwriter.NewWriter(os.Stdout, 0, 0, 2, ' ', 0)
+		for _, status := range statuses {
+			state := "pending"
+			if status.Applied {
+				state = "applied " + status.AppliedAt
+			}
+			fmt.Fprintf(w, "  %d\t%s\t%s\n", status.Version, status.Name, state)
+		}
+		w.Flush()
+		return nil
+	})
+}
+
+// forEachMigrationRunner loads the configuration and calls fn with a runner for
+// each adapter to migrate: the one named by --adapter, or every adapter with a
+// migrations directory. With single, --adapter is needed when several
+// adapters qualify.
+func forEachMigrationRunner(single bool, fn func(*hyperterse.Adapter, *migrations.Runner) error) error {
+	log := logger.New("migrate")
+	loadConfigEnvFiles()
+
+	var (
+		model *hyperterse.Model
+		err   error
+	)
+	if source != "" {
+		if configFile != "" {
+			return log.Errorf("cannot specify both --file and --source flags")
+		}
+		model, err = internal.LoadConfigFromStringWithFormat(source, configFormat)
+	} else {
+		if configFile == "" {
+			return log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
+		}
+		model, err = internal.LoadConfigWithFormat(configFile, configFormat)
+	}
+	if err != nil {
+		return err
+	}
+
+	if err := parser.Validate(model); err != nil {
+		return log.Errorf("validation failed: %w", err)
+	}
+
+	var targets []*hyperterse.Adapter
+	for _, adapter := range model.Adapters {
+		if migrateAdapter != "" && adapter.Name != migrateAdapter {
+			continue
+		}
+		if adapter.Migrations == "" {
+			if migrateAdapter != "" {
+				return log.Errorf("adapter '%s' has no migrations directory", adapter.Name)
+			}
+			continue
+		}
+		targets = append(targets, adapter)
+	}
+	switch {
+	case migrateAdapter != "" && len(targets) == 0:
+		return log.Errorf("adapter '%s' not found", migrateAdapter)
+	case len(targets) == 0:
+		return log.Errorf("no adapter has a migrations directory")
+	case len(targets) > 1 && single:
+		return log.Errorf("%d adapters have migrations, choose one with --adapter", len(targets))
+	}
+
+	for _, adapter := range targets {
+		loaded, err := migrations.Load(adapter.Migrations)
+		if err != nil {
+			return log.Errorf("adapter '%s': %w", adapter.Name, err)
+		}
+
+		conn, err := connectors.NewConnector(adapter)
+		if err != nil {
+			return log.Errorf("failed to connect to adapter '%s': %w", adapter.Name, err)
+		}
+		database, ok := conn.(connectors.SQLDatabase)
+		if !ok {
+			conn.Close()
+			return log.Errorf("adapter '%s' does not support migrations", adapter.Name)
+		}
+		err = fn(adapter, migrations.NewRunner(database.DB(), adapter.Connector, loaded))
+		conn.Close()
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// commandContext returns the command's context, or a background context when
+// the command was run without one
+func commandContext(cmd *cobra.Command) context.Context {
+	if ctx := cmd.Context(); ctx != nil {
+		return ctx
+	}
+	return context.Background()
+}
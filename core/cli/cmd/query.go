@@ -0,0 +1,315 @@
+package cmd
+
+import (
+	"context"
+	"encoding/csv"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"os/signal"
+	"slices"
+	"sort"
+	"strings"
+	"syscall"
+	"text/tabwriter"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	"github.com/spf13/cobra"
+)
+
+var (
+	queryInputs string
+	queryOutput string
+	queryWatch  time.Duration
+)
+
+// queryOutputFormats are the accepted values of --output
+var queryOutputFormats = []string{"table", "json", "csv"}
+
+// queryCmd represents the query command
+var queryCmd = &cobra.Command{
+	Use:   "query <name>",
+	Short: "Execute a query locally and print its results",
+	Long: `Execute a query through a local executor, without starting the HTTP server.
+Only the adapters the query uses are connected. Results are printed to stdout as a
+table, JSON or CSV, and logs are written to stderr. With --watch, the query runs
+again on an interval and the screen is redrawn with the latest results.`,
+	Args:          cobra.ExactArgs(1),
+	RunE:          runQuery,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse query get-user -f config.terse --inputs '{"id": 1}'
+  hyperterse query list-users -f config.terse --output json | jq '.[].email'
+  hyperterse query list-users -f config.terse --output csv > users.csv
+  hyperterse query active-sessions -f config.terse --watch 5s`,
+}
+
+func init() {
+	rootCmd.AddCommand(queryCmd)
+
+	queryCmd.Flags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	queryCmd.Flags().StringVar(&queryInputs, "inputs", "", "Query inputs as a JSON object")
+	queryCmd.Flags().StringVarP(&queryOutput, "output", "o", "table", "Output format: table, json or csv")
+	queryCmd.Flags().DurationVarP(&queryWatch, "watch", "w", 0, "Re-execute the query on this interval and redraw the results (e.g. 5s)")
+	queryCmd.Flags().BoolVar(&verbose, "verbose", false, "Enable verbose logging to stderr (sets log level to DEBUG)")
+}
+
+func runQuery(cmd *cobra.Command, args []string) error {
+	// stdout carries the results, so logs must go elsewhere
+	logger.SetOutput(os.Stderr)
+	if verbose {
+		logger.SetLogLevel(logger.LogLevelDebug)
+	} else {
+		logger.SetLogLevel(logger.LogLevelWarn)
+	}
+
+	log := logger.New("query")
+	queryName := args[0]
+
+	if !slices.Contains(queryOutputFormats, queryOutput) {
+		return log.Errorf("unknown --output '%s'; must be one of: %s", queryOutput, strings.Join(queryOutputFormats, ", "))
+	}
+
+	inputs := make(map[string]any)
+	if queryInputs != "" {
+		if err := json.Unmarshal([]byte(queryInputs), &inputs); err != nil {
+			return log.Errorf("invalid --inputs JSON: %w", err)
+		}
+	}
+
+	if queryWatch < 0 {
+		return log.Errorf("--watch must be a positive interval")
+	}
+
+	exec, query, manager, err := prepareQueryExecutor(queryName)
+	if err != nil {
+		return err
+	}
+	defer manager.CloseAll()
+
+	if queryWatch > 0 {
+		return watchQuery(commandContext(cmd), exec, query, inputs)
+	}
+
+	result, err := exec.Execute(commandContext(cmd), queryName, inputs)
+	if err != nil {
+		return err
+	}
+	return printQueryResult(query, result)
+}
+
+// printQueryResult writes a result to stdout in the --output format
+func printQueryResult(query *hyperterse.Query, result *executor.QueryResult) error {
+	log := logger.New("query")
+
+	var err error
+	switch queryOutput {
+	case "json":
+		err = writeQueryJSON(os.Stdout, result.Rows)
+	case "csv":
+		err = writeQueryCSV(os.Stdout, queryColumns(query, result.Rows), result.Rows)
+	default:
+		if err = writeQueryTable(os.Stdout, queryColumns(query, result.Rows), result.Rows); err == nil {
+			fmt.Printf("(%d row(s))\n", len(result.Rows))
+		}
+	}
+	if err != nil {
+		return log.Errorf("failed to write results: %w", err)
+	}
+	if result.Truncated {
+		log.Warnf("Results truncated at max_rows")
+	}
+	return nil
+}
+
+// watchQuery executes a query every --watch interval until interrupted,
+// clearing the screen and redrawing the results each time. A failed run shows
+// its error in place of the results and does not stop the watch.
+func watchQuery(ctx context.Context, exec *executor.Executor, query *hyperterse.Query, inputs map[string]any) error {
+	// Stop on Ctrl-C between runs so the adapters are closed
+	ctx, stop := signal.NotifyContext(ctx, os.Interrupt, syscall.SIGTERM)
+	defer stop()
+
+	ticker := time.NewTicker(queryWatch)
+	defer ticker.Stop()
+
+	for {
+		start := time.Now()
+		result, err := exec.Execute(ctx, query.Name, inputs)
+		elapsed := time.Since(start)
+		if ctx.Err() != nil {
+			return nil
+		}
+
+		// Move the cursor home and clear the screen
+		fmt.Print("\033[H\033[2J")
+		fmt.Printf("Every %s: %s    %s\n\n", queryWatch, query.Name, start.Format(time.TimeOnly))
+		if err != nil {
+			fmt.Printf("error: %v\n", err)
+		} else if err := printQueryResult(query, result); err != nil {
+			return err
+		} else {
+			fmt.Printf("\nTook %s\n", elapsed.Round(time.Microsecond))
+		}
+
+		select {
+		case <-ctx.Done():
+			return nil
+		case <-ticker.C:
+		}
+	}
+}
+
+// loadValidatedModel loads the configuration given by --file or --source and validates it
+func loadValidatedModel(log *logger.Logger) (*hyperterse.Model, error) {
+	loadConfigEnvFiles()
+
+	var (
+		model *hyperterse.Model
+		err   error
+	)
+	if source != "" {
+		if configFile != "" {
+			return nil, log.Errorf("cannot specify both --file and --source flags")
+		}
+		model, err = internal.LoadConfigFromStringWithFormat(source, configFormat)
+	} else {
+		if configFile == "" {
+			return nil, log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
+		}
+		model, err = internal.LoadConfigWithFormat(configFile, configFormat)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	if err := parser.Validate(model); err != nil {
+		return nil, log.Errorf("validation failed: %w", err)
+	}
+	return model, nil
+}
+
+// prepareQueryExecutor loads and validates the configuration, then builds an
+// executor with only the adapters used by the query connected
+func prepareQueryExecutor(queryName string) (*executor.Executor, *hyperterse.Query, *connectors.ConnectorManager, error) {
+	log := logger.New("query")
+
+	model, err := loadValidatedModel(log)
+	if err != nil {
+		return nil, nil, nil, err
+	}
+
+	var query *hyperterse.Query
+	for _, q := range model.Queries {
+		if q.Name == queryName {
+			query = q
+			break
+		}
+	}
+	if query == nil {
+		return nil, nil, nil, log.Errorf("query '%s' not found", queryName)
+	}
+
+	var adapters []*hyperterse.Adapter
+	for _, adapter := range model.Adapters {
+		if slices.Contains(query.Use, adapter.Name) {
+			adapters = append(adapters, adapter)
+		}
+	}
+
+	manager := connectors.NewConnectorManager()
+	if err := manager.InitializeAll(adapters); err != nil {
+		return nil, nil, nil, err
+	}
+	return executor.NewExecutor(model, manager), query, manager, nil
+}
+
+// queryColumns returns the columns to print: those declared in the query's
+// data, in order, followed by any other result columns sorted by name
+func queryColumns(query *hyperterse.Query, rows []map[string]any) []string {
+	var columns []string
+	for _, data := range query.Data {
+		columns = append(columns, data.Name)
+	}
+
+	var extra []string
+	for _, row := range rows {
+		for column := range row {
+			if !slices.Contains(columns, column) && !slices.Contains(extra, column) {
+				extra = append(extra, column)
+			}
+		}
+	}
+	sort.Strings(extra)
+	return append(columns, extra...)
+}
+
+// formatQueryValue renders a result value as text: strings as is, null as an
+// empty string, and everything else as JSON
+func formatQueryValue(value any) string {
+	switch v := value.(type) {
+	case nil:
+		return ""
+	case string:
+		return v
+	default:
+		encoded, err := json.Marshal(v)
+		if err != nil {
+			return fmt.Sprintf("%v", v)
+		}
+		return string(encoded)
+	}
+}
+
+func writeQueryJSON(w io.Writer, rows []map[string]any) error {
+	if rows == nil {
+		rows = []map[string]any{}
+	}
+	encoder := json.NewEncoder(w)
+	encoder.SetIndent("", "  ")
+	return encoder.Encode(rows)
+}
+
+func writeQueryCSV(w io.Writer, columns []string, rows []map[string]any) error {
+	writer := csv.NewWriter(w)
+	if err := writer.Write(columns); err != nil {
+		return err
+	}
+	record := make([]string, len(columns))
+	for _, row := range rows {
+		for i, column := range columns {
+			record[i] = formatQueryValue(row[column])
+		}
+		if err := writer.Write(record); err != nil {
+			return err
+		}
+	}
+	writer.Flush()
+	return writer.Error()
+}
+
+func writeQueryTable(w io.Writer, columns []string, rows []map[string]any) error {
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	fmt.Fprintln(tw, strings.Join(columns, "\t"))
+	for _, row := range rows {
+		values := make([]string, len(columns))
+		for i, column := range columns {
+			value := row[column]
+			if value == nil {
+				values[i] = "NULL"
+			} else {
+				// Tabs and newlines would break the column layout
+				values[i] = strings.NewReplacer("\t", " ", "\n", " ").Replace(formatQueryValue(value))
+			}
+		}
+		fmt.Fprintln(tw, strings.Join(values, "\t"))
+	}
+	return tw.Flush()
+}
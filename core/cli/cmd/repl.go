@@ -0,0 +1,357 @@
+package cmd
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"net/url"
+	"os"
+	"slices"
+	"strings"
+	"text/tabwriter"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/types"
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+)
+
+// replPrompt is shown while waiting for a query name or shell command
+const replPrompt = "hyperterse> "
+
+// replCommands are the shell commands, completed alongside query names
+var replCommands = []string{".help", ".queries", ".describe", ".output", ".exit"}
+
+// replCmd represents the repl command
+var replCmd = &cobra.Command{
+	Use:   "repl",
+	Short: "Run queries from an interactive shell",
+	Long: `Start an interactive shell for running the queries of a configuration.
+Type a query name to be prompted for each of its inputs, or follow the name with
+a JSON object of inputs. Tab completes query names and shell commands, and the
+up and down arrows recall earlier lines. Type .help for the shell commands.`,
+	Args:          cobra.NoArgs,
+	RunE:          runRepl,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse repl -f config.terse
+  hyperterse repl -f config.terse --output json`,
+}
+
+func init() {
+	rootCmd.AddCommand(replCmd)
+
+	replCmd.Flags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	replCmd.Flags().StringVarP(&queryOutput, "output", "o", "table", "Initial output format: table, json or csv")
+	replCmd.Flags().BoolVar(&verbose, "verbose", false, "Enable verbose logging (sets log level to DEBUG)")
+}
+
+// replSession is the state of an interactive shell
+type replSession struct {
+	term   *term.Terminal
+	model  *hyperterse.Model
+	exec   *executor.Executor
+	output string
+}
+
+func runRepl(cmd *cobra.Command, args []string) error {
+	log := logger.New("repl")
+
+	if !slices.Contains(queryOutputFormats, queryOutput) {
+		return log.Errorf("unknown --output '%s'; must be one of: %s", queryOutput, strings.Join(queryOutputFormats, ", "))
+	}
+	fd := int(os.Stdin.Fd())
+	if !term.IsTerminal(fd) {
+		return log.Errorf("repl needs an interactive terminal; use hyperterse query to run queries from scripts")
+	}
+
+	model, err := loadValidatedModel(log)
+	if err != nil {
+		return err
+	}
+	manager := connectors.NewConnectorManager()
+	if err := manager.InitializeAll(model.Adapters); err != nil {
+		return err
+	}
+	defer manager.CloseAll()
+
+	state, err := term.MakeRaw(fd)
+	if err != nil {
+		return log.Errorf("failed to enter raw terminal mode: %w", err)
+	}
+	defer term.Restore(fd, state)
+
+	terminal := term.NewTerminal(struct {
+		io.Reader
+		io.Writer
+	}{os.Stdin, os.Stdout}, replPrompt)
+	session := &replSession{
+		term:   terminal,
+		model:  model,
+		exec:   executor.NewExecutor(model, manager),
+		output: queryOutput,
+	}
+	if width, height, err := term.GetSize(fd); err == nil {
+		session.term.SetSize(width, height)
+	}
+	session.term.AutoCompleteCallback = session.complete
+
+	// The terminal translates newlines while it is in raw mode
+	logger.SetOutput(session.term)
+	if verbose {
+		logger.SetLogLevel(logger.LogLevelDebug)
+	} else {
+		logger.SetLogLevel(logger.LogLevelWarn)
+	}
+
+	fmt.Fprintf(session.term, "Connected to %d adapter(s) with %d queries. Type .help for commands.\n", len(model.Adapters), len(model.Queries))
+	return session.loop(commandContext(cmd))
+}
+
+// loop reads and runs lines until .exit, Ctrl-C or Ctrl-D
+func (s *replSession) loop(ctx context.Context) error {
+	for {
+		line, err := s.term.ReadLine()
+		if err != nil {
+			if errors.Is(err, io.EOF) {
+				return nil
+			}
+			return err
+		}
+		line = strings.TrimSpace(line)
+		switch {
+		case line == "":
+			continue
+		case strings.HasPrefix(line, "."):
+			if s.command(line) {
+				return nil
+			}
+		default:
+			s.run(ctx, line)
+		}
+	}
+}
+
+// command runs a shell command, returning true when the shell should exit
+func (s *replSession) command(line string) bool {
+	name, arg, _ := strings.Cut(line, " ")
+	arg = strings.TrimSpace(arg)
+	switch name {
+	case ".exit", ".quit":
+		return true
+	case ".help":
+		fmt.Fprint(s.term, `<query>              run a query, prompting for each input
+<query> {"id": 1}    run a query with a JSON object of inputs
+.queries             list the queries
+.describe <query>    show a query's description and inputs
+.output [format]     show or set the output format: table, json or csv
+.exit                leave the shell (also Ctrl-D)
+`)
+	case ".queries":
+		w := tabwriter.NewWriter(s.term, 0, 0, 2, ' ', 0)
+		for _, query := range s.model.Queries {
+			fmt.Fprintf(w, "%s\t%s\n", query.Name, query.Description)
+		}
+		w.Flush()
+	case ".describe":
+		if arg == "" {
+			fmt.Fprintln(s.term, "usage: .describe <query>")
+			return false
+		}
+		query := s.query(arg)
+		if query == nil {
+			return false
+		}
+		fmt.Fprintf(s.term, "%s\n", query.Description)
+		w := tabwriter.NewWriter(s.term, 0, 0, 2, ' ', 0)
+		for _, input := range query.Inputs {
+			fmt.Fprintf(w, "  %s\t%s\t%s\n", input.Name, replInputHint(input), input.Description)
+		}
+		w.Flush()
+	case ".output":
+		if arg == "" {
+			fmt.Fprintf(s.term, "%s\n", s.output)
+		} else if slices.Contains(queryOutputFormats, arg) {
+			s.output = arg
+		} else {
+			fmt.Fprintf(s.term, "unknown output format '%s'; must be one of: %s\n", arg, strings.Join(queryOutputFormats, ", "))
+		}
+	default:
+		fmt.Fprintf(s.term, "unknown command '%s'; type .help for commands\n", name)
+	}
+	return false
+}
+
+// query returns the query with the given name, printing an error when there is none
+func (s *replSession) query(name string) *hyperterse.Query {
+	for _, query := range s.model.Queries {
+		if query.Name == name {
+			return query
+		}
+	}
+	if suggestions := replComplete(s.queryNames(), name); len(suggestions) > 0 {
+		fmt.Fprintf(s.term, "query '%s' not found; did you mean %s?\n", name, strings.Join(suggestions, ", "))
+	} else {
+		fmt.Fprintf(s.term, "query '%s' not found; type .queries to list them\n", name)
+	}
+	return nil
+}
+
+// run executes a query line and prints its results with timing
+func (s *replSession) run(ctx context.Context, line string) {
+	name, rawInputs, _ := strings.Cut(line, " ")
+	query := s.query(name)
+	if query == nil {
+		return
+	}
+
+	var (
+		inputs map[string]any
+		err    error
+	)
+	if rawInputs = strings.TrimSpace(rawInputs); rawInputs != "" {
+		inputs, err = executorutils.DecodeJSONInputs(strings.NewReader(rawInputs))
+	} else {
+		inputs, err = s.promptInputs(query)
+	}
+	if errors.Is(err, io.EOF) {
+		fmt.Fprintln(s.term, "cancelled")
+		return
+	}
+	if err != nil {
+		fmt.Fprintf(s.term, "error: %v\n", err)
+		return
+	}
+
+	start := time.Now()
+	result, err := s.exec.Execute(ctx, query.Name, inputs)
+	elapsed := time.Since(start)
+	if err != nil {
+		fmt.Fprintf(s.term, "error: %v\n", err)
+		return
+	}
+
+	switch s.output {
+	case "json":
+		err = writeQueryJSON(s.term, result.Rows)
+	case "csv":
+		err = writeQueryCSV(s.term, queryColumns(query, result.Rows), result.Rows)
+	default:
+		err = writeQueryTable(s.term, queryColumns(query, result.Rows), result.Rows)
+	}
+	if err != nil {
+		fmt.Fprintf(s.term, "error: %v\n", err)
+		return
+	}
+
+	summary := fmt.Sprintf("(%d row(s) in %s", len(result.Rows), elapsed.Round(time.Microsecond))
+	if result.CacheHit {
+		summary += ", cached"
+	}
+	if result.Truncated {
+		summary += ", truncated at max_rows"
+	}
+	fmt.Fprintln(s.term, summary+")")
+}
+
+// promptInputs asks for the value of each input of a query. Values are parsed
+// from their text as they would be from a query string; an empty answer leaves
+// an optional input unset. Ctrl-C or Ctrl-D cancels with io.EOF.
+func (s *replSession) promptInputs(query *hyperterse.Query) (map[string]any, error) {
+	defer s.term.SetPrompt(replPrompt)
+
+	params := url.Values{}
+	for _, input := range query.Inputs {
+		s.term.SetPrompt(fmt.Sprintf("  %s (%s): ", input.Name, replInputHint(input)))
+		value, err := s.term.ReadLine()
+		if err != nil {
+			return nil, err
+		}
+		if value == "" && (input.Optional || input.DefaultValue != "") {
+			continue
+		}
+		params.Set(executorutils.InputParamPrefix+input.Name, value)
+	}
+	return executorutils.InputsFromQueryParams(query, params)
+}
+
+// replInputHint describes an input's type, allowed values and default for prompts
+func replInputHint(input *hyperterse.Input) string {
+	hint := types.PrimitiveEnumToString(input.Type)
+	if len(input.Enum) > 0 {
+		hint += " " + strings.Join(input.Enum, "|")
+	}
+	switch {
+	case input.DefaultValue != "":
+		hint += ", default " + input.DefaultValue
+	case input.Optional:
+		hint += ", optional"
+	}
+	return hint
+}
+
+func (s *replSession) queryNames() []string {
+	names := make([]string, len(s.model.Queries))
+	for i, query := range s.model.Queries {
+		names[i] = query.Name
+	}
+	return names
+}
+
+// complete is the terminal's tab completion. The first word completes to a
+// query name or shell command, and the argument of .describe to a query name.
+// When several candidates remain, their common prefix is filled in and the
+// candidates are listed.
+func (s *replSession) complete(line string, pos int, key rune) (string, int, bool) {
+	if key != '\t' {
+		return "", 0, false
+	}
+
+	prefix := line[:pos]
+	var candidates []string
+	base := ""
+	if command, arg, found := strings.Cut(prefix, " "); !found {
+		candidates = replComplete(append(s.queryNames(), replCommands...), prefix)
+	} else if command == ".describe" && !strings.Contains(strings.TrimLeft(arg, " "), " ") {
+		base = prefix[:len(prefix)-len(strings.TrimLeft(arg, " "))]
+		prefix = strings.TrimLeft(arg, " ")
+		candidates = replComplete(s.queryNames(), prefix)
+	}
+
+	switch len(candidates) {
+	case 0:
+		return "", 0, false
+	case 1:
+		completed := base + candidates[0] + " "
+		return completed + line[pos:], len(completed), true
+	}
+	common := candidates[0]
+	for _, candidate := range candidates[1:] {
+		for !strings.HasPrefix(candidate, common) {
+			common = common[:len(common)-1]
+		}
+	}
+	if len(common) > len(prefix) {
+		completed := base + common
+		return completed + line[pos:], len(completed), true
+	}
+	fmt.Fprintln(s.term, strings.Join(candidates, "  "))
+	return "", 0, false
+}
+
+// replComplete returns the names starting with prefix, in order
+func replComplete(names []string, prefix string) []string {
+	var matches []string
+	for _, name := range names {
+		if strings.HasPrefix(name, prefix) {
+			matches = append(matches, name)
+		}
+	}
+	return matches
+}
@@ -5,7 +5,9 @@ import (
 	"os"
 	"path/filepath"
 	"runtime/debug"
+	"time"
 
+	"github.com/hyperterse/hyperterse/core/cli/internal"
 	"github.com/joho/godotenv"
 	"github.com/spf13/cobra"
 )
@@ -24,13 +26,33 @@ func GetVersion() string {
 }
 
 var (
-	configFile string
-	source     string
-	port       string
-	logLevel   int
-	verbose    bool
-	logTags    string
-	logFile    bool
+	configFile   string
+	configFormat string
+	source       string
+	port         string
+	portAuto     bool
+	logLevel     int
+	logFormat    string
+	verbose      bool
+	logTags      string
+	logFile      bool
+	workers      int
+	maxThreads   int
+
+	// ignoreMissingEnv starts the server even when {{ env.NAME }} placeholders
+	// reference unset variables
+	ignoreMissingEnv bool
+
+	// bundleFile is a signed configuration bundle verified with verifyKey
+	// before it is loaded, as an alternative to --file
+	bundleFile string
+	verifyKey  string
+
+	// configRefresh is how often an http(s):// or s3:// --file is fetched
+	// again, reloading the server when it changed (0 disables). configCache
+	// holds the last good copy of a remote --file.
+	configRefresh time.Duration
+	configCache   string
 )
 
 // rootCmd represents the base command when called without any subcommands
@@ -39,6 +61,9 @@ var rootCmd = &cobra.Command{
 	Short:         "Hyperterse\nConnect your data to your AI agents",
 	SilenceUsage:  true,
 	SilenceErrors: true, // Errors are already logged, suppress Cobra's error output
+	Example: `  hyperterse -f config.terse
+  hyperterse run -f config.terse -p 3000
+  hyperterse generate man -o man`,
 }
 
 // completionCmd is a hidden command used by install.sh to generate shell completions
@@ -76,13 +101,24 @@ func init() {
 	// Persistent flags are optional - not required for help, version, upgrade, or init commands
 	rootCmd.PersistentFlags().StringVarP(&configFile, "file", "f", "", "Path to the configuration file (.terse)")
 	rootCmd.PersistentFlags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	rootCmd.PersistentFlags().StringVar(&configFormat, "format", "", "Configuration format: yaml, json, toml, or dsl (default: detected from file extension, yaml for --source)")
 
 	// Add flags that run command uses (for backward compatibility when using root command)
-	rootCmd.Flags().StringVarP(&port, "port", "p", "", "Server port (overrides config file and PORT env var)")
-	rootCmd.Flags().IntVar(&logLevel, "log-level", 0, "Log level: 1=ERROR, 2=WARN, 3=INFO, 4=DEBUG (overrides config file)")
+	rootCmd.Flags().StringVarP(&port, "port", "p", "", "Server port (overrides config file and HYPERTERSE_PORT and PORT env vars)")
+	rootCmd.Flags().BoolVar(&portAuto, "port-auto", false, "Use the next free port when the port is in use")
+	rootCmd.Flags().IntVar(&logLevel, "log-level", 0, "Log level: 1=ERROR, 2=WARN, 3=INFO, 4=DEBUG (overrides config file and HYPERTERSE_LOG_LEVEL env var)")
+	rootCmd.Flags().StringVar(&logFormat, "log-format", "", "Log format: text or json (overrides config file and HYPERTERSE_LOG_FORMAT env var)")
 	rootCmd.Flags().BoolVar(&verbose, "verbose", false, "Enable verbose logging (sets log level to DEBUG)")
 	rootCmd.Flags().StringVar(&logTags, "log-tags", "", "Filter logs by tags (comma-separated, use -tag to exclude). Overrides HYPERTERSE_LOG_TAGS env var")
-	rootCmd.Flags().BoolVar(&logFile, "log-file", false, "Stream logs to file in /tmp/.hyperterse/logs/")
+	rootCmd.Flags().BoolVar(&logFile, "log-file", false, "Stream logs to file in /tmp/.hyperterse/logs/ (the temp directory on Windows)")
+	rootCmd.Flags().IntVar(&workers, "workers", 0, "Number of OS threads executing Go code simultaneously (overrides config file, default: number of CPUs)")
+	rootCmd.Flags().IntVar(&maxThreads, "max-threads", 0, "Maximum number of OS threads, including blocked ones (overrides config file)")
+	rootCmd.Flags().BoolVar(&updateCheck, "update-check", false, "Check daily for a newer release and log a notice when one exists")
+	rootCmd.Flags().BoolVar(&ignoreMissingEnv, "ignore-missing-env", false, "Start even when environment variables referenced by the config are not set")
+	rootCmd.Flags().StringVar(&bundleFile, "bundle", "", "Signed configuration bundle (.htb) to serve (alternative to --file)")
+	rootCmd.Flags().StringVar(&verifyKey, "verify-key", "", "Ed25519 public key (PKIX PEM) the bundle signature must match, required with --bundle")
+	rootCmd.Flags().DurationVar(&configRefresh, "config-refresh", 0, "Fetch a remote --file again at this interval and reload when it changed, e.g. 1m")
+	rootCmd.Flags().StringVar(&configCache, "config-cache", "", "Directory the last good copy of a remote --file is saved to (default: configs in the hyperterse temp directory)")
 
 	// Add version flag
 	rootCmd.Flags().BoolP("version", "v", false, "Print version information")
@@ -100,7 +136,7 @@ func init() {
 		}
 
 		// Only require config when actually running the server
-		if configFile == "" && source == "" {
+		if configFile == "" && source == "" && bundleFile == "" {
 			// If no subcommand and no flags, show help instead of error
 			return cmd.Help()
 		}
@@ -127,6 +163,16 @@ func printVersion() {
 	fmt.Println(v)
 }
 
+// loadConfigEnvFiles loads the .env files placed next to a local --file, so
+// every command that reads the configuration resolves the same env values
+func loadConfigEnvFiles() {
+	if configFile != "" && !internal.IsRemoteConfig(configFile) {
+		if configDir := filepath.Dir(configFile); configDir != "" && configDir != "." {
+			LoadEnvFiles(configDir)
+		}
+	}
+}
+
 // LoadEnvFiles attempts to load .env files from multiple locations.
 // It tries each location in order and stops at the first successful load.
 // This ensures .env files work in development, when built, and when deployed.
@@ -1,9 +1,11 @@
 package cmd
 
 import (
+	"context"
 	"fmt"
 	"os"
-	"path/filepath"
+	goruntime "runtime"
+	"runtime/debug"
 	"strings"
 
 	"github.com/hyperterse/hyperterse/core/cli/internal"
@@ -22,18 +24,39 @@ var runCmd = &cobra.Command{
 	RunE:          runServer,
 	SilenceUsage:  true,
 	SilenceErrors: true, // Errors are already logged, suppress Cobra's error output
+	Example: `  hyperterse run -f config.terse
+  # Start from port 3000, moving on if it is taken
+  hyperterse run -f config.terse -p 3000 --port-auto
+  # Debug logging for the executor only
+  hyperterse run -f config.terse --log-level 4 --log-tags executor
+  # Serve a signed bundle written by 'hyperterse export --bundle'
+  hyperterse run --bundle dist/my-api-3f2a9c1b7e4d.htb --verify-key signing.pub
+  # Load the configuration from S3, checking for changes every minute
+  hyperterse run -f s3://my-configs/prod/api.terse --config-refresh 1m
+  # Load and watch the configuration in Consul
+  hyperterse run -f consul://127.0.0.1:8500/hyperterse/prod/api.terse`,
 }
 
 func init() {
 	rootCmd.AddCommand(runCmd)
 
 	// Use the same flags as root command (they're defined in root.go)
-	runCmd.Flags().StringVarP(&port, "port", "p", "", "Server port (overrides config file and PORT env var)")
-	runCmd.Flags().IntVar(&logLevel, "log-level", 0, "Log level: 1=ERROR, 2=WARN, 3=INFO, 4=DEBUG (overrides config file)")
+	runCmd.Flags().StringVarP(&port, "port", "p", "", "Server port (overrides config file and HYPERTERSE_PORT and PORT env vars)")
+	runCmd.Flags().BoolVar(&portAuto, "port-auto", false, "Use the next free port when the port is in use")
+	runCmd.Flags().IntVar(&logLevel, "log-level", 0, "Log level: 1=ERROR, 2=WARN, 3=INFO, 4=DEBUG (overrides config file and HYPERTERSE_LOG_LEVEL env var)")
+	runCmd.Flags().StringVar(&logFormat, "log-format", "", "Log format: text or json (overrides config file and HYPERTERSE_LOG_FORMAT env var)")
 	runCmd.Flags().BoolVarP(&verbose, "verbose", "", false, "Enable verbose logging (sets log level to DEBUG)")
 	runCmd.Flags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	runCmd.Flags().StringVar(&bundleFile, "bundle", "", "Signed configuration bundle (.htb) to serve (alternative to --file)")
+	runCmd.Flags().StringVar(&verifyKey, "verify-key", "", "Ed25519 public key (PKIX PEM) the bundle signature must match, required with --bundle")
+	runCmd.Flags().DurationVar(&configRefresh, "config-refresh", 0, "Fetch a remote --file again at this interval and reload when it changed, e.g. 1m")
+	runCmd.Flags().StringVar(&configCache, "config-cache", "", "Directory the last good copy of a remote --file is saved to (default: configs in the hyperterse temp directory)")
 	runCmd.Flags().StringVar(&logTags, "log-tags", "", "Filter logs by tags (comma-separated, use -tag to exclude). Overrides HYPERTERSE_LOG_TAGS env var")
-	runCmd.Flags().BoolVar(&logFile, "log-file", false, "Stream logs to file in /tmp/.hyperterse/logs/")
+	runCmd.Flags().BoolVar(&logFile, "log-file", false, "Stream logs to file in /tmp/.hyperterse/logs/ (the temp directory on Windows)")
+	runCmd.Flags().IntVar(&workers, "workers", 0, "Number of OS threads executing Go code simultaneously (overrides config file, default: number of CPUs)")
+	runCmd.Flags().IntVar(&maxThreads, "max-threads", 0, "Maximum number of OS threads, including blocked ones (overrides config file)")
+	runCmd.Flags().BoolVar(&updateCheck, "update-check", false, "Check daily for a newer release and log a notice when one exists")
+	runCmd.Flags().BoolVar(&ignoreMissingEnv, "ignore-missing-env", false, "Start even when environment variables referenced by the config are not set")
 }
 
 func runServer(cmd *cobra.Command, args []string) error {
@@ -41,23 +64,43 @@ func runServer(cmd *cobra.Command, args []string) error {
 	if err != nil {
 		return err
 	}
+	startUpdateCheck()
+	startConfigWatch(rt)
 	return rt.Start()
 }
 
+// startConfigWatch watches a remote --file and reloads the runtime when it
+// changes: consul:// and etcd:// keys as they change, other URLs every
+// --config-refresh interval
+func startConfigWatch(rt *runtime.Runtime) {
+	if !internal.IsRemoteConfig(configFile) {
+		if configRefresh > 0 {
+			logger.New("main").Warnf("--config-refresh only applies to a remote --file; use SIGHUP or 'hyperterse dev' to reload a local file")
+		}
+		return
+	}
+	go internal.WatchRemoteConfig(context.Background(), configFile, configRefresh, func() error {
+		logger.New("main").Infof("Remote configuration changed, reloading")
+		return rt.Reload()
+	})
+}
+
 // PrepareRuntime loads config, validates, and creates a runtime ready to start
 func PrepareRuntime() (*runtime.Runtime, error) {
 	log := logger.New("main")
-	// Set log level early based on CLI flags (before loading config)
-	// This ensures logs during config loading respect the log level
-	// We'll update it after loading config if config file specifies a different level
-	if verbose {
-		logger.SetLogLevel(logger.LogLevelDebug)
-	} else if logLevel > 0 {
-		logger.SetLogLevel(logLevel)
-	} else {
-		// Default to INFO if no CLI flag specified (will be updated from config if needed)
-		logger.SetLogLevel(logger.LogLevelInfo)
+	// Set log level and format early based on CLI flags and environment (before
+	// loading config), so logs during config loading respect them. They are
+	// resolved again once the config file is loaded.
+	earlyLogLevel, err := internal.ResolveLogLevel(verbose, logLevel, nil)
+	if err != nil {
+		return nil, log.Errorf("%w", err)
+	}
+	logger.SetLogLevel(earlyLogLevel)
+	earlyLogFormat, err := internal.ResolveLogFormat(logFormat, nil)
+	if err != nil {
+		return nil, log.Errorf("%w", err)
 	}
+	logger.SetOTELLogMode(earlyLogFormat == types.LogFormatJSON)
 
 	// Initialize tag filtering early (CLI flag takes precedence over env var)
 	tagFilterStr := logTags
@@ -71,7 +114,6 @@ func PrepareRuntime() (*runtime.Runtime, error) {
 	// Initialize log file streaming if enabled
 	var filePath string
 	if logFile {
-		var err error
 		filePath, err = logger.SetLogFile()
 		if err != nil {
 			return nil, log.Errorf("failed to initialize log file: %w", err)
@@ -80,37 +122,33 @@ func PrepareRuntime() (*runtime.Runtime, error) {
 
 	// Load .env files from config file directory if a config file is provided
 	// This allows .env files to be placed next to the config file
-	if configFile != "" {
-		if configDir := filepath.Dir(configFile); configDir != "" && configDir != "." {
-			LoadEnvFiles(configDir)
-		}
-	}
-
-	var model *hyperterse.Model
-	var err error
+	loadConfigEnvFiles()
 
-	// Load from source string if provided, otherwise from file
-	if source != "" {
-		if configFile != "" {
-			return nil, log.Errorf("cannot specify both --file and --source flags")
-		}
-		model, err = internal.LoadConfigFromString(source)
-	} else {
-		if configFile == "" {
-			return nil, log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
-		}
-		model, err = internal.LoadConfig(configFile)
+	if configCache != "" {
+		internal.SetConfigCacheDir(configCache)
 	}
+	model, err := loadModel()
 	if err != nil {
 		return nil, err
 	}
 
 	resolvedPort := internal.ResolvePort(port, model)
-	resolvedLogLevel := internal.ResolveLogLevel(verbose, logLevel, model)
-	// Update log level if config file specifies a different level and no CLI flag was provided
-	if logLevel == 0 && !verbose {
-		logger.SetLogLevel(resolvedLogLevel)
+	resolvedPortAuto := portAuto
+	if resolvedPort == internal.PortAuto {
+		resolvedPort = "8080"
+		resolvedPortAuto = true
 	}
+	// Apply the config file's log settings unless a flag or environment variable overrides them
+	resolvedLogLevel, err := internal.ResolveLogLevel(verbose, logLevel, model)
+	if err != nil {
+		return nil, log.Errorf("%w", err)
+	}
+	logger.SetLogLevel(resolvedLogLevel)
+	resolvedLogFormat, err := internal.ResolveLogFormat(logFormat, model)
+	if err != nil {
+		return nil, log.Errorf("%w", err)
+	}
+	logger.SetOTELLogMode(resolvedLogFormat == types.LogFormatJSON)
 
 	// Log file path if streaming is enabled
 	if logFile {
@@ -152,12 +190,124 @@ func PrepareRuntime() (*runtime.Runtime, error) {
 		return nil, err
 	}
 	log.Infof("Validation successful")
+	if err := checkEnv(model); err != nil {
+		return nil, err
+	}
+	saveLastGoodConfig()
+	if lintOnLoad {
+		logLintSummary(model)
+	}
+
+	// Apply runtime tuning after validation so invalid limits never reach the Go runtime
+	if resolvedWorkers := internal.ResolveWorkerThreads(workers, model); resolvedWorkers > 0 {
+		goruntime.GOMAXPROCS(resolvedWorkers)
+		log.Debugf("Worker threads: %d", resolvedWorkers)
+	}
+	if resolvedMaxThreads := internal.ResolveMaxThreads(maxThreads, model); resolvedMaxThreads > 0 {
+		if resolvedMaxThreads < parser.MinMaxThreads {
+			return nil, log.Errorf("--max-threads must be at least %d", parser.MinMaxThreads)
+		}
+		debug.SetMaxThreads(resolvedMaxThreads)
+		log.Debugf("Max threads: %d", resolvedMaxThreads)
+	}
 
 	rt, err := runtime.NewRuntime(model, resolvedPort, GetVersion())
 	if err != nil {
 		return nil, err
 	}
+	rt.SetPortAuto(resolvedPortAuto)
 	log.Infof("Runtime initialized")
 
+	// Allow `kill -HUP` to reload the configuration without restarting the server.
+	// Server-level settings such as the port only take effect on restart.
+	rt.SetReloader(func() (*hyperterse.Model, error) {
+		model, err := loadModel()
+		if err != nil {
+			return nil, err
+		}
+		if err := parser.Validate(model); err != nil {
+			return nil, err
+		}
+		if err := checkEnv(model); err != nil {
+			return nil, err
+		}
+		saveLastGoodConfig()
+		return model, nil
+	})
+
 	return rt, nil
 }
+
+// checkEnv fails when {{ env.NAME }} placeholders anywhere in the configuration
+// reference unset variables, so they are caught at startup rather than when a
+// connection or query first needs them. With --ignore-missing-env it only warns.
+func checkEnv(model *hyperterse.Model) error {
+	log := logger.New("main")
+	missing := parser.MissingEnvReferences(model)
+	if len(missing) == 0 {
+		return nil
+	}
+
+	descriptions := make([]string, len(missing))
+	for i, reference := range missing {
+		descriptions[i] = fmt.Sprintf("%s (%s)", reference.Name, strings.Join(reference.Fields, ", "))
+	}
+	if ignoreMissingEnv {
+		log.Warnf("%d environment variable(s) referenced by the configuration are not set: %s", len(missing), strings.Join(descriptions, "; "))
+		return nil
+	}
+	return log.Errorf("%d environment variable(s) referenced by the configuration are not set: %s. Set them, or pass --ignore-missing-env to start anyway", len(missing), strings.Join(descriptions, "; "))
+}
+
+// loadModel loads the configuration from --bundle or --source if provided,
+// otherwise from --file
+func loadModel() (*hyperterse.Model, error) {
+	log := logger.New("main")
+	if bundleFile != "" {
+		if configFile != "" || source != "" {
+			return nil, log.Errorf("cannot specify --bundle with --file or --source")
+		}
+		return loadBundle()
+	}
+	if source != "" {
+		if configFile != "" {
+			return nil, log.Errorf("cannot specify both --file and --source flags")
+		}
+		return internal.LoadConfigFromStringWithFormat(source, configFormat)
+	}
+	if configFile == "" {
+		return nil, log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
+	}
+	return internal.LoadConfigWithFormat(configFile, configFormat)
+}
+
+// saveLastGoodConfig keeps a validated remote --file on disk, where it is
+// loaded from if the remote source cannot be reached at the next start
+func saveLastGoodConfig() {
+	if bundleFile != "" || !internal.IsRemoteConfig(configFile) {
+		return
+	}
+	if err := internal.SaveLastGoodConfig(configFile); err != nil {
+		logger.New("main").Warnf("Failed to save the last good configuration: %v", err)
+	}
+}
+
+// loadBundle verifies the signed bundle against --verify-key and loads its
+// configuration. The bundle is read again on every reload, so replacing the
+// file and sending SIGHUP promotes a new verified configuration.
+func loadBundle() (*hyperterse.Model, error) {
+	log := logger.New("main")
+	if verifyKey == "" {
+		return nil, log.Errorf("--bundle requires --verify-key")
+	}
+	key, err := internal.LoadVerifyKey(verifyKey)
+	if err != nil {
+		return nil, log.Errorf("%w", err)
+	}
+	manifest, config, err := internal.ReadBundle(bundleFile, key)
+	if err != nil {
+		return nil, log.Errorf("%w", err)
+	}
+	log.Infof("Bundle verified: %s (sha256 %s, exported by hyperterse %s)", manifest.Name, manifest.SHA256, manifest.HyperterseVersion)
+	return internal.LoadConfigFromStringWithFormat(string(config), manifest.Format)
+}
@@ -0,0 +1,126 @@
+package cmd
+
+import (
+	"context"
+	"fmt"
+	"os"
+
+	"github.com/hyperterse/hyperterse/core/cli/internal"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/seed"
+	"github.com/spf13/cobra"
+)
+
+var seedAdapter string
+
+// seedCmd represents the seed command
+var seedCmd = &cobra.Command{
+	Use:   "seed <seed-file>...",
+	Short: "Run setup statements from seed files against an adapter",
+	Long: `Execute the statements of one or more seed files against an adapter, in order.
+SQL adapters (postgres, mysql, duckdb) take SQL scripts, mongodb adapters take a JSON
+object of documents to insert into each collection, and redis adapters take one command per line.`,
+	Args:          cobra.MinimumNArgs(1),
+	RunE:          runSeed,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse seed -f config.terse seeds/schema.sql seeds/data.sql
+  hyperterse seed -f config.terse --adapter main_db seeds/data.sql`,
+}
+
+func init() {
+	rootCmd.AddCommand(seedCmd)
+
+	seedCmd.Flags().StringVarP(&source, "source", "s", "", "Configuration as a string (alternative to --file)")
+	seedCmd.Flags().StringVarP(&seedAdapter, "adapter", "a", "", "Adapter to seed (default: the only adapter)")
+}
+
+func runSeed(cmd *cobra.Command, args []string) error {
+	log := logger.New("seed")
+	loadConfigEnvFiles()
+
+	var (
+		model *hyperterse.Model
+		err   error
+	)
+	if source != "" {
+		if configFile != "" {
+			return log.Errorf("cannot specify both --file and --source flags")
+		}
+		model, err = internal.LoadConfigFromStringWithFormat(source, configFormat)
+	} else {
+		if configFile == "" {
+			return log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
+		}
+		model, err = internal.LoadConfigWithFormat(configFile, configFormat)
+	}
+	if err != nil {
+		return err
+	}
+
+	if err := parser.Validate(model); err != nil {
+		return log.Errorf("validation failed: %w", err)
+	}
+
+	adapter, err := seedTarget(model)
+	if err != nil {
+		return log.Errorf("%w", err)
+	}
+
+	// Parse every file before connecting so a bad file changes nothing
+	statements := make([][]string, len(args))
+	for i, path := range args {
+		data, err := os.ReadFile(path)
+		if err != nil {
+			return log.Errorf("failed to read seed file: %w", err)
+		}
+		statements[i], err = seed.Statements(adapter.Connector, data)
+		if err != nil {
+			return log.Errorf("%s: %w", path, err)
+		}
+	}
+
+	conn, err := connectors.NewConnector(adapter)
+	if err != nil {
+		return log.Errorf("failed to connect to adapter '%s': %w", adapter.Name, err)
+	}
+	defer conn.Close()
+
+	ctx := cmd.Context()
+	if ctx == nil {
+		ctx = context.Background()
+	}
+	total := 0
+	for i, path := range args {
+		for n, statement := range statements[i] {
+			if _, err := conn.Execute(ctx, statement, nil); err != nil {
+				return log.Errorf("%s: statement %d failed: %w", path, n+1, err)
+			}
+			log.Debugf("%s: executed statement %d", path, n+1)
+		}
+		total += len(statements[i])
+		log.Infof("Seeded %s (%d statement(s))", path, len(statements[i]))
+	}
+
+	log.Successf("Seeded adapter '%s': %d statement(s) from %d file(s)", adapter.Name, total, len(args))
+	return nil
+}
+
+// seedTarget returns the adapter named by --adapter, or the only adapter of the model
+func seedTarget(model *hyperterse.Model) (*hyperterse.Adapter, error) {
+	if seedAdapter == "" {
+		if len(model.Adapters) != 1 {
+			return nil, fmt.Errorf("configuration has %d adapters, choose one with --adapter", len(model.Adapters))
+		}
+		return model.Adapters[0], nil
+	}
+	for _, adapter := range model.Adapters {
+		if adapter.Name == seedAdapter {
+			return adapter, nil
+		}
+	}
+	return nil, fmt.Errorf("adapter '%s' not found", seedAdapter)
+}
@@ -26,6 +26,8 @@ var skillsCmd = &cobra.Command{
 	Short:        "Generate an Agent Skills compatible archive",
 	RunE:         generateSkills,
 	SilenceUsage: true,
+	Example: `  hyperterse generate skills -f config.terse
+  hyperterse generate skills -f config.terse -o my-skill.zip --name my-data-api`,
 }
 
 func init() {
@@ -39,7 +41,7 @@ func generateSkills(cmd *cobra.Command, args []string) error {
 	log := logger.New("generate")
 
 	// Load config
-	model, err := internal.LoadConfig(configFile)
+	model, err := internal.LoadConfigWithFormat(configFile, configFormat)
 	if err != nil {
 		log.PrintError("Error loading config", err)
 		os.Exit(1)
@@ -0,0 +1,115 @@
+package cmd
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+// updateCheckInterval is how long a release lookup is reused before GitHub is asked again
+const updateCheckInterval = 24 * time.Hour
+
+var updateCheck bool
+
+// updateCheckCache is the result of the last release lookup, kept on disk
+type updateCheckCache struct {
+	CheckedAt time.Time `json:"checked_at"`
+	Channel   string    `json:"channel"`
+	Major     int       `json:"major"`
+	Latest    string    `json:"latest"`
+}
+
+// updateCheckEnabled reports whether the update check was opted into with
+// --update-check or HYPERTERSE_UPDATE_CHECK. HYPERTERSE_NO_UPDATE_CHECK turns
+// it off regardless.
+func updateCheckEnabled() bool {
+	if os.Getenv("HYPERTERSE_NO_UPDATE_CHECK") != "" {
+		return false
+	}
+	if updateCheck {
+		return true
+	}
+	switch strings.ToLower(os.Getenv("HYPERTERSE_UPDATE_CHECK")) {
+	case "1", "true", "yes":
+		return true
+	}
+	return false
+}
+
+// startUpdateCheck looks for a newer release in the background and logs a
+// single notice when one exists. Lookups are cached for a day, and failures
+// are only logged at debug level so they never disturb the server.
+func startUpdateCheck() {
+	version := strings.TrimPrefix(GetVersion(), "v")
+	if !updateCheckEnabled() || version == "dev" {
+		return
+	}
+
+	go func() {
+		log := logger.New("update")
+
+		major, err := parseMajorVersion(version)
+		if err != nil {
+			log.Debugf("Update check skipped: %v", err)
+			return
+		}
+		channel := loadReleaseChannel()
+		if channel == "" {
+			channel = versionChannel(version)
+		}
+
+		latest, err := cachedLatestRelease(channel, major)
+		if err != nil {
+			log.Debugf("Update check failed: %v", err)
+			return
+		}
+		if latest != "" && compareVersions(latest, version) > 0 {
+			log.Infof("Hyperterse %s is available (current: %s). Run 'hyperterse upgrade' to install it", latest, version)
+		}
+	}()
+}
+
+// cachedLatestRelease returns the latest release of a major version on a
+// channel, from the cache when it is fresh and from GitHub otherwise
+func cachedLatestRelease(channel string, major int) (string, error) {
+	path := updateCheckCacheFile()
+
+	var cache updateCheckCache
+	if path != "" {
+		if data, err := os.ReadFile(path); err == nil && json.Unmarshal(data, &cache) == nil &&
+			cache.Channel == channel && cache.Major == major && time.Since(cache.CheckedAt) < updateCheckInterval {
+			return cache.Latest, nil
+		}
+	}
+
+	releases, err := fetchReleases()
+	if err != nil {
+		return "", err
+	}
+	latest, err := findLatestInChannel(releases, channel, major)
+	if err != nil {
+		return "", err
+	}
+
+	if path != "" {
+		cache = updateCheckCache{CheckedAt: time.Now(), Channel: channel, Major: major, Latest: latest}
+		if data, err := json.Marshal(cache); err == nil && os.MkdirAll(filepath.Dir(path), 0755) == nil {
+			os.WriteFile(path, data, 0644)
+		}
+	}
+	return latest, nil
+}
+
+// updateCheckCacheFile is where the last release lookup is cached, or empty
+// when there is no user cache directory
+func updateCheckCacheFile() string {
+	dir, err := os.UserCacheDir()
+	if err != nil {
+		return ""
+	}
+	return filepath.Join(dir, "hyperterse", "update-check.json")
+}
@@ -9,7 +9,9 @@ import (
 	"os/exec"
 	"path/filepath"
 	"runtime"
+	"slices"
 	"strings"
+	"time"
 
 	"github.com/spf13/cobra"
 )
@@ -17,19 +19,42 @@ import (
 var (
 	upgradePrerelease bool
 	upgradeMajor      string
+	upgradeChannel    string
+	upgradeCheck      bool
 )
 
+// Release channels, from most to least conservative. Each channel includes
+// the releases of the channels before it.
+const (
+	ChannelStable  = "stable"
+	ChannelBeta    = "beta"
+	ChannelNightly = "nightly"
+)
+
+// releaseChannels are the accepted values of upgrade --channel
+var releaseChannels = []string{ChannelStable, ChannelBeta, ChannelNightly}
+
 var upgradeCmd = &cobra.Command{
-	Use:          "upgrade",
-	Short:        "Upgrade Hyperterse to the latest version",
+	Use:   "upgrade",
+	Short: "Upgrade Hyperterse to the latest version",
+	Long: `Download and install the latest release on the current major version and release
+channel. The stable channel only has full releases; beta adds alpha, beta and
+release candidate pre-releases; nightly adds nightly builds. A channel chosen with
+--channel is remembered, so later upgrades and checks stay on it.`,
 	RunE:         runUpgrade,
 	SilenceUsage: true,
+	Example: `  hyperterse upgrade
+  hyperterse upgrade --check
+  hyperterse upgrade --channel beta
+  hyperterse upgrade --major next`,
 }
 
 func init() {
 	rootCmd.AddCommand(upgradeCmd)
-	upgradeCmd.Flags().BoolVar(&upgradePrerelease, "prerelease", false, "Include pre-releases when finding the latest version")
+	upgradeCmd.Flags().BoolVar(&upgradePrerelease, "prerelease", false, "Include pre-releases when finding the latest version (same as --channel beta)")
 	upgradeCmd.Flags().StringVar(&upgradeMajor, "major", "", "Upgrade to a specific major version (e.g., '2') or use 'next' to upgrade to the next major version")
+	upgradeCmd.Flags().StringVar(&upgradeChannel, "channel", "", "Release channel: stable, beta or nightly (default: the remembered channel)")
+	upgradeCmd.Flags().BoolVar(&upgradeCheck, "check", false, "Report whether a newer release is available without installing it")
 }
 
 type Release struct {
@@ -46,12 +71,11 @@ func runUpgrade(cmd *cobra.Command, args []string) error {
 
 	fmt.Printf("Current version: %s\n", currentVersion)
 
-	// Auto-detect if current version is a prerelease and include prereleases automatically
-	// unless explicitly disabled by user
-	if !upgradePrerelease && isPrereleaseVersion(currentVersion) {
-		upgradePrerelease = true
-		fmt.Printf("Current version is a prerelease, including prereleases in search\n")
+	channel, err := resolveReleaseChannel(currentVersion)
+	if err != nil {
+		return err
 	}
+	fmt.Printf("Release channel: %s\n", channel)
 
 	// Determine target major version
 	var targetMajorVersion int
@@ -87,24 +111,28 @@ func runUpgrade(cmd *cobra.Command, args []string) error {
 		return fmt.Errorf("failed to fetch releases: %w", err)
 	}
 
-	// Filter to target major version and find latest
-	latestVersion, err := findLatestInMajorVersion(releases, targetMajorVersion, upgradePrerelease)
+	// Filter to the channel and target major version and find latest
+	latestVersion, err := findLatestInChannel(releases, channel, targetMajorVersion)
 	if err != nil {
 		return fmt.Errorf("failed to find latest version: %w", err)
 	}
 
 	if latestVersion == "" {
 		// No releases found for the target major version
-		return fmt.Errorf("no releases found for major version %d", targetMajorVersion)
+		return fmt.Errorf("no %s releases found for major version %d", channel, targetMajorVersion)
 	}
 
-	// Check if already on latest
-	if latestVersion == currentVersion {
-		fmt.Printf("Already on latest version: %s\n", currentVersion)
+	// Check if already on latest; a channel with only older releases is not a downgrade
+	if compareVersions(latestVersion, currentVersion) <= 0 {
+		fmt.Printf("Already on latest %s version: %s\n", channel, currentVersion)
 		return nil
 	}
 
-	fmt.Printf("Latest version in major version %d: %s\n", targetMajorVersion, latestVersion)
+	fmt.Printf("Latest %s version in major version %d: %s\n", channel, targetMajorVersion, latestVersion)
+	if upgradeCheck {
+		fmt.Printf("Run 'hyperterse upgrade' to install it\n")
+		return nil
+	}
 
 	// Download and install
 	if err := downloadAndInstall(latestVersion); err != nil {
@@ -115,6 +143,97 @@ func runUpgrade(cmd *cobra.Command, args []string) error {
 	return nil
 }
 
+// resolveReleaseChannel returns the channel chosen with --channel or
+// --prerelease, recording it for later runs, or else the recorded channel, or
+// else the channel the current version was released on
+func resolveReleaseChannel(currentVersion string) (string, error) {
+	channel := upgradeChannel
+	if channel == "" && upgradePrerelease {
+		channel = ChannelBeta
+	}
+	if channel != "" {
+		if !slices.Contains(releaseChannels, channel) {
+			return "", fmt.Errorf("unknown channel '%s': must be one of: %s", channel, strings.Join(releaseChannels, ", "))
+		}
+		if err := saveReleaseChannel(channel); err != nil {
+			fmt.Printf("Warning: could not remember the release channel: %v\n", err)
+		}
+		return channel, nil
+	}
+
+	if recorded := loadReleaseChannel(); recorded != "" {
+		return recorded, nil
+	}
+	return versionChannel(currentVersion), nil
+}
+
+// versionChannel returns the channel a version belongs to, from its
+// pre-release suffix
+func versionChannel(version string) string {
+	_, suffix, found := strings.Cut(strings.TrimPrefix(version, "v"), "-")
+	switch {
+	case !found:
+		return ChannelStable
+	case strings.HasPrefix(suffix, "nightly"):
+		return ChannelNightly
+	default:
+		return ChannelBeta
+	}
+}
+
+// filterReleasesByChannel keeps the releases available on a channel
+func filterReleasesByChannel(releases []Release, channel string) []Release {
+	rank := slices.Index(releaseChannels, channel)
+	var filtered []Release
+	for _, release := range releases {
+		releaseChannel := versionChannel(release.TagName)
+		if release.Prerelease && releaseChannel == ChannelStable {
+			// A pre-release flagged on GitHub without a suffix is treated as beta
+			releaseChannel = ChannelBeta
+		}
+		if slices.Index(releaseChannels, releaseChannel) <= rank {
+			filtered = append(filtered, release)
+		}
+	}
+	return filtered
+}
+
+// releaseChannelFile is where the chosen release channel is remembered
+func releaseChannelFile() (string, error) {
+	dir, err := os.UserConfigDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "hyperterse", "channel"), nil
+}
+
+func loadReleaseChannel() string {
+	path, err := releaseChannelFile()
+	if err != nil {
+		return ""
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return ""
+	}
+	channel := strings.TrimSpace(string(data))
+	if !slices.Contains(releaseChannels, channel) {
+		return ""
+	}
+	return channel
+}
+
+func saveReleaseChannel(channel string) error {
+	path, err := releaseChannelFile()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+	return os.WriteFile(path, []byte(channel+"\n"), 0644)
+}
+
 func getCurrentVersion() (string, error) {
 	// First, try to use the version from GetVersion()
 	currentVersion := GetVersion()
@@ -172,13 +291,6 @@ func getVersionFromGit() (string, error) {
 	return version, nil
 }
 
-func isPrereleaseVersion(version string) bool {
-	// Remove 'v' prefix if present
-	version = strings.TrimPrefix(version, "v")
-	// Check if version contains a prerelease suffix (e.g., 1.0.0-alpha.1, 1.0.0-beta.1)
-	return strings.Contains(version, "-")
-}
-
 func parseMajorVersion(version string) (int, error) {
 	// Remove 'v' prefix if present
 	version = strings.TrimPrefix(version, "v")
@@ -201,10 +313,13 @@ func parseMajorVersion(version string) (int, error) {
 	return major, nil
 }
 
+// releasesClient bounds release lookups, which also run in the background on server start
+var releasesClient = &http.Client{Timeout: 30 * time.Second}
+
 func fetchReleases() ([]Release, error) {
 	url := "https://api.github.com/repos/hyperterse/hyperterse/releases"
 
-	resp, err := http.Get(url)
+	resp, err := releasesClient.Get(url)
 	if err != nil {
 		return nil, err
 	}
@@ -428,6 +543,78 @@ func findLatestInMajorVersion(releases []Release, majorVersion int, includePrere
 	return latestVersion, nil
 }
 
+// findLatestInChannel returns the latest release of a major version available
+// on a channel
+func findLatestInChannel(releases []Release, channel string, majorVersion int) (string, error) {
+	releases = filterReleasesByChannel(releases, channel)
+	if channel == ChannelStable {
+		return findLatestInMajorVersion(releases, majorVersion, false)
+	}
+	return findNewestInMajorVersion(releases, majorVersion)
+}
+
+// findNewestInMajorVersion returns the highest version in a major version by
+// SemVer precedence, whether or not it is a pre-release
+func findNewestInMajorVersion(releases []Release, majorVersion int) (string, error) {
+	var newest string
+	for _, release := range releases {
+		version := strings.TrimPrefix(release.TagName, "v")
+		if major, err := parseMajorVersion(version); err != nil || major != majorVersion {
+			continue
+		}
+		if _, ok := parseVersionCore(version); !ok {
+			continue
+		}
+		if newest == "" || compareVersions(version, newest) > 0 {
+			newest = version
+		}
+	}
+	return newest, nil
+}
+
+// parseVersionCore parses the major, minor and patch numbers of a version
+func parseVersionCore(version string) ([3]int, bool) {
+	var core [3]int
+	base, _, _ := strings.Cut(strings.TrimPrefix(version, "v"), "-")
+	parts := strings.Split(base, ".")
+	if len(parts) < 3 {
+		return core, false
+	}
+	for i := range core {
+		if _, err := fmt.Sscanf(parts[i], "%d", &core[i]); err != nil {
+			return core, false
+		}
+	}
+	return core, true
+}
+
+// compareVersions compares two versions by SemVer precedence, where a release
+// is newer than its pre-releases. Returns: -1 if a < b, 0 if a == b, 1 if a > b
+func compareVersions(a, b string) int {
+	aCore, _ := parseVersionCore(a)
+	bCore, _ := parseVersionCore(b)
+	for i := range aCore {
+		if aCore[i] != bCore[i] {
+			if aCore[i] < bCore[i] {
+				return -1
+			}
+			return 1
+		}
+	}
+
+	_, aSuffix, _ := strings.Cut(strings.TrimPrefix(a, "v"), "-")
+	_, bSuffix, _ := strings.Cut(strings.TrimPrefix(b, "v"), "-")
+	switch {
+	case aSuffix == bSuffix:
+		return 0
+	case aSuffix == "":
+		return 1
+	case bSuffix == "":
+		return -1
+	}
+	return comparePrereleaseSuffixes(aSuffix, bSuffix)
+}
+
 func downloadAndInstall(version string) error {
 	// Detect OS and architecture
 	goos := runtime.GOOS
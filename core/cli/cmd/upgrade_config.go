@@ -0,0 +1,96 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/spf13/cobra"
+)
+
+var upgradeConfigDryRun bool
+
+// upgradeConfigCmd represents the upgrade-config command
+var upgradeConfigCmd = &cobra.Command{
+	Use:   "upgrade-config",
+	Short: "Rewrite a configuration file to the current layout",
+	Long: `Rewrite a configuration written for an older layout, such as list-based adapters
+and queries or legacy key names, to the current layout and set config_version.
+Older layouts still load through compatibility shims, with a warning. The file is
+re-encoded, so comments are not kept; review the change before committing it.`,
+	Args:          cobra.NoArgs,
+	RunE:          runUpgradeConfig,
+	SilenceUsage:  true,
+	SilenceErrors: true,
+	Example: `  hyperterse upgrade-config -f config.terse
+  hyperterse upgrade-config -f config.terse --dry-run`,
+}
+
+func init() {
+	rootCmd.AddCommand(upgradeConfigCmd)
+
+	upgradeConfigCmd.Flags().BoolVar(&upgradeConfigDryRun, "dry-run", false, "Print the upgraded configuration instead of writing the file")
+}
+
+func runUpgradeConfig(cmd *cobra.Command, args []string) error {
+	log := logger.New("upgrade-config")
+
+	if configFile == "" {
+		return log.Errorf("please provide a file path using -f or --file")
+	}
+	format := parser.DetectFormat(configFile)
+	if configFormat != "" {
+		var err error
+		if format, err = parser.ParseFormatName(configFormat); err != nil {
+			return log.Errorf("%w", err)
+		}
+	}
+
+	content, err := os.ReadFile(configFile)
+	if err != nil {
+		return log.Errorf("error reading file: %w", err)
+	}
+	raw, err := parser.UnmarshalWithFormat(content, format)
+	if err != nil {
+		return log.Errorf("config error: %w", err)
+	}
+
+	version, notes, err := parser.UpgradeRawConfig(raw)
+	if err != nil {
+		return log.Errorf("config error: %w", err)
+	}
+	if version == parser.CurrentConfigVersion {
+		log.Successf("%s already uses layout version %d", configFile, version)
+		return nil
+	}
+	raw["config_version"] = parser.CurrentConfigVersion
+
+	// Make sure the rewritten file still parses before replacing the original
+	upgraded, err := parser.MarshalWithFormat(raw, format)
+	if err != nil {
+		return log.Errorf("failed to encode configuration: %w", err)
+	}
+	if _, err := parser.ParseWithFormat(upgraded, format); err != nil {
+		return log.Errorf("upgraded configuration does not parse: %w", err)
+	}
+
+	if upgradeConfigDryRun {
+		fmt.Print(string(upgraded))
+		return nil
+	}
+
+	info, err := os.Stat(configFile)
+	if err != nil {
+		return log.Errorf("error reading file: %w", err)
+	}
+	if err := os.WriteFile(configFile, upgraded, info.Mode().Perm()); err != nil {
+		return log.Errorf("failed to write %s: %w", configFile, err)
+	}
+
+	for _, note := range notes {
+		log.Infof("%s", note)
+	}
+	log.Successf("Upgraded %s from layout version %d to %d", configFile, version, parser.CurrentConfigVersion)
+	return nil
+}
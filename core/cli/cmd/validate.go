@@ -15,6 +15,8 @@ var validateCmd = &cobra.Command{
 	RunE:          validateConfig,
 	SilenceUsage:  true,
 	SilenceErrors: true,
+	Example: `  hyperterse validate -f config.terse
+  hyperterse validate -s "$(cat config.terse)"`,
 }
 
 func init() {
@@ -36,13 +38,13 @@ func validateConfig(cmd *cobra.Command, args []string) error {
 		if configFile != "" {
 			return log.Errorf("cannot specify both --file and --source flags")
 		}
-		model, err = internal.LoadConfigFromString(source)
+		model, err = internal.LoadConfigFromStringWithFormat(source, configFormat)
 		loadFrom = "source"
 	} else {
 		if configFile == "" {
 			return log.Errorf("please provide a file path using -f or --file, or a source string using -s or --source")
 		}
-		model, err = internal.LoadConfig(configFile)
+		model, err = internal.LoadConfigWithFormat(configFile, configFormat)
 		loadFrom = configFile
 	}
 	if err != nil {
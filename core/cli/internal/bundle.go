@@ -0,0 +1,181 @@
+package internal
+
+import (
+	"archive/tar"
+	"bytes"
+	"compress/gzip"
+	"crypto/ed25519"
+	"crypto/sha256"
+	"crypto/x509"
+	"encoding/base64"
+	"encoding/hex"
+	"encoding/json"
+	"encoding/pem"
+	"fmt"
+	"io"
+	"os"
+	"time"
+)
+
+// BundleExtension is the file extension of signed configuration bundles
+const BundleExtension = ".htb"
+
+// Entries of a bundle archive. The configuration entry is named by the
+// manifest's config_file field.
+const (
+	bundleManifestEntry  = "manifest.json"
+	bundleSignatureEntry = "manifest.sig"
+)
+
+// BundleManifest describes the configuration held in a signed bundle. The
+// signature covers the manifest, and the manifest pins the configuration by
+// its SHA-256 checksum.
+type BundleManifest struct {
+	Name              string    `json:"name"`
+	Version           string    `json:"version,omitempty"` // The configuration's version field
+	ConfigFile        string    `json:"config_file"`
+	Format            string    `json:"format"`
+	SHA256            string    `json:"sha256"`
+	HyperterseVersion string    `json:"hyperterse_version"`
+	CreatedAt         time.Time `json:"created_at"`
+}
+
+// WriteBundle writes a gzipped tar archive holding the manifest, its
+// Ed25519 signature and the configuration, and returns the manifest with its
+// checksum filled in
+func WriteBundle(w io.Writer, manifest BundleManifest, config []byte, key ed25519.PrivateKey) (BundleManifest, error) {
+	sum := sha256.Sum256(config)
+	manifest.SHA256 = hex.EncodeToString(sum[:])
+	manifestJSON, err := json.MarshalIndent(manifest, "", "  ")
+	if err != nil {
+		return manifest, err
+	}
+	signature := base64.StdEncoding.EncodeToString(ed25519.Sign(key, manifestJSON))
+
+	gz := gzip.NewWriter(w)
+	tw := tar.NewWriter(gz)
+	for _, entry := range []struct {
+		name    string
+		content []byte
+	}{
+		{bundleManifestEntry, manifestJSON},
+		{bundleSignatureEntry, []byte(signature)},
+		{manifest.ConfigFile, config},
+	} {
+		header := &tar.Header{Name: entry.name, Mode: 0644, Size: int64(len(entry.content)), ModTime: manifest.CreatedAt}
+		if err := tw.WriteHeader(header); err != nil {
+			return manifest, err
+		}
+		if _, err := tw.Write(entry.content); err != nil {
+			return manifest, err
+		}
+	}
+	if err := tw.Close(); err != nil {
+		return manifest, err
+	}
+	return manifest, gz.Close()
+}
+
+// ReadBundle opens a signed bundle, verifies the manifest signature against
+// key and the configuration against the manifest checksum, and returns both.
+// Nothing from the bundle is returned unless every check passes.
+func ReadBundle(path string, key ed25519.PublicKey) (BundleManifest, []byte, error) {
+	var manifest BundleManifest
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return manifest, nil, fmt.Errorf("error reading bundle: %w", err)
+	}
+	gz, err := gzip.NewReader(bytes.NewReader(content))
+	if err != nil {
+		return manifest, nil, fmt.Errorf("%s is not a configuration bundle: %w", path, err)
+	}
+	entries := make(map[string][]byte)
+	tr := tar.NewReader(gz)
+	for {
+		header, err := tr.Next()
+		if err == io.EOF {
+			break
+		}
+		if err != nil {
+			return manifest, nil, fmt.Errorf("%s is not a configuration bundle: %w", path, err)
+		}
+		data, err := io.ReadAll(tr)
+		if err != nil {
+			return manifest, nil, fmt.Errorf("error reading bundle entry %s: %w", header.Name, err)
+		}
+		entries[header.Name] = data
+	}
+
+	manifestJSON, ok := entries[bundleManifestEntry]
+	if !ok {
+		return manifest, nil, fmt.Errorf("bundle has no %s", bundleManifestEntry)
+	}
+	signature, err := base64.StdEncoding.DecodeString(string(entries[bundleSignatureEntry]))
+	if err != nil || len(signature) != ed25519.SignatureSize {
+		return manifest, nil, fmt.Errorf("bundle signature is missing or malformed")
+	}
+	if !ed25519.Verify(key, manifestJSON, signature) {
+		return manifest, nil, fmt.Errorf("bundle signature does not match the verify key")
+	}
+
+	if err := json.Unmarshal(manifestJSON, &manifest); err != nil {
+		return manifest, nil, fmt.Errorf("bundle manifest is invalid: %w", err)
+	}
+	config, ok := entries[manifest.ConfigFile]
+	if !ok {
+		return manifest, nil, fmt.Errorf("bundle has no %s", manifest.ConfigFile)
+	}
+	sum := sha256.Sum256(config)
+	if hex.EncodeToString(sum[:]) != manifest.SHA256 {
+		return manifest, nil, fmt.Errorf("bundle configuration does not match the manifest checksum")
+	}
+	return manifest, config, nil
+}
+
+// LoadSigningKey reads an Ed25519 private key from a PKCS #8 PEM file, such as
+// one written by 'openssl genpkey -algorithm ed25519'
+func LoadSigningKey(path string) (ed25519.PrivateKey, error) {
+	block, err := readPEM(path)
+	if err != nil {
+		return nil, err
+	}
+	key, err := x509.ParsePKCS8PrivateKey(block.Bytes)
+	if err != nil {
+		return nil, fmt.Errorf("%s is not a PKCS #8 private key: %w", path, err)
+	}
+	signingKey, ok := key.(ed25519.PrivateKey)
+	if !ok {
+		return nil, fmt.Errorf("%s is not an Ed25519 key", path)
+	}
+	return signingKey, nil
+}
+
+// LoadVerifyKey reads an Ed25519 public key from a PKIX PEM file, such as one
+// written by 'openssl pkey -pubout'
+func LoadVerifyKey(path string) (ed25519.PublicKey, error) {
+	block, err := readPEM(path)
+	if err != nil {
+		return nil, err
+	}
+	key, err := x509.ParsePKIXPublicKey(block.Bytes)
+	if err != nil {
+		return nil, fmt.Errorf("%s is not a PKIX public key: %w", path, err)
+	}
+	verifyKey, ok := key.(ed25519.PublicKey)
+	if !ok {
+		return nil, fmt.Errorf("%s is not an Ed25519 key", path)
+	}
+	return verifyKey, nil
+}
+
+func readPEM(path string) (*pem.Block, error) {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("error reading key: %w", err)
+	}
+	block, _ := pem.Decode(content)
+	if block == nil {
+		return nil, fmt.Errorf("%s is not a PEM file", path)
+	}
+	return block, nil
+}
@@ -11,54 +11,87 @@ import (
 
 // LoadConfig loads and parses a configuration file, returning the model (which includes server config)
 func LoadConfig(filePath string) (*hyperterse.Model, error) {
+	return LoadConfigWithFormat(filePath, "")
+}
+
+// LoadConfigWithFormat loads and parses a configuration file using an explicit format.
+// When format is empty the format is detected from the file extension. The file
+// may also be an http(s):// or s3:// URL.
+func LoadConfigWithFormat(filePath string, format string) (*hyperterse.Model, error) {
 	log := logger.New("parser")
 
 	log.Debugf("Loading configuration file")
-	log.Debugf("File path: %s", filePath)
 
-	content, err := os.ReadFile(filePath)
-	if err != nil {
-		return nil, log.Errorf("error reading file: %w", err)
+	var content []byte
+	var err error
+	detectFrom := filePath
+	if IsRemoteConfig(filePath) {
+		log.Debugf("URL: %s", redactLocation(filePath))
+		content, err = FetchRemoteConfig(filePath)
+		if err != nil {
+			return nil, log.Errorf("%w", err)
+		}
+		detectFrom = RemoteConfigPath(filePath)
+	} else {
+		log.Debugf("File path: %s", filePath)
+		content, err = os.ReadFile(filePath)
+		if err != nil {
+			return nil, log.Errorf("error reading file: %w", err)
+		}
 	}
 
 	log.Debugf("File size: %d bytes", len(content))
 
-	var model *hyperterse.Model
-	parserType := ""
-
-	// Determine parser based on file extension
-	if strings.HasSuffix(filePath, ".terse") {
-		parserType = "YAML"
-		log.Debugf("Parsing configuration with YAML parser")
-		model, err = parser.ParseYAMLWithConfig(content)
+	// Determine parser based on explicit format or file extension
+	parserFormat := parser.DetectFormat(detectFrom)
+	if format != "" {
+		parserFormat, err = parser.ParseFormatName(format)
 		if err != nil {
-			return nil, log.Errorf("config error: %w", err)
+			return nil, log.Errorf("%w", err)
 		}
-	} else {
-		parserType = "DSL"
-		log.Debugf("Parsing configuration with DSL parser")
-		p := parser.NewParser(string(content))
-		model, err = p.Parse()
-		if err != nil {
+	}
+
+	log.Debugf("Parsing configuration with %s parser", strings.ToUpper(string(parserFormat)))
+	model, err := parser.ParseWithFormat(content, parserFormat)
+	if err != nil {
+		if parserFormat == parser.FormatDSL {
 			return nil, log.Errorf("parsing error: %w", err)
 		}
+		return nil, log.Errorf("config error: %w", err)
 	}
 
-	log.Debugf("Parser type: %s", parserType)
 	log.Debugf("Configuration parsed successfully")
+	if model.ConfigVersion > 0 && model.ConfigVersion < parser.CurrentConfigVersion {
+		log.Warnf("Configuration uses layout version %d; run 'hyperterse upgrade-config -f %s' to rewrite it to version %d", model.ConfigVersion, filePath, parser.CurrentConfigVersion)
+	}
 
 	return model, nil
 }
 
 // LoadConfigFromString loads and parses a configuration from a YAML string, returning the model
 func LoadConfigFromString(yamlContent string) (*hyperterse.Model, error) {
+	return LoadConfigFromStringWithFormat(yamlContent, "")
+}
+
+// LoadConfigFromStringWithFormat loads and parses a configuration string using an explicit format.
+// When format is empty the content is parsed as YAML.
+func LoadConfigFromStringWithFormat(content string, format string) (*hyperterse.Model, error) {
 	log := logger.New("parser")
 
 	log.Debugf("Loading configuration from string")
-	log.Debugf("Content length: %d bytes", len(yamlContent))
-	log.Debugf("Parsing configuration with YAML parser")
+	log.Debugf("Content length: %d bytes", len(content))
 
-	model, err := parser.ParseYAMLWithConfig([]byte(yamlContent))
+	parserFormat := parser.FormatYAML
+	if format != "" {
+		var err error
+		parserFormat, err = parser.ParseFormatName(format)
+		if err != nil {
+			return nil, log.Errorf("%w", err)
+		}
+	}
+	log.Debugf("Parsing configuration with %s parser", strings.ToUpper(string(parserFormat)))
+
+	model, err := parser.ParseWithFormat([]byte(content), parserFormat)
 	if err != nil {
 		return nil, log.Errorf("config error: %w", err)
 	}
@@ -66,39 +99,3 @@ func LoadConfigFromString(yamlContent string) (*hyperterse.Model, error) {
 	log.Debugf("Configuration parsed successfully")
 	return model, nil
 }
-
-// ResolvePort resolves the port from CLI flag, config file, env var, or default
-func ResolvePort(cliPort string, model *hyperterse.Model) string {
-	if cliPort != "" {
-		return cliPort
-	}
-	if model != nil && model.Server != nil && model.Server.Port != "" {
-		return model.Server.Port
-	}
-	if port := os.Getenv("PORT"); port != "" {
-		return port
-	}
-	return "8080"
-}
-
-// ResolveLogLevel resolves the log level from verbose flag, CLI flag, config file, or default
-func ResolveLogLevel(verbose bool, cliLogLevel int, model *hyperterse.Model) int {
-	if verbose {
-		return logger.LogLevelDebug
-	}
-	if cliLogLevel > 0 {
-		return cliLogLevel
-	}
-	if model != nil && model.Server != nil && model.Server.LogLevel > 0 {
-		return int(model.Server.LogLevel)
-	}
-	return logger.LogLevelInfo
-}
-
-// ResolveOTLPEndpoint resolves the OTLP endpoint from CLI/env/default.
-func ResolveOTLPEndpoint() string {
-	if envEndpoint := os.Getenv("HYPERTERSE_OTEL_ENDPOINT"); envEndpoint != "" {
-		return envEndpoint
-	}
-	return "localhost:4317"
-}
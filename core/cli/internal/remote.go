@@ -0,0 +1,362 @@
+package internal
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"path"
+	"path/filepath"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+)
+
+// EnvConfigToken is sent as a bearer token when fetching an https:// config.
+// It is never sent over plain http://.
+const EnvConfigToken = "HYPERTERSE_CONFIG_TOKEN"
+
+const (
+	// remoteConfigTimeout bounds each fetch of a remote configuration
+	remoteConfigTimeout = 30 * time.Second
+
+	// watchRetryDelay is the wait before a failed watch is retried
+	watchRetryDelay = 5 * time.Second
+)
+
+// configProvider loads a remote configuration and watches it for changes.
+// Providers keep the state of the last fetch, such as an ETag or a Consul
+// index, so each instance watches on its own without coordination.
+type configProvider interface {
+	// fetch returns the current content
+	fetch(ctx context.Context) ([]byte, error)
+	// watch blocks until the content differs from the last fetch, returning
+	// the new content, or until ctx is done. interval is the poll interval
+	// for providers that cannot wait for changes.
+	watch(ctx context.Context, interval time.Duration) ([]byte, error)
+	// polls reports whether watch needs an interval
+	polls() bool
+	// accept marks the content last fetched as applied, so watch reports
+	// changes from it. Until then a failed reload is retried at the next poll.
+	accept()
+}
+
+var (
+	providersMu sync.Mutex
+	providers   = make(map[string]configProvider)
+	// lastFetched holds the content of the last fetch of each location, saved
+	// to disk once it is validated
+	lastFetched = make(map[string][]byte)
+
+	// configCacheDir is where the last good copy of each remote configuration
+	// is saved, see SetConfigCacheDir
+	configCacheDir = filepath.Join(logger.TempDir(), "configs")
+)
+
+// configClient fetches http(s):// configurations. Redirects that leave
+// https:// drop the Authorization header carrying the config token.
+var configClient = &http.Client{CheckRedirect: func(req *http.Request, via []*http.Request) error {
+	if len(via) >= 10 {
+		return errors.New("stopped after 10 redirects")
+	}
+	if req.URL.Scheme != "https" {
+		req.Header.Del("Authorization")
+	}
+	return nil
+}}
+
+// IsRemoteConfig reports whether location is an http(s)://, s3://, consul://
+// or etcd:// URL rather than a local path
+func IsRemoteConfig(location string) bool {
+	scheme, _, ok := strings.Cut(location, "://")
+	if !ok {
+		return false
+	}
+	switch strings.TrimSuffix(scheme, "+https") {
+	case "http", "https", "s3", "consul", "etcd":
+		return true
+	}
+	return false
+}
+
+// RemoteConfigPath returns the path part of a remote configuration URL, from
+// which its format is detected
+func RemoteConfigPath(location string) string {
+	if u, err := url.Parse(location); err == nil {
+		return u.Path
+	}
+	return location
+}
+
+// SetConfigCacheDir sets the directory the last good copy of each remote
+// configuration is saved to (default: configs under the hyperterse temp dir)
+func SetConfigCacheDir(dir string) {
+	configCacheDir = dir
+}
+
+// FetchRemoteConfig returns the content of a remote configuration. When the
+// provider cannot be reached, the last good copy saved by SaveLastGoodConfig
+// is returned instead with a warning, so a restart survives an outage.
+func FetchRemoteConfig(location string) ([]byte, error) {
+	provider, err := providerFor(location)
+	if err != nil {
+		return nil, err
+	}
+	ctx, cancel := context.WithTimeout(context.Background(), remoteConfigTimeout)
+	defer cancel()
+	content, err := provider.fetch(ctx)
+	if err != nil {
+		cached, cacheErr := os.ReadFile(lastGoodPath(location))
+		if cacheErr != nil {
+			return nil, err
+		}
+		logger.New("parser").Warnf("%v; using the last good copy saved at %s", err, lastGoodPath(location))
+		content = cached
+	}
+	providersMu.Lock()
+	lastFetched[location] = content
+	providersMu.Unlock()
+	return content, nil
+}
+
+// SaveLastGoodConfig saves the content last fetched from location to the
+// config cache directory. Call it once that content has been validated.
+func SaveLastGoodConfig(location string) error {
+	providersMu.Lock()
+	content, ok := lastFetched[location]
+	providersMu.Unlock()
+	if !ok {
+		return nil
+	}
+	target := lastGoodPath(location)
+	if err := os.MkdirAll(filepath.Dir(target), 0700); err != nil {
+		return err
+	}
+	// Write then rename so a crash never leaves a partial copy behind
+	tmp := target + ".tmp"
+	if err := os.WriteFile(tmp, content, 0600); err != nil {
+		return err
+	}
+	return os.Rename(tmp, target)
+}
+
+// WatchRemoteConfig calls changed each time the configuration at location
+// changes, until ctx is done. consul:// and etcd:// keys are watched as they
+// change; other URLs are fetched again every interval, and are not watched
+// when interval is zero. Failures are logged and retried; the configuration
+// already loaded keeps serving meanwhile. A change is only recorded once
+// changed returns nil, so an http(s):// or s3:// configuration whose reload
+// failed is reloaded again at the next poll.
+func WatchRemoteConfig(ctx context.Context, location string, interval time.Duration, changed func() error) {
+	provider, err := providerFor(location)
+	if err != nil || (provider.polls() && interval <= 0) {
+		return
+	}
+	log := logger.New("main")
+	for ctx.Err() == nil {
+		content, err := provider.watch(ctx, interval)
+		if err != nil {
+			if ctx.Err() != nil {
+				return
+			}
+			log.Warnf("Watching %s failed, keeping current model: %v", redactLocation(location), err)
+			select {
+			case <-ctx.Done():
+			case <-time.After(watchRetryDelay):
+			}
+			continue
+		}
+		providersMu.Lock()
+		lastFetched[location] = content
+		providersMu.Unlock()
+		if changed() == nil {
+			provider.accept()
+		}
+	}
+}
+
+// providerFor returns the provider of location, creating it on first use
+func providerFor(location string) (configProvider, error) {
+	providersMu.Lock()
+	defer providersMu.Unlock()
+	if provider, ok := providers[location]; ok {
+		return provider, nil
+	}
+
+	u, err := url.Parse(location)
+	if err != nil {
+		return nil, fmt.Errorf("invalid config URL: %w", err)
+	}
+	var provider configProvider
+	switch u.Scheme {
+	case "http", "https", "s3":
+		if u.Scheme == "http" && os.Getenv(EnvConfigToken) != "" {
+			logger.New("main").Warnf("%s is set but %s is not https://; fetching it without the token", EnvConfigToken, redactLocation(location))
+		}
+		provider = &urlProvider{location: location, u: u}
+	case "consul", "consul+https":
+		provider, err = newConsulProvider(u)
+	case "etcd", "etcd+https":
+		provider, err = newEtcdProvider(u)
+	default:
+		err = fmt.Errorf("unsupported config URL scheme '%s'", u.Scheme)
+	}
+	if err != nil {
+		return nil, err
+	}
+	providers[location] = provider
+	return provider, nil
+}
+
+// lastGoodPath returns the file the last good copy of location is saved to,
+// named after a hash of the location and keeping its extension
+func lastGoodPath(location string) string {
+	sum := sha256.Sum256([]byte(location))
+	return filepath.Join(configCacheDir, hex.EncodeToString(sum[:8])+path.Ext(RemoteConfigPath(location)))
+}
+
+// urlProvider fetches http(s):// and s3:// configurations, polling for
+// changes with conditional requests. The ETag and content of the applied
+// configuration are kept apart from those of the latest fetch, which only
+// replace them once accepted.
+type urlProvider struct {
+	location string
+	u        *url.URL
+
+	mu            sync.Mutex
+	etag          string
+	content       []byte
+	fetched       bool
+	latestETag    string
+	latestContent []byte
+	pending       bool
+}
+
+func (p *urlProvider) polls() bool { return true }
+
+func (p *urlProvider) accept() {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if p.pending {
+		p.etag, p.content, p.fetched = p.latestETag, p.latestContent, true
+		p.pending = false
+	}
+}
+
+func (p *urlProvider) fetch(ctx context.Context) ([]byte, error) {
+	content, _, err := p.get(ctx)
+	return content, err
+}
+
+func (p *urlProvider) watch(ctx context.Context, interval time.Duration) ([]byte, error) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+	for {
+		select {
+		case <-ctx.Done():
+			return nil, ctx.Err()
+		case <-ticker.C:
+		}
+		fetchCtx, cancel := context.WithTimeout(ctx, remoteConfigTimeout)
+		content, changed, err := p.get(fetchCtx)
+		cancel()
+		if err != nil {
+			return nil, err
+		}
+		if changed {
+			return content, nil
+		}
+	}
+}
+
+// get fetches the configuration, sending the ETag of the applied
+// configuration, and reports whether it differs from the applied one by ETag
+// or, when the server sends none, by content. The first fetch is applied
+// right away, being the configuration the process starts with.
+func (p *urlProvider) get(ctx context.Context) ([]byte, bool, error) {
+	req, err := p.request(ctx)
+	if err != nil {
+		return nil, false, err
+	}
+	p.mu.Lock()
+	previousETag, previous, fetched := p.etag, p.content, p.fetched
+	p.mu.Unlock()
+	if fetched && previousETag != "" {
+		req.Header.Set("If-None-Match", previousETag)
+	}
+
+	resp, err := configClient.Do(req)
+	if err != nil {
+		return nil, false, fmt.Errorf("failed to fetch %s: %w", redactLocation(p.location), err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode == http.StatusNotModified && fetched {
+		return previous, false, nil
+	}
+	if resp.StatusCode != http.StatusOK {
+		detail, _ := io.ReadAll(io.LimitReader(resp.Body, 1024))
+		return nil, false, fmt.Errorf("failed to fetch %s: status %d: %s", redactLocation(p.location), resp.StatusCode, strings.TrimSpace(string(detail)))
+	}
+	content, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return nil, false, fmt.Errorf("failed to read %s: %w", redactLocation(p.location), err)
+	}
+
+	etag := resp.Header.Get("ETag")
+	changed := !fetched
+	if fetched {
+		if etag != "" && previousETag != "" {
+			changed = etag != previousETag
+		} else {
+			changed = !bytes.Equal(content, previous)
+		}
+	}
+	p.mu.Lock()
+	if p.fetched {
+		p.latestETag, p.latestContent, p.pending = etag, content, true
+	} else {
+		p.etag, p.content, p.fetched = etag, content, true
+	}
+	p.mu.Unlock()
+	return content, changed, nil
+}
+
+// request builds the GET request for the configuration. s3:// objects are
+// signed with the AWS credentials of the environment, and https:// requests
+// carry HYPERTERSE_CONFIG_TOKEN as a bearer token when set. The token is never
+// sent over plain http://, where anyone on the path could read it.
+func (p *urlProvider) request(ctx context.Context) (*http.Request, error) {
+	if p.u.Scheme == "s3" {
+		key := strings.TrimPrefix(p.u.Path, "/")
+		if p.u.Host == "" || key == "" {
+			return nil, fmt.Errorf("config URL '%s' must be s3://bucket/key", p.location)
+		}
+		return connectors.NewS3GetRequest(ctx, p.u.Host, key)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, p.location, nil)
+	if err != nil {
+		return nil, err
+	}
+	if token := os.Getenv(EnvConfigToken); token != "" && p.u.Scheme == "https" {
+		req.Header.Set("Authorization", "Bearer "+token)
+	}
+	return req, nil
+}
+
+// redactLocation hides credentials written in a config URL
+func redactLocation(location string) string {
+	if u, err := url.Parse(location); err == nil {
+		return u.Redacted()
+	}
+	return location
+}
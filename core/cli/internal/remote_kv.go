@@ -0,0 +1,336 @@
+package internal
+
+import (
+	"bytes"
+	"context"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"net/url"
+	"os"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+// consulWait is how long a Consul blocking query waits for a change
+const consulWait = 5 * time.Minute
+
+// consulProvider reads a configuration from a Consul KV key and watches it
+// with blocking queries. CONSUL_HTTP_TOKEN is sent as the ACL token when set.
+//
+//	consul://127.0.0.1:8500/hyperterse/prod/api.terse
+type consulProvider struct {
+	base string // e.g. http://127.0.0.1:8500/v1/kv/hyperterse/prod/api.terse
+	key  string
+
+	mu      sync.Mutex
+	index   uint64
+	content []byte
+}
+
+func newConsulProvider(u *url.URL) (configProvider, error) {
+	key := strings.TrimPrefix(u.Path, "/")
+	if key == "" {
+		return nil, fmt.Errorf("config URL must be consul://host:port/key")
+	}
+	return &consulProvider{base: kvEndpoint(u, "8500") + "/v1/kv/" + key, key: key}, nil
+}
+
+func (p *consulProvider) polls() bool { return false }
+
+// accept does nothing: watches block on the index of the last change,
+// which must advance whether or not the reload succeeded
+func (p *consulProvider) accept() {}
+
+func (p *consulProvider) fetch(ctx context.Context) ([]byte, error) {
+	content, index, err := p.get(ctx, 0)
+	if err != nil {
+		return nil, err
+	}
+	p.mu.Lock()
+	p.index, p.content = index, content
+	p.mu.Unlock()
+	return content, nil
+}
+
+func (p *consulProvider) watch(ctx context.Context, _ time.Duration) ([]byte, error) {
+	for {
+		p.mu.Lock()
+		index, previous := p.index, p.content
+		p.mu.Unlock()
+
+		content, newIndex, err := p.get(ctx, index)
+		if err != nil {
+			return nil, err
+		}
+		// Consul may reset the index, e.g. after a snapshot restore; start over
+		if newIndex < index {
+			newIndex = 0
+		}
+		p.mu.Lock()
+		p.index, p.content = newIndex, content
+		p.mu.Unlock()
+		if newIndex != index && !bytes.Equal(content, previous) {
+			return content, nil
+		}
+	}
+}
+
+// get reads the key, blocking until its index passes index when index is set
+func (p *consulProvider) get(ctx context.Context, index uint64) ([]byte, uint64, error) {
+	query := url.Values{"raw": {""}}
+	if index > 0 {
+		query.Set("index", strconv.FormatUint(index, 10))
+		query.Set("wait", consulWait.String())
+	} else {
+		var cancel context.CancelFunc
+		ctx, cancel = context.WithTimeout(ctx, remoteConfigTimeout)
+		defer cancel()
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, p.base+"?"+query.Encode(), nil)
+	if err != nil {
+		return nil, 0, err
+	}
+	if token := os.Getenv("CONSUL_HTTP_TOKEN"); token != "" {
+		req.Header.Set("X-Consul-Token", token)
+	}
+
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		return nil, 0, fmt.Errorf("failed to read consul key %s: %w", p.key, err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode == http.StatusNotFound {
+		return nil, 0, fmt.Errorf("consul key %s does not exist", p.key)
+	}
+	if resp.StatusCode != http.StatusOK {
+		detail, _ := io.ReadAll(io.LimitReader(resp.Body, 1024))
+		return nil, 0, fmt.Errorf("failed to read consul key %s: status %d: %s", p.key, resp.StatusCode, strings.TrimSpace(string(detail)))
+	}
+	content, err := io.ReadAll(resp.Body)
+	if err != nil {
+		return nil, 0, fmt.Errorf("failed to read consul key %s: %w", p.key, err)
+	}
+	newIndex, _ := strconv.ParseUint(resp.Header.Get("X-Consul-Index"), 10, 64)
+	return content, newIndex, nil
+}
+
+// etcdProvider reads a configuration from an etcd key through the v3 JSON
+// gateway and watches it with a watch stream. The key is the URL path, and
+// credentials in the URL authenticate with etcd's user authentication.
+//
+//	etcd://127.0.0.1:2379/hyperterse/prod/api.terse
+type etcdProvider struct {
+	base string // e.g. http://127.0.0.1:2379
+	key  string
+	user *url.Userinfo
+
+	mu       sync.Mutex
+	revision int64
+	content  []byte
+}
+
+func newEtcdProvider(u *url.URL) (configProvider, error) {
+	if u.Path == "" || u.Path == "/" {
+		return nil, fmt.Errorf("config URL must be etcd://host:port/key")
+	}
+	return &etcdProvider{base: kvEndpoint(u, "2379"), key: u.Path, user: u.User}, nil
+}
+
+func (p *etcdProvider) polls() bool { return false }
+
+// accept does nothing: watches block on the revision of the last change,
+// which must advance whether or not the reload succeeded
+func (p *etcdProvider) accept() {}
+
+// etcdKeyValue is a key-value pair of the gateway's JSON encoding. Bytes are
+// base64 encoded and 64-bit integers are strings.
+type etcdKeyValue struct {
+	Value       string `json:"value"`
+	ModRevision string `json:"mod_revision"`
+}
+
+func (p *etcdProvider) fetch(ctx context.Context) ([]byte, error) {
+	ctx, cancel := context.WithTimeout(ctx, remoteConfigTimeout)
+	defer cancel()
+
+	var response struct {
+		Header struct {
+			Revision string `json:"revision"`
+		} `json:"header"`
+		Kvs []etcdKeyValue `json:"kvs"`
+	}
+	if err := p.call(ctx, "/v3/kv/range", map[string]any{"key": etcdBytes(p.key)}, &response); err != nil {
+		return nil, err
+	}
+	if len(response.Kvs) == 0 {
+		return nil, fmt.Errorf("etcd key %s does not exist", p.key)
+	}
+	content, err := base64.StdEncoding.DecodeString(response.Kvs[0].Value)
+	if err != nil {
+		return nil, fmt.Errorf("etcd key %s has an invalid value: %w", p.key, err)
+	}
+	revision, _ := strconv.ParseInt(response.Header.Revision, 10, 64)
+	p.mu.Lock()
+	p.revision, p.content = revision, content
+	p.mu.Unlock()
+	return content, nil
+}
+
+func (p *etcdProvider) watch(ctx context.Context, _ time.Duration) ([]byte, error) {
+	p.mu.Lock()
+	revision, previous := p.revision, p.content
+	p.mu.Unlock()
+	if revision == 0 {
+		// Never read, e.g. when startup used the last good copy; read it now
+		return p.fetch(ctx)
+	}
+
+	body := map[string]any{"create_request": map[string]any{
+		"key":            etcdBytes(p.key),
+		"start_revision": strconv.FormatInt(revision+1, 10),
+	}}
+	resp, err := p.post(ctx, "/v3/watch", body)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	decoder := json.NewDecoder(resp.Body)
+	for {
+		var message struct {
+			Result struct {
+				CompactRevision string `json:"compact_revision"`
+				Canceled        bool   `json:"canceled"`
+				CancelReason    string `json:"cancel_reason"`
+				Events          []struct {
+					Type string       `json:"type"`
+					Kv   etcdKeyValue `json:"kv"`
+				} `json:"events"`
+			} `json:"result"`
+		}
+		if err := decoder.Decode(&message); err != nil {
+			return nil, fmt.Errorf("etcd watch of %s ended: %w", p.key, err)
+		}
+		result := message.Result
+		if result.CompactRevision != "" && result.CompactRevision != "0" {
+			// Revisions since the last fetch were compacted away; read the key again
+			content, err := p.fetch(ctx)
+			if err != nil {
+				return nil, err
+			}
+			if !bytes.Equal(content, previous) {
+				return content, nil
+			}
+			return p.watch(ctx, 0)
+		}
+		if result.Canceled {
+			return nil, fmt.Errorf("etcd watch of %s canceled: %s", p.key, result.CancelReason)
+		}
+		for _, event := range result.Events {
+			modRevision, _ := strconv.ParseInt(event.Kv.ModRevision, 10, 64)
+			p.mu.Lock()
+			p.revision = modRevision
+			p.mu.Unlock()
+			if event.Type == "DELETE" {
+				return nil, fmt.Errorf("etcd key %s was deleted", p.key)
+			}
+			content, err := base64.StdEncoding.DecodeString(event.Kv.Value)
+			if err != nil {
+				return nil, fmt.Errorf("etcd key %s has an invalid value: %w", p.key, err)
+			}
+			if !bytes.Equal(content, previous) {
+				p.mu.Lock()
+				p.content = content
+				p.mu.Unlock()
+				return content, nil
+			}
+		}
+	}
+}
+
+// call posts a JSON request to the gateway and decodes the response
+func (p *etcdProvider) call(ctx context.Context, path string, body any, response any) error {
+	resp, err := p.post(ctx, path, body)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+	if err := json.NewDecoder(resp.Body).Decode(response); err != nil {
+		return fmt.Errorf("invalid etcd response: %w", err)
+	}
+	return nil
+}
+
+// post sends a JSON request to the gateway, authenticating first when the URL
+// has credentials
+func (p *etcdProvider) post(ctx context.Context, path string, body any) (*http.Response, error) {
+	token := ""
+	if p.user != nil {
+		password, _ := p.user.Password()
+		var auth struct {
+			Token string `json:"token"`
+		}
+		credentials := map[string]string{"name": p.user.Username(), "password": password}
+		resp, err := p.send(ctx, "/v3/auth/authenticate", credentials, "")
+		if err != nil {
+			return nil, err
+		}
+		err = json.NewDecoder(resp.Body).Decode(&auth)
+		resp.Body.Close()
+		if err != nil || auth.Token == "" {
+			return nil, fmt.Errorf("etcd authentication failed")
+		}
+		token = auth.Token
+	}
+	return p.send(ctx, path, body, token)
+}
+
+func (p *etcdProvider) send(ctx context.Context, path string, body any, token string) (*http.Response, error) {
+	payload, err := json.Marshal(body)
+	if err != nil {
+		return nil, err
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, p.base+path, bytes.NewReader(payload))
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	if token != "" {
+		req.Header.Set("Authorization", token)
+	}
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		return nil, fmt.Errorf("etcd request failed: %w", err)
+	}
+	if resp.StatusCode != http.StatusOK {
+		defer resp.Body.Close()
+		detail, _ := io.ReadAll(io.LimitReader(resp.Body, 1024))
+		return nil, fmt.Errorf("etcd request failed with status %d: %s", resp.StatusCode, strings.TrimSpace(string(detail)))
+	}
+	return resp, nil
+}
+
+// etcdBytes encodes a key as the gateway expects bytes fields
+func etcdBytes(value string) string {
+	return base64.StdEncoding.EncodeToString([]byte(value))
+}
+
+// kvEndpoint returns the HTTP base URL of a consul:// or etcd:// location,
+// using https for the +https schemes and defaultPort when none is given
+func kvEndpoint(u *url.URL, defaultPort string) string {
+	scheme := "http"
+	if strings.HasSuffix(u.Scheme, "+https") {
+		scheme = "https"
+	}
+	host := u.Host
+	if u.Port() == "" {
+		host = net.JoinHostPort(u.Hostname(), defaultPort)
+	}
+	return scheme + "://" + host
+}
@@ -0,0 +1,118 @@
+package internal
+
+import (
+	"fmt"
+	"os"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// Server settings are resolved from, in order: CLI flags, environment
+// variables, the configuration file, then defaults. Each environment variable
+// below overrides its configuration field so one configuration can be deployed
+// with a different port or log level per environment.
+const (
+	EnvPort      = "HYPERTERSE_PORT"       // Overrides server.port
+	EnvLogLevel  = "HYPERTERSE_LOG_LEVEL"  // Overrides server.log_level
+	EnvLogFormat = "HYPERTERSE_LOG_FORMAT" // Overrides server.log_format
+)
+
+// PortAuto is the server.port value that picks the next free port from 8080
+const PortAuto = "auto"
+
+// ResolvePort resolves the port from CLI flag, HYPERTERSE_PORT or PORT, config
+// file, or default. PORT is the variable set by most hosting platforms.
+func ResolvePort(cliPort string, model *hyperterse.Model) string {
+	if cliPort != "" {
+		return cliPort
+	}
+	if port := os.Getenv(EnvPort); port != "" {
+		return port
+	}
+	if port := os.Getenv("PORT"); port != "" {
+		return port
+	}
+	if model != nil && model.Server != nil && model.Server.Port != "" {
+		return model.Server.Port
+	}
+	return "8080"
+}
+
+// ResolveLogLevel resolves the log level from verbose flag, CLI flag,
+// HYPERTERSE_LOG_LEVEL, config file, or default. The model may be nil before
+// the configuration is loaded.
+func ResolveLogLevel(verbose bool, cliLogLevel int, model *hyperterse.Model) (int, error) {
+	if verbose {
+		return logger.LogLevelDebug, nil
+	}
+	if cliLogLevel > 0 {
+		return cliLogLevel, nil
+	}
+	if raw := os.Getenv(EnvLogLevel); raw != "" {
+		level, err := strconv.Atoi(strings.TrimSpace(raw))
+		if err != nil || level < logger.LogLevelError || level > logger.LogLevelDebug {
+			return 0, fmt.Errorf("%s '%s' must be a number from %d to %d", EnvLogLevel, raw, logger.LogLevelError, logger.LogLevelDebug)
+		}
+		return level, nil
+	}
+	if model != nil && model.Server != nil && model.Server.LogLevel > 0 {
+		return int(model.Server.LogLevel), nil
+	}
+	return logger.LogLevelInfo, nil
+}
+
+// ResolveLogFormat resolves the log format from CLI flag, HYPERTERSE_LOG_FORMAT,
+// config file, or default
+func ResolveLogFormat(cliLogFormat string, model *hyperterse.Model) (string, error) {
+	if cliLogFormat != "" {
+		if !slices.Contains(types.LogFormats, cliLogFormat) {
+			return "", fmt.Errorf("--log-format '%s' must be one of: %s", cliLogFormat, strings.Join(types.LogFormats, ", "))
+		}
+		return cliLogFormat, nil
+	}
+	if raw := os.Getenv(EnvLogFormat); raw != "" {
+		if !slices.Contains(types.LogFormats, raw) {
+			return "", fmt.Errorf("%s '%s' must be one of: %s", EnvLogFormat, raw, strings.Join(types.LogFormats, ", "))
+		}
+		return raw, nil
+	}
+	if model != nil && model.Server != nil && model.Server.LogFormat != "" {
+		return model.Server.LogFormat, nil
+	}
+	return types.LogFormatText, nil
+}
+
+// ResolveWorkerThreads resolves the worker thread count from CLI flag or config file (0 = runtime default)
+func ResolveWorkerThreads(cliWorkers int, model *hyperterse.Model) int {
+	if cliWorkers > 0 {
+		return cliWorkers
+	}
+	if model != nil && model.Server != nil && model.Server.WorkerThreads > 0 {
+		return int(model.Server.WorkerThreads)
+	}
+	return 0
+}
+
+// ResolveMaxThreads resolves the OS thread limit from CLI flag or config file (0 = runtime default)
+func ResolveMaxThreads(cliMaxThreads int, model *hyperterse.Model) int {
+	if cliMaxThreads > 0 {
+		return cliMaxThreads
+	}
+	if model != nil && model.Server != nil && model.Server.MaxThreads > 0 {
+		return int(model.Server.MaxThreads)
+	}
+	return 0
+}
+
+// ResolveOTLPEndpoint resolves the OTLP endpoint from CLI/env/default.
+func ResolveOTLPEndpoint() string {
+	if envEndpoint := os.Getenv("HYPERTERSE_OTEL_ENDPOINT"); envEndpoint != "" {
+		return envEndpoint
+	}
+	return "localhost:4317"
+}
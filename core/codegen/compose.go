@@ -0,0 +1,328 @@
+package codegen
+
+import (
+	"fmt"
+	"net"
+	"net/url"
+	"regexp"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+// composePlaceholderPattern matches a value that is a single {{ env.NAME }}
+// placeholder
+var composePlaceholderPattern = regexp.MustCompile(`^\s*\{\{\s*env\.(\w+)\s*\}\}\s*$`)
+
+// ComposeOptions configures the generated Compose file
+type ComposeOptions struct {
+	Bundle        string // Exported bundle path, relative to the Compose file
+	WithDatabases bool   // Add a service for each database adapter
+}
+
+// composeDatabase describes the container run for a database connector
+type composeDatabase struct {
+	image       string
+	port        string
+	data        string            // Volume mount path
+	environment func(user, password, database string) [][2]string
+	healthcheck func(user, password, database string) []string
+	scheme      string
+	query       string // Connection URL query, e.g. to disable TLS
+	database    string // Default database, used when the adapter names none
+	credentials bool   // Whether the image creates a user from its environment
+}
+
+// composeDatabases holds the connectors that get a database service with
+// --with-databases
+var composeDatabases = map[connectors.Connector]composeDatabase{
+	connectors.Connector_CONNECTOR_POSTGRES: {
+		image: "postgres:16",
+		port:  "5432",
+		data:  "/var/lib/postgresql/data",
+		environment: func(user, password, database string) [][2]string {
+			return [][2]string{{"POSTGRES_USER", user}, {"POSTGRES_PASSWORD", password}, {"POSTGRES_DB", database}}
+		},
+		healthcheck: func(user, password, database string) []string {
+			return []string{"CMD", "pg_isready", "-U", user, "-d", database}
+		},
+		scheme:      "postgres",
+		query:       "sslmode=disable",
+		credentials: true,
+	},
+	connectors.Connector_CONNECTOR_MYSQL: {
+		image: "mysql:8.4",
+		port:  "3306",
+		data:  "/var/lib/mysql",
+		environment: func(user, password, database string) [][2]string {
+			return [][2]string{{"MYSQL_USER", user}, {"MYSQL_PASSWORD", password}, {"MYSQL_DATABASE", database}, {"MYSQL_ROOT_PASSWORD", password}}
+		},
+		healthcheck: func(user, password, database string) []string {
+			return []string{"CMD", "mysqladmin", "ping", "-h", "127.0.0.1", "-u", user, "-p" + password}
+		},
+		scheme:      "mysql",
+		credentials: true,
+	},
+	connectors.Connector_CONNECTOR_REDIS: {
+		image: "redis:7",
+		port:  "6379",
+		data:  "/data",
+		healthcheck: func(user, password, database string) []string {
+			return []string{"CMD", "redis-cli", "ping"}
+		},
+		scheme:   "redis",
+		database: "0",
+	},
+	connectors.Connector_CONNECTOR_MONGODB: {
+		image: "mongo:7",
+		port:  "27017",
+		data:  "/data/db",
+		environment: func(user, password, database string) [][2]string {
+			return [][2]string{{"MONGO_INITDB_ROOT_USERNAME", user}, {"MONGO_INITDB_ROOT_PASSWORD", password}, {"MONGO_INITDB_DATABASE", database}}
+		},
+		healthcheck: func(user, password, database string) []string {
+			return []string{"CMD", "mongosh", "--quiet", "--eval", "db.adminCommand('ping')"}
+		},
+		scheme:      "mongodb",
+		query:       "authSource=admin",
+		credentials: true,
+	},
+}
+
+// composeService is a database service generated for an adapter
+type composeService struct {
+	name     string
+	adapter  string
+	database composeDatabase
+	user     string
+	password string
+	dbName   string
+}
+
+// DockerCompose generates a Docker Compose file running the exported bundle of
+// model. Every environment variable the configuration reads is passed through
+// from the shell or the .env file next to the Compose file. With
+// WithDatabases, each postgres, mysql, redis and mongodb adapter whose
+// connection is read from environment variables also gets a database service
+// with a volume and a healthcheck, and those variables are set to reach it.
+// The returned warnings name the database adapters that could not be wired.
+func DockerCompose(model *hyperterse.Model, options ComposeOptions) (string, []string) {
+	port := "8080"
+	basePath := ""
+	if model.Server != nil {
+		if model.Server.Port != "" && model.Server.Port != "auto" {
+			port = model.Server.Port
+		}
+		basePath = model.Server.BasePath
+	}
+
+	// Variables set by the generated database services
+	wired := make(map[string]string)
+	var services []composeService
+	var warnings []string
+	if options.WithDatabases {
+		for _, adapter := range model.Adapters {
+			database, ok := composeDatabases[adapter.Connector]
+			if !ok {
+				continue
+			}
+			service, values, err := composeAdapterService(adapter, database)
+			if err != nil {
+				warnings = append(warnings, fmt.Sprintf("adapter '%s' was not given a database service: %s", adapter.Name, err))
+				continue
+			}
+			services = append(services, service)
+			for name, value := range values {
+				wired[name] = value
+			}
+		}
+	}
+
+	var sb strings.Builder
+	fmt.Fprintf(&sb, "# Docker Compose file for %s.\n", model.Name)
+	sb.WriteString("# Generated by 'hyperterse generate compose'. Export the bundle for linux first:\n")
+	fmt.Fprintf(&sb, "#   hyperterse export -f <config> -o %s\n", composeDir(options.Bundle))
+	sb.WriteString("\nservices:\n")
+
+	fmt.Fprintf(&sb, "  %s:\n", model.Name)
+	sb.WriteString("    build:\n")
+	sb.WriteString("      context: .\n")
+	sb.WriteString("      dockerfile_inline: |\n")
+	sb.WriteString("        FROM alpine:3.19\n")
+	sb.WriteString("        RUN apk --no-cache add bash ca-certificates\n")
+	sb.WriteString("        WORKDIR /app\n")
+	fmt.Fprintf(&sb, "        COPY %s /app/%s\n", options.Bundle, model.Name)
+	fmt.Fprintf(&sb, "        RUN chmod +x /app/%s\n", model.Name)
+	fmt.Fprintf(&sb, "        CMD [\"/app/%s\"]\n", model.Name)
+	sb.WriteString("    ports:\n")
+	fmt.Fprintf(&sb, "      - %s\n", composeString(port+":"+port))
+	sb.WriteString("    environment:\n")
+	fmt.Fprintf(&sb, "      HYPERTERSE_PORT: %s\n", composeString(port))
+	for _, reference := range parser.EnvReferences(model) {
+		if value, ok := wired[reference.Name]; ok {
+			fmt.Fprintf(&sb, "      %s: %s\n", reference.Name, composeString(value))
+		} else {
+			fmt.Fprintf(&sb, "      %s: \"${%s}\"\n", reference.Name, reference.Name)
+		}
+	}
+	if len(services) > 0 {
+		sb.WriteString("    depends_on:\n")
+		for _, service := range services {
+			fmt.Fprintf(&sb, "      %s:\n", service.name)
+			sb.WriteString("        condition: service_healthy\n")
+		}
+	}
+	sb.WriteString("    healthcheck:\n")
+	fmt.Fprintf(&sb, "      test: %s\n", composeList([]string{"CMD", "wget", "-q", "-O", "-", "http://localhost:" + port + basePath + "/heartbeat"}))
+	composeHealthTimings(&sb)
+	sb.WriteString("    restart: unless-stopped\n")
+
+	for _, service := range services {
+		fmt.Fprintf(&sb, "\n  # Database for adapter '%s'\n", service.adapter)
+		fmt.Fprintf(&sb, "  %s:\n", service.name)
+		fmt.Fprintf(&sb, "    image: %s\n", service.database.image)
+		if service.database.environment != nil {
+			sb.WriteString("    environment:\n")
+			for _, pair := range service.database.environment(service.user, service.password, service.dbName) {
+				fmt.Fprintf(&sb, "      %s: %s\n", pair[0], composeString(pair[1]))
+			}
+		}
+		sb.WriteString("    volumes:\n")
+		fmt.Fprintf(&sb, "      - %s-data:%s\n", service.name, service.database.data)
+		sb.WriteString("    healthcheck:\n")
+		fmt.Fprintf(&sb, "      test: %s\n", composeList(service.database.healthcheck(service.user, service.password, service.dbName)))
+		composeHealthTimings(&sb)
+		sb.WriteString("    restart: unless-stopped\n")
+	}
+
+	if len(services) > 0 {
+		sb.WriteString("\nvolumes:\n")
+		for _, service := range services {
+			fmt.Fprintf(&sb, "  %s-data:\n", service.name)
+		}
+	}
+
+	return sb.String(), warnings
+}
+
+// composeAdapterService builds the database service for an adapter and the
+// environment variable values that point the adapter at it. The connection
+// must be read from environment variables: either a connection_string that is
+// a single placeholder, or connection fields whose host is a placeholder.
+func composeAdapterService(adapter *hyperterse.Adapter, database composeDatabase) (composeService, map[string]string, error) {
+	service := composeService{
+		name:     strings.ReplaceAll(adapter.Name, "_", "-"),
+		adapter:  adapter.Name,
+		database: database,
+		user:     "hyperterse",
+		password: "hyperterse",
+		dbName:   database.database,
+	}
+	if service.dbName == "" {
+		service.dbName = adapter.Name
+	}
+	if !database.credentials {
+		service.user, service.password = "", ""
+	}
+	values := make(map[string]string)
+
+	if fields := adapter.Connection; fields != nil && fields.Host != "" {
+		host, ok := composePlaceholder(fields.Host)
+		if !ok {
+			return service, nil, fmt.Errorf("connection.host is not a single {{ env.NAME }} placeholder")
+		}
+		values[host] = service.name
+		if fields.Port != "" {
+			name, ok := composePlaceholder(fields.Port)
+			if !ok && fields.Port != database.port {
+				return service, nil, fmt.Errorf("connection.port is set to %s, not the image port %s", fields.Port, database.port)
+			}
+			if ok {
+				values[name] = database.port
+			}
+		}
+		// Literal credentials and database names are used for the container
+		for _, field := range []struct {
+			raw    string
+			target *string
+		}{
+			{fields.User, &service.user},
+			{fields.Password, &service.password},
+			{fields.Database, &service.dbName},
+		} {
+			if field.raw == "" {
+				continue
+			}
+			if name, ok := composePlaceholder(field.raw); ok {
+				values[name] = *field.target
+			} else if field.target != &service.dbName && !database.credentials {
+				return service, nil, fmt.Errorf("the %s image runs without credentials", database.image)
+			} else {
+				*field.target = field.raw
+			}
+		}
+		if name, ok := composePlaceholder(fields.Ssl); ok {
+			values[name] = "false"
+		}
+		return service, values, nil
+	}
+
+	name, ok := composePlaceholder(adapter.ConnectionString)
+	if !ok {
+		return service, nil, fmt.Errorf("connection_string is not a single {{ env.NAME }} placeholder")
+	}
+	u := &url.URL{
+		Scheme:   database.scheme,
+		Host:     net.JoinHostPort(service.name, database.port),
+		Path:     "/" + service.dbName,
+		RawQuery: database.query,
+	}
+	if service.user != "" {
+		u.User = url.UserPassword(service.user, service.password)
+	}
+	values[name] = u.String()
+	return service, values, nil
+}
+
+// composePlaceholder returns the variable name when value is exactly one
+// {{ env.NAME }} placeholder
+func composePlaceholder(value string) (string, bool) {
+	match := composePlaceholderPattern.FindStringSubmatch(value)
+	if match == nil {
+		return "", false
+	}
+	return match[1], true
+}
+
+// composeHealthTimings writes the healthcheck timings shared by every service
+func composeHealthTimings(sb *strings.Builder) {
+	sb.WriteString("      interval: 10s\n")
+	sb.WriteString("      timeout: 5s\n")
+	sb.WriteString("      retries: 5\n")
+	sb.WriteString("      start_period: 10s\n")
+}
+
+// composeString quotes a value for the Compose file, doubling '$' so Compose
+// does not interpolate literal values
+func composeString(value string) string {
+	return yamlString(strings.ReplaceAll(value, "$", "$$"))
+}
+
+// composeList writes values as a YAML flow sequence
+func composeList(values []string) string {
+	quoted := make([]string, len(values))
+	for i, value := range values {
+		quoted[i] = composeString(value)
+	}
+	return "[" + strings.Join(quoted, ", ") + "]"
+}
+
+// composeDir returns the directory of the bundle path, for the export hint
+func composeDir(bundle string) string {
+	if i := strings.LastIndex(bundle, "/"); i != -1 {
+		return bundle[:i]
+	}
+	return "."
+}
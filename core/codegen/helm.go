@@ -0,0 +1,284 @@
+package codegen
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+// HelmChart generates a Helm chart deploying the exported bundle of model,
+// with the configuration file mounted from a ConfigMap or Secret so it can be
+// changed without rebuilding the image. It returns the chart's files keyed by
+// their path relative to the chart root.
+func HelmChart(model *hyperterse.Model, configName string, config []byte) map[string]string {
+	port := "8080"
+	basePath := ""
+	if model.Server != nil {
+		if model.Server.Port != "" && model.Server.Port != "auto" {
+			port = model.Server.Port
+		}
+		basePath = model.Server.BasePath
+	}
+
+	replacer := strings.NewReplacer("<config>", configName, "<base_path>", basePath, "<name>", model.Name)
+	return map[string]string{
+		"Chart.yaml":                helmChartManifest(model),
+		"values.yaml":               helmValues(model, port),
+		"files/" + configName:       string(config),
+		"templates/_helpers.tpl":    helmHelpersTemplate,
+		"templates/deployment.yaml": replacer.Replace(helmDeploymentTemplate),
+		"templates/service.yaml":    helmServiceTemplate,
+		"templates/config.yaml":     replacer.Replace(helmConfigTemplate),
+		"templates/secret.yaml":     helmSecretTemplate,
+		"templates/hpa.yaml":        helmHPATemplate,
+	}
+}
+
+// HelmChartName returns the chart name for model. Chart names may not contain
+// underscores, so snake_case config names are written in kebab-case.
+func HelmChartName(model *hyperterse.Model) string {
+	return strings.ReplaceAll(model.Name, "_", "-")
+}
+
+func helmChartManifest(model *hyperterse.Model) string {
+	appVersion := model.Version
+	if appVersion == "" {
+		appVersion = "latest"
+	}
+	return fmt.Sprintf(`apiVersion: v2
+name: %s
+description: %s
+type: application
+version: 0.1.0
+appVersion: %s
+`, HelmChartName(model), yamlString("Hyperterse query gateway "+model.Name), yamlString(appVersion))
+}
+
+func helmValues(model *hyperterse.Model, port string) string {
+	var sb strings.Builder
+	fmt.Fprintf(&sb, "# Values for the %s chart.\n", HelmChartName(model))
+	sb.WriteString("# Generated by 'hyperterse export --target helm'.\n\n")
+	sb.WriteString("replicaCount: 1\n\n")
+	sb.WriteString("image:\n")
+	sb.WriteString("  # Image running the exported bundle; see the Docker deployment guide\n")
+	fmt.Fprintf(&sb, "  repository: %s\n", model.Name)
+	sb.WriteString("  tag: latest\n")
+	sb.WriteString("  pullPolicy: IfNotPresent\n\n")
+	sb.WriteString("# Port the server listens on, passed as HYPERTERSE_PORT\n")
+	fmt.Fprintf(&sb, "port: %s\n\n", port)
+	sb.WriteString("service:\n")
+	sb.WriteString("  type: ClusterIP\n")
+	sb.WriteString("  port: 80\n\n")
+	sb.WriteString("config:\n")
+	sb.WriteString("  # Mount the configuration file from a Secret instead of a ConfigMap\n")
+	sb.WriteString("  secret: false\n\n")
+	sb.WriteString("# Environment variables set in plain text\n")
+	sb.WriteString("env: {}\n\n")
+	sb.WriteString("# Environment variables stored in a Secret created by the chart. The\n")
+	sb.WriteString("# configuration reads each variable listed here.\n")
+	references := parser.EnvReferences(model)
+	if len(references) == 0 {
+		sb.WriteString("secrets: {}\n\n")
+	} else {
+		sb.WriteString("secrets:\n")
+		for _, reference := range references {
+			fmt.Fprintf(&sb, "  # %s\n", strings.Join(reference.Fields, ", "))
+			fmt.Fprintf(&sb, "  %s: \"\"\n", reference.Name)
+		}
+		sb.WriteString("\n")
+	}
+	sb.WriteString("# Existing Secret to read environment variables from instead of secrets\n")
+	sb.WriteString("existingSecret: \"\"\n\n")
+	sb.WriteString("resources: {}\n\n")
+	sb.WriteString("autoscaling:\n")
+	sb.WriteString("  enabled: false\n")
+	sb.WriteString("  minReplicas: 1\n")
+	sb.WriteString("  maxReplicas: 5\n")
+	sb.WriteString("  targetCPUUtilizationPercentage: 80\n")
+	return sb.String()
+}
+
+// yamlString quotes a value as a YAML double-quoted scalar. JSON strings are
+// valid YAML double-quoted scalars.
+func yamlString(value string) string {
+	quoted, _ := json.Marshal(value)
+	return string(quoted)
+}
+
+const helmHelpersTemplate = `{{- define "hyperterse.fullname" -}}
+{{- if .Values.fullnameOverride -}}
+{{- .Values.fullnameOverride | trunc 63 | trimSuffix "-" -}}
+{{- else if contains .Chart.Name .Release.Name -}}
+{{- .Release.Name | trunc 63 | trimSuffix "-" -}}
+{{- else -}}
+{{- printf "%s-%s" .Release.Name .Chart.Name | trunc 63 | trimSuffix "-" -}}
+{{- end -}}
+{{- end -}}
+
+{{- define "hyperterse.selectorLabels" -}}
+app.kubernetes.io/name: {{ .Chart.Name }}
+app.kubernetes.io/instance: {{ .Release.Name }}
+{{- end -}}
+
+{{- define "hyperterse.labels" -}}
+helm.sh/chart: {{ printf "%s-%s" .Chart.Name .Chart.Version | replace "+" "_" | trunc 63 | trimSuffix "-" }}
+{{ include "hyperterse.selectorLabels" . }}
+app.kubernetes.io/version: {{ .Chart.AppVersion | quote }}
+app.kubernetes.io/managed-by: {{ .Release.Service }}
+{{- end -}}
+
+{{- define "hyperterse.envSecretName" -}}
+{{- .Values.existingSecret | default (printf "%s-env" (include "hyperterse.fullname" .)) -}}
+{{- end -}}
+`
+
+// helmDeploymentTemplate runs the bundle at /app/<name>, where the documented
+// image copies it, and passes --file so the mounted configuration replaces the
+// embedded one. The image sets CMD rather than ENTRYPOINT, so args alone would
+// replace the bundle path.
+const helmDeploymentTemplate = `apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: {{ include "hyperterse.fullname" . }}
+  labels:
+    {{- include "hyperterse.labels" . | nindent 4 }}
+spec:
+  {{- if not .Values.autoscaling.enabled }}
+  replicas: {{ .Values.replicaCount }}
+  {{- end }}
+  selector:
+    matchLabels:
+      {{- include "hyperterse.selectorLabels" . | nindent 6 }}
+  template:
+    metadata:
+      labels:
+        {{- include "hyperterse.selectorLabels" . | nindent 8 }}
+      annotations:
+        checksum/config: {{ include (print $.Template.BasePath "/config.yaml") . | sha256sum }}
+    spec:
+      containers:
+        - name: {{ .Chart.Name }}
+          image: "{{ .Values.image.repository }}:{{ .Values.image.tag }}"
+          imagePullPolicy: {{ .Values.image.pullPolicy }}
+          command: ["/app/<name>"]
+          args: ["--file", "/etc/hyperterse/<config>"]
+          ports:
+            - name: http
+              containerPort: {{ .Values.port }}
+          env:
+            - name: HYPERTERSE_PORT
+              value: {{ .Values.port | quote }}
+            {{- range $name, $value := .Values.env }}
+            - name: {{ $name }}
+              value: {{ $value | quote }}
+            {{- end }}
+          {{- if or .Values.existingSecret .Values.secrets }}
+          envFrom:
+            - secretRef:
+                name: {{ include "hyperterse.envSecretName" . }}
+          {{- end }}
+          volumeMounts:
+            - name: config
+              mountPath: /etc/hyperterse
+              readOnly: true
+          livenessProbe:
+            httpGet:
+              path: <base_path>/heartbeat
+              port: http
+          readinessProbe:
+            httpGet:
+              path: <base_path>/health/ready
+              port: http
+          resources:
+            {{- toYaml .Values.resources | nindent 12 }}
+      volumes:
+        - name: config
+          {{- if .Values.config.secret }}
+          secret:
+            secretName: {{ include "hyperterse.fullname" . }}-config
+          {{- else }}
+          configMap:
+            name: {{ include "hyperterse.fullname" . }}-config
+          {{- end }}
+`
+
+const helmServiceTemplate = `apiVersion: v1
+kind: Service
+metadata:
+  name: {{ include "hyperterse.fullname" . }}
+  labels:
+    {{- include "hyperterse.labels" . | nindent 4 }}
+spec:
+  type: {{ .Values.service.type }}
+  ports:
+    - name: http
+      port: {{ .Values.service.port }}
+      targetPort: http
+  selector:
+    {{- include "hyperterse.selectorLabels" . | nindent 4 }}
+`
+
+const helmConfigTemplate = `{{- if .Values.config.secret }}
+apiVersion: v1
+kind: Secret
+metadata:
+  name: {{ include "hyperterse.fullname" . }}-config
+  labels:
+    {{- include "hyperterse.labels" . | nindent 4 }}
+type: Opaque
+data:
+  <config>: {{ .Files.Get "files/<config>" | b64enc }}
+{{- else }}
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: {{ include "hyperterse.fullname" . }}-config
+  labels:
+    {{- include "hyperterse.labels" . | nindent 4 }}
+data:
+  <config>: |-
+    {{- .Files.Get "files/<config>" | nindent 4 }}
+{{- end }}
+`
+
+const helmSecretTemplate = `{{- if and .Values.secrets (not .Values.existingSecret) }}
+apiVersion: v1
+kind: Secret
+metadata:
+  name: {{ include "hyperterse.envSecretName" . }}
+  labels:
+    {{- include "hyperterse.labels" . | nindent 4 }}
+type: Opaque
+stringData:
+  {{- range $name, $value := .Values.secrets }}
+  {{ $name }}: {{ $value | quote }}
+  {{- end }}
+{{- end }}
+`
+
+const helmHPATemplate = `{{- if .Values.autoscaling.enabled }}
+apiVersion: autoscaling/v2
+kind: HorizontalPodAutoscaler
+metadata:
+  name: {{ include "hyperterse.fullname" . }}
+  labels:
+    {{- include "hyperterse.labels" . | nindent 4 }}
+spec:
+  scaleTargetRef:
+    apiVersion: apps/v1
+    kind: Deployment
+    name: {{ include "hyperterse.fullname" . }}
+  minReplicas: {{ .Values.autoscaling.minReplicas }}
+  maxReplicas: {{ .Values.autoscaling.maxReplicas }}
+  metrics:
+    - type: Resource
+      resource:
+        name: cpu
+        target:
+          type: Utilization
+          averageUtilization: {{ .Values.autoscaling.targetCPUUtilizationPercentage }}
+{{- end }}
+`
@@ -0,0 +1,456 @@
+package codegen
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+	"unicode"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// rustTypes maps primitive type names to the Rust types used for them.
+// Datetimes and UUIDs are sent as strings so the crate needs no extra
+// dependencies.
+var rustTypes = map[string]string{
+	"string":   "String",
+	"int":      "i64",
+	"float":    "f64",
+	"boolean":  "bool",
+	"datetime": "String",
+	"uuid":     "String",
+}
+
+// rustKeywords are the identifiers that must be written as raw identifiers
+var rustKeywords = map[string]bool{
+	"as": true, "async": true, "await": true, "break": true, "const": true, "continue": true,
+	"crate": true, "dyn": true, "else": true, "enum": true, "extern": true, "false": true,
+	"fn": true, "for": true, "if": true, "impl": true, "in": true, "let": true, "loop": true,
+	"match": true, "mod": true, "move": true, "mut": true, "pub": true, "ref": true,
+	"return": true, "static": true, "struct": true, "trait": true, "true": true,
+	"type": true, "unsafe": true, "use": true, "where": true, "while": true, "abstract": true,
+	"become": true, "box": true, "do": true, "final": true, "gen": true, "macro": true,
+	"override": true, "priv": true, "try": true, "typeof": true, "unsized": true,
+	"virtual": true, "yield": true,
+}
+
+// RustClient generates a Rust crate with a typed client for the query
+// endpoints of model. It returns the crate's files keyed by their path
+// relative to the crate root.
+func RustClient(model *hyperterse.Model, crateName string) map[string]string {
+	return map[string]string{
+		"Cargo.toml":     rustCargoManifest(crateName),
+		"src/lib.rs":     strings.ReplaceAll(rustLibSource, "{{name}}", model.Name),
+		"src/queries.rs": rustQueriesSource(model),
+	}
+}
+
+func rustCargoManifest(crateName string) string {
+	return fmt.Sprintf(`[package]
+name = %q
+version = "0.1.0"
+edition = "2021"
+description = "Typed client for the Hyperterse query API"
+
+[dependencies]
+reqwest = { version = "0.12", default-features = false, features = ["json", "rustls-tls"] }
+serde = { version = "1", features = ["derive"] }
+serde_json = "1"
+`, crateName)
+}
+
+// rustLibSource holds the client, response and error types shared by every
+// generated crate
+const rustLibSource = `//! Typed client for the {{name}} Hyperterse API.
+//!
+//! Generated by hyperterse generate client. Do not edit.
+
+mod queries;
+
+pub use queries::*;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Client for the query endpoints of a Hyperterse server
+#[derive(Clone, Debug)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a client for the server at base_url, e.g. "http://localhost:8080"
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_http_client(base_url, reqwest::Client::new())
+    }
+
+    /// Creates a client sending requests through http, for example one built
+    /// with default headers or timeouts
+    pub fn with_http_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self { http, base_url }
+    }
+
+    async fn execute<I, R>(&self, query: &str, inputs: &I) -> Result<QueryResponse<R>, Error>
+    where
+        I: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let response = self
+            .http
+            .post(format!("{}/query/{}", self.base_url, query))
+            .json(inputs)
+            .send()
+            .await
+            .map_err(Error::Transport)?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        let body = response.bytes().await.map_err(Error::Transport)?;
+
+        if status.is_success() {
+            return serde_json::from_slice(&body).map_err(Error::Decode);
+        }
+
+        let ErrorBody { error: message, code, constraint } = serde_json::from_slice(&body)
+            .unwrap_or_else(|_| ErrorBody {
+                error: String::from_utf8_lossy(&body).into_owned(),
+                code: String::new(),
+                constraint: None,
+            });
+        Err(match status.as_u16() {
+            400 => Error::InvalidInput { message },
+            409 => Error::Conflict { message, constraint },
+            422 => Error::ConstraintViolation { message, code, constraint },
+            429 => Error::RateLimited { message, retry_after },
+            500 => Error::Query { message },
+            503 => Error::Unavailable { message, retry_after },
+            504 => Error::Timeout { message },
+            status => Error::Http { status, message },
+        })
+    }
+}
+
+/// Rows returned by a query
+#[derive(Clone, Debug, Deserialize)]
+pub struct QueryResponse<R> {
+    /// Result rows
+    pub results: Vec<R>,
+    /// Rows were cut off at the server's max_rows limit
+    #[serde(default)]
+    pub truncated: bool,
+    /// Cursor of the next page, present when a query with a cursor has more rows
+    #[serde(default)]
+    pub next_cursor: Option<String>,
+    /// Execution details, present when the server enables include_meta
+    #[serde(default)]
+    pub meta: Option<ResponseMeta>,
+}
+
+/// Execution details of a query response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResponseMeta {
+    pub query_name: String,
+    pub duration_ms: f64,
+    pub row_count: i32,
+    pub cache_hit: bool,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    constraint: Option<String>,
+}
+
+/// Errors returned by query methods
+#[derive(Debug)]
+pub enum Error {
+    /// The server rejected the inputs (HTTP 400)
+    InvalidInput { message: String },
+    /// A write violated a unique constraint (HTTP 409). constraint is set when
+    /// the server exposes constraint names.
+    Conflict { message: String, constraint: Option<String> },
+    /// A write violated a foreign key, not-null or check constraint (HTTP 422).
+    /// code names the kind of constraint.
+    ConstraintViolation { message: String, code: String, constraint: Option<String> },
+    /// The client exceeded the server's rate limit (HTTP 429). retry_after is
+    /// the number of seconds until requests are accepted again.
+    RateLimited { message: String, retry_after: Option<u64> },
+    /// The query failed to execute on the server (HTTP 500)
+    Query { message: String },
+    /// The server is in maintenance mode or the database is unavailable
+    /// (HTTP 503). retry_after is the number of seconds to wait when given.
+    Unavailable { message: String, retry_after: Option<u64> },
+    /// The query timed out (HTTP 504)
+    Timeout { message: String },
+    /// The server answered with another unsuccessful status
+    Http { status: u16, message: String },
+    /// The request could not be sent or its response could not be read
+    Transport(reqwest::Error),
+    /// The response body did not match the expected shape
+    Decode(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidInput { message } => write!(f, "invalid input: {message}"),
+            Error::Conflict { message, .. } => write!(f, "conflict: {message}"),
+            Error::ConstraintViolation { message, .. } => write!(f, "constraint violation: {message}"),
+            Error::RateLimited { message, .. } => write!(f, "rate limited: {message}"),
+            Error::Query { message } => write!(f, "query failed: {message}"),
+            Error::Unavailable { message, .. } => write!(f, "unavailable: {message}"),
+            Error::Timeout { message } => write!(f, "timed out: {message}"),
+            Error::Http { status, message } => write!(f, "HTTP {status}: {message}"),
+            Error::Transport(err) => write!(f, "request failed: {err}"),
+            Error::Decode(err) => write!(f, "invalid response: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(err) => Some(err),
+            Error::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+`
+
+// rustQueriesSource generates the input and row types of every query and the
+// Client method calling it
+func rustQueriesSource(model *hyperterse.Model) string {
+	var sb strings.Builder
+
+	keyCase := ""
+	if model.Server != nil {
+		keyCase = model.Server.ResultKeyCase
+	}
+
+	var methods strings.Builder
+	for _, query := range model.Queries {
+		typeName := rustTypeName(query.Name)
+		inputsType := typeName + "Inputs"
+		rowType := typeName + "Row"
+
+		// Inputs
+		if len(query.Inputs) > 0 {
+			allOptional := true
+			for _, input := range query.Inputs {
+				allOptional = allOptional && input.Optional
+			}
+			derives := "Clone, Debug, Serialize"
+			if allOptional {
+				derives = "Clone, Debug, Default, Serialize"
+			}
+			fmt.Fprintf(&sb, "\n/// Inputs of %s\n#[derive(%s)]\npub struct %s {\n", query.Name, derives, inputsType)
+			fields := newRustFieldNames()
+			for _, input := range query.Inputs {
+				writeRustDoc(&sb, "    ", rustInputDoc(input))
+				field := fields.ident(input.Name)
+				if strings.TrimPrefix(field, "r#") != input.Name {
+					fmt.Fprintf(&sb, "    #[serde(rename = %q)]\n", input.Name)
+				}
+				fieldType := rustTypes[types.PrimitiveEnumToString(input.Type)]
+				if input.Optional {
+					sb.WriteString("    #[serde(skip_serializing_if = \"Option::is_none\")]\n")
+					fieldType = "Option<" + fieldType + ">"
+				}
+				fmt.Fprintf(&sb, "    pub %s: %s,\n", field, fieldType)
+			}
+			if query.Cursor != nil {
+				writeRustDoc(&sb, "    ", fmt.Sprintf("next_cursor of the previous page, in place of %s", query.Cursor.Input))
+				sb.WriteString("    #[serde(skip_serializing_if = \"Option::is_none\")]\n")
+				fmt.Fprintf(&sb, "    pub %s: Option<String>,\n", fields.ident(utils.CursorParam))
+			}
+			sb.WriteString("}\n")
+		}
+
+		// Rows are typed when the query declares its data columns
+		if len(query.Data) > 0 {
+			fmt.Fprintf(&sb, "\n/// Row returned by %s\n#[derive(Clone, Debug, Deserialize)]\npub struct %s {\n", query.Name, rowType)
+			fields := newRustFieldNames()
+			for _, data := range query.Data {
+				key := utils.ConvertKey(data.Name, keyCase)
+				writeRustDoc(&sb, "    ", data.Description)
+				field := fields.ident(key)
+				if strings.TrimPrefix(field, "r#") != key {
+					fmt.Fprintf(&sb, "    #[serde(rename = %q)]\n", key)
+				}
+				// Columns may be null unless nulls: fill replaces null with a default
+				fieldType := rustTypes[types.PrimitiveEnumToString(data.Type)]
+				if query.Nulls != utils.NullsFill || data.DefaultValue == "" {
+					fieldType = "Option<" + fieldType + ">"
+				}
+				fmt.Fprintf(&sb, "    pub %s: %s,\n", field, fieldType)
+			}
+			sb.WriteString("}\n")
+		} else {
+			fmt.Fprintf(&sb, "\n/// Row returned by %s\npub type %s = serde_json::Map<String, serde_json::Value>;\n", query.Name, rowType)
+		}
+
+		// Client method
+		methods.WriteString("\n")
+		writeRustDoc(&methods, "    ", query.Description)
+		method := rustIdent(rustSnakeCase(query.Name))
+		if len(query.Inputs) > 0 {
+			fmt.Fprintf(&methods, "    pub async fn %s(&self, inputs: &%s) -> Result<QueryResponse<%s>, Error> {\n", method, inputsType, rowType)
+			fmt.Fprintf(&methods, "        self.execute(%q, inputs).await\n", query.Name)
+		} else {
+			fmt.Fprintf(&methods, "    pub async fn %s(&self) -> Result<QueryResponse<%s>, Error> {\n", method, rowType)
+			fmt.Fprintf(&methods, "        self.execute(%q, &serde_json::Map::new()).await\n", query.Name)
+		}
+		methods.WriteString("    }\n")
+	}
+
+	// Only import the derives that are used, so the crate builds without warnings
+	var derives []string
+	if strings.Contains(sb.String(), "Deserialize)]") {
+		derives = append(derives, "Deserialize")
+	}
+	if strings.Contains(sb.String(), "Serialize)]") {
+		derives = append(derives, "Serialize")
+	}
+
+	var out strings.Builder
+	out.WriteString("//! Inputs, rows and methods of each query. Generated; do not edit.\n\n")
+	out.WriteString("use crate::{Client, Error, QueryResponse};\n")
+	switch len(derives) {
+	case 1:
+		fmt.Fprintf(&out, "use serde::%s;\n", derives[0])
+	case 2:
+		out.WriteString("use serde::{Deserialize, Serialize};\n")
+	}
+	out.WriteString(sb.String())
+	out.WriteString("\nimpl Client {")
+	out.WriteString(methods.String())
+	out.WriteString("}\n")
+	return out.String()
+}
+
+// rustInputDoc describes an input, including its server-side default
+func rustInputDoc(input *hyperterse.Input) string {
+	doc := input.Description
+	switch types.SchemaForPrimitive(types.PrimitiveEnumToString(input.Type)).Format {
+	case "date-time":
+		doc = strings.TrimSpace(doc + "\n\nRFC 3339 timestamp.")
+	case "uuid":
+		doc = strings.TrimSpace(doc + "\n\nUUID string.")
+	}
+	if input.Optional {
+		doc = strings.TrimSpace(fmt.Sprintf("%s\n\nDefaults to %s when omitted.", doc, input.DefaultValue))
+	}
+	if len(input.Examples) > 0 {
+		examples := make([]string, len(input.Examples))
+		for i, value := range types.ExampleValues(input.Examples, types.PrimitiveEnumToString(input.Type)) {
+			encoded, _ := json.Marshal(value)
+			examples[i] = "`" + string(encoded) + "`"
+		}
+		label := "Example"
+		if len(examples) > 1 {
+			label = "Examples"
+		}
+		doc = strings.TrimSpace(fmt.Sprintf("%s\n\n%s: %s.", doc, label, strings.Join(examples, ", ")))
+	}
+	return doc
+}
+
+// writeRustDoc writes text as /// doc comment lines
+func writeRustDoc(sb *strings.Builder, indent, text string) {
+	text = strings.TrimSpace(text)
+	if text == "" {
+		return
+	}
+	for _, line := range strings.Split(text, "\n") {
+		line = strings.TrimRight(line, " \t")
+		if line == "" {
+			fmt.Fprintf(sb, "%s///\n", indent)
+		} else {
+			fmt.Fprintf(sb, "%s/// %s\n", indent, line)
+		}
+	}
+}
+
+// rustTypeName converts a query name to a Rust type name, e.g. get-user to GetUser
+func rustTypeName(name string) string {
+	var sb strings.Builder
+	for _, part := range strings.Split(rustSnakeCase(name), "_") {
+		if part == "" {
+			continue
+		}
+		runes := []rune(part)
+		sb.WriteRune(unicode.ToUpper(runes[0]))
+		sb.WriteString(string(runes[1:]))
+	}
+	return sb.String()
+}
+
+// rustSnakeCase converts a name to snake_case, replacing characters that are
+// not valid in identifiers with underscores
+func rustSnakeCase(name string) string {
+	var sb strings.Builder
+	runes := []rune(name)
+	for i, r := range runes {
+		switch {
+		case unicode.IsUpper(r):
+			if i > 0 && (unicode.IsLower(runes[i-1]) || unicode.IsDigit(runes[i-1])) {
+				sb.WriteRune('_')
+			}
+			sb.WriteRune(unicode.ToLower(r))
+		case unicode.IsLetter(r) || unicode.IsDigit(r):
+			sb.WriteRune(r)
+		default:
+			sb.WriteRune('_')
+		}
+	}
+	ident := strings.Trim(sb.String(), "_")
+	if ident == "" || unicode.IsDigit([]rune(ident)[0]) {
+		ident = "_" + ident
+	}
+	return ident
+}
+
+// rustIdent returns ident, written as a raw identifier when it is a keyword
+func rustIdent(ident string) string {
+	switch ident {
+	case "self", "Self", "super", "crate":
+		return ident + "_"
+	}
+	if rustKeywords[ident] {
+		return "r#" + ident
+	}
+	return ident
+}
+
+// rustFieldNames hands out unique field identifiers within one struct
+type rustFieldNames map[string]bool
+
+func newRustFieldNames() rustFieldNames {
+	return make(rustFieldNames)
+}
+
+// ident returns the field identifier for name, suffixed with a number when
+// another field already converted to the same identifier
+func (names rustFieldNames) ident(name string) string {
+	base := rustSnakeCase(name)
+	ident := base
+	for i := 2; names[ident]; i++ {
+		ident = fmt.Sprintf("%s_%d", base, i)
+	}
+	names[ident] = true
+	return rustIdent(ident)
+}
@@ -10,6 +10,7 @@ import (
 	"io"
 	"os"
 	"path/filepath"
+	"runtime"
 	"strconv"
 	"strings"
 	"sync"
@@ -181,6 +182,16 @@ func shouldLogTag(tag string) bool {
 	return true
 }
 
+// TempDir returns the directory Hyperterse keeps log and port files in:
+// /tmp/.hyperterse, or .hyperterse in the user's temporary directory on
+// Windows, which has no /tmp
+func TempDir() string {
+	if runtime.GOOS == "windows" {
+		return filepath.Join(os.TempDir(), ".hyperterse")
+	}
+	return "/tmp/.hyperterse"
+}
+
 // SetLogFile enables log file streaming with auto-generated filename
 // Returns the file path if successful, or error if failed
 func SetLogFile() (string, error) {
@@ -188,7 +199,7 @@ func SetLogFile() (string, error) {
 	defer logFileMutex.Unlock()
 
 	// Create log directory
-	logDir := "/tmp/.hyperterse/logs"
+	logDir := filepath.Join(TempDir(), "logs")
 	if err := os.MkdirAll(logDir, 0755); err != nil {
 		return "", fmt.Errorf("failed to create log directory: %w", err)
 	}
@@ -212,6 +223,14 @@ func SetLogFile() (string, error) {
 	return filePath, nil
 }
 
+// SetOutput redirects log output to the given writer.
+// Used when stdout is reserved for a protocol stream (e.g. the language server).
+func SetOutput(w io.Writer) {
+	logFileMutex.Lock()
+	defer logFileMutex.Unlock()
+	logWriter = w
+}
+
 // generateLogFileHash generates a short hash for log filename
 func generateLogFileHash() string {
 	// Combine timestamp, PID, and random bytes
@@ -0,0 +1,477 @@
+package lsp
+
+import (
+	"errors"
+	"fmt"
+	"net/url"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/parser"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/types"
+	"gopkg.in/yaml.v3"
+)
+
+var (
+	// lineRefPattern extracts "line N" references from YAML decoder and duplicate-key errors
+	lineRefPattern = regexp.MustCompile(`line (\d+)`)
+	// quotedNamePattern extracts 'name' references from validation errors
+	quotedNamePattern = regexp.MustCompile(`'([^']+)'`)
+	// keyPrefixPattern matches "key: partial" at the cursor
+	keyPrefixPattern = regexp.MustCompile(`^\s*(?:-\s*)?([A-Za-z_][A-Za-z0-9_-]*):\s*\S*$`)
+	// listItemPrefixPattern matches "- partial" at the cursor
+	listItemPrefixPattern = regexp.MustCompile(`^(\s*)-\s*\S*$`)
+)
+
+// document is an open text document along with the index of its last successful parse
+type document struct {
+	uri   string
+	text  string
+	lines []string
+	index *documentIndex
+}
+
+// documentIndex records where adapters, queries, and inputs are defined
+type documentIndex struct {
+	adapters     map[string]*adapterIndex
+	adapterOrder []string
+	queries      []*queryIndex
+}
+
+type adapterIndex struct {
+	name      string
+	connector string
+	key       *yaml.Node
+}
+
+type queryIndex struct {
+	name        string
+	description string
+	key         *yaml.Node
+	startLine   int // 1-based, inclusive
+	endLine     int // 1-based, inclusive
+	use         []*yaml.Node
+	inputs      map[string]*inputIndex
+	inputOrder  []string
+}
+
+type inputIndex struct {
+	name         string
+	typ          string
+	description  string
+	optional     bool
+	defaultValue string
+	key          *yaml.Node
+}
+
+func newDocument(uri, text string) *document {
+	doc := &document{uri: uri, index: &documentIndex{adapters: make(map[string]*adapterIndex)}}
+	doc.update(text)
+	return doc
+}
+
+// update replaces the document text. The index is only replaced when the new
+// text parses, so completion keeps working while the user is mid-edit.
+func (d *document) update(text string) {
+	d.text = text
+	d.lines = strings.Split(text, "\n")
+	if index, ok := buildIndex(text); ok {
+		d.index = index
+	}
+}
+
+// format returns the configuration format implied by the document URI
+func (d *document) format() parser.Format {
+	path := d.uri
+	if parsed, err := url.Parse(d.uri); err == nil && parsed.Path != "" {
+		path = parsed.Path
+	}
+	return parser.DetectFormat(path)
+}
+
+func (d *document) line(n int) string {
+	if n < 0 || n >= len(d.lines) {
+		return ""
+	}
+	return strings.TrimRight(d.lines[n], "\r")
+}
+
+// diagnostics parses and validates the document, returning one diagnostic per error
+func (d *document) diagnostics() []diagnostic {
+	model, err := parser.ParseWithFormat([]byte(d.text), d.format())
+	if err != nil {
+		return d.errorDiagnostics(err)
+	}
+	if err := parser.Validate(model); err != nil {
+		return d.errorDiagnostics(err)
+	}
+	return []diagnostic{}
+}
+
+func (d *document) errorDiagnostics(err error) []diagnostic {
+	var validationErr *parser.ValidationErrors
+	if errors.As(err, &validationErr) {
+		diags := make([]diagnostic, 0, len(validationErr.Errors))
+		for _, msg := range validationErr.Errors {
+			diags = append(diags, d.diagnosticFor(msg))
+		}
+		return diags
+	}
+	return []diagnostic{d.diagnosticFor(err.Error())}
+}
+
+// diagnosticFor places an error message on the most specific line it can find:
+// an explicit "line N" reference, then a named query or adapter, then the first line
+func (d *document) diagnosticFor(msg string) diagnostic {
+	line := 0
+	if match := lineRefPattern.FindStringSubmatch(msg); match != nil {
+		if n, err := strconv.Atoi(match[1]); err == nil && n > 0 {
+			line = n - 1
+		}
+	} else if key := d.locate(msg); key != nil {
+		line = key.Line - 1
+	}
+	return diagnostic{
+		Range:    d.lineRange(line),
+		Severity: severityError,
+		Source:   "hyperterse",
+		Message:  msg,
+	}
+}
+
+// locate finds the definition a validation message refers to
+func (d *document) locate(msg string) *yaml.Node {
+	var candidates []string
+	for _, match := range quotedNamePattern.FindAllStringSubmatch(msg, -1) {
+		candidates = append(candidates, match[1])
+	}
+	// Messages like "get-user.description is required" lead with the query name
+	if head, _, ok := strings.Cut(msg, "."); ok && !strings.Contains(head, " ") {
+		candidates = append(candidates, head)
+	}
+
+	for _, name := range candidates {
+		for _, q := range d.index.queries {
+			if q.name == name {
+				return q.key
+			}
+		}
+		if adapter, ok := d.index.adapters[name]; ok {
+			return adapter.key
+		}
+	}
+	return nil
+}
+
+func (d *document) lineRange(line int) textRange {
+	return textRange{
+		Start: position{Line: line, Character: 0},
+		End:   position{Line: line, Character: len(d.line(line))},
+	}
+}
+
+func (d *document) nodeRange(node *yaml.Node) textRange {
+	start := position{Line: node.Line - 1, Character: node.Column - 1}
+	return textRange{
+		Start: start,
+		End:   position{Line: start.Line, Character: start.Character + len(node.Value)},
+	}
+}
+
+// queryAt returns the query whose block contains the given 0-based line
+func (d *document) queryAt(line int) *queryIndex {
+	for _, q := range d.index.queries {
+		if line+1 >= q.startLine && line+1 <= q.endLine {
+			return q
+		}
+	}
+	return nil
+}
+
+// completion suggests values based on the text before the cursor
+func (d *document) completion(pos position) []completionItem {
+	line := d.line(pos.Line)
+	prefix := line
+	if pos.Character < len(line) {
+		prefix = line[:pos.Character]
+	}
+
+	// Template expressions inside statements
+	if open := strings.LastIndex(prefix, "{{"); open >= 0 && !strings.Contains(prefix[open:], "}}") {
+		expr := strings.TrimSpace(prefix[open+2:])
+		if strings.HasPrefix(expr, "inputs.") {
+			return d.inputCompletions(d.queryAt(pos.Line))
+		}
+		if name, ok := strings.CutPrefix(expr, "fn."); ok {
+			// Inside a call's argument list, arguments are input references
+			if open := strings.LastIndexAny(name, "(,"); open >= 0 {
+				if strings.HasPrefix(strings.TrimSpace(name[open+1:]), "inputs.") {
+					return d.inputCompletions(d.queryAt(pos.Line))
+				}
+				return []completionItem{{Label: "inputs", Kind: completionKindVariable, Detail: "Query input reference"}}
+			}
+			items := make([]completionItem, 0)
+			for _, function := range executorutils.FunctionNames() {
+				items = append(items, completionItem{Label: function, Kind: completionKindFunction, Detail: "Template function"})
+			}
+			return items
+		}
+		return []completionItem{
+			{Label: "inputs", Kind: completionKindVariable, Detail: "Query input reference"},
+			{Label: "env", Kind: completionKindVariable, Detail: "Environment variable reference"},
+			{Label: "fn", Kind: completionKindVariable, Detail: "Template function"},
+		}
+	}
+
+	key := ""
+	if match := keyPrefixPattern.FindStringSubmatch(prefix); match != nil {
+		key = match[1]
+	} else if match := listItemPrefixPattern.FindStringSubmatch(prefix); match != nil {
+		key = d.parentKey(pos.Line, len(match[1]))
+	}
+
+	switch key {
+	case "connector":
+		items := make([]completionItem, 0)
+		for _, connector := range types.GetValidConnectors() {
+			items = append(items, completionItem{Label: connector, Kind: completionKindEnum, Detail: "Connector"})
+		}
+		return items
+	case "type":
+		items := make([]completionItem, 0)
+		for _, primitive := range types.GetValidPrimitives() {
+			items = append(items, completionItem{Label: primitive, Kind: completionKindEnum, Detail: "Primitive type"})
+		}
+		return items
+	case "use":
+		items := make([]completionItem, 0, len(d.index.adapterOrder))
+		for _, name := range d.index.adapterOrder {
+			adapter := d.index.adapters[name]
+			items = append(items, completionItem{Label: name, Kind: completionKindField, Detail: adapter.connector + " adapter"})
+		}
+		return items
+	}
+
+	return []completionItem{}
+}
+
+func (d *document) inputCompletions(q *queryIndex) []completionItem {
+	items := make([]completionItem, 0)
+	if q == nil {
+		return items
+	}
+	for _, name := range q.inputOrder {
+		input := q.inputs[name]
+		items = append(items, completionItem{
+			Label:         name,
+			Kind:          completionKindVariable,
+			Detail:        input.typ,
+			Documentation: input.description,
+		})
+	}
+	return items
+}
+
+// parentKey finds the nearest preceding "key:" line indented less than indent
+func (d *document) parentKey(lineNum int, indent int) string {
+	for i := lineNum - 1; i >= 0; i-- {
+		line := d.line(i)
+		trimmed := strings.TrimSpace(line)
+		if trimmed == "" || strings.HasPrefix(trimmed, "#") {
+			continue
+		}
+		lineIndent := len(line) - len(strings.TrimLeft(line, " \t"))
+		if lineIndent < indent || (lineIndent == indent && !strings.HasPrefix(trimmed, "-")) {
+			if key, _, ok := strings.Cut(trimmed, ":"); ok {
+				return strings.TrimSpace(key)
+			}
+			return ""
+		}
+	}
+	return ""
+}
+
+// wordAt returns the identifier-like token under the cursor
+func (d *document) wordAt(pos position) string {
+	line := d.line(pos.Line)
+	if pos.Character > len(line) {
+		return ""
+	}
+	isWordChar := func(c byte) bool {
+		return c == '_' || c == '-' || c == '.' ||
+			(c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9')
+	}
+	start, end := pos.Character, pos.Character
+	for start > 0 && isWordChar(line[start-1]) {
+		start--
+	}
+	for end < len(line) && isWordChar(line[end]) {
+		end++
+	}
+	return strings.Trim(line[start:end], ".")
+}
+
+// hover returns markdown documentation for the query input, query, or adapter under the cursor
+func (d *document) hover(pos position) string {
+	word := d.wordAt(pos)
+	if word == "" {
+		return ""
+	}
+	q := d.queryAt(pos.Line)
+
+	if name, ok := strings.CutPrefix(word, "inputs."); ok && q != nil {
+		if input, ok := q.inputs[name]; ok {
+			return formatInputHover(input)
+		}
+	}
+	if q != nil {
+		if input, ok := q.inputs[word]; ok && input.key.Line-1 == pos.Line {
+			return formatInputHover(input)
+		}
+		if q.name == word && q.key.Line-1 == pos.Line {
+			return fmt.Sprintf("**query** `%s`\n\n%s", q.name, q.description)
+		}
+	}
+	if adapter, ok := d.index.adapters[word]; ok {
+		return fmt.Sprintf("**adapter** `%s`\n\nConnector: `%s`", adapter.name, adapter.connector)
+	}
+	return ""
+}
+
+func formatInputHover(input *inputIndex) string {
+	var sb strings.Builder
+	sb.WriteString(fmt.Sprintf("**input** `%s`: `%s`", input.name, input.typ))
+	if input.optional {
+		sb.WriteString(" (optional)")
+	}
+	if input.description != "" {
+		sb.WriteString("\n\n" + input.description)
+	}
+	if input.defaultValue != "" {
+		sb.WriteString(fmt.Sprintf("\n\nDefault: `%s`", input.defaultValue))
+	}
+	return sb.String()
+}
+
+// definition resolves "use:" adapter references and {{ inputs.x }} references
+func (d *document) definition(pos position) *location {
+	word := d.wordAt(pos)
+	if word == "" {
+		return nil
+	}
+	q := d.queryAt(pos.Line)
+	if q == nil {
+		return nil
+	}
+
+	if name, ok := strings.CutPrefix(word, "inputs."); ok {
+		if input, ok := q.inputs[name]; ok {
+			return &location{URI: d.uri, Range: d.nodeRange(input.key)}
+		}
+		return nil
+	}
+
+	for _, useNode := range q.use {
+		if useNode.Line-1 == pos.Line && useNode.Value == word {
+			if adapter, ok := d.index.adapters[word]; ok {
+				return &location{URI: d.uri, Range: d.nodeRange(adapter.key)}
+			}
+		}
+	}
+	return nil
+}
+
+// buildIndex walks the YAML node tree to record definition positions
+func buildIndex(text string) (*documentIndex, bool) {
+	var root yaml.Node
+	if err := yaml.Unmarshal([]byte(text), &root); err != nil || len(root.Content) == 0 {
+		return nil, false
+	}
+	top := root.Content[0]
+	if top.Kind != yaml.MappingNode {
+		return nil, false
+	}
+
+	index := &documentIndex{adapters: make(map[string]*adapterIndex)}
+
+	if adapters := mappingValue(top, "adapters"); adapters != nil && adapters.Kind == yaml.MappingNode {
+		for i := 0; i+1 < len(adapters.Content); i += 2 {
+			key, value := adapters.Content[i], adapters.Content[i+1]
+			adapter := &adapterIndex{name: key.Value, key: key}
+			if connector := mappingValue(value, "connector"); connector != nil {
+				adapter.connector = connector.Value
+			}
+			if _, exists := index.adapters[key.Value]; !exists {
+				index.adapterOrder = append(index.adapterOrder, key.Value)
+			}
+			index.adapters[key.Value] = adapter
+		}
+	}
+
+	if queries := mappingValue(top, "queries"); queries != nil && queries.Kind == yaml.MappingNode {
+		for i := 0; i+1 < len(queries.Content); i += 2 {
+			key, value := queries.Content[i], queries.Content[i+1]
+			q := &queryIndex{
+				name:      key.Value,
+				key:       key,
+				startLine: key.Line,
+				endLine:   int(^uint(0) >> 1),
+				inputs:    make(map[string]*inputIndex),
+			}
+			if len(index.queries) > 0 {
+				index.queries[len(index.queries)-1].endLine = key.Line - 1
+			}
+			if description := mappingValue(value, "description"); description != nil {
+				q.description = description.Value
+			}
+			if use := mappingValue(value, "use"); use != nil {
+				switch use.Kind {
+				case yaml.ScalarNode:
+					q.use = append(q.use, use)
+				case yaml.SequenceNode:
+					q.use = append(q.use, use.Content...)
+				}
+			}
+			if inputs := mappingValue(value, "inputs"); inputs != nil && inputs.Kind == yaml.MappingNode {
+				for j := 0; j+1 < len(inputs.Content); j += 2 {
+					inputKey, inputValue := inputs.Content[j], inputs.Content[j+1]
+					input := &inputIndex{name: inputKey.Value, key: inputKey}
+					if typ := mappingValue(inputValue, "type"); typ != nil {
+						input.typ = typ.Value
+					}
+					if description := mappingValue(inputValue, "description"); description != nil {
+						input.description = description.Value
+					}
+					if optional := mappingValue(inputValue, "optional"); optional != nil {
+						input.optional = optional.Value == "true"
+					}
+					if defaultValue := mappingValue(inputValue, "default"); defaultValue != nil {
+						input.defaultValue = defaultValue.Value
+					}
+					if _, exists := q.inputs[inputKey.Value]; !exists {
+						q.inputOrder = append(q.inputOrder, inputKey.Value)
+					}
+					q.inputs[inputKey.Value] = input
+				}
+			}
+			index.queries = append(index.queries, q)
+		}
+	}
+
+	return index, true
+}
+
+// mappingValue returns the value node for key in a mapping node
+func mappingValue(node *yaml.Node, key string) *yaml.Node {
+	if node == nil || node.Kind != yaml.MappingNode {
+		return nil
+	}
+	for i := 0; i+1 < len(node.Content); i += 2 {
+		if node.Content[i].Value == key {
+			return node.Content[i+1]
+		}
+	}
+	return nil
+}
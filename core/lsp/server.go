@@ -0,0 +1,337 @@
+package lsp
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/textproto"
+	"strconv"
+	"strings"
+	"sync"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+// JSON-RPC 2.0 error codes used by the language server
+const (
+	codeParseError     = -32700
+	codeMethodNotFound = -32601
+	codeInvalidParams  = -32602
+)
+
+// LSP enum values
+const (
+	textDocumentSyncFull = 1
+
+	severityError = 1
+
+	completionKindFunction = 3
+	completionKindField    = 5
+	completionKindVariable = 6
+	completionKindEnum     = 13
+)
+
+// Server is a minimal Language Server Protocol implementation for Hyperterse
+// configuration files. It speaks JSON-RPC 2.0 with Content-Length framing over
+// the given reader and writer (normally stdin/stdout).
+type Server struct {
+	reader    *bufio.Reader
+	writer    io.Writer
+	writeMu   sync.Mutex
+	documents map[string]*document
+	shutdown  bool
+}
+
+type message struct {
+	JSONRPC string          `json:"jsonrpc"`
+	ID      json.RawMessage `json:"id,omitempty"`
+	Method  string          `json:"method"`
+	Params  json.RawMessage `json:"params,omitempty"`
+}
+
+type responseError struct {
+	Code    int    `json:"code"`
+	Message string `json:"message"`
+}
+
+type position struct {
+	Line      int `json:"line"`
+	Character int `json:"character"`
+}
+
+type textRange struct {
+	Start position `json:"start"`
+	End   position `json:"end"`
+}
+
+type location struct {
+	URI   string    `json:"uri"`
+	Range textRange `json:"range"`
+}
+
+type diagnostic struct {
+	Range    textRange `json:"range"`
+	Severity int       `json:"severity"`
+	Source   string    `json:"source"`
+	Message  string    `json:"message"`
+}
+
+type completionItem struct {
+	Label         string `json:"label"`
+	Kind          int    `json:"kind"`
+	Detail        string `json:"detail,omitempty"`
+	Documentation string `json:"documentation,omitempty"`
+}
+
+type markupContent struct {
+	Kind  string `json:"kind"`
+	Value string `json:"value"`
+}
+
+type hoverResult struct {
+	Contents markupContent `json:"contents"`
+}
+
+type textDocumentPositionParams struct {
+	TextDocument struct {
+		URI string `json:"uri"`
+	} `json:"textDocument"`
+	Position position `json:"position"`
+}
+
+// NewServer creates a language server reading requests from in and writing responses to out
+func NewServer(in io.Reader, out io.Writer) *Server {
+	return &Server{
+		reader:    bufio.NewReader(in),
+		writer:    out,
+		documents: make(map[string]*document),
+	}
+}
+
+// Run processes messages until the client sends "exit" or closes the stream
+func (s *Server) Run() error {
+	log := logger.New("lsp")
+	log.Infof("Language server started")
+
+	for {
+		body, err := s.readMessage()
+		if err == io.EOF {
+			return nil
+		}
+		if err != nil {
+			return log.Errorf("failed to read message: %w", err)
+		}
+
+		var msg message
+		if err := json.Unmarshal(body, &msg); err != nil {
+			s.replyError(nil, codeParseError, "Parse error")
+			continue
+		}
+
+		if msg.Method == "exit" {
+			log.Infof("Language server exiting")
+			return nil
+		}
+
+		s.handle(&msg)
+	}
+}
+
+func (s *Server) handle(msg *message) {
+	log := logger.New("lsp")
+	log.Debugf("Received %s", msg.Method)
+
+	switch msg.Method {
+	case "initialize":
+		s.reply(msg.ID, map[string]any{
+			"capabilities": map[string]any{
+				"textDocumentSync": textDocumentSyncFull,
+				"completionProvider": map[string]any{
+					"triggerCharacters": []string{" ", ".", ":"},
+				},
+				"hoverProvider":      true,
+				"definitionProvider": true,
+			},
+			"serverInfo": map[string]any{
+				"name": "hyperterse",
+			},
+		})
+
+	case "initialized":
+		// Notification, nothing to do
+
+	case "shutdown":
+		s.shutdown = true
+		s.reply(msg.ID, nil)
+
+	case "textDocument/didOpen":
+		var params struct {
+			TextDocument struct {
+				URI  string `json:"uri"`
+				Text string `json:"text"`
+			} `json:"textDocument"`
+		}
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return
+		}
+		doc := newDocument(params.TextDocument.URI, params.TextDocument.Text)
+		s.documents[doc.uri] = doc
+		s.publishDiagnostics(doc)
+
+	case "textDocument/didChange":
+		var params struct {
+			TextDocument struct {
+				URI string `json:"uri"`
+			} `json:"textDocument"`
+			ContentChanges []struct {
+				Text string `json:"text"`
+			} `json:"contentChanges"`
+		}
+		if err := json.Unmarshal(msg.Params, &params); err != nil || len(params.ContentChanges) == 0 {
+			return
+		}
+		// Full sync: the last change carries the complete document
+		text := params.ContentChanges[len(params.ContentChanges)-1].Text
+		doc, ok := s.documents[params.TextDocument.URI]
+		if !ok {
+			doc = newDocument(params.TextDocument.URI, text)
+			s.documents[doc.uri] = doc
+		} else {
+			doc.update(text)
+		}
+		s.publishDiagnostics(doc)
+
+	case "textDocument/didClose":
+		var params struct {
+			TextDocument struct {
+				URI string `json:"uri"`
+			} `json:"textDocument"`
+		}
+		if err := json.Unmarshal(msg.Params, &params); err != nil {
+			return
+		}
+		delete(s.documents, params.TextDocument.URI)
+		s.notify("textDocument/publishDiagnostics", map[string]any{
+			"uri":         params.TextDocument.URI,
+			"diagnostics": []diagnostic{},
+		})
+
+	case "textDocument/completion":
+		doc, params, ok := s.positionRequest(msg)
+		if !ok {
+			return
+		}
+		s.reply(msg.ID, doc.completion(params.Position))
+
+	case "textDocument/hover":
+		doc, params, ok := s.positionRequest(msg)
+		if !ok {
+			return
+		}
+		if contents := doc.hover(params.Position); contents != "" {
+			s.reply(msg.ID, hoverResult{Contents: markupContent{Kind: "markdown", Value: contents}})
+		} else {
+			s.reply(msg.ID, nil)
+		}
+
+	case "textDocument/definition":
+		doc, params, ok := s.positionRequest(msg)
+		if !ok {
+			return
+		}
+		if loc := doc.definition(params.Position); loc != nil {
+			s.reply(msg.ID, loc)
+		} else {
+			s.reply(msg.ID, nil)
+		}
+
+	default:
+		// Only requests (with an ID) get a method-not-found error; unknown notifications are ignored
+		if len(msg.ID) > 0 {
+			s.replyError(msg.ID, codeMethodNotFound, fmt.Sprintf("Method not found: %s", msg.Method))
+		}
+	}
+}
+
+// positionRequest decodes position-based request params and resolves the target document
+func (s *Server) positionRequest(msg *message) (*document, *textDocumentPositionParams, bool) {
+	var params textDocumentPositionParams
+	if err := json.Unmarshal(msg.Params, &params); err != nil {
+		s.replyError(msg.ID, codeInvalidParams, "Invalid params")
+		return nil, nil, false
+	}
+	doc, ok := s.documents[params.TextDocument.URI]
+	if !ok {
+		s.reply(msg.ID, nil)
+		return nil, nil, false
+	}
+	return doc, &params, true
+}
+
+func (s *Server) publishDiagnostics(doc *document) {
+	s.notify("textDocument/publishDiagnostics", map[string]any{
+		"uri":         doc.uri,
+		"diagnostics": doc.diagnostics(),
+	})
+}
+
+func (s *Server) reply(id json.RawMessage, result any) {
+	s.writeMessage(map[string]any{
+		"jsonrpc": "2.0",
+		"id":      id,
+		"result":  result,
+	})
+}
+
+func (s *Server) replyError(id json.RawMessage, code int, msg string) {
+	s.writeMessage(map[string]any{
+		"jsonrpc": "2.0",
+		"id":      id,
+		"error":   responseError{Code: code, Message: msg},
+	})
+}
+
+func (s *Server) notify(method string, params any) {
+	s.writeMessage(map[string]any{
+		"jsonrpc": "2.0",
+		"method":  method,
+		"params":  params,
+	})
+}
+
+// readMessage reads a single Content-Length framed message body
+func (s *Server) readMessage() ([]byte, error) {
+	headers, err := textproto.NewReader(s.reader).ReadMIMEHeader()
+	if err != nil {
+		return nil, err
+	}
+
+	lengthHeader := strings.TrimSpace(headers.Get("Content-Length"))
+	if lengthHeader == "" {
+		return nil, fmt.Errorf("missing Content-Length header")
+	}
+	length, err := strconv.Atoi(lengthHeader)
+	if err != nil {
+		return nil, fmt.Errorf("invalid Content-Length header: %w", err)
+	}
+
+	body := make([]byte, length)
+	if _, err := io.ReadFull(s.reader, body); err != nil {
+		return nil, err
+	}
+	return body, nil
+}
+
+func (s *Server) writeMessage(v any) {
+	body, err := json.Marshal(v)
+	if err != nil {
+		logger.New("lsp").Warnf("Failed to encode message: %v", err)
+		return
+	}
+
+	s.writeMu.Lock()
+	defer s.writeMu.Unlock()
+	fmt.Fprintf(s.writer, "Content-Length: %d\r\n\r\n", len(body))
+	s.writer.Write(body)
+}
@@ -5,22 +5,27 @@ import (
 )
 
 const (
-	AttrServiceName         = "service.name"
-	AttrServiceVersion      = "service.version"
-	AttrDeploymentEnv       = "deployment.environment"
-	AttrTraceID             = "trace_id"
-	AttrSpanID              = "span_id"
-	AttrRequestID           = "request.id"
-	AttrLogTag              = "log.tag"
-	AttrQueryName           = "query.name"
-	AttrAdapterName         = "adapter.name"
-	AttrConnectorType       = "connector.type"
-	AttrHTTPMethod          = "http.request.method"
-	AttrHTTPRoute           = "http.route"
-	AttrHTTPStatusCode      = "http.response.status_code"
-	AttrErrorType           = "error.type"
-	AttrErrorMessage        = "error.message"
-	AttrExceptionStacktrace = "exception.stacktrace"
+	AttrServiceName             = "service.name"
+	AttrServiceVersion          = "service.version"
+	AttrDeploymentEnv           = "deployment.environment"
+	AttrTraceID                 = "trace_id"
+	AttrSpanID                  = "span_id"
+	AttrRequestID               = "request.id"
+	AttrLogTag                  = "log.tag"
+	AttrQueryName               = "query.name"
+	AttrQueryCoalesced          = "query.coalesced"
+	AttrQueueWaitMS             = "query.queue_wait_ms"
+	AttrQuerySource             = "query.source"
+	AttrAdapterName             = "adapter.name"
+	AttrAdapterOverride         = "adapter.override"
+	AttrConnectorType           = "connector.type"
+	AttrHTTPMethod              = "http.request.method"
+	AttrHTTPRoute               = "http.route"
+	AttrHTTPStatusCode          = "http.response.status_code"
+	AttrHTTPRequestHeaderPrefix = "http.request.header."
+	AttrErrorType               = "error.type"
+	AttrErrorMessage            = "error.message"
+	AttrExceptionStacktrace     = "exception.stacktrace"
 )
 
 var secretKeySubstrings = []string{
@@ -19,8 +19,18 @@ type metrics struct {
 	httpRequestDuration  metric.Float64Histogram
 	queryExecutionsTotal metric.Int64Counter
 	queryDuration        metric.Float64Histogram
+	queryQueueWait       metric.Float64Histogram
 	connectorOpsTotal    metric.Int64Counter
 	connectorOpDuration  metric.Float64Histogram
+	connectorHealthy     metric.Int64Gauge
+	poolConnections      metric.Int64Gauge
+	poolWaitCount        metric.Int64Gauge
+	poolWaitDuration     metric.Float64Gauge
+	configReloadsTotal   metric.Int64Counter
+	adapterExecsTotal    metric.Int64Counter
+	adapterExecDuration  metric.Float64Histogram
+	adapterRowsTotal     metric.Int64Counter
+	adapterBytesTotal    metric.Int64Counter
 }
 
 var (
@@ -69,8 +79,18 @@ func initInstruments() {
 		m.httpRequestDuration, _ = meter.Float64Histogram("hyperterse.http.server.request_duration_ms")
 		m.queryExecutionsTotal, _ = meter.Int64Counter("hyperterse.query.executions_total")
 		m.queryDuration, _ = meter.Float64Histogram("hyperterse.query.execution_duration_ms")
+		m.queryQueueWait, _ = meter.Float64Histogram("hyperterse.query.queue_wait_ms")
 		m.connectorOpsTotal, _ = meter.Int64Counter("hyperterse.connector.operations_total")
 		m.connectorOpDuration, _ = meter.Float64Histogram("hyperterse.connector.operation_duration_ms")
+		m.connectorHealthy, _ = meter.Int64Gauge("hyperterse.connector.healthy")
+		m.poolConnections, _ = meter.Int64Gauge("hyperterse.connector.pool.connections")
+		m.poolWaitCount, _ = meter.Int64Gauge("hyperterse.connector.pool.wait_count")
+		m.poolWaitDuration, _ = meter.Float64Gauge("hyperterse.connector.pool.wait_duration_ms")
+		m.configReloadsTotal, _ = meter.Int64Counter("hyperterse.config.reloads_total")
+		m.adapterExecsTotal, _ = meter.Int64Counter("hyperterse.adapter.executions_total")
+		m.adapterExecDuration, _ = meter.Float64Histogram("hyperterse.adapter.execution_duration_ms")
+		m.adapterRowsTotal, _ = meter.Int64Counter("hyperterse.adapter.rows_total")
+		m.adapterBytesTotal, _ = meter.Int64Counter("hyperterse.adapter.bytes_total")
 	})
 }
 
@@ -95,6 +115,14 @@ func RecordQueryExecution(ctx context.Context, queryName string, success bool, d
 	m.queryDuration.Record(ctx, durationMS, attrs)
 }
 
+func RecordQueueWait(ctx context.Context, adapterName, queryName string, waitMS float64) {
+	initInstruments()
+	m.queryQueueWait.Record(ctx, waitMS, metric.WithAttributes(
+		attribute.String(AttrAdapterName, adapterName),
+		attribute.String(AttrQueryName, queryName),
+	))
+}
+
 func RecordConnectorOperation(ctx context.Context, adapterName, connectorType, operation string, success bool, durationMS float64) {
 	initInstruments()
 	attrs := metric.WithAttributes(
@@ -106,3 +134,38 @@ func RecordConnectorOperation(ctx context.Context, adapterName, connectorType, o
 	m.connectorOpsTotal.Add(ctx, 1, attrs)
 	m.connectorOpDuration.Record(ctx, durationMS, attrs)
 }
+
+func RecordConnectorHealth(ctx context.Context, adapterName string, healthy bool) {
+	initInstruments()
+	value := int64(0)
+	if healthy {
+		value = 1
+	}
+	m.connectorHealthy.Record(ctx, value, metric.WithAttributes(attribute.String(AttrAdapterName, adapterName)))
+}
+
+func RecordPoolStats(ctx context.Context, adapterName string, inUse, idle int, waitCount int64, waitMS float64) {
+	initInstruments()
+	adapter := attribute.String(AttrAdapterName, adapterName)
+	m.poolConnections.Record(ctx, int64(inUse), metric.WithAttributes(adapter, attribute.String("state", "in_use")))
+	m.poolConnections.Record(ctx, int64(idle), metric.WithAttributes(adapter, attribute.String("state", "idle")))
+	m.poolWaitCount.Record(ctx, waitCount, metric.WithAttributes(adapter))
+	m.poolWaitDuration.Record(ctx, waitMS, metric.WithAttributes(adapter))
+}
+
+func RecordConfigReload(ctx context.Context, success bool) {
+	initInstruments()
+	m.configReloadsTotal.Add(ctx, 1, metric.WithAttributes(attribute.Bool("success", success)))
+}
+
+func RecordAdapterExecution(ctx context.Context, adapterName string, success bool, durationMS float64, rows, bytes int64) {
+	initInstruments()
+	adapter := attribute.String(AttrAdapterName, adapterName)
+	attrs := metric.WithAttributes(adapter, attribute.Bool("success", success))
+	m.adapterExecsTotal.Add(ctx, 1, attrs)
+	m.adapterExecDuration.Record(ctx, durationMS, attrs)
+	if success {
+		m.adapterRowsTotal.Add(ctx, rows, metric.WithAttributes(adapter))
+		m.adapterBytesTotal.Add(ctx, bytes, metric.WithAttributes(adapter))
+	}
+}
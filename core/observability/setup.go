@@ -58,10 +58,9 @@ func Setup(ctx context.Context, model *hyperterse.Model, serviceVersion string)
 	otel.SetMeterProvider(meterProvider)
 	otel.SetErrorHandler(otelLoggerErrorHandler{log: logger.New("observability")})
 
+	// Terminal output keeps the format chosen by server.log_format; OTel
+	// signals are exported via traces/metrics providers, not stdout logs.
 	logger.SetServiceContext(cfg.ServiceName, cfg.ServiceVersion, cfg.Environment)
-	// Always keep terminal output in pretty logger format.
-	// OTel signals are exported via traces/metrics providers, not stdout JSON logs.
-	logger.SetOTELLogMode(false)
 
 	p := &Providers{
 		config:        cfg,
@@ -0,0 +1,102 @@
+package parser
+
+import (
+	"fmt"
+	"regexp"
+	"sort"
+	"strings"
+)
+
+// defsRefPattern matches {{ defs.name }} references to statement fragments
+var defsRefPattern = regexp.MustCompile(`\{\{\s*defs\.([a-zA-Z][a-zA-Z0-9_-]*)\s*\}\}`)
+
+// defNamePattern is the pattern def names must follow to be referenced
+var defNamePattern = regexp.MustCompile(`^[a-zA-Z][a-zA-Z0-9_-]*$`)
+
+// parseDefs reads the defs section: named constants and statement fragments.
+// Fragments may reference other fragments; references are expanded here so
+// later lookups are a single substitution. Circular references are rejected.
+func parseDefs(raw map[string]any) (map[string]string, error) {
+	values := make(map[string]string, len(raw))
+	for name, value := range raw {
+		if !defNamePattern.MatchString(name) {
+			return nil, fmt.Errorf("invalid def name '%s': must start with a letter and can contain letters, numbers, hyphens, and underscores", name)
+		}
+		switch v := value.(type) {
+		case string:
+			values[name] = v
+		case int, int64, float64, bool:
+			values[name] = fmt.Sprintf("%v", v)
+		default:
+			return nil, fmt.Errorf("invalid value for defs.%s: must be a string, number or boolean", name)
+		}
+	}
+
+	// Expand in name order so the reported cycle is deterministic
+	names := make([]string, 0, len(values))
+	for name := range values {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	expanded := make(map[string]string, len(values))
+	for _, name := range names {
+		if _, err := expandDef(name, values, expanded, nil); err != nil {
+			return nil, err
+		}
+	}
+	return expanded, nil
+}
+
+// expandDef returns the fully expanded value of def name. path holds the defs
+// being expanded above this one and is used to report cycles.
+func expandDef(name string, values, expanded map[string]string, path []string) (string, error) {
+	if value, ok := expanded[name]; ok {
+		return value, nil
+	}
+	for i, parent := range path {
+		if parent == name {
+			cycle := append(path[i:], name)
+			return "", fmt.Errorf("circular reference in defs: %s", strings.Join(cycle, " -> "))
+		}
+	}
+
+	path = append(path, name)
+	var err error
+	value := defsRefPattern.ReplaceAllStringFunc(values[name], func(ref string) string {
+		if err != nil {
+			return ref
+		}
+		refName := defsRefPattern.FindStringSubmatch(ref)[1]
+		if _, ok := values[refName]; !ok {
+			err = fmt.Errorf("defs.%s references '{{ defs.%s }}' but defs does not contain '%s'", name, refName, refName)
+			return ref
+		}
+		var refValue string
+		refValue, err = expandDef(refName, values, expanded, path)
+		return refValue
+	})
+	if err != nil {
+		return "", err
+	}
+
+	expanded[name] = value
+	return value, nil
+}
+
+// expandDefRefs replaces {{ defs.name }} references in a query statement
+func expandDefRefs(queryName, statement string, defs map[string]string) (string, error) {
+	var err error
+	result := defsRefPattern.ReplaceAllStringFunc(statement, func(ref string) string {
+		refName := defsRefPattern.FindStringSubmatch(ref)[1]
+		value, ok := defs[refName]
+		if !ok {
+			if err == nil {
+				err = fmt.Errorf("query '%s' references '{{ defs.%s }}' but defs does not contain '%s'", queryName, refName, refName)
+			}
+			return ref
+		}
+		return value
+	})
+	return result, err
+}
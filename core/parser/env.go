@@ -0,0 +1,117 @@
+package parser
+
+import (
+	"fmt"
+	"maps"
+	"os"
+	"slices"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	runtimeutils "github.com/hyperterse/hyperterse/core/runtime/utils"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+// EnvReference is an environment variable a configuration reads through
+// {{ env.NAME }} placeholders, with the fields it appears in
+type EnvReference struct {
+	Name   string
+	Fields []string // e.g. "adapters.main_db.connection_string"
+}
+
+// EnvReferences returns every environment variable referenced by a
+// configuration, sorted by name. Fields are written as dotted paths, with
+// list items named by their name field or index.
+func EnvReferences(model *hyperterse.Model) []EnvReference {
+	fields := make(map[string][]string)
+	collectEnvReferences(model.ProtoReflect(), "", fields)
+
+	references := make([]EnvReference, 0, len(fields))
+	for _, name := range slices.Sorted(maps.Keys(fields)) {
+		paths := fields[name]
+		slices.Sort(paths)
+		references = append(references, EnvReference{Name: name, Fields: slices.Compact(paths)})
+	}
+	return references
+}
+
+// MissingEnvReferences returns the environment variables referenced by a
+// configuration that are not set. Query enabled toggles are left out: an
+// unset toggle variable turns its query off rather than being an error.
+func MissingEnvReferences(model *hyperterse.Model) []EnvReference {
+	toggles := make(map[string]bool, len(model.Queries))
+	for i, query := range model.Queries {
+		path := fmt.Sprintf("queries[%d]", i)
+		if query.Name != "" {
+			path = "queries." + query.Name
+		}
+		toggles[path+".enabled"] = true
+	}
+
+	var missing []EnvReference
+	for _, reference := range EnvReferences(model) {
+		if _, ok := os.LookupEnv(reference.Name); ok {
+			continue
+		}
+		fields := slices.DeleteFunc(reference.Fields, func(field string) bool { return toggles[field] })
+		if len(fields) > 0 {
+			missing = append(missing, EnvReference{Name: reference.Name, Fields: fields})
+		}
+	}
+	return missing
+}
+
+// collectEnvReferences records the placeholders in every string field of msg
+// and the messages nested in it
+func collectEnvReferences(msg protoreflect.Message, path string, fields map[string][]string) {
+	msg.Range(func(field protoreflect.FieldDescriptor, value protoreflect.Value) bool {
+		fieldPath := string(field.Name())
+		if path != "" {
+			fieldPath = path + "." + fieldPath
+		}
+
+		switch {
+		case field.IsList():
+			list := value.List()
+			for i := 0; i < list.Len(); i++ {
+				item := list.Get(i)
+				itemPath := fmt.Sprintf("%s[%d]", fieldPath, i)
+				if field.Kind() == protoreflect.MessageKind {
+					if name := messageName(item.Message()); name != "" {
+						itemPath = fieldPath + "." + name
+					}
+				}
+				collectEnvValue(field.Kind(), item, itemPath, fields)
+			}
+		case field.IsMap():
+			value.Map().Range(func(key protoreflect.MapKey, item protoreflect.Value) bool {
+				collectEnvValue(field.MapValue().Kind(), item, fieldPath+"."+key.String(), fields)
+				return true
+			})
+		default:
+			collectEnvValue(field.Kind(), value, fieldPath, fields)
+		}
+		return true
+	})
+}
+
+// collectEnvValue records the placeholders of a single string or message value
+func collectEnvValue(kind protoreflect.Kind, value protoreflect.Value, path string, fields map[string][]string) {
+	switch kind {
+	case protoreflect.MessageKind, protoreflect.GroupKind:
+		collectEnvReferences(value.Message(), path, fields)
+	case protoreflect.StringKind:
+		for _, name := range runtimeutils.ExtractEnvVarNames(value.String()) {
+			fields[name] = append(fields[name], path)
+		}
+	}
+}
+
+// messageName returns the name field of msg, which identifies adapters,
+// queries and inputs, or an empty string
+func messageName(msg protoreflect.Message) string {
+	field := msg.Descriptor().Fields().ByName("name")
+	if field == nil || field.Kind() != protoreflect.StringKind || field.IsList() {
+		return ""
+	}
+	return msg.Get(field).String()
+}
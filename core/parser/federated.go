@@ -0,0 +1,184 @@
+package parser
+
+import (
+	"fmt"
+	"maps"
+	"regexp"
+	"slices"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+)
+
+// sourceNamePattern matches federated source names, which key join.keys
+var sourceNamePattern = regexp.MustCompile(`^[a-zA-Z][a-zA-Z0-9_-]*$`)
+
+// queryStatement is a statement a query runs and the adapter it runs on
+type queryStatement struct {
+	statement string
+	adapter   string
+}
+
+// queryStatements returns the statement of a query, or the statement of each
+// source of a federated query
+func queryStatements(query *hyperterse.Query) []queryStatement {
+	if query.Federated != nil {
+		statements := make([]queryStatement, 0, len(query.Federated.Sources))
+		for _, source := range query.Federated.Sources {
+			statements = append(statements, queryStatement{statement: source.Statement, adapter: source.Use})
+		}
+		return statements
+	}
+	var adapter string
+	if len(query.Use) > 0 {
+		adapter = query.Use[0]
+	}
+	return []queryStatement{{statement: query.Statement, adapter: adapter}}
+}
+
+// queryInputReferences returns the inputs referenced by any statement of query
+func queryInputReferences(query *hyperterse.Query) map[string]bool {
+	referenced := make(map[string]bool)
+	for _, statement := range queryStatements(query) {
+		for name := range statementInputReferences(statement.statement) {
+			referenced[name] = true
+		}
+	}
+	return referenced
+}
+
+// validateFederatedQuery checks the sources of a federated query, the inputs
+// and headers their statements reference, and how their rows are merged
+func validateFederatedQuery(prefix string, query *hyperterse.Query, inputNames map[string]bool, inputEnums map[string][]string, adapterNameList []string, adapterConnectors map[string]connectors.Connector, passthroughHeaders map[string]bool) []string {
+	var errors []string
+	federated := query.Federated
+
+	if len(federated.Sources) < 2 {
+		errors = append(errors, fmt.Sprintf("%s.federated.sources must list at least 2 sources", prefix))
+	}
+
+	sourceNames := make(map[string]bool)
+	for i, source := range federated.Sources {
+		sourcePrefix := fmt.Sprintf("%s.federated.sources[%d]", prefix, i)
+		if source.Name == "" {
+			errors = append(errors, fmt.Sprintf("%s.name is required", sourcePrefix))
+		} else {
+			if !sourceNamePattern.MatchString(source.Name) {
+				errors = append(errors, fmt.Sprintf("%s.name '%s' is invalid. Must start with a letter and can contain letters, numbers, hyphens, and underscores", sourcePrefix, source.Name))
+			}
+			if sourceNames[source.Name] {
+				errors = append(errors, fmt.Sprintf("%s.name '%s' must be unique within the query", sourcePrefix, source.Name))
+			}
+			sourceNames[source.Name] = true
+		}
+
+		connector, adapterExists := adapterConnectors[source.Use]
+		switch {
+		case source.Use == "":
+			errors = append(errors, fmt.Sprintf("%s.use is required", sourcePrefix))
+		case !adapterExists:
+			errors = append(errors, fmt.Sprintf("%s.use '%s' is invalid. Must reference one of the defined adapter names: %s", sourcePrefix, source.Use, strings.Join(adapterNameList, ", ")))
+		case connector == connectors.Connector_CONNECTOR_KAFKA:
+			errors = append(errors, fmt.Sprintf("%s.use '%s' is a kafka adapter, which returns no rows to merge", sourcePrefix, source.Use))
+		}
+
+		if source.Statement == "" {
+			errors = append(errors, fmt.Sprintf("%s.statement is required", sourcePrefix))
+			continue
+		}
+		if adapterExists {
+			if err := executorutils.ValidateStatement(connector, source.Statement); err != nil {
+				errors = append(errors, fmt.Sprintf("%s.statement: %v", sourcePrefix, err))
+			}
+		}
+
+		for _, refInput := range extractInputReferences(source.Statement) {
+			if !inputNames[refInput] {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ inputs.%s }}' but %s.inputs does not contain '%s'", sourcePrefix, refInput, prefix, refInput))
+			}
+		}
+
+		calls, err := executorutils.ParseFunctionCalls(source.Statement)
+		if err != nil {
+			errors = append(errors, fmt.Sprintf("%s.statement: %v", sourcePrefix, err))
+		}
+		for _, call := range calls {
+			for _, refInput := range call.Inputs {
+				if !inputNames[refInput] {
+					errors = append(errors, fmt.Sprintf("%s.statement passes 'inputs.%s' to fn.%s but %s.inputs does not contain '%s'", sourcePrefix, refInput, call.Name, prefix, refInput))
+				}
+			}
+		}
+
+		for _, header := range executorutils.HeaderReferences(source.Statement) {
+			if !passthroughHeaders[header] {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ headers.%s }}' but server.passthrough_headers does not contain '%s'", sourcePrefix, header, header))
+			}
+		}
+
+		sqlSource := !adapterExists || executorutils.StatementKindOf(connector) == executorutils.StatementSQL
+		for _, refInput := range executorutils.IdentReferences(source.Statement) {
+			if !inputNames[refInput] {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ ident.%s }}' but %s.inputs does not contain '%s'", sourcePrefix, refInput, prefix, refInput))
+				continue
+			}
+			if len(inputEnums[refInput]) == 0 {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ ident.%s }}' but input '%s' has no enum of allowed identifiers", sourcePrefix, refInput, refInput))
+			}
+			if !sqlSource {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ ident.%s }}', which is only supported on postgres, mysql and duckdb adapters", sourcePrefix, refInput))
+			}
+		}
+
+		likeCalls, err := executorutils.ParseLikeCalls(source.Statement)
+		if err != nil {
+			errors = append(errors, fmt.Sprintf("%s.statement: %v", sourcePrefix, err))
+		}
+		for _, call := range likeCalls {
+			if !inputNames[call.Input] {
+				errors = append(errors, fmt.Sprintf("%s.statement passes 'inputs.%s' to like.%s but %s.inputs does not contain '%s'", sourcePrefix, call.Input, call.Name, prefix, call.Input))
+			}
+		}
+		if len(likeCalls) > 0 && !sqlSource {
+			errors = append(errors, fmt.Sprintf("%s.statement uses LIKE helpers, which are only supported on postgres, mysql and duckdb adapters", sourcePrefix))
+		}
+	}
+
+	if federated.Merge == "" {
+		errors = append(errors, fmt.Sprintf("%s.federated.merge is required. Must be one of: %s", prefix, strings.Join(executorutils.FederatedMerges, ", ")))
+		return errors
+	}
+	if !slices.Contains(executorutils.FederatedMerges, federated.Merge) {
+		errors = append(errors, fmt.Sprintf("%s.federated.merge '%s' must be one of: %s", prefix, federated.Merge, strings.Join(executorutils.FederatedMerges, ", ")))
+		return errors
+	}
+
+	if federated.Merge == executorutils.FederatedMergeConcat {
+		if federated.Join != nil {
+			errors = append(errors, fmt.Sprintf("%s.federated.join must be omitted when merge is '%s'", prefix, executorutils.FederatedMergeConcat))
+		}
+		return errors
+	}
+
+	// Joins match rows on a key column of every source
+	if federated.Join == nil || len(federated.Join.Keys) == 0 {
+		errors = append(errors, fmt.Sprintf("%s.federated.join.keys is required when merge is '%s'", prefix, executorutils.FederatedMergeJoin))
+		return errors
+	}
+	if federated.Join.Type != "" && !slices.Contains(executorutils.FederatedJoinTypes, federated.Join.Type) {
+		errors = append(errors, fmt.Sprintf("%s.federated.join.type '%s' must be one of: %s", prefix, federated.Join.Type, strings.Join(executorutils.FederatedJoinTypes, ", ")))
+	}
+	for _, source := range federated.Sources {
+		if source.Name != "" && federated.Join.Keys[source.Name] == "" {
+			errors = append(errors, fmt.Sprintf("%s.federated.join.keys is missing a key column for source '%s'", prefix, source.Name))
+		}
+	}
+	for _, name := range slices.Sorted(maps.Keys(federated.Join.Keys)) {
+		if !sourceNames[name] {
+			errors = append(errors, fmt.Sprintf("%s.federated.join.keys '%s' does not name a source", prefix, name))
+		}
+	}
+	return errors
+}
@@ -0,0 +1,178 @@
+package parser
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"path/filepath"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/pelletier/go-toml/v2"
+	"gopkg.in/yaml.v3"
+)
+
+// Format identifies the serialization format of a configuration
+type Format string
+
+const (
+	FormatYAML Format = "yaml"
+	FormatJSON Format = "json"
+	FormatTOML Format = "toml"
+	FormatDSL  Format = "dsl"
+)
+
+// ValidFormats lists the accepted values for an explicit format override
+var ValidFormats = []string{string(FormatYAML), string(FormatJSON), string(FormatTOML), string(FormatDSL)}
+
+// ParseFormatName converts a user-supplied format name into a Format.
+// "terse" and "yml" are accepted as aliases for YAML.
+func ParseFormatName(name string) (Format, error) {
+	switch strings.ToLower(strings.TrimSpace(name)) {
+	case "yaml", "yml", "terse":
+		return FormatYAML, nil
+	case "json":
+		return FormatJSON, nil
+	case "toml":
+		return FormatTOML, nil
+	case "dsl", "hyperterse":
+		return FormatDSL, nil
+	default:
+		return "", fmt.Errorf("unsupported config format '%s': must be one of: %s", name, strings.Join(ValidFormats, ", "))
+	}
+}
+
+// DetectFormat infers the configuration format from a file extension.
+// Unknown extensions fall back to the DSL parser.
+func DetectFormat(filePath string) Format {
+	switch strings.ToLower(filepath.Ext(filePath)) {
+	case ".terse", ".yaml", ".yml":
+		return FormatYAML
+	case ".json":
+		return FormatJSON
+	case ".toml":
+		return FormatTOML
+	default:
+		return FormatDSL
+	}
+}
+
+// ParseJSON parses JSON content into a protobuf Model
+func ParseJSON(data []byte) (*hyperterse.Model, error) {
+	// JSON is a subset of YAML, so the node walker can report duplicate keys
+	// that encoding/json would otherwise silently overwrite
+	if err := detectDuplicateKeys(data); err != nil {
+		return nil, err
+	}
+
+	var raw map[string]any
+	if err := json.Unmarshal(data, &raw); err != nil {
+		return nil, fmt.Errorf("failed to unmarshal JSON: %w", err)
+	}
+	return parseRawModel(raw)
+}
+
+// ParseTOML parses TOML content into a protobuf Model
+func ParseTOML(data []byte) (*hyperterse.Model, error) {
+	var raw map[string]any
+	if err := toml.Unmarshal(data, &raw); err != nil {
+		return nil, fmt.Errorf("failed to unmarshal TOML: %w", err)
+	}
+	return parseRawModel(raw)
+}
+
+// ParseWithFormat parses configuration content using the given format
+func ParseWithFormat(data []byte, format Format) (*hyperterse.Model, error) {
+	switch format {
+	case FormatYAML:
+		return ParseYAMLWithConfig(data)
+	case FormatJSON:
+		return ParseJSON(data)
+	case FormatTOML:
+		return ParseTOML(data)
+	case FormatDSL:
+		return NewParser(string(data)).Parse()
+	default:
+		return nil, fmt.Errorf("unsupported config format '%s'", format)
+	}
+}
+
+// UnmarshalWithFormat decodes configuration content into a map without
+// upgrading or parsing it. The DSL has no map form and is rejected.
+func UnmarshalWithFormat(data []byte, format Format) (map[string]any, error) {
+	var raw map[string]any
+	var err error
+	switch format {
+	case FormatYAML, FormatJSON:
+		if err := detectDuplicateKeys(data); err != nil {
+			return nil, err
+		}
+		err = yaml.Unmarshal(data, &raw)
+	case FormatTOML:
+		err = toml.Unmarshal(data, &raw)
+	default:
+		return nil, fmt.Errorf("cannot decode configurations in %s format", format)
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to unmarshal %s: %w", strings.ToUpper(string(format)), err)
+	}
+	return raw, nil
+}
+
+// topLevelKeyOrder is the order MarshalWithFormat writes top-level YAML keys
+// in; other keys follow, sorted
+var topLevelKeyOrder = []string{"name", "version", "config_version", "export", "server", "defs", "adapters", "hooks", "queries"}
+
+// MarshalWithFormat encodes a decoded configuration in the given format. YAML
+// keeps the conventional order of top-level sections; nested keys are sorted.
+// Comments in the original file are not preserved.
+func MarshalWithFormat(raw map[string]any, format Format) ([]byte, error) {
+	switch format {
+	case FormatYAML:
+		root := &yaml.Node{Kind: yaml.MappingNode}
+		keys := make([]string, 0, len(raw))
+		for key := range raw {
+			keys = append(keys, key)
+		}
+		sort.SliceStable(keys, func(i, j int) bool {
+			return topLevelKeyRank(keys[i]) < topLevelKeyRank(keys[j]) ||
+				topLevelKeyRank(keys[i]) == topLevelKeyRank(keys[j]) && keys[i] < keys[j]
+		})
+		for _, key := range keys {
+			value := &yaml.Node{}
+			if err := value.Encode(raw[key]); err != nil {
+				return nil, fmt.Errorf("failed to encode '%s': %w", key, err)
+			}
+			root.Content = append(root.Content, &yaml.Node{Kind: yaml.ScalarNode, Value: key}, value)
+		}
+		var buf bytes.Buffer
+		encoder := yaml.NewEncoder(&buf)
+		encoder.SetIndent(2)
+		if err := encoder.Encode(root); err != nil {
+			return nil, err
+		}
+		if err := encoder.Close(); err != nil {
+			return nil, err
+		}
+		return buf.Bytes(), nil
+	case FormatJSON:
+		data, err := json.MarshalIndent(raw, "", "  ")
+		if err != nil {
+			return nil, err
+		}
+		return append(data, '\n'), nil
+	case FormatTOML:
+		return toml.Marshal(raw)
+	default:
+		return nil, fmt.Errorf("cannot write configurations in %s format", format)
+	}
+}
+
+func topLevelKeyRank(key string) int {
+	if rank := slices.Index(topLevelKeyOrder, key); rank >= 0 {
+		return rank
+	}
+	return len(topLevelKeyOrder)
+}
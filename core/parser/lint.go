@@ -0,0 +1,176 @@
+package parser
+
+import (
+	"fmt"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+)
+
+// Lint rules. Each flags configuration that is valid but likely a mistake.
+const (
+	LintUnusedAdapter      = "unused-adapter"
+	LintMissingDescription = "missing-description"
+	LintUnusedInput        = "unused-input"
+	LintSelectStar         = "select-star"
+	LintMissingLimit       = "missing-limit"
+	LintNameCollision      = "name-collision"
+)
+
+var (
+	// SELECT * or SELECT table.*
+	selectStarPattern = regexp.MustCompile(`(?i)\bselect\s+(distinct\s+)?([a-z_][a-z0-9_]*\.)?\*`)
+	// Statements reading rows from a table
+	selectFromPattern = regexp.MustCompile(`(?is)^\s*(select|with)\b.*\bfrom\b`)
+	// Clauses bounding the number of rows returned
+	rowLimitPattern = regexp.MustCompile(`(?i)\b(limit|fetch\s+first|fetch\s+next|top)\b`)
+	// Aggregates returning a single row without GROUP BY
+	aggregatePattern = regexp.MustCompile(`(?i)\b(count|sum|avg|min|max)\s*\(`)
+	groupByPattern   = regexp.MustCompile(`(?i)\bgroup\s+by\b`)
+)
+
+// LintWarning is a finding of Lint
+type LintWarning struct {
+	Rule    string `json:"rule"`
+	Path    string `json:"path"`
+	Message string `json:"message"`
+}
+
+// String formats the warning as "path: message (rule)"
+func (w LintWarning) String() string {
+	return fmt.Sprintf("%s: %s (%s)", w.Path, w.Message, w.Rule)
+}
+
+// Lint reports configuration that passes Validate but is likely a mistake:
+// unused adapters and inputs, missing descriptions, SELECT *, unbounded list
+// queries and names that differ only in case. Warnings are sorted by path.
+func Lint(model *hyperterse.Model) []LintWarning {
+	var warnings []LintWarning
+	warn := func(rule, path, format string, args ...any) {
+		warnings = append(warnings, LintWarning{Rule: rule, Path: path, Message: fmt.Sprintf(format, args...)})
+	}
+
+	usedAdapters := make(map[string]bool)
+	adapterConnectors := make(map[string]connectors.Connector, len(model.Adapters))
+	adapterNames := make([]string, 0, len(model.Adapters))
+	for _, adapter := range model.Adapters {
+		adapterConnectors[adapter.Name] = adapter.Connector
+		adapterNames = append(adapterNames, adapter.Name)
+	}
+	for _, query := range model.Queries {
+		for _, name := range query.Use {
+			usedAdapters[name] = true
+		}
+		for _, name := range query.AllowedAdapters {
+			usedAdapters[name] = true
+		}
+		for _, statement := range queryStatements(query) {
+			usedAdapters[statement.adapter] = true
+		}
+	}
+	for _, adapter := range model.Adapters {
+		if !usedAdapters[adapter.Name] {
+			warn(LintUnusedAdapter, "adapters."+adapter.Name, "adapter is not used by any query")
+		}
+	}
+	lintNameCollisions(adapterNames, "adapters", warn)
+
+	serverMaxRows := model.GetServer().GetMaxRows()
+	queryNames := make([]string, 0, len(model.Queries))
+	for _, query := range model.Queries {
+		prefix := "queries." + query.Name
+		queryNames = append(queryNames, query.Name)
+
+		if strings.TrimSpace(query.Description) == "" {
+			warn(LintMissingDescription, prefix, "query has no description; it is shown to MCP clients and in generated docs")
+		}
+
+		referenced := queryInputReferences(query)
+		inputNames := make([]string, 0, len(query.Inputs))
+		for _, input := range query.Inputs {
+			inputNames = append(inputNames, input.Name)
+			if !referenced[input.Name] {
+				warn(LintUnusedInput, prefix+".inputs."+input.Name, "input is never referenced in the statement")
+			}
+		}
+		lintNameCollisions(inputNames, prefix+".inputs", warn)
+
+		dataNames := make([]string, 0, len(query.Data))
+		for _, data := range query.Data {
+			dataNames = append(dataNames, data.Name)
+		}
+		lintNameCollisions(dataNames, prefix+".data", warn)
+
+		for i, statement := range queryStatements(query) {
+			if executorutils.StatementKindOf(adapterConnectors[statement.adapter]) != executorutils.StatementSQL {
+				continue
+			}
+			statementPath := prefix + ".statement"
+			if query.Federated != nil {
+				statementPath = fmt.Sprintf("%s.federated.sources[%d].statement", prefix, i)
+			}
+			if selectStarPattern.MatchString(statement.statement) {
+				warn(LintSelectStar, statementPath, "SELECT * returns every column, including ones added later; list the columns instead")
+			}
+			if query.MaxRows == 0 && serverMaxRows == 0 && isUnboundedListQuery(statement.statement) {
+				warn(LintMissingLimit, statementPath, "list query has no LIMIT and no max_rows; it returns every matching row")
+			}
+		}
+	}
+	lintNameCollisions(queryNames, "queries", warn)
+
+	sort.SliceStable(warnings, func(i, j int) bool {
+		return warnings[i].Path < warnings[j].Path
+	})
+	return warnings
+}
+
+// statementInputReferences returns the inputs a statement references through
+// {{ inputs.x }}, {{ ident.x }}, {{ fn.name(inputs.x) }} or {{ like.name(inputs.x) }}
+func statementInputReferences(statement string) map[string]bool {
+	referenced := make(map[string]bool)
+	for _, name := range extractInputReferences(statement) {
+		referenced[name] = true
+	}
+	for _, name := range executorutils.IdentReferences(statement) {
+		referenced[name] = true
+	}
+	// Malformed calls are reported by Validate
+	calls, _ := executorutils.ParseFunctionCalls(statement)
+	for _, call := range calls {
+		for _, name := range call.Inputs {
+			referenced[name] = true
+		}
+	}
+	likeCalls, _ := executorutils.ParseLikeCalls(statement)
+	for _, call := range likeCalls {
+		referenced[call.Input] = true
+	}
+	return referenced
+}
+
+// isUnboundedListQuery reports whether statement reads rows from a table with
+// no row limit, leaving out aggregates that return a single row
+func isUnboundedListQuery(statement string) bool {
+	if !selectFromPattern.MatchString(statement) || rowLimitPattern.MatchString(statement) {
+		return false
+	}
+	return !aggregatePattern.MatchString(statement) || groupByPattern.MatchString(statement)
+}
+
+// lintNameCollisions warns about names in a section that differ only in case
+func lintNameCollisions(names []string, section string, warn func(rule, path, format string, args ...any)) {
+	first := make(map[string]string, len(names))
+	for _, name := range names {
+		key := strings.ToLower(name)
+		if other, ok := first[key]; ok && other != name {
+			warn(LintNameCollision, section+"."+name, "name differs from '%s' only in case", other)
+			continue
+		}
+		first[key] = name
+	}
+}
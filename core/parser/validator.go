@@ -3,11 +3,16 @@ package parser
 import (
 	"fmt"
 	"regexp"
+	"slices"
+	"strconv"
 	"strings"
+	"time"
 
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/proto/connectors"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	runtimeutils "github.com/hyperterse/hyperterse/core/runtime/utils"
 	"github.com/hyperterse/hyperterse/core/types"
 )
 
@@ -40,6 +45,11 @@ func (ve *ValidationErrors) Format() string {
 	return ve.Error()
 }
 
+// MinMaxThreads is the smallest accepted server.max_threads. The Go runtime
+// aborts the process when it exceeds the thread limit, and it needs a handful
+// of threads for itself before any request is served.
+const MinMaxThreads = 16
+
 // Validate performs comprehensive validation on the Model
 func Validate(model *hyperterse.Model) error {
 	log.Infof("Starting validation")
@@ -69,6 +79,191 @@ func Validate(model *hyperterse.Model) error {
 		}
 	}
 
+	// 0c. Validate optional server.max_rows
+	if model.Server != nil && model.Server.MaxRows < 0 {
+		errors = append(errors, "server.max_rows must not be negative (omit or use 0 for no limit)")
+	}
+
+	// 0c2. Validate optional server.result_key_case
+	if model.Server != nil && model.Server.ResultKeyCase != "" && !slices.Contains(executorutils.KeyCases, model.Server.ResultKeyCase) {
+		errors = append(errors, fmt.Sprintf("server.result_key_case '%s' must be one of: %s", model.Server.ResultKeyCase, strings.Join(executorutils.KeyCases, ", ")))
+	}
+
+	// 0c3. Validate optional server.passthrough_headers
+	passthroughHeaders := make(map[string]bool)
+	if model.Server != nil {
+		for _, header := range model.Server.PassthroughHeaders {
+			if !headerNamePattern.MatchString(header) {
+				errors = append(errors, fmt.Sprintf("server.passthrough_headers '%s' is not a valid header name; use letters, numbers and hyphens", header))
+				continue
+			}
+			if passthroughHeaders[strings.ToLower(header)] {
+				errors = append(errors, fmt.Sprintf("server.passthrough_headers '%s' is listed more than once", header))
+			}
+			passthroughHeaders[strings.ToLower(header)] = true
+		}
+	}
+
+	// 0c4. Validate optional server.openapi_version
+	if model.Server != nil {
+		switch model.Server.OpenapiVersion {
+		case "", "3.0", "3.1":
+		default:
+			errors = append(errors, fmt.Sprintf("server.openapi_version '%s' must be one of: 3.0, 3.1", model.Server.OpenapiVersion))
+		}
+	}
+
+	// 0c5. Validate optional server.mcp_log_level
+	if model.Server != nil && model.Server.McpLogLevel != "" && !slices.Contains(types.MCPLogLevels, model.Server.McpLogLevel) {
+		errors = append(errors, fmt.Sprintf("server.mcp_log_level '%s' must be one of: %s", model.Server.McpLogLevel, strings.Join(types.MCPLogLevels, ", ")))
+	}
+
+	// 0c6. Validate optional server.management_port and server.routes ports
+	if model.Server != nil && model.Server.ManagementPort != "" {
+		if port, err := strconv.Atoi(model.Server.ManagementPort); err != nil || port < 1 || port > 65535 {
+			errors = append(errors, fmt.Sprintf("server.management_port '%s' must be a port number between 1 and 65535", model.Server.ManagementPort))
+		} else if model.Server.ManagementPort == model.Server.Port {
+			errors = append(errors, "server.management_port must differ from server.port; omit it to serve every route on the main port")
+		}
+	}
+	if model.Server != nil && model.Server.Routes != nil {
+		routes := model.Server.Routes
+		groups := []struct {
+			name  string
+			group *hyperterse.RouteGroupConfig
+		}{{"queries", routes.Queries}, {"mcp", routes.Mcp}, {"docs", routes.Docs}, {"health", routes.Health}, {"admin", routes.Admin}}
+		for _, g := range groups {
+			if g.group == nil || g.group.Port == "" {
+				continue
+			}
+			if port, err := strconv.Atoi(g.group.Port); err != nil || port < 1 || port > 65535 {
+				errors = append(errors, fmt.Sprintf("server.routes.%s.port '%s' must be a port number between 1 and 65535", g.name, g.group.Port))
+			} else if g.group.Port == model.Server.Port {
+				errors = append(errors, fmt.Sprintf("server.routes.%s.port must differ from server.port; omit it to serve the group on the main port", g.name))
+			}
+		}
+	}
+
+	// 0c7. Validate optional server.request_log
+	if model.Server != nil && model.Server.RequestLog != nil {
+		if model.Server.RequestLog.Sample < 0 {
+			errors = append(errors, "server.request_log.sample must not be negative (omit or use 1 to log every request)")
+		}
+		if model.Server.RequestLog.SlowMs < 0 {
+			errors = append(errors, "server.request_log.slow_ms must not be negative (omit or use 0 to disable)")
+		}
+	}
+
+	// 0c8. Validate optional server.error_detail
+	if model.Server != nil && model.Server.ErrorDetail != "" && !slices.Contains(types.ErrorDetails, model.Server.ErrorDetail) {
+		errors = append(errors, fmt.Sprintf("server.error_detail '%s' must be one of: %s", model.Server.ErrorDetail, strings.Join(types.ErrorDetails, ", ")))
+	}
+
+	// 0c9. Validate optional server.base_path
+	if model.Server != nil && model.Server.BasePath != "" && !basePathPattern.MatchString(model.Server.BasePath) {
+		errors = append(errors, fmt.Sprintf("server.base_path '%s' must start with '/' and contain only path segments, e.g. /api/v1", model.Server.BasePath))
+	}
+
+	// 0c10. Validate optional server.log_format
+	if model.Server != nil && model.Server.LogFormat != "" && !slices.Contains(types.LogFormats, model.Server.LogFormat) {
+		errors = append(errors, fmt.Sprintf("server.log_format '%s' must be one of: %s", model.Server.LogFormat, strings.Join(types.LogFormats, ", ")))
+	}
+
+	// 0c11. Validate optional server.maintenance
+	if model.Server != nil && model.Server.Maintenance != nil && model.Server.Maintenance.RetryAfter < 0 {
+		errors = append(errors, "server.maintenance.retry_after must not be negative (omit or use 0 to leave out Retry-After)")
+	}
+
+	// 0c12. Validate optional server.failure_capture
+	if model.Server != nil && model.Server.FailureCapture != nil && model.Server.FailureCapture.Max < 0 {
+		errors = append(errors, "server.failure_capture.max must not be negative (omit or use 0 to keep 100)")
+	}
+
+	// 0d. Validate optional runtime tuning
+	if model.Server != nil {
+		if model.Server.WorkerThreads < 0 {
+			errors = append(errors, "server.worker_threads must not be negative (omit or use 0 for the number of CPUs)")
+		}
+		if model.Server.MaxThreads < 0 {
+			errors = append(errors, "server.max_threads must not be negative (omit or use 0 for the Go default)")
+		} else if model.Server.MaxThreads > 0 && model.Server.MaxThreads < MinMaxThreads {
+			errors = append(errors, fmt.Sprintf("server.max_threads must be at least %d when specified", MinMaxThreads))
+		}
+		if model.Server.ReadyTimeout < 0 {
+			errors = append(errors, "server.ready_timeout must not be negative (omit or use 0 to fail on the first connection error)")
+		}
+		if model.Server.HealthCheckInterval < 0 {
+			errors = append(errors, "server.health_check_interval must not be negative (omit or use 0 for the default of 30 seconds)")
+		}
+	}
+
+	// 0e. Validate optional server.sessions
+	if model.Server != nil && model.Server.Sessions != nil {
+		sessions := model.Server.Sessions
+		switch sessions.Store {
+		case "", "memory":
+		case "redis":
+			if sessions.Url == "" {
+				errors = append(errors, "server.sessions.url is required when server.sessions.store is 'redis'")
+			}
+		default:
+			errors = append(errors, fmt.Sprintf("server.sessions.store '%s' must be one of: memory, redis", sessions.Store))
+		}
+		if sessions.Ttl < 0 {
+			errors = append(errors, "server.sessions.ttl must not be negative")
+		}
+	}
+
+	// 0f. Validate optional server.rate_limit
+	if model.Server != nil && model.Server.RateLimit != nil {
+		rateLimit := model.Server.RateLimit
+		if rateLimit.Requests <= 0 {
+			errors = append(errors, "server.rate_limit.requests is required and must be greater than 0")
+		}
+		if rateLimit.Window < 0 {
+			errors = append(errors, "server.rate_limit.window must not be negative")
+		}
+		switch rateLimit.Store {
+		case "", "memory":
+		case "redis":
+			if rateLimit.Url == "" {
+				errors = append(errors, "server.rate_limit.url is required when server.rate_limit.store is 'redis'")
+			}
+		default:
+			errors = append(errors, fmt.Sprintf("server.rate_limit.store '%s' must be one of: memory, redis", rateLimit.Store))
+		}
+	}
+
+	// 0g. Validate optional hooks
+	for _, hook := range model.Hooks {
+		prefix := fmt.Sprintf("hooks.%s", hook.Name)
+		if !queryNamePattern.MatchString(hook.Name) {
+			errors = append(errors, fmt.Sprintf("Hook '%s' - name is invalid. Must start with a letter and be in lower-snake-case or lower-kebab-case (lowercase letters, numbers, hyphens, and underscores only)", hook.Name))
+		}
+		if hook.Url == "" {
+			errors = append(errors, fmt.Sprintf("%s.url is required", prefix))
+		} else if !strings.HasPrefix(hook.Url, "{{") && !strings.HasPrefix(hook.Url, "https://") && !strings.HasPrefix(hook.Url, "http://") {
+			errors = append(errors, fmt.Sprintf("%s.url '%s' must be an http or https URL", prefix, hook.Url))
+		}
+		if len(hook.Events) == 0 {
+			errors = append(errors, fmt.Sprintf("%s.events is required and should have at least one entry", prefix))
+		}
+		for _, event := range hook.Events {
+			if !types.IsHookEventName(event) {
+				errors = append(errors, fmt.Sprintf("%s.events '%s' must be one of: %s", prefix, event, strings.Join(types.HookEventNames, ", ")))
+			}
+		}
+		if hook.Retries < 0 {
+			errors = append(errors, fmt.Sprintf("%s.retries must not be negative", prefix))
+		}
+		if hook.Timeout < 0 {
+			errors = append(errors, fmt.Sprintf("%s.timeout must not be negative", prefix))
+		}
+		if hook.FailureThreshold < 0 {
+			errors = append(errors, fmt.Sprintf("%s.failure_threshold must not be negative", prefix))
+		}
+	}
+
 	// 1. Validate adapters is required and has at least one entry
 	if len(model.Adapters) == 0 {
 		errors = append(errors, "adapters is required and should have at least one entry")
@@ -76,6 +271,10 @@ func Validate(model *hyperterse.Model) error {
 
 	// Track adapter names for uniqueness and cross-reference validation
 	adapterNames := make(map[string]bool)
+	// Track concurrency limits so query weights can be checked against them
+	adapterConcurrency := make(map[string]int32)
+	// Track connector types so allowed_adapters can be checked against use
+	adapterConnectors := make(map[string]connectors.Connector)
 	// Name pattern: must start with a letter, followed by letters, numbers, hyphens, and underscores
 	namePattern := regexp.MustCompile(`^[a-zA-Z][a-zA-Z0-9_-]*$`)
 
@@ -100,21 +299,89 @@ func Validate(model *hyperterse.Model) error {
 			errors = append(errors, fmt.Sprintf("Adapter '%s' - already defined. Adapters must be unique", adapter.Name))
 		}
 		adapterNames[adapter.Name] = true
+		adapterConcurrency[adapter.Name] = adapter.MaxConcurrency
+		adapterConnectors[adapter.Name] = adapter.Connector
 
-		// 3. Connector is required and must be one of: postgres, redis, mysql, mongodb
+		// 3. Connector is required and must be one of: postgres, redis, mysql, mongodb, objectstore, duckdb, kafka, static
 		if adapter.Connector == connectors.Connector_CONNECTOR_UNSPECIFIED {
 			errors = append(errors, fmt.Sprintf("Adapter '%s' requires a connector", prefix))
-		} else if adapter.Connector != connectors.Connector_CONNECTOR_POSTGRES &&
-			adapter.Connector != connectors.Connector_CONNECTOR_REDIS &&
-			adapter.Connector != connectors.Connector_CONNECTOR_MYSQL &&
-			adapter.Connector != connectors.Connector_CONNECTOR_MONGODB {
+		} else if executorutils.StatementKindOf(adapter.Connector) == executorutils.StatementUnknown {
 			errors = append(errors, fmt.Sprintf("Adapter '%s' - connector is invalid. Must be one of: %s", prefix, strings.Join(types.GetValidConnectors(), ", ")))
 		}
 
-		// 4. Connection string is required
-		if adapter.ConnectionString == "" {
+		// 4. Connection string, or structured connection fields, is required unless
+		// credentials supply the connection string. Static adapters connect to nothing.
+		if adapter.Connector == connectors.Connector_CONNECTOR_STATIC {
+			if adapter.ConnectionString != "" || adapter.Connection != nil || adapter.Credentials != nil {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - static adapters have no connection; remove connection_string, connection fields and credentials", prefix))
+			}
+		} else if adapter.Connection != nil {
+			errors = append(errors, validateConnectionFields(prefix, adapter)...)
+		} else if adapter.ConnectionString == "" && (adapter.Credentials == nil || adapter.Credentials.Target != "connection_string") {
 			errors = append(errors, fmt.Sprintf("Adapter '%s' - connection_string is required", prefix))
 		}
+
+		// 4a. Timezone must be a known IANA time zone
+		if adapter.Timezone != "" {
+			if _, err := time.LoadLocation(adapter.Timezone); err != nil {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - timezone '%s' is not a known IANA time zone (e.g. UTC, Europe/Berlin)", prefix, adapter.Timezone))
+			}
+		}
+
+		// 4b. on_connect runs session statements, which only SQL server connectors have
+		if len(adapter.OnConnect) > 0 {
+			if !executorutils.SQLServer(adapter.Connector) {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - on_connect is only supported for postgres and mysql adapters", prefix))
+			}
+			for j, stmt := range adapter.OnConnect {
+				if strings.TrimSpace(stmt) == "" {
+					errors = append(errors, fmt.Sprintf("Adapter '%s' - on_connect[%d] must not be empty", prefix, j))
+				}
+			}
+		}
+
+		// 4c. TLS settings are passed to the postgres, mysql and mongodb drivers
+		if adapter.Tls != nil {
+			errors = append(errors, validateTLSSettings(prefix, adapter)...)
+		}
+
+		// 4d. IAM auth replaces the password with tokens for postgres and mysql
+		if adapter.IamAuth != nil {
+			errors = append(errors, validateIAMAuth(prefix, adapter)...)
+		}
+
+		// 4e. Refreshable credentials need a provider and a target to apply to
+		if adapter.Credentials != nil {
+			errors = append(errors, validateCredentials(prefix, adapter)...)
+		}
+
+		// 4f. max_concurrency caps the queries running on the adapter at once
+		if adapter.MaxConcurrency < 0 {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - max_concurrency must be non-negative", prefix))
+		}
+
+		// 4g. Pool settings apply to the database/sql pools of postgres and mysql
+		if adapter.Pool != nil {
+			if !executorutils.SQLServer(adapter.Connector) {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - pool is only supported for postgres and mysql adapters", prefix))
+			}
+			if adapter.Pool.MinConnections < 0 {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - pool.min_connections must be non-negative", prefix))
+			}
+			if adapter.Pool.MaxConnections < 0 {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - pool.max_connections must be non-negative", prefix))
+			} else if adapter.Pool.MaxConnections > 0 && adapter.Pool.MinConnections > adapter.Pool.MaxConnections {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - pool.min_connections must not exceed pool.max_connections", prefix))
+			}
+			if adapter.Pool.Warm && adapter.Pool.MinConnections == 0 {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - pool.warm requires pool.min_connections", prefix))
+			}
+		}
+
+		// 4h. Migrations are SQL files, run by hyperterse migrate
+		if adapter.Migrations != "" && executorutils.StatementKindOf(adapter.Connector) != executorutils.StatementSQL {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - migrations are only supported for postgres, mysql and duckdb adapters", prefix))
+		}
 	}
 
 	// 5. Validate queries is required and has at least one entry
@@ -152,8 +419,13 @@ func Validate(model *hyperterse.Model) error {
 		if !queryNamePattern.MatchString(query.Name) {
 			errors = append(errors, fmt.Sprintf("Query '%s' - name is invalid. Must start with a letter and be in lower-snake-case or lower-kebab-case (lowercase letters, numbers, hyphens, and underscores only)", query.Name))
 		}
-		// 7. Query use is required and must reference a valid adapter
-		if len(query.Use) == 0 {
+		// 7. Query use is required and must reference a valid adapter. Federated
+		// queries name an adapter for each source instead.
+		if query.Federated != nil {
+			if len(query.Use) > 0 {
+				errors = append(errors, fmt.Sprintf("%s.use must be omitted when %s.federated is specified; set use on each source", prefix, prefix))
+			}
+		} else if len(query.Use) == 0 {
 			errors = append(errors, fmt.Sprintf("Query '%s' - use is required", prefix))
 		} else {
 			for _, useAdapter := range query.Use {
@@ -168,13 +440,19 @@ func Validate(model *hyperterse.Model) error {
 			errors = append(errors, fmt.Sprintf("%s.description is required", prefix))
 		}
 
-		// 9. Query statement is required
-		if query.Statement == "" {
+		// 9. Query statement is required, except on federated queries whose
+		// sources each have one
+		if query.Federated != nil {
+			if query.Statement != "" {
+				errors = append(errors, fmt.Sprintf("%s.statement must be omitted when %s.federated is specified; set statement on each source", prefix, prefix))
+			}
+		} else if query.Statement == "" {
 			errors = append(errors, fmt.Sprintf("%s.statement is required", prefix))
 		}
 
 		// 10. Validate inputs if specified
 		inputNames := make(map[string]bool)
+		inputEnums := make(map[string][]string)
 		for j, input := range query.Inputs {
 			inputPrefix := fmt.Sprintf("%s.inputs[%d]", prefix, j)
 
@@ -210,6 +488,49 @@ func Validate(model *hyperterse.Model) error {
 			if input.Optional && input.DefaultValue == "" {
 				errors = append(errors, fmt.Sprintf("%s is marked as optional but does not have a default value", inputPrefix))
 			}
+
+			// default: auto generates a value, which only uuid and datetime inputs support
+			if input.DefaultValue == executorutils.DefaultAuto && typeStr != "uuid" && typeStr != "datetime" {
+				errors = append(errors, fmt.Sprintf("%s.default 'auto' is only supported on uuid and datetime inputs", inputPrefix))
+			}
+
+			// Transforms must be known
+			for _, transform := range input.Transforms {
+				if _, err := executorutils.ParseTransform(transform); err != nil {
+					errors = append(errors, fmt.Sprintf("%s.transform: %v", inputPrefix, err))
+				}
+			}
+
+			// enum lists the allowed values of a string input, including its default
+			if len(input.Enum) > 0 {
+				if typeStr != "string" {
+					errors = append(errors, fmt.Sprintf("%s.enum is only supported on string inputs", inputPrefix))
+				}
+				seen := make(map[string]bool)
+				for _, value := range input.Enum {
+					if seen[value] {
+						errors = append(errors, fmt.Sprintf("%s.enum lists '%s' more than once", inputPrefix, value))
+					}
+					seen[value] = true
+				}
+				if input.DefaultValue != "" && !seen[input.DefaultValue] {
+					errors = append(errors, fmt.Sprintf("%s.default '%s' must be one of the enum values", inputPrefix, input.DefaultValue))
+				}
+			}
+			inputEnums[input.Name] = input.Enum
+
+			// Examples must be valid values of the input
+			for _, example := range input.Examples {
+				if _, err := executorutils.ConvertInputExample(input, example); err != nil {
+					errors = append(errors, fmt.Sprintf("%s.example '%s' is invalid: %v", inputPrefix, example, err))
+				} else if len(input.Enum) > 0 && !slices.Contains(input.Enum, example) {
+					errors = append(errors, fmt.Sprintf("%s.example '%s' must be one of the enum values", inputPrefix, example))
+				}
+			}
+
+			if input.EscapeLike && typeStr != "string" {
+				errors = append(errors, fmt.Sprintf("%s.escape_like is only supported on string inputs", inputPrefix))
+			}
 		}
 
 		// 10a. Validate that all {{ inputs.x }} references in statement are defined
@@ -230,6 +551,98 @@ func Validate(model *hyperterse.Model) error {
 			}
 		}
 
+		// 10b. Validate {{ fn.name(...) }} calls and the inputs passed to them
+		if query.Statement != "" {
+			calls, err := executorutils.ParseFunctionCalls(query.Statement)
+			if err != nil {
+				errors = append(errors, fmt.Sprintf("%s.statement: %v", prefix, err))
+			}
+			for _, call := range calls {
+				for _, refInput := range call.Inputs {
+					if !inputNames[refInput] {
+						errors = append(errors, fmt.Sprintf("%s.statement passes 'inputs.%s' to fn.%s but %s.inputs does not contain '%s'", prefix, refInput, call.Name, prefix, refInput))
+					}
+				}
+			}
+		}
+
+		// 10c. Validate {{ headers.name }} references against server.passthrough_headers
+		for _, header := range executorutils.HeaderReferences(query.Statement) {
+			if !passthroughHeaders[header] {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ headers.%s }}' but server.passthrough_headers does not contain '%s'", prefix, header, header))
+			}
+		}
+
+		// 10d. Validate {{ ident.x }} references. The value is quoted as an
+		// identifier, so the input must list the identifiers it allows.
+		for _, refInput := range executorutils.IdentReferences(query.Statement) {
+			if !inputNames[refInput] {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ ident.%s }}' but %s.inputs does not contain '%s'", prefix, refInput, prefix, refInput))
+				continue
+			}
+			if len(inputEnums[refInput]) == 0 {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ ident.%s }}' but input '%s' has no enum of allowed identifiers", prefix, refInput, refInput))
+			}
+			for _, value := range inputEnums[refInput] {
+				if !executorutils.IdentifierPattern.MatchString(value) {
+					errors = append(errors, fmt.Sprintf("%s.inputs.%s.enum value '%s' is not a valid identifier for '{{ ident.%s }}'. Must be a name or table.name of letters, numbers and underscores", prefix, refInput, value, refInput))
+				}
+			}
+			if len(query.Use) > 0 && adapterNames[query.Use[0]] && executorutils.StatementKindOf(adapterConnectors[query.Use[0]]) != executorutils.StatementSQL {
+				errors = append(errors, fmt.Sprintf("%s.statement references '{{ ident.%s }}', which is only supported on postgres, mysql and duckdb adapters", prefix, refInput))
+			}
+		}
+
+		// 10e. Validate {{ like.name(inputs.x) }} helpers, which build quoted
+		// LIKE patterns for SQL adapters
+		if query.Statement != "" {
+			calls, err := executorutils.ParseLikeCalls(query.Statement)
+			if err != nil {
+				errors = append(errors, fmt.Sprintf("%s.statement: %v", prefix, err))
+			}
+			for _, call := range calls {
+				if !inputNames[call.Input] {
+					errors = append(errors, fmt.Sprintf("%s.statement passes 'inputs.%s' to like.%s but %s.inputs does not contain '%s'", prefix, call.Input, call.Name, prefix, call.Input))
+				}
+			}
+			if len(calls) > 0 && len(query.Use) > 0 && adapterNames[query.Use[0]] && executorutils.StatementKindOf(adapterConnectors[query.Use[0]]) != executorutils.StatementSQL {
+				errors = append(errors, fmt.Sprintf("%s.statement uses LIKE helpers, which are only supported on postgres, mysql and duckdb adapters", prefix))
+			}
+		}
+
+		// 10f. With server.strict_unused_inputs, every declared input must be
+		// referenced, which catches typos between inputs and the statement
+		if model.Server != nil && model.Server.StrictUnusedInputs {
+			referenced := queryInputReferences(query)
+			statementPath := prefix + ".statement"
+			if query.Federated != nil {
+				statementPath = prefix + ".federated.sources"
+			}
+			for _, input := range query.Inputs {
+				if input.Name == "" || referenced[input.Name] {
+					continue
+				}
+				message := fmt.Sprintf("%s.inputs.%s is never referenced in %s (server.strict_unused_inputs)", prefix, input.Name, statementPath)
+				if similar := similarInputReference(input.Name, referenced); similar != "" {
+					message += fmt.Sprintf(". Did you mean '%s'?", similar)
+				}
+				errors = append(errors, message)
+			}
+		}
+
+		// 10g. Validate federated sources and how their rows are merged
+		if query.Federated != nil {
+			errors = append(errors, validateFederatedQuery(prefix, query, inputNames, inputEnums, adapterNameList, adapterConnectors, passthroughHeaders)...)
+		}
+
+		// 10h. Validate the statement in the language of the adapter's
+		// connector, such as the JSON of static and mongodb statements
+		if len(query.Use) > 0 && adapterNames[query.Use[0]] {
+			if err := executorutils.ValidateStatement(adapterConnectors[query.Use[0]], query.Statement); err != nil {
+				errors = append(errors, fmt.Sprintf("%s.statement: %v", prefix, err))
+			}
+		}
+
 		// 11. Validate data if specified
 		dataNames := make(map[string]bool)
 		for j, data := range query.Data {
@@ -259,6 +672,8 @@ func Validate(model *hyperterse.Model) error {
 				errors = append(errors, fmt.Sprintf("%s.type is required", dataPrefix))
 			} else if !types.IsValidPrimitiveType(typeStr) {
 				errors = append(errors, fmt.Sprintf("%s.type '%s' must be one of: %s", dataPrefix, typeStr, strings.Join(types.GetValidPrimitives(), ", ")))
+			} else if _, err := executorutils.ConvertDataDefault(data); err != nil {
+				errors = append(errors, fmt.Sprintf("%s.default '%s' is invalid: %v", dataPrefix, data.DefaultValue, err))
 			}
 		}
 
@@ -271,6 +686,109 @@ func Validate(model *hyperterse.Model) error {
 				errors = append(errors, fmt.Sprintf("%s.cache.ttl must be greater than 0 when specified", prefix))
 			}
 		}
+
+		// 13. Validate optional query.max_rows override
+		if query.MaxRows < 0 {
+			errors = append(errors, fmt.Sprintf("%s.max_rows must not be negative (omit or use 0 to inherit server.max_rows)", prefix))
+		}
+
+		// 14. Validate optional query.cache_control header value
+		if query.CacheControl != "" {
+			if err := validateCacheControl(query.CacheControl); err != nil {
+				errors = append(errors, fmt.Sprintf("%s.cache_control %v", prefix, err))
+			}
+		}
+
+		// 15. Validate optional query.nulls mode
+		if query.Nulls != "" && !slices.Contains(executorutils.NullModes, query.Nulls) {
+			errors = append(errors, fmt.Sprintf("%s.nulls '%s' must be one of: %s", prefix, query.Nulls, strings.Join(executorutils.NullModes, ", ")))
+		}
+
+		// 16. Validate optional query.weight, which only applies on adapters
+		// with a concurrency limit
+		if query.Weight != 0 {
+			if query.Weight < 1 || query.Weight > maxQueryWeight {
+				errors = append(errors, fmt.Sprintf("%s.weight must be between 1 and %d", prefix, maxQueryWeight))
+			}
+			if len(query.Use) > 0 && adapterConcurrency[query.Use[0]] == 0 {
+				errors = append(errors, fmt.Sprintf("%s.weight requires max_concurrency on adapter '%s'", prefix, query.Use[0]))
+			}
+		}
+
+		// 17. Validate optional query.allowed_adapters. The statement is built
+		// for the query's own adapter, so alternates must use the same connector.
+		if len(query.AllowedAdapters) > 0 {
+			if query.Federated != nil {
+				errors = append(errors, fmt.Sprintf("%s.allowed_adapters is not supported on federated queries", prefix))
+			}
+			if model.Server == nil || model.Server.AdminToken == "" {
+				errors = append(errors, fmt.Sprintf("%s.allowed_adapters requires server.admin_token", prefix))
+			}
+			for _, allowed := range query.AllowedAdapters {
+				if !adapterNames[allowed] {
+					errors = append(errors, fmt.Sprintf("%s.allowed_adapters '%s' is invalid. Must reference one of the defined adapter names: %s", prefix, allowed, strings.Join(adapterNameList, ", ")))
+				} else if len(query.Use) > 0 && adapterNames[query.Use[0]] && adapterConnectors[allowed] != adapterConnectors[query.Use[0]] {
+					errors = append(errors, fmt.Sprintf("%s.allowed_adapters '%s' must use the same connector as adapter '%s'", prefix, allowed, query.Use[0]))
+				}
+			}
+		}
+
+		// 18. Validate optional query.log_sample override
+		if query.LogSample < 0 {
+			errors = append(errors, fmt.Sprintf("%s.log_sample must not be negative (omit or use 0 to inherit server.request_log.sample)", prefix))
+		}
+
+		// 19. Validate optional query.soft_delete_filter, which must be a single
+		// predicate the runtime can add to the statement's WHERE clause
+		if query.SoftDeleteFilter != "" {
+			if err := executorutils.ValidateSoftDeleteFilter(query.SoftDeleteFilter); err != nil {
+				errors = append(errors, fmt.Sprintf("%s.soft_delete_filter %v", prefix, err))
+			} else if query.Federated != nil {
+				errors = append(errors, fmt.Sprintf("%s.soft_delete_filter is not supported on federated queries; add the predicate to each source statement", prefix))
+			} else if len(query.Use) > 0 && adapterNames[query.Use[0]] && executorutils.StatementKindOf(adapterConnectors[query.Use[0]]) != executorutils.StatementSQL {
+				errors = append(errors, fmt.Sprintf("%s.soft_delete_filter is only supported on postgres, mysql and duckdb adapters", prefix))
+			} else if query.Statement != "" {
+				if _, err := executorutils.ApplySoftDeleteFilter(query.Statement, query.SoftDeleteFilter); err != nil {
+					errors = append(errors, fmt.Sprintf("%s.statement: %v", prefix, err))
+				}
+			}
+		}
+
+		// 20. Validate optional query.enabled, which is true, false or read
+		// from environment variables when the model is loaded
+		if query.Enabled != "" && len(runtimeutils.ExtractEnvVarNames(query.Enabled)) == 0 {
+			if _, err := strconv.ParseBool(query.Enabled); err != nil {
+				errors = append(errors, fmt.Sprintf("%s.enabled '%s' is invalid. Must be true, false or a {{ env.VAR }} placeholder", prefix, query.Enabled))
+			}
+		}
+
+		// 21. Validate optional query.cursor. Pages end at max_rows, and the first
+		// page is requested without a cursor, so its input must be optional.
+		if query.Cursor != nil {
+			if query.Cursor.Key == "" {
+				errors = append(errors, fmt.Sprintf("%s.cursor.key is required", prefix))
+			}
+			var cursorInput *hyperterse.Input
+			for _, input := range query.Inputs {
+				if input.Name == query.Cursor.Input {
+					cursorInput = input
+				}
+				if input.Name == executorutils.CursorParam {
+					errors = append(errors, fmt.Sprintf("%s.inputs.%s is reserved for the cursor of queries with cursor pagination", prefix, input.Name))
+				}
+			}
+			switch {
+			case query.Cursor.Input == "":
+				errors = append(errors, fmt.Sprintf("%s.cursor.input is required", prefix))
+			case cursorInput == nil:
+				errors = append(errors, fmt.Sprintf("%s.cursor.input '%s' must be one of the query's inputs", prefix, query.Cursor.Input))
+			case !cursorInput.Optional:
+				errors = append(errors, fmt.Sprintf("%s.cursor.input '%s' must be optional, since the first page has no cursor", prefix, query.Cursor.Input))
+			}
+			if query.MaxRows == 0 && model.GetServer().GetMaxRows() == 0 {
+				errors = append(errors, fmt.Sprintf("%s.cursor requires max_rows on the query or the server, which sets the page size", prefix))
+			}
+		}
 	}
 
 	if len(errors) > 0 {
@@ -281,6 +799,263 @@ func Validate(model *hyperterse.Model) error {
 	return nil
 }
 
+// cacheControlDirectives maps the Cache-Control response directives a query may
+// declare to whether they take a delta-seconds value
+var cacheControlDirectives = map[string]bool{
+	"public":                 false,
+	"private":                false,
+	"no-cache":               false,
+	"no-store":               false,
+	"no-transform":           false,
+	"must-revalidate":        false,
+	"proxy-revalidate":       false,
+	"must-understand":        false,
+	"immutable":              false,
+	"max-age":                true,
+	"s-maxage":               true,
+	"stale-while-revalidate": true,
+	"stale-if-error":         true,
+}
+
+var deltaSecondsPattern = regexp.MustCompile(`^[0-9]+$`)
+
+// basePathPattern matches server.base_path: one or more non-empty path segments
+var basePathPattern = regexp.MustCompile(`^(/[A-Za-z0-9._~-]+)+$`)
+
+// envPlaceholderPattern matches values that are resolved from the environment at
+// startup and so can only be checked then
+var envPlaceholderPattern = regexp.MustCompile(`\{\{\s*env\.\w+\s*\}\}`)
+
+// validateConnectionFields checks an adapter's structured connection fields,
+// which replace connection_string for postgres, mysql, redis and mongodb
+func validateConnectionFields(prefix string, adapter *hyperterse.Adapter) []string {
+	var errors []string
+	fields := adapter.Connection
+
+	if adapter.ConnectionString != "" {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - connection_string cannot be combined with host, port, user, password, database or ssl", prefix))
+	}
+	switch kind := executorutils.StatementKindOf(adapter.Connector); {
+	case executorutils.SQLServer(adapter.Connector),
+		kind == executorutils.StatementRedisCommand, kind == executorutils.StatementMongoJSON:
+	default:
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - host, port, user, password, database and ssl are only supported for postgres, mysql, redis and mongodb adapters; use connection_string", prefix))
+		return errors
+	}
+
+	if fields.Host == "" {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - host is required when connection_string is omitted", prefix))
+	}
+	if fields.Port != "" && !envPlaceholderPattern.MatchString(fields.Port) {
+		if port, err := strconv.Atoi(fields.Port); err != nil || port < 1 || port > 65535 {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - port '%s' must be a number between 1 and 65535", prefix, fields.Port))
+		}
+	}
+	if adapter.Connector == connectors.Connector_CONNECTOR_REDIS && fields.Database != "" && !envPlaceholderPattern.MatchString(fields.Database) {
+		if _, err := strconv.Atoi(fields.Database); err != nil {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - database '%s' must be a database number for redis adapters", prefix, fields.Database))
+		}
+	}
+	if fields.Ssl != "" && fields.Ssl != "true" && fields.Ssl != "false" && !envPlaceholderPattern.MatchString(fields.Ssl) {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - ssl must be true or false, got '%s'", prefix, fields.Ssl))
+	}
+	return errors
+}
+
+// sslModes are the values accepted by an adapter's ssl_mode
+var sslModes = []string{"disable", "require", "verify-ca", "verify-full"}
+
+// validateTLSSettings checks an adapter's ssl_mode and certificate files
+func validateTLSSettings(prefix string, adapter *hyperterse.Adapter) []string {
+	var errors []string
+	settings := adapter.Tls
+
+	switch {
+	case executorutils.SQLServer(adapter.Connector),
+		executorutils.StatementKindOf(adapter.Connector) == executorutils.StatementMongoJSON:
+	default:
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - ssl_mode, ca_cert_file, client_cert_file and client_key_file are only supported for postgres, mysql and mongodb adapters", prefix))
+		return errors
+	}
+
+	if settings.SslMode != "" && !slices.Contains(sslModes, settings.SslMode) {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - ssl_mode '%s' must be one of: %s", prefix, settings.SslMode, strings.Join(sslModes, ", ")))
+	}
+	if (settings.ClientCertFile == "") != (settings.ClientKeyFile == "") {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - client_cert_file and client_key_file must be set together", prefix))
+	}
+	if settings.SslMode == "disable" && (settings.CaCertFile != "" || settings.ClientCertFile != "") {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - certificate files cannot be used with ssl_mode 'disable'", prefix))
+	}
+	if adapter.Connection != nil && adapter.Connection.Ssl != "" {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - ssl cannot be combined with ssl_mode; use ssl_mode alone", prefix))
+	}
+	return errors
+}
+
+// iamAuthProviders are the values accepted by an adapter's auth
+var iamAuthProviders = []string{"aws_iam", "gcp_iam"}
+
+// validateIAMAuth checks an adapter's auth and aws_region settings
+func validateIAMAuth(prefix string, adapter *hyperterse.Adapter) []string {
+	var errors []string
+	auth := adapter.IamAuth
+
+	if !executorutils.SQLServer(adapter.Connector) {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - auth is only supported for postgres and mysql adapters", prefix))
+		return errors
+	}
+
+	switch {
+	case auth.Provider == "":
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - aws_region requires auth: aws_iam", prefix))
+	case !slices.Contains(iamAuthProviders, auth.Provider):
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - auth '%s' must be one of: %s", prefix, auth.Provider, strings.Join(iamAuthProviders, ", ")))
+	case auth.Region != "" && auth.Provider != "aws_iam":
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - aws_region is only used with auth: aws_iam", prefix))
+	}
+
+	if auth.Provider != "" {
+		if adapter.Connection != nil {
+			if adapter.Connection.Password != "" {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - password cannot be combined with auth; the token is used as the password", prefix))
+			}
+			if adapter.Connection.User == "" {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - user is required with auth", prefix))
+			}
+			if adapter.Connection.Ssl == "false" {
+				errors = append(errors, fmt.Sprintf("Adapter '%s' - auth requires TLS and cannot be used with ssl: false", prefix))
+			}
+		}
+		if adapter.Tls != nil && adapter.Tls.SslMode == "disable" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - auth requires TLS and cannot be used with ssl_mode 'disable'", prefix))
+		}
+	}
+	return errors
+}
+
+// maxQueryWeight is the largest scheduling weight a query may have
+const maxQueryWeight = 100
+
+// credentialProviders and credentialTargets are the values accepted by an
+// adapter's credentials provider and target
+var (
+	credentialProviders = []string{"env", "file", "command"}
+	credentialTargets   = []string{"password", "connection_string"}
+)
+
+// validateCredentials checks an adapter's refreshable credentials settings
+func validateCredentials(prefix string, adapter *hyperterse.Adapter) []string {
+	var errors []string
+	credentials := adapter.Credentials
+
+	switch credentials.Provider {
+	case "":
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.provider is required (%s)", prefix, strings.Join(credentialProviders, ", ")))
+	case "env":
+		if credentials.Variable == "" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.variable is required for the env provider", prefix))
+		}
+		if credentials.Path == "" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.path is required for the env provider", prefix))
+		}
+	case "file":
+		if credentials.Path == "" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.path is required for the file provider", prefix))
+		}
+	case "command":
+		if len(credentials.Command) == 0 || strings.TrimSpace(credentials.Command[0]) == "" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.command is required for the command provider", prefix))
+		}
+	default:
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.provider '%s' must be one of: %s", prefix, credentials.Provider, strings.Join(credentialProviders, ", ")))
+	}
+	if credentials.Variable != "" && credentials.Provider != "env" {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.variable is only used with the env provider", prefix))
+	}
+	if len(credentials.Command) > 0 && credentials.Provider != "command" {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.command is only used with the command provider", prefix))
+	}
+	if credentials.RefreshInterval < 0 {
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.refresh_interval must be non-negative", prefix))
+	}
+
+	switch credentials.Target {
+	case "", "password":
+		if adapter.Connection != nil && adapter.Connection.Password != "" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - password cannot be combined with credentials; the credential is used as the password", prefix))
+		}
+		if adapter.IamAuth != nil && adapter.IamAuth.Provider != "" {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials cannot be combined with auth, which generates its own password", prefix))
+		}
+	case "connection_string":
+		if adapter.ConnectionString != "" || adapter.Connection != nil {
+			errors = append(errors, fmt.Sprintf("Adapter '%s' - connection_string and connection fields cannot be combined with credentials target 'connection_string'", prefix))
+		}
+	default:
+		errors = append(errors, fmt.Sprintf("Adapter '%s' - credentials.target '%s' must be one of: %s", prefix, credentials.Target, strings.Join(credentialTargets, ", ")))
+	}
+	return errors
+}
+
+// headerNamePattern matches the header names allowed in server.passthrough_headers,
+// which must also be valid in {{ headers.name }} references
+var headerNamePattern = regexp.MustCompile(`^[a-zA-Z0-9-]+$`)
+
+// validateCacheControl checks that value is a comma-separated list of known
+// Cache-Control response directives, each used at most once
+func validateCacheControl(value string) error {
+	seen := make(map[string]bool)
+	for _, part := range strings.Split(value, ",") {
+		directive := strings.TrimSpace(part)
+		if directive == "" {
+			return fmt.Errorf("'%s' contains an empty directive", value)
+		}
+		name, arg, hasArg := strings.Cut(directive, "=")
+		name = strings.ToLower(strings.TrimSpace(name))
+		arg = strings.TrimSpace(arg)
+
+		takesSeconds, known := cacheControlDirectives[name]
+		if !known {
+			return fmt.Errorf("directive '%s' is not a supported Cache-Control response directive", name)
+		}
+		if seen[name] {
+			return fmt.Errorf("directive '%s' is specified more than once", name)
+		}
+		seen[name] = true
+
+		if takesSeconds && (!hasArg || !deltaSecondsPattern.MatchString(arg)) {
+			return fmt.Errorf("directive '%s' requires a number of seconds (e.g. %s=60)", name, name)
+		}
+		if !takesSeconds && hasArg {
+			return fmt.Errorf("directive '%s' does not take a value", name)
+		}
+	}
+	if seen["public"] && seen["private"] {
+		return fmt.Errorf("cannot combine 'public' and 'private'")
+	}
+	return nil
+}
+
+// similarInputReference returns a referenced name that matches name when case,
+// hyphens and underscores are ignored, such as user_id for userId
+func similarInputReference(name string, referenced map[string]bool) string {
+	normalize := func(s string) string {
+		return strings.ToLower(strings.NewReplacer("_", "", "-", "").Replace(s))
+	}
+	var matches []string
+	for ref := range referenced {
+		if normalize(ref) == normalize(name) {
+			matches = append(matches, ref)
+		}
+	}
+	if len(matches) == 0 {
+		return ""
+	}
+	slices.Sort(matches)
+	return matches[0]
+}
+
 // extractInputReferences extracts all input names referenced in the statement
 // using the pattern {{ inputs.x }} and returns them as a unique set
 func extractInputReferences(statement string) []string {
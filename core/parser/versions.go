@@ -0,0 +1,221 @@
+package parser
+
+import (
+	"fmt"
+	"sort"
+)
+
+// CurrentConfigVersion is the configuration layout understood natively by this
+// release and written by hyperterse upgrade-config
+const CurrentConfigVersion = 2
+
+// configUpgrade rewrites a decoded configuration from one layout version to
+// the next, returning a note for each change it made
+type configUpgrade func(raw map[string]any) ([]string, error)
+
+// configUpgrades holds the compatibility shims; configUpgrades[i] upgrades
+// layout version i+1 to version i+2
+var configUpgrades = []configUpgrade{upgradeConfigV1}
+
+// Keys renamed in version 2, by section
+var (
+	legacyAdapterKeys = map[string]string{"type": "connector", "url": "connection_string"}
+	legacyQueryKeys   = map[string]string{"sql": "statement", "adapter": "use", "params": "inputs"}
+)
+
+// UpgradeRawConfig upgrades a decoded configuration in place to the current
+// layout. The starting version is read from config_version, or detected from
+// the layout when it is not declared. It returns the starting version and a
+// note for each change, sorted.
+func UpgradeRawConfig(raw map[string]any) (int, []string, error) {
+	version, err := detectConfigVersion(raw)
+	if err != nil {
+		return 0, nil, err
+	}
+
+	var notes []string
+	for v := version; v < CurrentConfigVersion; v++ {
+		upgradeNotes, err := configUpgrades[v-1](raw)
+		if err != nil {
+			return 0, nil, fmt.Errorf("config_version %d: %w", v, err)
+		}
+		notes = append(notes, upgradeNotes...)
+	}
+	sort.Strings(notes)
+	return version, notes, nil
+}
+
+// detectConfigVersion returns the declared config_version, or 1 for
+// undeclared configurations using the legacy layout and the current version
+// otherwise
+func detectConfigVersion(raw map[string]any) (int, error) {
+	if versionRaw, ok := raw["config_version"]; ok {
+		version := int(parseInt32(versionRaw))
+		if version < 1 {
+			return 0, fmt.Errorf("invalid config_version '%v': must be a positive integer", versionRaw)
+		}
+		if version > CurrentConfigVersion {
+			return 0, fmt.Errorf("config_version %d is newer than this release supports (%d); upgrade hyperterse", version, CurrentConfigVersion)
+		}
+		return version, nil
+	}
+
+	for _, section := range []string{"adapters", "queries", "hooks"} {
+		if _, ok := raw[section].([]any); ok {
+			return 1, nil
+		}
+	}
+	if adapters, ok := raw["adapters"].(map[string]any); ok && hasLegacyKeys(adapters, legacyAdapterKeys) {
+		return 1, nil
+	}
+	if queries, ok := raw["queries"].(map[string]any); ok && hasLegacyKeys(queries, legacyQueryKeys) {
+		return 1, nil
+	}
+	return CurrentConfigVersion, nil
+}
+
+// hasLegacyKeys reports whether any entry of a section uses a legacy key
+// without its replacement
+func hasLegacyKeys(section map[string]any, renames map[string]string) bool {
+	for _, entryRaw := range section {
+		entry, ok := entryRaw.(map[string]any)
+		if !ok {
+			continue
+		}
+		for oldKey, newKey := range renames {
+			_, hasOld := entry[oldKey]
+			_, hasNew := entry[newKey]
+			if hasOld && !hasNew {
+				return true
+			}
+		}
+	}
+	return false
+}
+
+// upgradeConfigV1 converts the version 1 layout, where adapters, queries,
+// hooks, inputs and data were lists of entries with a name field and some keys
+// had other names, to version 2
+func upgradeConfigV1(raw map[string]any) ([]string, error) {
+	var notes []string
+
+	for _, section := range []string{"adapters", "queries", "hooks"} {
+		converted, ok, err := keyByName(raw[section], section)
+		if err != nil {
+			return nil, err
+		}
+		if ok {
+			raw[section] = converted
+			notes = append(notes, fmt.Sprintf("%s: list converted to a map keyed by name", section))
+		}
+	}
+
+	if adapters, ok := raw["adapters"].(map[string]any); ok {
+		for name, adapterRaw := range adapters {
+			adapter, ok := adapterRaw.(map[string]any)
+			if !ok {
+				continue
+			}
+			renamed, err := renameKeys(adapter, "adapters."+name, legacyAdapterKeys)
+			if err != nil {
+				return nil, err
+			}
+			notes = append(notes, renamed...)
+		}
+	}
+
+	if queries, ok := raw["queries"].(map[string]any); ok {
+		for name, queryRaw := range queries {
+			query, ok := queryRaw.(map[string]any)
+			if !ok {
+				continue
+			}
+			path := "queries." + name
+			renamed, err := renameKeys(query, path, legacyQueryKeys)
+			if err != nil {
+				return nil, err
+			}
+			notes = append(notes, renamed...)
+
+			for _, field := range []string{"inputs", "data"} {
+				converted, ok, err := keyByName(query[field], path+"."+field)
+				if err != nil {
+					return nil, err
+				}
+				if ok {
+					query[field] = converted
+					notes = append(notes, fmt.Sprintf("%s.%s: list converted to a map keyed by name", path, field))
+				}
+			}
+
+			// Inputs were required unless marked otherwise; version 2 marks optional ones
+			inputs, _ := query["inputs"].(map[string]any)
+			for inputName, inputRaw := range inputs {
+				input, ok := inputRaw.(map[string]any)
+				if !ok {
+					continue
+				}
+				required, ok := input["required"].(bool)
+				if !ok {
+					continue
+				}
+				delete(input, "required")
+				if !required {
+					input["optional"] = true
+				}
+				notes = append(notes, fmt.Sprintf("%s.inputs.%s: required replaced by optional", path, inputName))
+			}
+		}
+	}
+	return notes, nil
+}
+
+// keyByName converts a list of entries with a name field into a map keyed by
+// name. It reports false when the value is not a list.
+func keyByName(value any, path string) (map[string]any, bool, error) {
+	list, ok := value.([]any)
+	if !ok {
+		return nil, false, nil
+	}
+
+	keyed := make(map[string]any, len(list))
+	for i, itemRaw := range list {
+		item, ok := itemRaw.(map[string]any)
+		if !ok {
+			return nil, false, fmt.Errorf("%s[%d] must be a map", path, i)
+		}
+		name, ok := item["name"].(string)
+		if !ok || name == "" {
+			return nil, false, fmt.Errorf("%s[%d] has no name", path, i)
+		}
+		if _, exists := keyed[name]; exists {
+			return nil, false, fmt.Errorf("%s: duplicate name '%s'", path, name)
+		}
+		entry := make(map[string]any, len(item)-1)
+		for key, value := range item {
+			if key != "name" {
+				entry[key] = value
+			}
+		}
+		keyed[name] = entry
+	}
+	return keyed, true, nil
+}
+
+// renameKeys moves each legacy key of an entry to its replacement
+func renameKeys(entry map[string]any, path string, renames map[string]string) ([]string, error) {
+	var notes []string
+	for oldKey, newKey := range renames {
+		value, ok := entry[oldKey]
+		if !ok {
+			continue
+		}
+		if _, exists := entry[newKey]; exists {
+			return nil, fmt.Errorf("%s: both '%s' and its replacement '%s' are set", path, oldKey, newKey)
+		}
+		delete(entry, oldKey)
+		entry[newKey] = value
+		notes = append(notes, fmt.Sprintf("%s: %s renamed to %s", path, oldKey, newKey))
+	}
+	return notes, nil
+}
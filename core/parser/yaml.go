@@ -2,8 +2,11 @@ package parser
 
 import (
 	"fmt"
+	"strconv"
 	"strings"
+	"time"
 
+	"github.com/hyperterse/hyperterse/core/proto/connectors"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
 	"github.com/hyperterse/hyperterse/core/types"
 	"gopkg.in/yaml.v3"
@@ -17,13 +20,33 @@ func ParseYAML(data []byte) (*hyperterse.Model, error) {
 
 // ParseYAMLWithConfig parses YAML content into a protobuf Model with ServerConfig
 func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
+	// Reject duplicate keys up front so the error can name every location,
+	// instead of surfacing the decoder's first-hit message
+	if err := detectDuplicateKeys(data); err != nil {
+		return nil, err
+	}
+
 	var raw map[string]any
 	if err := yaml.Unmarshal(data, &raw); err != nil {
 		return nil, fmt.Errorf("failed to unmarshal YAML: %w", err)
 	}
 
+	return parseRawModel(raw)
+}
+
+// parseRawModel builds a protobuf Model from a decoded configuration map.
+// It is shared by every configuration format (YAML, JSON, TOML) so they all
+// map onto the same schema.
+func parseRawModel(raw map[string]any) (*hyperterse.Model, error) {
 	model := &hyperterse.Model{}
 
+	// Older layouts are upgraded through compatibility shims before parsing
+	configVersion, _, err := UpgradeRawConfig(raw)
+	if err != nil {
+		return nil, err
+	}
+	model.ConfigVersion = int32(configVersion)
+
 	// Parse name (required)
 	if nameRaw, ok := raw["name"].(string); ok {
 		model.Name = nameRaw
@@ -62,21 +85,215 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 			switch v := portRaw.(type) {
 			case int:
 				serverConfig.Port = fmt.Sprintf("%d", v)
+			case int64:
+				serverConfig.Port = fmt.Sprintf("%d", v)
+			case float64:
+				serverConfig.Port = fmt.Sprintf("%d", int64(v))
 			case string:
 				serverConfig.Port = v
 			}
 		}
 
+		// Parse management_port
+		if managementPortRaw, ok := serverRaw["management_port"]; ok {
+			serverConfig.ManagementPort = formatConnectionField(managementPortRaw)
+		}
+
 		// Parse log_level
 		if logLevelRaw, ok := serverRaw["log_level"]; ok {
 			switch v := logLevelRaw.(type) {
 			case int:
 				serverConfig.LogLevel = int32(v)
+			case int64:
+				serverConfig.LogLevel = int32(v)
 			case float64:
 				serverConfig.LogLevel = int32(v)
 			}
 		}
 
+		// Parse max_rows
+		if maxRowsRaw, ok := serverRaw["max_rows"]; ok {
+			serverConfig.MaxRows = parseInt32(maxRowsRaw)
+		}
+
+		// Parse runtime tuning
+		if workerThreadsRaw, ok := serverRaw["worker_threads"]; ok {
+			serverConfig.WorkerThreads = parseInt32(workerThreadsRaw)
+		}
+		if maxThreadsRaw, ok := serverRaw["max_threads"]; ok {
+			serverConfig.MaxThreads = parseInt32(maxThreadsRaw)
+		}
+
+		// Parse ready_timeout
+		if readyTimeoutRaw, ok := serverRaw["ready_timeout"]; ok {
+			serverConfig.ReadyTimeout = parseInt32(readyTimeoutRaw)
+		}
+
+		// Parse admin_token
+		if adminToken, ok := serverRaw["admin_token"].(string); ok {
+			serverConfig.AdminToken = adminToken
+		}
+
+		// Parse case_insensitive_queries
+		if caseInsensitive, ok := serverRaw["case_insensitive_queries"].(bool); ok {
+			serverConfig.CaseInsensitiveQueries = caseInsensitive
+		}
+
+		// Parse query_index
+		if queryIndex, ok := serverRaw["query_index"].(bool); ok {
+			serverConfig.QueryIndex = queryIndex
+		}
+
+		// Parse error_detail
+		if errorDetail, ok := serverRaw["error_detail"].(string); ok {
+			serverConfig.ErrorDetail = errorDetail
+		}
+
+		// Parse expose_constraint_names
+		if exposeConstraintNames, ok := serverRaw["expose_constraint_names"].(bool); ok {
+			serverConfig.ExposeConstraintNames = exposeConstraintNames
+		}
+
+		// Parse strict_unused_inputs
+		if strictUnusedInputs, ok := serverRaw["strict_unused_inputs"].(bool); ok {
+			serverConfig.StrictUnusedInputs = strictUnusedInputs
+		}
+
+		// Parse base_path, ignoring a trailing slash
+		if basePath, ok := serverRaw["base_path"].(string); ok {
+			serverConfig.BasePath = strings.TrimRight(basePath, "/")
+		}
+
+		// Parse log_format
+		if logFormat, ok := serverRaw["log_format"].(string); ok {
+			serverConfig.LogFormat = logFormat
+		}
+
+		// Parse maintenance mode
+		if maintenanceRaw, ok := serverRaw["maintenance"].(map[string]any); ok {
+			maintenanceConfig := &hyperterse.MaintenanceConfig{}
+			if enabled, ok := maintenanceRaw["enabled"].(bool); ok {
+				maintenanceConfig.Enabled = enabled
+			}
+			if message, ok := maintenanceRaw["message"].(string); ok {
+				maintenanceConfig.Message = message
+			}
+			if retryAfterRaw, ok := maintenanceRaw["retry_after"]; ok {
+				maintenanceConfig.RetryAfter = parseInt32(retryAfterRaw)
+			}
+			serverConfig.Maintenance = maintenanceConfig
+		}
+
+		// Parse failure capture
+		if failureCaptureRaw, ok := serverRaw["failure_capture"].(map[string]any); ok {
+			failureCaptureConfig := &hyperterse.FailureCaptureConfig{}
+			if enabled, ok := failureCaptureRaw["enabled"].(bool); ok {
+				failureCaptureConfig.Enabled = enabled
+			}
+			if dir, ok := failureCaptureRaw["dir"].(string); ok {
+				failureCaptureConfig.Dir = dir
+			}
+			if maxRaw, ok := failureCaptureRaw["max"]; ok {
+				failureCaptureConfig.Max = parseInt32(maxRaw)
+			}
+			serverConfig.FailureCapture = failureCaptureConfig
+		}
+
+		// Parse mcp_log_level
+		if mcpLogLevel, ok := serverRaw["mcp_log_level"].(string); ok {
+			serverConfig.McpLogLevel = mcpLogLevel
+		}
+
+		// Parse health_check_interval
+		if healthCheckIntervalRaw, ok := serverRaw["health_check_interval"]; ok {
+			serverConfig.HealthCheckInterval = parseInt32(healthCheckIntervalRaw)
+		}
+
+		// Parse MCP session store configuration
+		if sessionsRaw, ok := serverRaw["sessions"].(map[string]any); ok {
+			sessionConfig := &hyperterse.SessionConfig{}
+			if store, ok := sessionsRaw["store"].(string); ok {
+				sessionConfig.Store = store
+			}
+			if url, ok := sessionsRaw["url"].(string); ok {
+				sessionConfig.Url = url
+			}
+			if ttlRaw, ok := sessionsRaw["ttl"]; ok {
+				sessionConfig.Ttl = parseInt32(ttlRaw)
+			}
+			serverConfig.Sessions = sessionConfig
+		}
+
+		// Parse route groups; each is a boolean or a map with enabled and port
+		if routesRaw, ok := serverRaw["routes"].(map[string]any); ok {
+			serverConfig.Routes = &hyperterse.RoutesConfig{
+				Queries: parseRouteGroup(routesRaw["queries"]),
+				Mcp:     parseRouteGroup(routesRaw["mcp"]),
+				Docs:    parseRouteGroup(routesRaw["docs"]),
+				Health:  parseRouteGroup(routesRaw["health"]),
+				Admin:   parseRouteGroup(routesRaw["admin"]),
+			}
+		}
+
+		// Parse request log sampling
+		if requestLogRaw, ok := serverRaw["request_log"].(map[string]any); ok {
+			requestLogConfig := &hyperterse.RequestLogConfig{}
+			if sampleRaw, ok := requestLogRaw["sample"]; ok {
+				requestLogConfig.Sample = parseInt32(sampleRaw)
+			}
+			if slowRaw, ok := requestLogRaw["slow_ms"]; ok {
+				requestLogConfig.SlowMs = parseInt32(slowRaw)
+			}
+			serverConfig.RequestLog = requestLogConfig
+		}
+
+		// Parse rate limit configuration
+		if rateLimitRaw, ok := serverRaw["rate_limit"].(map[string]any); ok {
+			rateLimitConfig := &hyperterse.RateLimitConfig{}
+			if requestsRaw, ok := rateLimitRaw["requests"]; ok {
+				rateLimitConfig.Requests = parseInt32(requestsRaw)
+			}
+			if windowRaw, ok := rateLimitRaw["window"]; ok {
+				rateLimitConfig.Window = parseInt32(windowRaw)
+			}
+			if store, ok := rateLimitRaw["store"].(string); ok {
+				rateLimitConfig.Store = store
+			}
+			if url, ok := rateLimitRaw["url"].(string); ok {
+				rateLimitConfig.Url = url
+			}
+			serverConfig.RateLimit = rateLimitConfig
+		}
+
+		// Parse include_meta
+		if includeMetaRaw, ok := serverRaw["include_meta"].(bool); ok {
+			serverConfig.IncludeMeta = includeMetaRaw
+		}
+
+		// Parse result_key_case
+		if keyCaseRaw, ok := serverRaw["result_key_case"].(string); ok {
+			serverConfig.ResultKeyCase = keyCaseRaw
+		}
+
+		// Parse openapi_version; unquoted versions such as 3.1 arrive as numbers
+		if openAPIVersionRaw, ok := serverRaw["openapi_version"]; ok {
+			switch v := openAPIVersionRaw.(type) {
+			case float64:
+				serverConfig.OpenapiVersion = fmt.Sprintf("%.1f", v)
+			case string:
+				serverConfig.OpenapiVersion = v
+			}
+		}
+
+		// Parse passthrough_headers
+		if headersRaw, ok := serverRaw["passthrough_headers"].([]any); ok {
+			for _, item := range headersRaw {
+				if header, ok := item.(string); ok {
+					serverConfig.PassthroughHeaders = append(serverConfig.PassthroughHeaders, header)
+				}
+			}
+		}
+
 		// Parse server.queries.cache configuration
 		if queriesRaw, ok := serverRaw["queries"].(map[string]any); ok {
 			serverQueriesConfig := &hyperterse.ServerQueriesConfig{}
@@ -113,6 +330,102 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 			if connStr, ok := adapterMap["connection_string"].(string); ok {
 				adapter.ConnectionString = connStr
 			}
+			// Structured connection fields are an alternative to connection_string
+			connection := &hyperterse.ConnectionFields{}
+			hasConnection := false
+			for key, target := range map[string]*string{
+				"host":     &connection.Host,
+				"port":     &connection.Port,
+				"user":     &connection.User,
+				"password": &connection.Password,
+				"database": &connection.Database,
+				"ssl":      &connection.Ssl,
+			} {
+				if value, ok := adapterMap[key]; ok {
+					*target = formatConnectionField(value)
+					hasConnection = true
+				}
+			}
+			if hasConnection {
+				adapter.Connection = connection
+			}
+			// TLS settings for servers that need custom certificates
+			tlsConfig := &connectors.TLSConfig{}
+			hasTLS := false
+			for key, target := range map[string]*string{
+				"ssl_mode":         &tlsConfig.SslMode,
+				"ca_cert_file":     &tlsConfig.CaCertFile,
+				"client_cert_file": &tlsConfig.ClientCertFile,
+				"client_key_file":  &tlsConfig.ClientKeyFile,
+			} {
+				if value, ok := adapterMap[key].(string); ok {
+					*target = value
+					hasTLS = true
+				}
+			}
+			if hasTLS {
+				adapter.Tls = tlsConfig
+			}
+			// IAM auth generates short-lived tokens in place of a password
+			provider, hasProvider := adapterMap["auth"].(string)
+			region, hasRegion := adapterMap["aws_region"].(string)
+			if hasProvider || hasRegion {
+				adapter.IamAuth = &connectors.IAMAuth{Provider: provider, Region: region}
+			}
+			// Refreshable credentials are re-checked while serving
+			if credentialsRaw, ok := adapterMap["credentials"].(map[string]any); ok {
+				credentials := &hyperterse.CredentialsConfig{}
+				credentials.Provider, _ = credentialsRaw["provider"].(string)
+				credentials.Variable, _ = credentialsRaw["variable"].(string)
+				credentials.Path, _ = credentialsRaw["path"].(string)
+				credentials.Target, _ = credentialsRaw["target"].(string)
+				// command can be a single program or a list of program and arguments
+				switch v := credentialsRaw["command"].(type) {
+				case string:
+					credentials.Command = []string{v}
+				case []any:
+					for _, item := range v {
+						if str, ok := item.(string); ok {
+							credentials.Command = append(credentials.Command, str)
+						}
+					}
+				}
+				if intervalRaw, ok := credentialsRaw["refresh_interval"]; ok {
+					credentials.RefreshInterval = parseInt32(intervalRaw)
+				}
+				adapter.Credentials = credentials
+			}
+			if maxConcurrencyRaw, ok := adapterMap["max_concurrency"]; ok {
+				adapter.MaxConcurrency = parseInt32(maxConcurrencyRaw)
+			}
+			if poolRaw, ok := adapterMap["pool"].(map[string]any); ok {
+				pool := &connectors.PoolConfig{}
+				if minRaw, ok := poolRaw["min_connections"]; ok {
+					pool.MinConnections = parseInt32(minRaw)
+				}
+				if maxRaw, ok := poolRaw["max_connections"]; ok {
+					pool.MaxConnections = parseInt32(maxRaw)
+				}
+				pool.Warm, _ = poolRaw["warm"].(bool)
+				adapter.Pool = pool
+			}
+			if timezone, ok := adapterMap["timezone"].(string); ok {
+				adapter.Timezone = timezone
+			}
+			if migrations, ok := adapterMap["migrations"].(string); ok {
+				adapter.Migrations = migrations
+			}
+			// on_connect can be a single statement or a list run in order
+			switch v := adapterMap["on_connect"].(type) {
+			case string:
+				adapter.OnConnect = []string{v}
+			case []any:
+				for _, item := range v {
+					if str, ok := item.(string); ok {
+						adapter.OnConnect = append(adapter.OnConnect, str)
+					}
+				}
+			}
 			// Parse optional connector-specific options
 			if optionsRaw, ok := adapterMap["options"].(map[string]any); ok {
 				adapter.Options = &hyperterse.AdapterOptions{
@@ -132,6 +445,59 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 		}
 	}
 
+	// Parse hooks - a map where keys are names
+	if hooksRaw, ok := raw["hooks"].(map[string]any); ok {
+		for hookName, hookRaw := range hooksRaw {
+			hookMap, ok := hookRaw.(map[string]any)
+			if !ok {
+				return nil, fmt.Errorf("invalid hook structure for '%s'", hookName)
+			}
+
+			hook := &hyperterse.Hook{
+				Name: hookName,
+			}
+			if url, ok := hookMap["url"].(string); ok {
+				hook.Url = url
+			}
+			// Handle events field: can be string or []string
+			switch v := hookMap["events"].(type) {
+			case string:
+				hook.Events = []string{v}
+			case []any:
+				for _, item := range v {
+					if str, ok := item.(string); ok {
+						hook.Events = append(hook.Events, str)
+					}
+				}
+			}
+			if secret, ok := hookMap["secret"].(string); ok {
+				hook.Secret = secret
+			}
+			if retriesRaw, ok := hookMap["retries"]; ok {
+				hook.Retries = parseInt32(retriesRaw)
+				hook.HasRetries = true
+			}
+			if timeoutRaw, ok := hookMap["timeout"]; ok {
+				hook.Timeout = parseInt32(timeoutRaw)
+			}
+			if thresholdRaw, ok := hookMap["failure_threshold"]; ok {
+				hook.FailureThreshold = parseInt32(thresholdRaw)
+			}
+
+			model.Hooks = append(model.Hooks, hook)
+		}
+	}
+
+	// Parse defs - reusable constants and statement fragments, expanded into
+	// statements at parse time
+	defs := map[string]string{}
+	if defsRaw, ok := raw["defs"].(map[string]any); ok {
+		var err error
+		if defs, err = parseDefs(defsRaw); err != nil {
+			return nil, err
+		}
+	}
+
 	// Parse queries - now a map where keys are names
 	if queriesRaw, ok := raw["queries"].(map[string]any); ok {
 		for queryName, queryRaw := range queriesRaw {
@@ -147,7 +513,11 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 				query.Description = description
 			}
 			if statement, ok := queryMap["statement"].(string); ok {
-				query.Statement = statement
+				expanded, err := expandDefRefs(queryName, statement, defs)
+				if err != nil {
+					return nil, err
+				}
+				query.Statement = expanded
 			}
 
 			// Handle use field: can be string or []string
@@ -194,6 +564,46 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 					if defaultValueRaw, ok := inputMap["default"]; ok {
 						input.DefaultValue = fmt.Sprintf("%v", defaultValueRaw)
 					}
+					if coerce, ok := inputMap["coerce"].(bool); ok {
+						input.Coerce = coerce
+					}
+					if escapeLike, ok := inputMap["escape_like"].(bool); ok {
+						input.EscapeLike = escapeLike
+					}
+					if enumRaw, ok := inputMap["enum"].([]any); ok {
+						for _, item := range enumRaw {
+							input.Enum = append(input.Enum, fmt.Sprintf("%v", item))
+						}
+					}
+					// example is a single example value, examples a list of them
+					exampleRaw, hasExample := inputMap["example"]
+					examplesRaw, hasExamples := inputMap["examples"]
+					if hasExample && hasExamples {
+						return nil, fmt.Errorf("input '%s' in query '%s' sets both example and examples; use one", inputName, queryName)
+					}
+					if hasExample {
+						input.Examples = []string{exampleString(exampleRaw)}
+					}
+					if hasExamples {
+						items, ok := examplesRaw.([]any)
+						if !ok {
+							return nil, fmt.Errorf("examples of input '%s' in query '%s' must be a list", inputName, queryName)
+						}
+						for _, item := range items {
+							input.Examples = append(input.Examples, exampleString(item))
+						}
+					}
+					// transform can be a single transform or a list applied in order
+					switch v := inputMap["transform"].(type) {
+					case string:
+						input.Transforms = []string{v}
+					case []any:
+						for _, item := range v {
+							if str, ok := item.(string); ok {
+								input.Transforms = append(input.Transforms, str)
+							}
+						}
+					}
 
 					query.Inputs = append(query.Inputs, input)
 				}
@@ -226,6 +636,9 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 					if mapTo, ok := dataMap["map_to"].(string); ok {
 						data.MapTo = mapTo
 					}
+					if defaultValueRaw, ok := dataMap["default"]; ok {
+						data.DefaultValue = fmt.Sprintf("%v", defaultValueRaw)
+					}
 
 					query.Data = append(query.Data, data)
 				}
@@ -236,6 +649,76 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 				query.Cache = parseCacheConfig(cacheRaw)
 			}
 
+			// Parse optional query-level row limit
+			if maxRowsRaw, ok := queryMap["max_rows"]; ok {
+				query.MaxRows = parseInt32(maxRowsRaw)
+			}
+
+			// Parse optional Cache-Control response header
+			if cacheControl, ok := queryMap["cache_control"].(string); ok {
+				query.CacheControl = cacheControl
+			}
+
+			// Parse optional null handling mode
+			if nulls, ok := queryMap["nulls"].(string); ok {
+				query.Nulls = nulls
+			}
+
+			// Parse optional scheduling weight
+			if weightRaw, ok := queryMap["weight"]; ok {
+				query.Weight = parseInt32(weightRaw)
+			}
+
+			// Parse optional in-flight request coalescing
+			if coalesce, ok := queryMap["coalesce"].(bool); ok {
+				query.Coalesce = coalesce
+			}
+
+			// Parse optional request log sample rate
+			if logSampleRaw, ok := queryMap["log_sample"]; ok {
+				query.LogSample = parseInt32(logSampleRaw)
+			}
+
+			// Parse optional enable toggle, kept as a string so it can read an env var
+			if enabledRaw, ok := queryMap["enabled"]; ok {
+				query.Enabled = formatConnectionField(enabledRaw)
+			}
+
+			// Parse optional soft delete predicate
+			if filter, ok := queryMap["soft_delete_filter"].(string); ok {
+				query.SoftDeleteFilter = filter
+			}
+
+			// Parse optional cursor pagination
+			if cursorRaw, ok := queryMap["cursor"].(map[string]any); ok {
+				cursor := &hyperterse.CursorConfig{}
+				if key, ok := cursorRaw["key"].(string); ok {
+					cursor.Key = key
+				}
+				if input, ok := cursorRaw["input"].(string); ok {
+					cursor.Input = input
+				}
+				query.Cursor = cursor
+			}
+
+			// Parse adapters admin requests may switch the query to
+			if allowedRaw, ok := queryMap["allowed_adapters"].([]any); ok {
+				for _, item := range allowedRaw {
+					if str, ok := item.(string); ok {
+						query.AllowedAdapters = append(query.AllowedAdapters, str)
+					}
+				}
+			}
+
+			// Parse optional federation of sub-queries across adapters
+			if federatedRaw, ok := queryMap["federated"].(map[string]any); ok {
+				federated, err := parseFederatedConfig(queryName, federatedRaw, defs)
+				if err != nil {
+					return nil, err
+				}
+				query.Federated = federated
+			}
+
 			model.Queries = append(model.Queries, query)
 		}
 	}
@@ -243,6 +726,121 @@ func ParseYAMLWithConfig(data []byte) (*hyperterse.Model, error) {
 	return model, nil
 }
 
+// parseFederatedConfig parses a query's federated section. Sources are a list
+// because their order matters: the first is the base of a join.
+func parseFederatedConfig(queryName string, federatedRaw map[string]any, defs map[string]string) (*hyperterse.FederatedConfig, error) {
+	federated := &hyperterse.FederatedConfig{}
+	if merge, ok := federatedRaw["merge"].(string); ok {
+		federated.Merge = merge
+	}
+
+	if sourcesRaw, ok := federatedRaw["sources"]; ok {
+		sourcesList, ok := sourcesRaw.([]any)
+		if !ok {
+			return nil, fmt.Errorf("federated.sources in query '%s' must be a list", queryName)
+		}
+		for i, sourceRaw := range sourcesList {
+			sourceMap, ok := sourceRaw.(map[string]any)
+			if !ok {
+				return nil, fmt.Errorf("invalid federated source structure at index %d in query '%s'", i, queryName)
+			}
+			source := &hyperterse.FederatedSource{}
+			if name, ok := sourceMap["name"].(string); ok {
+				source.Name = name
+			}
+			if use, ok := sourceMap["use"].(string); ok {
+				source.Use = use
+			}
+			if statement, ok := sourceMap["statement"].(string); ok {
+				expanded, err := expandDefRefs(queryName, statement, defs)
+				if err != nil {
+					return nil, err
+				}
+				source.Statement = expanded
+			}
+			federated.Sources = append(federated.Sources, source)
+		}
+	}
+
+	if joinRaw, ok := federatedRaw["join"].(map[string]any); ok {
+		join := &hyperterse.FederatedJoin{}
+		if joinType, ok := joinRaw["type"].(string); ok {
+			join.Type = joinType
+		}
+		if keysRaw, ok := joinRaw["keys"].(map[string]any); ok {
+			join.Keys = make(map[string]string, len(keysRaw))
+			for sourceName, keyRaw := range keysRaw {
+				join.Keys[sourceName] = fmt.Sprintf("%v", keyRaw)
+			}
+		}
+		federated.Join = join
+	}
+	return federated, nil
+}
+
+// detectDuplicateKeys walks the YAML node tree and reports every mapping key
+// that is defined more than once, along with the lines it appears on
+func detectDuplicateKeys(data []byte) error {
+	var root yaml.Node
+	if err := yaml.Unmarshal(data, &root); err != nil {
+		// Leave syntax errors to the regular unmarshal path
+		return nil
+	}
+
+	var errors []string
+	collectDuplicateKeys(&root, "", &errors)
+	if len(errors) > 0 {
+		return &ValidationErrors{Errors: errors}
+	}
+	return nil
+}
+
+func collectDuplicateKeys(node *yaml.Node, path string, errors *[]string) {
+	switch node.Kind {
+	case yaml.DocumentNode:
+		for _, child := range node.Content {
+			collectDuplicateKeys(child, path, errors)
+		}
+	case yaml.SequenceNode:
+		for i, child := range node.Content {
+			collectDuplicateKeys(child, fmt.Sprintf("%s[%d]", path, i), errors)
+		}
+	case yaml.MappingNode:
+		keyLines := make(map[string][]int)
+		var keyOrder []string
+		for i := 0; i+1 < len(node.Content); i += 2 {
+			keyNode := node.Content[i]
+			// Merge keys (<<) may legitimately repeat
+			if keyNode.Tag == "!!merge" {
+				continue
+			}
+			if _, seen := keyLines[keyNode.Value]; !seen {
+				keyOrder = append(keyOrder, keyNode.Value)
+			}
+			keyLines[keyNode.Value] = append(keyLines[keyNode.Value], keyNode.Line)
+			collectDuplicateKeys(node.Content[i+1], joinKeyPath(path, keyNode.Value), errors)
+		}
+		for _, key := range keyOrder {
+			lines := keyLines[key]
+			if len(lines) < 2 {
+				continue
+			}
+			lineStrs := make([]string, len(lines))
+			for i, line := range lines {
+				lineStrs[i] = fmt.Sprintf("%d", line)
+			}
+			*errors = append(*errors, fmt.Sprintf("Key '%s' - defined %d times (lines %s). Keys must be unique", joinKeyPath(path, key), len(lines), strings.Join(lineStrs, ", ")))
+		}
+	}
+}
+
+func joinKeyPath(path, key string) string {
+	if path == "" {
+		return key
+	}
+	return path + "." + key
+}
+
 func parseCacheConfig(cacheRaw map[string]any) *hyperterse.CacheConfig {
 	cacheConfig := &hyperterse.CacheConfig{}
 	hasAnyField := false
@@ -261,6 +859,10 @@ func parseCacheConfig(cacheRaw map[string]any) *hyperterse.CacheConfig {
 			cacheConfig.Ttl = int32(v)
 			cacheConfig.HasTtl = true
 			hasAnyField = true
+		case int64:
+			cacheConfig.Ttl = int32(v)
+			cacheConfig.HasTtl = true
+			hasAnyField = true
 		case float64:
 			cacheConfig.Ttl = int32(v)
 			cacheConfig.HasTtl = true
@@ -274,3 +876,56 @@ func parseCacheConfig(cacheRaw map[string]any) *hyperterse.CacheConfig {
 
 	return cacheConfig
 }
+
+// formatConnectionField converts a connection field value, which YAML may
+// parse as a number or boolean, to its string form
+func formatConnectionField(raw any) string {
+	switch v := raw.(type) {
+	case string:
+		return v
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64)
+	default:
+		return fmt.Sprintf("%v", v)
+	}
+}
+
+// parseRouteGroup parses a server.routes entry, either a boolean enabling the
+// group or a map with enabled and port. Unset groups return nil.
+func parseRouteGroup(raw any) *hyperterse.RouteGroupConfig {
+	switch v := raw.(type) {
+	case bool:
+		return &hyperterse.RouteGroupConfig{Disabled: !v}
+	case map[string]any:
+		group := &hyperterse.RouteGroupConfig{}
+		if enabled, ok := v["enabled"].(bool); ok {
+			group.Disabled = !enabled
+		}
+		if portRaw, ok := v["port"]; ok {
+			group.Port = formatConnectionField(portRaw)
+		}
+		return group
+	}
+	return nil
+}
+
+func parseInt32(raw any) int32 {
+	switch v := raw.(type) {
+	case int:
+		return int32(v)
+	case int64:
+		return int32(v)
+	case float64:
+		return int32(v)
+	}
+	return 0
+}
+
+// exampleString stores an input example as a string, like default values.
+// Unquoted YAML timestamps are written back in RFC 3339 form.
+func exampleString(value any) string {
+	if t, ok := value.(time.Time); ok {
+		return t.Format(time.RFC3339Nano)
+	}
+	return fmt.Sprintf("%v", value)
+}
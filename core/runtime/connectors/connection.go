@@ -0,0 +1,82 @@
+package connectors
+
+import (
+	"fmt"
+	"net"
+	"net/url"
+
+	"github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/utils"
+)
+
+// connectionSchemes maps the connectors that accept structured connection
+// fields to their URL scheme
+var connectionSchemes = map[connectors.Connector]string{
+	connectors.Connector_CONNECTOR_POSTGRES: "postgres",
+	connectors.Connector_CONNECTOR_MYSQL:    "mysql",
+	connectors.Connector_CONNECTOR_REDIS:    "redis",
+	connectors.Connector_CONNECTOR_MONGODB:  "mongodb",
+}
+
+// buildConnectionString assembles the connector URL from structured connection
+// fields. Environment variables are substituted in each field first, so every
+// component is percent-encoded after its final value is known.
+func buildConnectionString(connector connectors.Connector, fields *hyperterse.ConnectionFields) (string, error) {
+	scheme, ok := connectionSchemes[connector]
+	if !ok {
+		return "", fmt.Errorf("connection fields are not supported for %s adapters", connector.String())
+	}
+
+	values := make(map[string]string, 6)
+	for name, raw := range map[string]string{
+		"host":     fields.Host,
+		"port":     fields.Port,
+		"user":     fields.User,
+		"password": fields.Password,
+		"database": fields.Database,
+		"ssl":      fields.Ssl,
+	} {
+		value, err := utils.SubstituteEnvVars(raw)
+		if err != nil {
+			return "", fmt.Errorf("%s: %w", name, err)
+		}
+		values[name] = value
+	}
+
+	u := &url.URL{Scheme: scheme, Host: values["host"]}
+	if values["port"] != "" {
+		u.Host = net.JoinHostPort(values["host"], values["port"])
+	}
+	if values["user"] != "" || values["password"] != "" {
+		if values["password"] != "" {
+			u.User = url.UserPassword(values["user"], values["password"])
+		} else {
+			u.User = url.User(values["user"])
+		}
+	}
+	if values["database"] != "" {
+		u.Path = "/" + values["database"]
+	}
+
+	switch ssl := values["ssl"]; {
+	case ssl == "":
+		// Leave the driver's default
+	case ssl != "true" && ssl != "false":
+		return "", fmt.Errorf("ssl must be true or false, got '%s'", ssl)
+	case connector == connectors.Connector_CONNECTOR_REDIS:
+		if ssl == "true" {
+			u.Scheme = "rediss"
+		}
+	case connector == connectors.Connector_CONNECTOR_POSTGRES:
+		mode := "disable"
+		if ssl == "true" {
+			mode = "require"
+		}
+		u.RawQuery = url.Values{"sslmode": {mode}}.Encode()
+	default:
+		u.RawQuery = url.Values{"tls": {ssl}}.Encode()
+	}
+
+	return u.String(), nil
+}
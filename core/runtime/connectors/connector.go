@@ -2,10 +2,12 @@ package connectors
 
 import (
 	"context"
+	"database/sql"
 	"fmt"
 
 	"github.com/hyperterse/hyperterse/core/proto/connectors"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
 	"github.com/hyperterse/hyperterse/core/runtime/utils"
 )
 
@@ -24,17 +26,49 @@ type Connector interface {
 	Close() error
 }
 
+// HealthChecker is implemented by connectors that can verify their backend is
+// reachable. The runtime pings them periodically to publish health changes.
+type HealthChecker interface {
+	Ping(ctx context.Context) error
+}
+
+// PoolStatsReporter is implemented by connectors backed by a database/sql
+// pool. The runtime reports the statistics as metrics and in /health/ready.
+type PoolStatsReporter interface {
+	PoolStats() sql.DBStats
+}
+
+// SQLDatabase is implemented by connectors backed by database/sql. The
+// migration runner uses the handle to apply each migration in a transaction.
+type SQLDatabase interface {
+	DB() *sql.DB
+}
+
 // NewConnector creates a new connector based on the adapter configuration.
-// Environment variables in connection_string are substituted at runtime (server startup).
+// Environment variables in connection_string, connection fields, on_connect,
+// certificate paths and aws_region are substituted at runtime (server startup).
 func NewConnector(adapter *hyperterse.Adapter) (Connector, error) {
-	if adapter.ConnectionString == "" {
-		return nil, fmt.Errorf("adapter '%s' missing connection string", adapter.Name)
+	// Static adapters serve rows from their statements and connect to nothing
+	if adapter.Connector == connectors.Connector_CONNECTOR_STATIC {
+		return NewStaticConnector(), nil
 	}
 
-	// Substitute environment variables in connection_string at runtime
-	connectionString, err := utils.SubstituteEnvVars(adapter.ConnectionString)
-	if err != nil {
-		return nil, fmt.Errorf("adapter '%s': %w", adapter.Name, err)
+	var connectionString string
+	var err error
+	switch {
+	case adapter.ConnectionString != "":
+		// Substitute environment variables in connection_string at runtime
+		connectionString, err = utils.SubstituteEnvVars(adapter.ConnectionString)
+		if err != nil {
+			return nil, fmt.Errorf("adapter '%s': %w", adapter.Name, err)
+		}
+	case adapter.Connection != nil:
+		connectionString, err = buildConnectionString(adapter.Connector, adapter.Connection)
+		if err != nil {
+			return nil, fmt.Errorf("adapter '%s': %w", adapter.Name, err)
+		}
+	default:
+		return nil, fmt.Errorf("adapter '%s' missing connection string", adapter.Name)
 	}
 
 	var options map[string]string
@@ -42,12 +76,54 @@ func NewConnector(adapter *hyperterse.Adapter) (Connector, error) {
 		options = adapter.Options.Options
 	}
 
+	onConnect := make([]string, 0, len(adapter.OnConnect))
+	for _, stmt := range adapter.OnConnect {
+		substituted, err := utils.SubstituteEnvVars(stmt)
+		if err != nil {
+			return nil, fmt.Errorf("adapter '%s' on_connect: %w", adapter.Name, err)
+		}
+		onConnect = append(onConnect, substituted)
+	}
+
+	var tlsConfig *connectors.TLSConfig
+	if adapter.Tls != nil {
+		tlsConfig = &connectors.TLSConfig{SslMode: adapter.Tls.SslMode}
+		for _, file := range []struct {
+			name   string
+			value  string
+			target *string
+		}{
+			{"ca_cert_file", adapter.Tls.CaCertFile, &tlsConfig.CaCertFile},
+			{"client_cert_file", adapter.Tls.ClientCertFile, &tlsConfig.ClientCertFile},
+			{"client_key_file", adapter.Tls.ClientKeyFile, &tlsConfig.ClientKeyFile},
+		} {
+			substituted, err := utils.SubstituteEnvVars(file.value)
+			if err != nil {
+				return nil, fmt.Errorf("adapter '%s' %s: %w", adapter.Name, file.name, err)
+			}
+			*file.target = substituted
+		}
+	}
+
+	var iamAuth *connectors.IAMAuth
+	if adapter.IamAuth != nil {
+		region, err := utils.SubstituteEnvVars(adapter.IamAuth.Region)
+		if err != nil {
+			return nil, fmt.Errorf("adapter '%s' aws_region: %w", adapter.Name, err)
+		}
+		iamAuth = &connectors.IAMAuth{Provider: adapter.IamAuth.Provider, Region: region}
+	}
+
 	// Build ConnectorDef from adapter fields
 	def := &connectors.ConnectorDef{
 		ConnectionString: connectionString,
 		Options:          options,
+		OnConnect:        onConnect,
+		Tls:              tlsConfig,
+		IamAuth:          iamAuth,
+		Pool:             adapter.Pool,
 		Config: &connectors.ConnectorConfig{
-			JsonStatements: false,
+			JsonStatements: executorutils.JSONStatements(adapter.Connector),
 		},
 	}
 
@@ -59,8 +135,13 @@ func NewConnector(adapter *hyperterse.Adapter) (Connector, error) {
 	case connectors.Connector_CONNECTOR_REDIS:
 		return NewRedisConnector(def)
 	case connectors.Connector_CONNECTOR_MONGODB:
-		def.Config.JsonStatements = true
 		return NewMongoDBConnector(def)
+	case connectors.Connector_CONNECTOR_OBJECTSTORE:
+		return NewObjectStoreConnector(def)
+	case connectors.Connector_CONNECTOR_DUCKDB:
+		return NewDuckDBConnector(def)
+	case connectors.Connector_CONNECTOR_KAFKA:
+		return NewKafkaConnector(def)
 	case connectors.Connector_CONNECTOR_UNSPECIFIED:
 		return nil, fmt.Errorf("adapter '%s' has unspecified connector type", adapter.Name)
 	default:
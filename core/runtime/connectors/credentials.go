@@ -0,0 +1,224 @@
+package connectors
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io/fs"
+	"net/url"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/fsnotify/fsnotify"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/utils"
+	"github.com/joho/godotenv"
+	"google.golang.org/protobuf/proto"
+)
+
+// Credential providers and targets accepted by an adapter's credentials
+const (
+	credentialProviderEnv     = "env"
+	credentialProviderFile    = "file"
+	credentialProviderCommand = "command"
+
+	credentialTargetConnectionString = "connection_string"
+)
+
+const (
+	// defaultCredentialRefreshInterval is how often credentials are re-checked
+	// when refresh_interval is unset
+	defaultCredentialRefreshInterval = 60 * time.Second
+
+	// credentialCommandTimeout bounds a command provider's run
+	credentialCommandTimeout = 30 * time.Second
+
+	// credentialDrainDelay is how long a replaced pool stays open for queries
+	// that fetched it just before the swap
+	credentialDrainDelay = 30 * time.Second
+)
+
+// credentialRefreshInterval returns how often settings are re-checked
+func credentialRefreshInterval(settings *hyperterse.CredentialsConfig) time.Duration {
+	if settings.RefreshInterval > 0 {
+		return time.Duration(settings.RefreshInterval) * time.Second
+	}
+	return defaultCredentialRefreshInterval
+}
+
+// resolveCredential reads the current value of a credential from its provider
+func resolveCredential(ctx context.Context, settings *hyperterse.CredentialsConfig) (string, error) {
+	switch settings.Provider {
+	case credentialProviderEnv:
+		// The env file is read first, since the process environment still holds
+		// the value loaded from it at startup
+		values, err := godotenv.Read(settings.Path)
+		if err != nil && !errors.Is(err, fs.ErrNotExist) {
+			return "", fmt.Errorf("failed to read env file: %w", err)
+		}
+		if value, ok := values[settings.Variable]; ok {
+			return value, nil
+		}
+		if value, ok := os.LookupEnv(settings.Variable); ok {
+			return value, nil
+		}
+		return "", fmt.Errorf("environment variable '%s' not found", settings.Variable)
+
+	case credentialProviderFile:
+		data, err := os.ReadFile(settings.Path)
+		if err != nil {
+			return "", fmt.Errorf("failed to read credentials file: %w", err)
+		}
+		return strings.TrimSpace(string(data)), nil
+
+	case credentialProviderCommand:
+		if len(settings.Command) == 0 {
+			return "", fmt.Errorf("credentials command is empty")
+		}
+		ctx, cancel := context.WithTimeout(ctx, credentialCommandTimeout)
+		defer cancel()
+		output, err := exec.CommandContext(ctx, settings.Command[0], settings.Command[1:]...).Output()
+		if err != nil {
+			var exitErr *exec.ExitError
+			if errors.As(err, &exitErr) && len(exitErr.Stderr) > 0 {
+				return "", fmt.Errorf("credentials command failed: %w: %s", err, strings.TrimSpace(string(exitErr.Stderr)))
+			}
+			return "", fmt.Errorf("credentials command failed: %w", err)
+		}
+		return strings.TrimSpace(string(output)), nil
+
+	default:
+		return "", fmt.Errorf("unknown credentials provider '%s'", settings.Provider)
+	}
+}
+
+// withCredential returns a copy of adapter with value applied to its
+// credentials target: the password of its connection, or the whole
+// connection string
+func withCredential(adapter *hyperterse.Adapter, value string) (*hyperterse.Adapter, error) {
+	resolved := proto.Clone(adapter).(*hyperterse.Adapter)
+
+	if adapter.Credentials.Target == credentialTargetConnectionString {
+		resolved.ConnectionString = value
+		resolved.Connection = nil
+		return resolved, nil
+	}
+
+	if resolved.Connection != nil {
+		resolved.Connection.Password = value
+		return resolved, nil
+	}
+
+	connectionString, err := utils.SubstituteEnvVars(resolved.ConnectionString)
+	if err != nil {
+		return nil, err
+	}
+	u, err := url.Parse(connectionString)
+	if err != nil || u.Scheme == "" || u.Host == "" {
+		return nil, fmt.Errorf("credentials target 'password' requires a connection URL or connection fields")
+	}
+	u.User = url.UserPassword(u.User.Username(), value)
+	resolved.ConnectionString = u.String()
+	return resolved, nil
+}
+
+// watchCredentials re-checks the adapter's credential until the manager is
+// closed, rebuilding its pool whenever the value differs from current. File
+// providers are also checked as soon as the file's directory changes. A pool
+// that fails to connect with the new value is not swapped in, and the change
+// is retried at the next check.
+func (m *ConnectorManager) watchCredentials(adapter *hyperterse.Adapter, current string) {
+	log := logger.New(fmt.Sprintf("connector:%s", adapter.Name))
+	settings := adapter.Credentials
+
+	ticker := time.NewTicker(credentialRefreshInterval(settings))
+	defer ticker.Stop()
+
+	// Watch the directory rather than the file, since secret mounts such as
+	// Kubernetes volumes replace files through a symlink swap
+	var fileEvents <-chan fsnotify.Event
+	var fileErrors <-chan error
+	if settings.Provider == credentialProviderFile {
+		watcher, err := fsnotify.NewWatcher()
+		if err == nil {
+			err = watcher.Add(filepath.Dir(settings.Path))
+		}
+		if err != nil {
+			log.Warnf("Failed to watch credentials file, checking every %s instead: %v", credentialRefreshInterval(settings), err)
+		} else {
+			defer watcher.Close()
+			fileEvents = watcher.Events
+			fileErrors = watcher.Errors
+		}
+	}
+
+	for {
+		select {
+		case <-m.ctx.Done():
+			return
+		case <-ticker.C:
+		case <-fileEvents:
+		case err := <-fileErrors:
+			log.Debugf("Credentials file watcher error: %v", err)
+			continue
+		}
+
+		value, err := resolveCredential(m.ctx, settings)
+		if err != nil {
+			log.Warnf("Failed to refresh credentials: %v", err)
+			continue
+		}
+		if value == current {
+			continue
+		}
+
+		log.Infof("Credentials changed, rebuilding connection pool")
+		if err := m.replace(adapter, value); err != nil {
+			if m.ctx.Err() != nil {
+				return
+			}
+			log.Warnf("Failed to connect with new credentials, keeping the current pool: %v", err)
+			continue
+		}
+		current = value
+		log.Infof("Connection pool rebuilt with new credentials")
+	}
+}
+
+// replace connects the adapter with credential and swaps the new connector in.
+// The previous connector is closed after credentialDrainDelay, giving queries
+// that already hold it time to finish.
+func (m *ConnectorManager) replace(adapter *hyperterse.Adapter, credential string) error {
+	resolved, err := withCredential(adapter, credential)
+	if err != nil {
+		return err
+	}
+	conn, err := NewConnector(resolved)
+	if err != nil {
+		return err
+	}
+
+	m.mu.Lock()
+	if m.ctx.Err() != nil {
+		// CloseAll ran while connecting
+		m.mu.Unlock()
+		conn.Close()
+		return m.ctx.Err()
+	}
+	previous := m.connectors[adapter.Name]
+	m.connectors[adapter.Name] = conn
+	m.mu.Unlock()
+
+	if previous != nil {
+		time.AfterFunc(credentialDrainDelay, func() {
+			if err := previous.Close(); err != nil {
+				logger.New(fmt.Sprintf("connector:%s", adapter.Name)).Warnf("Failed to close replaced connection pool: %v", err)
+			}
+		})
+	}
+	return nil
+}
@@ -0,0 +1,255 @@
+//go:build cgo
+
+package connectors
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"math/big"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/duckdb/duckdb-go/v2"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/observability"
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"go.opentelemetry.io/otel"
+	"go.opentelemetry.io/otel/attribute"
+	"go.opentelemetry.io/otel/codes"
+)
+
+// DuckDBConnector implements the Connector interface for an embedded DuckDB database.
+// Queries can read Parquet, CSV and JSON files from allow-listed directories and
+// work with tables held in the database file or in memory.
+type DuckDBConnector struct {
+	db *sql.DB
+}
+
+// NewDuckDBConnector creates a new DuckDB connector.
+// The connection string is the path to a database file, or ":memory:" for an
+// in-memory database. A "duckdb://" prefix is accepted and ignored.
+//
+// File access is denied unless the directories are listed in the "allowed_paths"
+// option. The sandbox settings are locked so statements cannot relax them.
+func NewDuckDBConnector(def *protoconnectors.ConnectorDef) (*DuckDBConnector, error) {
+	options := def.GetOptions()
+
+	if def.GetConfig().GetJsonStatements() {
+		return nil, fmt.Errorf("json_statements is not supported for duckdb")
+	}
+
+	dsn := strings.TrimPrefix(def.GetConnectionString(), "duckdb://")
+	if dsn == ":memory:" {
+		dsn = ""
+	}
+	if readOnly, ok := options["read_only"]; ok {
+		enabled, err := strconv.ParseBool(readOnly)
+		if err != nil {
+			return nil, fmt.Errorf("invalid duckdb option read_only '%s': %w", readOnly, err)
+		}
+		if enabled {
+			if dsn == "" {
+				return nil, fmt.Errorf("duckdb option read_only requires a database file")
+			}
+			dsn += "?access_mode=READ_ONLY"
+		}
+	}
+
+	allowedDirs, err := parseAllowedPaths(options["allowed_paths"])
+	if err != nil {
+		return nil, err
+	}
+
+	log := logger.New("connector:duckdb")
+	if dsn == "" {
+		log.Debugf("Opening in-memory DuckDB database")
+	} else {
+		log.Debugf("Opening DuckDB database %s", dsn)
+	}
+
+	db, err := sql.Open("duckdb", dsn)
+	if err != nil {
+		return nil, fmt.Errorf("failed to open duckdb database: %w", err)
+	}
+
+	// Settings are global to the database instance and shared by every pooled
+	// connection. allowed_directories must be set before external access is
+	// disabled, and locking the configuration prevents statements from undoing it.
+	setup := []string{
+		fmt.Sprintf("SET GLOBAL allowed_directories = %s", duckdbStringList(allowedDirs)),
+		"SET GLOBAL enable_external_access = false",
+		"SET GLOBAL autoinstall_known_extensions = false",
+		"SET GLOBAL autoload_known_extensions = false",
+	}
+	if initStatement := options["init_statement"]; initStatement != "" {
+		setup = append(setup, initStatement)
+	}
+	setup = append(setup, "SET GLOBAL lock_configuration = true")
+
+	for _, stmt := range setup {
+		if _, err := db.Exec(stmt); err != nil {
+			db.Close()
+			return nil, fmt.Errorf("failed to configure duckdb database: %w", err)
+		}
+	}
+
+	log.Debugf("DuckDB database opened with %d allowed path(s)", len(allowedDirs))
+	return &DuckDBConnector{db: db}, nil
+}
+
+// Execute executes a SQL statement against DuckDB with context support
+func (d *DuckDBConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	start := time.Now()
+	tracer := otel.Tracer("runtime/connectors/duckdb")
+	ctx, span := tracer.Start(ctx, "connector.duckdb.execute")
+	defer span.End()
+	span.SetAttributes(attribute.String(observability.AttrConnectorType, "duckdb"))
+
+	rows, err := d.db.QueryContext(ctx, statement)
+	if err != nil {
+		span.SetStatus(codes.Error, "query_failed")
+		observability.RecordConnectorOperation(ctx, "", "duckdb", "execute", false, float64(time.Since(start).Milliseconds()))
+		return nil, fmt.Errorf("failed to execute query: %w", err)
+	}
+	defer rows.Close()
+
+	// Get column names
+	columns, err := rows.Columns()
+	if err != nil {
+		span.SetStatus(codes.Error, "columns_failed")
+		observability.RecordConnectorOperation(ctx, "", "duckdb", "execute", false, float64(time.Since(start).Milliseconds()))
+		return nil, fmt.Errorf("failed to get columns: %w", err)
+	}
+
+	// Build result slice. Scan buffers are reused across rows since every value is
+	// copied into the row map, and column names are shared by all row maps.
+	var results []map[string]any
+	values := make([]any, len(columns))
+	valuePtrs := make([]any, len(columns))
+	for i := range values {
+		valuePtrs[i] = &values[i]
+	}
+	for rows.Next() {
+		// Scan row into values
+		if err := rows.Scan(valuePtrs...); err != nil {
+			span.SetStatus(codes.Error, "scan_failed")
+			observability.RecordConnectorOperation(ctx, "", "duckdb", "execute", false, float64(time.Since(start).Milliseconds()))
+			return nil, fmt.Errorf("failed to scan row: %w", err)
+		}
+
+		// Create map for this row
+		rowMap := make(map[string]any, len(columns))
+		for i, col := range columns {
+			rowMap[col] = duckdbValueToJSON(values[i])
+		}
+
+		results = append(results, rowMap)
+	}
+
+	if err := rows.Err(); err != nil {
+		span.SetStatus(codes.Error, "rows_iteration_failed")
+		observability.RecordConnectorOperation(ctx, "", "duckdb", "execute", false, float64(time.Since(start).Milliseconds()))
+		return nil, fmt.Errorf("error iterating rows: %w", err)
+	}
+
+	observability.RecordConnectorOperation(ctx, "", "duckdb", "execute", true, float64(time.Since(start).Milliseconds()))
+	return results, nil
+}
+
+// Ping verifies the database is open
+func (d *DuckDBConnector) Ping(ctx context.Context) error {
+	return d.db.PingContext(ctx)
+}
+
+// DB returns the underlying database handle
+func (d *DuckDBConnector) DB() *sql.DB {
+	return d.db
+}
+
+// Close closes the database
+func (d *DuckDBConnector) Close() error {
+	if d.db != nil {
+		log := logger.New("connector:duckdb")
+		log.Debugf("Closing DuckDB database")
+		err := d.db.Close()
+		if err == nil {
+			log.Debugf("DuckDB database closed")
+		}
+		return err
+	}
+	return nil
+}
+
+// parseAllowedPaths resolves a comma-separated list of directories to absolute
+// paths with a trailing separator, so "/data" does not also allow "/data-private".
+func parseAllowedPaths(value string) ([]string, error) {
+	var dirs []string
+	for _, entry := range strings.Split(value, ",") {
+		entry = strings.TrimSpace(entry)
+		if entry == "" {
+			continue
+		}
+		abs, err := filepath.Abs(entry)
+		if err != nil {
+			return nil, fmt.Errorf("invalid duckdb allowed path '%s': %w", entry, err)
+		}
+		if !strings.HasSuffix(abs, string(filepath.Separator)) {
+			abs += string(filepath.Separator)
+		}
+		dirs = append(dirs, abs)
+	}
+	return dirs, nil
+}
+
+// duckdbStringList renders values as a DuckDB list literal of quoted strings
+func duckdbStringList(values []string) string {
+	quoted := make([]string, len(values))
+	for i, value := range values {
+		quoted[i] = "'" + strings.ReplaceAll(value, "'", "''") + "'"
+	}
+	return "[" + strings.Join(quoted, ", ") + "]"
+}
+
+// duckdbValueToJSON converts values returned by the DuckDB driver into types that
+// serialize cleanly to JSON. Nested LIST, STRUCT and MAP values are converted recursively.
+func duckdbValueToJSON(val any) any {
+	switch v := val.(type) {
+	case []byte:
+		return string(v)
+	case *big.Int:
+		// HUGEINT and UHUGEINT: keep as a number when it fits, otherwise a string
+		if v.IsInt64() {
+			return v.Int64()
+		}
+		return v.String()
+	case duckdb.Decimal:
+		return v.Float64()
+	case duckdb.Interval:
+		return map[string]any{"months": v.Months, "days": v.Days, "micros": v.Micros}
+	case duckdb.Union:
+		return duckdbValueToJSON(v.Value)
+	case duckdb.Map:
+		converted := make(map[string]any, len(v))
+		for key, item := range v {
+			converted[fmt.Sprint(key)] = duckdbValueToJSON(item)
+		}
+		return converted
+	case map[string]any:
+		converted := make(map[string]any, len(v))
+		for key, item := range v {
+			converted[key] = duckdbValueToJSON(item)
+		}
+		return converted
+	case []any:
+		converted := make([]any, len(v))
+		for i, item := range v {
+			converted[i] = duckdbValueToJSON(item)
+		}
+		return converted
+	default:
+		return v
+	}
+}
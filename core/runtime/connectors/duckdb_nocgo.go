@@ -0,0 +1,29 @@
+//go:build !cgo
+
+package connectors
+
+import (
+	"context"
+	"fmt"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// DuckDBConnector is unavailable in builds without cgo, which the embedded
+// DuckDB engine requires.
+type DuckDBConnector struct{}
+
+// NewDuckDBConnector reports that DuckDB support was not compiled in
+func NewDuckDBConnector(def *protoconnectors.ConnectorDef) (*DuckDBConnector, error) {
+	return nil, fmt.Errorf("duckdb connector is not available in this build: rebuild with CGO_ENABLED=1")
+}
+
+// Execute is never reached since the connector cannot be constructed
+func (d *DuckDBConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	return nil, fmt.Errorf("duckdb connector is not available in this build")
+}
+
+// Close is a no-op
+func (d *DuckDBConnector) Close() error {
+	return nil
+}
@@ -0,0 +1,182 @@
+package connectors
+
+import (
+	"context"
+	"database/sql/driver"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"strings"
+	"syscall"
+)
+
+// AdapterError is the failure of a single adapter. It wraps the driver error
+// so callers can inspect it with errors.Is and errors.As.
+type AdapterError struct {
+	Adapter string
+	Err     error
+}
+
+func (e *AdapterError) Error() string {
+	return fmt.Sprintf("%s (%v)", e.Adapter, e.Err)
+}
+
+func (e *AdapterError) Unwrap() error {
+	return e.Err
+}
+
+// adapterErrors joins the failures of several adapters into one error, listed
+// on a single line, that still unwraps to each of them
+type adapterErrors []error
+
+func (e adapterErrors) Error() string {
+	messages := make([]string, len(e))
+	for i, err := range e {
+		messages[i] = err.Error()
+	}
+	return strings.Join(messages, ", ")
+}
+
+func (e adapterErrors) Unwrap() []error {
+	return e
+}
+
+// ErrorClass groups failures by how a caller should react to them
+type ErrorClass string
+
+const (
+	// ErrorClassPermanent is an error in the query or its data, such as a
+	// syntax error or constraint violation, that fails again if retried
+	ErrorClassPermanent ErrorClass = "permanent"
+	// ErrorClassTransient is a conflict with concurrent work, such as a
+	// deadlock or serialization failure, that may succeed if retried
+	ErrorClassTransient ErrorClass = "transient"
+	// ErrorClassTimeout is a deadline or statement timeout
+	ErrorClassTimeout ErrorClass = "timeout"
+	// ErrorClassAuthFailure is a rejection of the adapter's credentials or a
+	// missing privilege
+	ErrorClassAuthFailure ErrorClass = "auth_failure"
+	// ErrorClassUnavailable is a refused or dropped connection, or a server that
+	// is starting, shutting down or out of connections
+	ErrorClassUnavailable ErrorClass = "unavailable"
+)
+
+// Retryable reports whether an error of this class may succeed if retried unchanged
+func (c ErrorClass) Retryable() bool {
+	return c == ErrorClassTransient || c == ErrorClassTimeout || c == ErrorClassUnavailable
+}
+
+// driverErrors recognizes the errors of one connector driver. Each driver
+// compiled into the build registers one from its errors file.
+type driverErrors struct {
+	// classify returns the class of the first error in err's chain the driver
+	// recognizes, reporting false when it recognizes none
+	classify func(err error) (ErrorClass, bool)
+	// constraint reports whether err is a constraint violation raised by the
+	// driver; it is nil for drivers without constraints
+	constraint func(err error) (ConstraintViolation, bool)
+}
+
+// registeredDriverErrors holds the driverErrors of the drivers in this build
+var registeredDriverErrors []driverErrors
+
+// ClassifyError returns the class of err from the first error in its chain
+// that a connector driver recognizes, or ErrorClassPermanent when none does.
+// Cancellation by the caller is permanent. A nil error has no class.
+func ClassifyError(err error) ErrorClass {
+	switch {
+	case err == nil:
+		return ""
+	case errors.Is(err, context.Canceled):
+		return ErrorClassPermanent
+	case errors.Is(err, context.DeadlineExceeded):
+		return ErrorClassTimeout
+	case errors.Is(err, driver.ErrBadConn),
+		errors.Is(err, io.ErrUnexpectedEOF),
+		errors.Is(err, syscall.ECONNREFUSED),
+		errors.Is(err, syscall.ECONNRESET),
+		errors.Is(err, syscall.EPIPE):
+		return ErrorClassUnavailable
+	}
+
+	for _, driverErrs := range registeredDriverErrors {
+		if class, ok := driverErrs.classify(err); ok {
+			return class
+		}
+	}
+
+	var netErr net.Error
+	if errors.As(err, &netErr) && netErr.Timeout() {
+		return ErrorClassTimeout
+	}
+	var opErr *net.OpError
+	if errors.As(err, &opErr) {
+		return ErrorClassUnavailable
+	}
+	return ErrorClassPermanent
+}
+
+// classOr returns class, or ErrorClassPermanent for an unlisted driver error
+func classOr(class ErrorClass) ErrorClass {
+	if class == "" {
+		return ErrorClassPermanent
+	}
+	return class
+}
+
+// IsTransient reports whether err, or any error it wraps, is a failure that
+// may succeed if retried unchanged
+func IsTransient(err error) bool {
+	return ClassifyError(err).Retryable()
+}
+
+// ConstraintKind names the kind of database constraint a write violated
+type ConstraintKind string
+
+const (
+	ConstraintUnique     ConstraintKind = "unique_violation"
+	ConstraintForeignKey ConstraintKind = "foreign_key_violation"
+	ConstraintNotNull    ConstraintKind = "not_null_violation"
+	ConstraintCheck      ConstraintKind = "check_violation"
+)
+
+// ConstraintViolation is a write rejected by a database constraint. Constraint
+// is the constraint or unique index name, or the column for not-null
+// violations, and is empty when the driver does not report it.
+type ConstraintViolation struct {
+	Kind       ConstraintKind
+	Constraint string
+}
+
+// Message describes the violation without the constraint name or the values
+// that violated it
+func (v ConstraintViolation) Message() string {
+	switch v.Kind {
+	case ConstraintUnique:
+		return "a record with the same unique value already exists"
+	case ConstraintForeignKey:
+		return "a referenced record does not exist or is still referenced"
+	case ConstraintNotNull:
+		return "a required value is missing"
+	default:
+		return "a value failed a check constraint"
+	}
+}
+
+// AsConstraintViolation reports whether err, or any error it wraps, is a
+// unique, foreign key, not-null or check constraint violation, and which one
+func AsConstraintViolation(err error) (ConstraintViolation, bool) {
+	if err == nil {
+		return ConstraintViolation{}, false
+	}
+	for _, driverErrs := range registeredDriverErrors {
+		if driverErrs.constraint == nil {
+			continue
+		}
+		if violation, ok := driverErrs.constraint(err); ok {
+			return violation, true
+		}
+	}
+	return ConstraintViolation{}, false
+}
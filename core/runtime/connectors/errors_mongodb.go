@@ -0,0 +1,68 @@
+//go:build !no_mongodb
+
+package connectors
+
+import (
+	"errors"
+	"regexp"
+	"sync"
+
+	"go.mongodb.org/mongo-driver/v2/mongo"
+)
+
+func init() {
+	registeredDriverErrors = append(registeredDriverErrors, driverErrors{
+		classify:   classifyMongoDBError,
+		constraint: mongoDBConstraintViolation,
+	})
+}
+
+// mongoErrorCodes classifies MongoDB server error codes
+var mongoErrorCodes = map[int]ErrorClass{
+	13:    ErrorClassAuthFailure, // Unauthorized
+	18:    ErrorClassAuthFailure, // AuthenticationFailed
+	50:    ErrorClassTimeout,     // MaxTimeMSExpired
+	91:    ErrorClassUnavailable, // ShutdownInProgress
+	189:   ErrorClassUnavailable, // PrimarySteppedDown
+	10107: ErrorClassUnavailable, // NotWritablePrimary
+	11600: ErrorClassUnavailable, // InterruptedAtShutdown
+}
+
+// mongoDuplicateIndexPattern extracts the index name from a duplicate key error.
+// It is compiled on first use rather than at startup.
+var mongoDuplicateIndexPattern = sync.OnceValue(func() *regexp.Regexp {
+	return regexp.MustCompile(`index: (\S+) dup key`)
+})
+
+func classifyMongoDBError(err error) (ErrorClass, bool) {
+	var mongoErr mongo.ServerError
+	if errors.As(err, &mongoErr) {
+		for code, class := range mongoErrorCodes {
+			if mongoErr.HasErrorCode(code) {
+				return class, true
+			}
+		}
+		if mongoErr.HasErrorLabel("TransientTransactionError") || mongoErr.HasErrorLabel("RetryableWriteError") {
+			return ErrorClassTransient, true
+		}
+		return ErrorClassPermanent, true
+	}
+	if mongo.IsTimeout(err) {
+		return ErrorClassTimeout, true
+	}
+	if mongo.IsNetworkError(err) {
+		return ErrorClassUnavailable, true
+	}
+	return "", false
+}
+
+func mongoDBConstraintViolation(err error) (ConstraintViolation, bool) {
+	if !mongo.IsDuplicateKeyError(err) {
+		return ConstraintViolation{}, false
+	}
+	violation := ConstraintViolation{Kind: ConstraintUnique}
+	if match := mongoDuplicateIndexPattern().FindStringSubmatch(err.Error()); match != nil {
+		violation.Constraint = match[1]
+	}
+	return violation, true
+}
@@ -0,0 +1,73 @@
+//go:build !no_mysql
+
+package connectors
+
+import (
+	"errors"
+	"regexp"
+
+	"github.com/go-sql-driver/mysql"
+)
+
+func init() {
+	registeredDriverErrors = append(registeredDriverErrors, driverErrors{
+		classify:   classifyMySQLError,
+		constraint: mysqlConstraintViolation,
+	})
+}
+
+// mysqlErrorClasses classifies MySQL server error numbers
+var mysqlErrorClasses = map[uint16]ErrorClass{
+	1040: ErrorClassUnavailable, // ER_CON_COUNT_ERROR
+	1044: ErrorClassAuthFailure, // ER_DBACCESS_DENIED_ERROR
+	1045: ErrorClassAuthFailure, // ER_ACCESS_DENIED_ERROR
+	1053: ErrorClassUnavailable, // ER_SERVER_SHUTDOWN
+	1142: ErrorClassAuthFailure, // ER_TABLEACCESS_DENIED_ERROR
+	1205: ErrorClassTimeout,     // ER_LOCK_WAIT_TIMEOUT
+	1213: ErrorClassTransient,   // ER_LOCK_DEADLOCK
+	1698: ErrorClassAuthFailure, // ER_ACCESS_DENIED_NO_PASSWORD_ERROR
+	3024: ErrorClassTimeout,     // ER_QUERY_TIMEOUT
+}
+
+// mysqlConstraintKinds maps MySQL constraint error numbers, with the pattern
+// extracting the constraint name from the error message
+var mysqlConstraintKinds = map[uint16]struct {
+	kind ConstraintKind
+	name *regexp.Regexp
+}{
+	1048: {ConstraintNotNull, regexp.MustCompile(`Column '([^']+)'`)},            // ER_BAD_NULL_ERROR
+	1062: {ConstraintUnique, regexp.MustCompile(`for key '([^']+)'`)},            // ER_DUP_ENTRY
+	1216: {ConstraintForeignKey, regexp.MustCompile("CONSTRAINT `([^`]+)`")},     // ER_NO_REFERENCED_ROW
+	1217: {ConstraintForeignKey, regexp.MustCompile("CONSTRAINT `([^`]+)`")},     // ER_ROW_IS_REFERENCED
+	1364: {ConstraintNotNull, regexp.MustCompile(`Field '([^']+)'`)},             // ER_NO_DEFAULT_FOR_FIELD
+	1451: {ConstraintForeignKey, regexp.MustCompile("CONSTRAINT `([^`]+)`")},     // ER_ROW_IS_REFERENCED_2
+	1452: {ConstraintForeignKey, regexp.MustCompile("CONSTRAINT `([^`]+)`")},     // ER_NO_REFERENCED_ROW_2
+	3819: {ConstraintCheck, regexp.MustCompile(`[Cc]heck constraint '([^']+)'`)}, // ER_CHECK_CONSTRAINT_VIOLATED
+}
+
+func classifyMySQLError(err error) (ErrorClass, bool) {
+	if errors.Is(err, mysql.ErrInvalidConn) {
+		return ErrorClassUnavailable, true
+	}
+	var mysqlErr *mysql.MySQLError
+	if errors.As(err, &mysqlErr) {
+		return classOr(mysqlErrorClasses[mysqlErr.Number]), true
+	}
+	return "", false
+}
+
+func mysqlConstraintViolation(err error) (ConstraintViolation, bool) {
+	var mysqlErr *mysql.MySQLError
+	if !errors.As(err, &mysqlErr) {
+		return ConstraintViolation{}, false
+	}
+	constraint, ok := mysqlConstraintKinds[mysqlErr.Number]
+	if !ok {
+		return ConstraintViolation{}, false
+	}
+	violation := ConstraintViolation{Kind: constraint.kind}
+	if match := constraint.name.FindStringSubmatch(mysqlErr.Message); match != nil {
+		violation.Constraint = match[1]
+	}
+	return violation, true
+}
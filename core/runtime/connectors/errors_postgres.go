@@ -0,0 +1,68 @@
+//go:build !no_postgres
+
+package connectors
+
+import (
+	"errors"
+
+	"github.com/lib/pq"
+)
+
+func init() {
+	registeredDriverErrors = append(registeredDriverErrors, driverErrors{
+		classify:   classifyPostgresError,
+		constraint: postgresConstraintViolation,
+	})
+}
+
+// pqErrorCodes classifies PostgreSQL SQLSTATE codes, taking precedence over
+// pqErrorClasses
+var pqErrorCodes = map[pq.ErrorCode]ErrorClass{
+	"42501": ErrorClassAuthFailure, // insufficient_privilege
+	"55P03": ErrorClassTransient,   // lock_not_available
+	"57014": ErrorClassTimeout,     // query_canceled, raised by statement_timeout
+}
+
+// pqErrorClasses classifies PostgreSQL SQLSTATE classes
+var pqErrorClasses = map[pq.ErrorClass]ErrorClass{
+	"08": ErrorClassUnavailable, // connection exception
+	"28": ErrorClassAuthFailure, // invalid authorization specification
+	"40": ErrorClassTransient,   // transaction rollback
+	"53": ErrorClassUnavailable, // insufficient resources
+	"57": ErrorClassUnavailable, // operator intervention, such as admin shutdown
+}
+
+// pqConstraintKinds maps PostgreSQL integrity constraint violation SQLSTATEs
+var pqConstraintKinds = map[pq.ErrorCode]ConstraintKind{
+	"23502": ConstraintNotNull,
+	"23503": ConstraintForeignKey,
+	"23505": ConstraintUnique,
+	"23514": ConstraintCheck,
+}
+
+func classifyPostgresError(err error) (ErrorClass, bool) {
+	var pqErr *pq.Error
+	if !errors.As(err, &pqErr) {
+		return "", false
+	}
+	if class, ok := pqErrorCodes[pqErr.Code]; ok {
+		return class, true
+	}
+	return classOr(pqErrorClasses[pqErr.Code.Class()]), true
+}
+
+func postgresConstraintViolation(err error) (ConstraintViolation, bool) {
+	var pqErr *pq.Error
+	if !errors.As(err, &pqErr) {
+		return ConstraintViolation{}, false
+	}
+	kind, ok := pqConstraintKinds[pqErr.Code]
+	if !ok {
+		return ConstraintViolation{}, false
+	}
+	name := pqErr.Constraint
+	if kind == ConstraintNotNull {
+		name = pqErr.Column
+	}
+	return ConstraintViolation{Kind: kind, Constraint: name}, true
+}
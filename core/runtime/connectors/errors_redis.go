@@ -0,0 +1,44 @@
+//go:build !no_redis
+
+package connectors
+
+import (
+	"errors"
+	"strings"
+
+	"github.com/redis/go-redis/v9"
+)
+
+func init() {
+	registeredDriverErrors = append(registeredDriverErrors, driverErrors{
+		classify: classifyRedisError,
+	})
+}
+
+// redisErrorPrefixes classifies Redis error replies by their prefix
+var redisErrorPrefixes = map[string]ErrorClass{
+	"LOADING":     ErrorClassUnavailable,
+	"BUSY":        ErrorClassUnavailable,
+	"MASTERDOWN":  ErrorClassUnavailable,
+	"CLUSTERDOWN": ErrorClassUnavailable,
+	"TRYAGAIN":    ErrorClassTransient,
+	"NOAUTH":      ErrorClassAuthFailure,
+	"WRONGPASS":   ErrorClassAuthFailure,
+	"NOPERM":      ErrorClassAuthFailure,
+}
+
+func classifyRedisError(err error) (ErrorClass, bool) {
+	if errors.Is(err, redis.ErrPoolTimeout) {
+		return ErrorClassTimeout, true
+	}
+	var redisErr redis.Error
+	if !errors.As(err, &redisErr) {
+		return "", false
+	}
+	for prefix, class := range redisErrorPrefixes {
+		if strings.HasPrefix(redisErr.Error(), prefix) {
+			return class, true
+		}
+	}
+	return ErrorClassPermanent, true
+}
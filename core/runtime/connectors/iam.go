@@ -0,0 +1,126 @@
+package connectors
+
+import (
+	"context"
+	"fmt"
+	"net"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+	"sync"
+	"time"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// IAM auth providers accepted by an adapter's auth setting
+const (
+	iamProviderAWS = "aws_iam"
+	iamProviderGCP = "gcp_iam"
+)
+
+// iamTokenRefreshMargin is how long before expiry a cached token is replaced,
+// so a connection is never opened with a token about to lapse
+const iamTokenRefreshMargin = 5 * time.Minute
+
+// iamHTTPClient is used for the credential and token requests made by IAM auth
+var iamHTTPClient = &http.Client{Timeout: 10 * time.Second}
+
+// iamTokenSource generates IAM auth tokens, reusing each until it nears expiry.
+// Tokens are only checked when a connection is opened, so connections already
+// in the pool stay valid after the token they were opened with expires.
+type iamTokenSource struct {
+	fetch func(ctx context.Context) (string, time.Time, error)
+
+	mu      sync.Mutex
+	token   string
+	expires time.Time
+}
+
+// newIAMTokenSource returns a token source for user on the database at host
+// and port
+func newIAMTokenSource(auth *protoconnectors.IAMAuth, host, port, user string) (*iamTokenSource, error) {
+	if host == "" || user == "" {
+		return nil, fmt.Errorf("iam auth requires a host and user in the connection settings")
+	}
+
+	switch auth.GetProvider() {
+	case iamProviderAWS:
+		region := auth.GetRegion()
+		if region == "" {
+			region = os.Getenv("AWS_REGION")
+		}
+		if region == "" {
+			region = os.Getenv("AWS_DEFAULT_REGION")
+		}
+		if region == "" {
+			return nil, fmt.Errorf("aws_iam auth requires aws_region or the AWS_REGION environment variable")
+		}
+		endpoint := net.JoinHostPort(host, port)
+		return &iamTokenSource{fetch: func(ctx context.Context) (string, time.Time, error) {
+			creds, err := loadAWSCredentials(ctx, region)
+			if err != nil {
+				return "", time.Time{}, err
+			}
+			now := time.Now().UTC()
+			expires := now.Add(rdsTokenLifetime)
+			if !creds.Expires.IsZero() && creds.Expires.Before(expires) {
+				expires = creds.Expires
+			}
+			return buildRDSAuthToken(endpoint, region, user, creds, now), expires, nil
+		}}, nil
+	case iamProviderGCP:
+		return &iamTokenSource{fetch: fetchGCPAccessToken}, nil
+	default:
+		return nil, fmt.Errorf("unknown iam auth provider '%s'", auth.GetProvider())
+	}
+}
+
+// Token returns a current token, generating a new one when the cached token
+// is missing or close to expiry
+func (s *iamTokenSource) Token(ctx context.Context) (string, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if s.token != "" && time.Until(s.expires) > iamTokenRefreshMargin {
+		return s.token, nil
+	}
+	token, expires, err := s.fetch(ctx)
+	if err != nil {
+		return "", fmt.Errorf("failed to generate iam auth token: %w", err)
+	}
+	s.token, s.expires = token, expires
+	return token, nil
+}
+
+// postgresIAMDSN returns a function building connectionString with a current
+// IAM token as its password, called for each new connection
+func postgresIAMDSN(connectionString string, auth *protoconnectors.IAMAuth) (func(context.Context) (string, error), error) {
+	if !strings.HasPrefix(connectionString, "postgres://") && !strings.HasPrefix(connectionString, "postgresql://") {
+		return nil, fmt.Errorf("iam auth requires a postgres:// connection URL or connection fields")
+	}
+	base, err := url.Parse(connectionString)
+	if err != nil {
+		return nil, fmt.Errorf("failed to parse postgres connection string: %w", err)
+	}
+	port := base.Port()
+	if port == "" {
+		port = "5432"
+	}
+	user := base.User.Username()
+
+	tokens, err := newIAMTokenSource(auth, base.Hostname(), port, user)
+	if err != nil {
+		return nil, err
+	}
+	return func(ctx context.Context) (string, error) {
+		token, err := tokens.Token(ctx)
+		if err != nil {
+			return "", err
+		}
+		withToken := *base
+		withToken.User = url.UserPassword(user, token)
+		return withToken.String(), nil
+	}, nil
+}
@@ -0,0 +1,342 @@
+package connectors
+
+import (
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"encoding/xml"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+const (
+	// rdsTokenLifetime is how long RDS accepts an auth token after it is signed
+	rdsTokenLifetime = 15 * time.Minute
+
+	awsSigningAlgorithm = "AWS4-HMAC-SHA256"
+
+	// awsEmptyPayloadHash is the SHA-256 of an empty body, which presigned
+	// RDS tokens are signed with
+	awsEmptyPayloadHash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+
+	// ecsCredentialsEndpoint serves task role credentials to ECS containers,
+	// at the path in AWS_CONTAINER_CREDENTIALS_RELATIVE_URI
+	ecsCredentialsEndpoint = "http://169.254.170.2"
+
+	// imdsEndpoint is the EC2 instance metadata service
+	imdsEndpoint = "http://169.254.169.254"
+
+	// imdsTimeout bounds the instance metadata requests, which go unanswered
+	// outside EC2
+	imdsTimeout = 2 * time.Second
+)
+
+// errNoAWSCredentials is returned by loadAWSCredentials when no source of
+// credentials is available
+var errNoAWSCredentials = errors.New("no AWS credentials found: set AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY, or AWS_ROLE_ARN and AWS_WEB_IDENTITY_TOKEN_FILE, or run with an ECS task role or EC2 instance profile")
+
+// metadataHTTPClient reaches the link-local ECS and EC2 credential endpoints,
+// which are never reached through a proxy
+var metadataHTTPClient = &http.Client{Timeout: 10 * time.Second, Transport: &http.Transport{}}
+
+// roleCredentials caches the credentials of an ECS task role or EC2 instance
+// profile, which are fetched again shortly before they expire
+var roleCredentials struct {
+	mu    sync.Mutex
+	creds awsCredentials
+}
+
+// awsCredentials are the keys tokens are signed with. Expires is zero for
+// long-lived keys.
+type awsCredentials struct {
+	AccessKeyID     string
+	SecretAccessKey string
+	SessionToken    string
+	Expires         time.Time
+}
+
+// loadAWSCredentials reads credentials from the first source available: access
+// keys in AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY, a role assumed with the
+// web identity token in AWS_WEB_IDENTITY_TOKEN_FILE as EKS service accounts
+// provide, the ECS container credentials endpoint, then the EC2 instance
+// metadata service unless AWS_EC2_METADATA_DISABLED is true
+func loadAWSCredentials(ctx context.Context, region string) (awsCredentials, error) {
+	if accessKey := os.Getenv("AWS_ACCESS_KEY_ID"); accessKey != "" {
+		return awsCredentials{
+			AccessKeyID:     accessKey,
+			SecretAccessKey: os.Getenv("AWS_SECRET_ACCESS_KEY"),
+			SessionToken:    os.Getenv("AWS_SESSION_TOKEN"),
+		}, nil
+	}
+	if tokenFile := os.Getenv("AWS_WEB_IDENTITY_TOKEN_FILE"); tokenFile != "" {
+		return assumeRoleWithWebIdentity(ctx, region, tokenFile)
+	}
+	if os.Getenv("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI") != "" || os.Getenv("AWS_CONTAINER_CREDENTIALS_FULL_URI") != "" {
+		return cachedRoleCredentials(ctx, fetchContainerCredentials)
+	}
+	if strings.EqualFold(os.Getenv("AWS_EC2_METADATA_DISABLED"), "true") {
+		return awsCredentials{}, errNoAWSCredentials
+	}
+	creds, err := cachedRoleCredentials(ctx, fetchInstanceCredentials)
+	if err != nil {
+		return awsCredentials{}, fmt.Errorf("%w (instance metadata: %v)", errNoAWSCredentials, err)
+	}
+	return creds, nil
+}
+
+// cachedRoleCredentials returns the cached role credentials, calling fetch
+// when there are none or they are close to expiry
+func cachedRoleCredentials(ctx context.Context, fetch func(context.Context) (awsCredentials, error)) (awsCredentials, error) {
+	roleCredentials.mu.Lock()
+	defer roleCredentials.mu.Unlock()
+
+	if creds := roleCredentials.creds; creds.AccessKeyID != "" && time.Until(creds.Expires) > iamTokenRefreshMargin {
+		return creds, nil
+	}
+	creds, err := fetch(ctx)
+	if err != nil {
+		return awsCredentials{}, err
+	}
+	roleCredentials.creds = creds
+	return creds, nil
+}
+
+// roleCredentialsResponse is the JSON returned by the ECS container and EC2
+// instance metadata credential endpoints
+type roleCredentialsResponse struct {
+	AccessKeyID     string    `json:"AccessKeyId"`
+	SecretAccessKey string    `json:"SecretAccessKey"`
+	Token           string    `json:"Token"`
+	Expiration      time.Time `json:"Expiration"`
+}
+
+// fetchContainerCredentials requests the task role credentials of an ECS
+// container, or of an EKS pod through EKS Pod Identity, from the endpoint in
+// AWS_CONTAINER_CREDENTIALS_RELATIVE_URI or AWS_CONTAINER_CREDENTIALS_FULL_URI
+func fetchContainerCredentials(ctx context.Context) (awsCredentials, error) {
+	endpoint := os.Getenv("AWS_CONTAINER_CREDENTIALS_FULL_URI")
+	if relative := os.Getenv("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI"); relative != "" {
+		endpoint = ecsCredentialsEndpoint + relative
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, endpoint, nil)
+	if err != nil {
+		return awsCredentials{}, fmt.Errorf("invalid container credentials endpoint: %w", err)
+	}
+	token := os.Getenv("AWS_CONTAINER_AUTHORIZATION_TOKEN")
+	if tokenFile := os.Getenv("AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE"); tokenFile != "" {
+		content, err := os.ReadFile(tokenFile)
+		if err != nil {
+			return awsCredentials{}, fmt.Errorf("failed to read container authorization token: %w", err)
+		}
+		token = strings.TrimSpace(string(content))
+	}
+	if token != "" {
+		req.Header.Set("Authorization", token)
+	}
+
+	body, err := metadataRequest(req)
+	if err != nil {
+		return awsCredentials{}, fmt.Errorf("container credentials request failed: %w", err)
+	}
+	return decodeRoleCredentials(body)
+}
+
+// fetchInstanceCredentials requests the instance profile credentials of an
+// EC2 instance from the instance metadata service, using an IMDSv2 session
+// token. AWS_EC2_METADATA_SERVICE_ENDPOINT overrides the endpoint.
+func fetchInstanceCredentials(ctx context.Context) (awsCredentials, error) {
+	endpoint := strings.TrimSuffix(os.Getenv("AWS_EC2_METADATA_SERVICE_ENDPOINT"), "/")
+	if endpoint == "" {
+		endpoint = imdsEndpoint
+	}
+	ctx, cancel := context.WithTimeout(ctx, imdsTimeout)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPut, endpoint+"/latest/api/token", nil)
+	if err != nil {
+		return awsCredentials{}, err
+	}
+	req.Header.Set("X-aws-ec2-metadata-token-ttl-seconds", "21600")
+	token, err := metadataRequest(req)
+	if err != nil {
+		return awsCredentials{}, err
+	}
+
+	get := func(path string) ([]byte, error) {
+		req, err := http.NewRequestWithContext(ctx, http.MethodGet, endpoint+"/latest/meta-data/iam/security-credentials/"+path, nil)
+		if err != nil {
+			return nil, err
+		}
+		req.Header.Set("X-aws-ec2-metadata-token", string(token))
+		return metadataRequest(req)
+	}
+	roles, err := get("")
+	if err != nil {
+		return awsCredentials{}, err
+	}
+	role, _, _ := strings.Cut(strings.TrimSpace(string(roles)), "\n")
+	if role == "" {
+		return awsCredentials{}, fmt.Errorf("the instance has no instance profile")
+	}
+	body, err := get(role)
+	if err != nil {
+		return awsCredentials{}, err
+	}
+	return decodeRoleCredentials(body)
+}
+
+// metadataRequest sends req to a credentials endpoint and returns the body of
+// a successful response
+func metadataRequest(req *http.Request) ([]byte, error) {
+	resp, err := metadataHTTPClient.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("%s %s returned %s", req.Method, req.URL.Path, resp.Status)
+	}
+	return io.ReadAll(io.LimitReader(resp.Body, 1<<20))
+}
+
+// decodeRoleCredentials decodes a roleCredentialsResponse
+func decodeRoleCredentials(body []byte) (awsCredentials, error) {
+	var response roleCredentialsResponse
+	if err := json.Unmarshal(body, &response); err != nil {
+		return awsCredentials{}, fmt.Errorf("failed to decode role credentials: %w", err)
+	}
+	if response.AccessKeyID == "" || response.SecretAccessKey == "" {
+		return awsCredentials{}, fmt.Errorf("role credentials response contained no access keys")
+	}
+	return awsCredentials{
+		AccessKeyID:     response.AccessKeyID,
+		SecretAccessKey: response.SecretAccessKey,
+		SessionToken:    response.Token,
+		Expires:         response.Expiration,
+	}, nil
+}
+
+// assumeRoleWithWebIdentity exchanges the web identity token in tokenFile for
+// temporary credentials of the role in AWS_ROLE_ARN
+func assumeRoleWithWebIdentity(ctx context.Context, region, tokenFile string) (awsCredentials, error) {
+	roleARN := os.Getenv("AWS_ROLE_ARN")
+	if roleARN == "" {
+		return awsCredentials{}, fmt.Errorf("AWS_WEB_IDENTITY_TOKEN_FILE is set without AWS_ROLE_ARN")
+	}
+	identityToken, err := os.ReadFile(tokenFile)
+	if err != nil {
+		return awsCredentials{}, fmt.Errorf("failed to read web identity token: %w", err)
+	}
+	sessionName := os.Getenv("AWS_ROLE_SESSION_NAME")
+	if sessionName == "" {
+		sessionName = "hyperterse"
+	}
+
+	endpoint := fmt.Sprintf("https://sts.%s.amazonaws.com/", region)
+	if strings.HasPrefix(region, "cn-") {
+		endpoint = fmt.Sprintf("https://sts.%s.amazonaws.com.cn/", region)
+	}
+	form := url.Values{
+		"Action":           {"AssumeRoleWithWebIdentity"},
+		"Version":          {"2011-06-15"},
+		"RoleArn":          {roleARN},
+		"RoleSessionName":  {sessionName},
+		"WebIdentityToken": {strings.TrimSpace(string(identityToken))},
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, strings.NewReader(form.Encode()))
+	if err != nil {
+		return awsCredentials{}, err
+	}
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+
+	resp, err := iamHTTPClient.Do(req)
+	if err != nil {
+		return awsCredentials{}, fmt.Errorf("sts request failed: %w", err)
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return awsCredentials{}, fmt.Errorf("sts AssumeRoleWithWebIdentity returned %s", resp.Status)
+	}
+
+	var result struct {
+		Credentials struct {
+			AccessKeyID     string `xml:"AccessKeyId"`
+			SecretAccessKey string
+			SessionToken    string
+			Expiration      time.Time
+		} `xml:"AssumeRoleWithWebIdentityResult>Credentials"`
+	}
+	if err := xml.NewDecoder(resp.Body).Decode(&result); err != nil {
+		return awsCredentials{}, fmt.Errorf("failed to decode sts response: %w", err)
+	}
+	return awsCredentials{
+		AccessKeyID:     result.Credentials.AccessKeyID,
+		SecretAccessKey: result.Credentials.SecretAccessKey,
+		SessionToken:    result.Credentials.SessionToken,
+		Expires:         result.Credentials.Expiration,
+	}, nil
+}
+
+// buildRDSAuthToken returns an RDS IAM auth token for user at endpoint
+// (host:port): a connect request presigned with Signature Version 4, without
+// its scheme
+func buildRDSAuthToken(endpoint, region, user string, creds awsCredentials, now time.Time) string {
+	date := now.Format("20060102")
+	amzDate := now.Format("20060102T150405Z")
+	scope := date + "/" + region + "/rds-db/aws4_request"
+
+	query := url.Values{
+		"Action":              {"connect"},
+		"DBUser":              {user},
+		"X-Amz-Algorithm":     {awsSigningAlgorithm},
+		"X-Amz-Credential":    {creds.AccessKeyID + "/" + scope},
+		"X-Amz-Date":          {amzDate},
+		"X-Amz-Expires":       {strconv.Itoa(int(rdsTokenLifetime.Seconds()))},
+		"X-Amz-SignedHeaders": {"host"},
+	}
+	if creds.SessionToken != "" {
+		query.Set("X-Amz-Security-Token", creds.SessionToken)
+	}
+	// Signature Version 4 encodes spaces as %20 where url.Values uses +
+	canonicalQuery := strings.ReplaceAll(query.Encode(), "+", "%20")
+
+	canonicalRequest := strings.Join([]string{
+		http.MethodGet,
+		"/",
+		canonicalQuery,
+		"host:" + endpoint + "\n",
+		"host",
+		awsEmptyPayloadHash,
+	}, "\n")
+	requestHash := sha256.Sum256([]byte(canonicalRequest))
+	stringToSign := strings.Join([]string{
+		awsSigningAlgorithm,
+		amzDate,
+		scope,
+		hex.EncodeToString(requestHash[:]),
+	}, "\n")
+
+	key := []byte("AWS4" + creds.SecretAccessKey)
+	for _, part := range []string{date, region, "rds-db", "aws4_request"} {
+		key = hmacSHA256(key, part)
+	}
+	signature := hex.EncodeToString(hmacSHA256(key, stringToSign))
+
+	return endpoint + "/?" + canonicalQuery + "&X-Amz-Signature=" + signature
+}
+
+func hmacSHA256(key []byte, data string) []byte {
+	mac := hmac.New(sha256.New, key)
+	mac.Write([]byte(data))
+	return mac.Sum(nil)
+}
@@ -0,0 +1,100 @@
+package connectors
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+const roleCredentialsJSON = `{"AccessKeyId": "ASIAROLE", "SecretAccessKey": "secret", "Token": "session", "Expiration": "2099-01-01T00:00:00Z"}`
+
+// isolateAWSCredentials clears every credential source of the environment and
+// the role credentials cache for the rest of the test
+func isolateAWSCredentials(t *testing.T) {
+	t.Helper()
+	for _, name := range []string{
+		"AWS_ACCESS_KEY_ID", "AWS_WEB_IDENTITY_TOKEN_FILE", "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI",
+		"AWS_CONTAINER_CREDENTIALS_FULL_URI", "AWS_CONTAINER_AUTHORIZATION_TOKEN", "AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE",
+		"AWS_EC2_METADATA_SERVICE_ENDPOINT",
+	} {
+		t.Setenv(name, "")
+	}
+	t.Setenv("AWS_EC2_METADATA_DISABLED", "true")
+	roleCredentials.creds = awsCredentials{}
+	t.Cleanup(func() { roleCredentials.creds = awsCredentials{} })
+}
+
+func TestLoadAWSCredentialsFromContainerEndpoint(t *testing.T) {
+	isolateAWSCredentials(t)
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		if req.Header.Get("Authorization") != "pod-token" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		w.Write([]byte(roleCredentialsJSON))
+	}))
+	defer server.Close()
+
+	tokenFile := filepath.Join(t.TempDir(), "token")
+	if err := os.WriteFile(tokenFile, []byte("pod-token\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv("AWS_CONTAINER_CREDENTIALS_FULL_URI", server.URL+"/v1/credentials")
+	t.Setenv("AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE", tokenFile)
+
+	creds, err := loadAWSCredentials(context.Background(), "us-east-1")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if creds.AccessKeyID != "ASIAROLE" || creds.SecretAccessKey != "secret" || creds.SessionToken != "session" || creds.Expires.IsZero() {
+		t.Errorf("loadAWSCredentials() = %+v, want the task role credentials", creds)
+	}
+}
+
+func TestLoadAWSCredentialsFromInstanceMetadata(t *testing.T) {
+	isolateAWSCredentials(t)
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		switch {
+		case req.Method == http.MethodPut && req.URL.Path == "/latest/api/token":
+			w.Write([]byte("imds-token"))
+		case req.Header.Get("X-aws-ec2-metadata-token") != "imds-token":
+			w.WriteHeader(http.StatusUnauthorized)
+		case req.URL.Path == "/latest/meta-data/iam/security-credentials/":
+			w.Write([]byte("app-role\n"))
+		case req.URL.Path == "/latest/meta-data/iam/security-credentials/app-role":
+			w.Write([]byte(roleCredentialsJSON))
+		default:
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}))
+	defer server.Close()
+	t.Setenv("AWS_EC2_METADATA_DISABLED", "")
+	t.Setenv("AWS_EC2_METADATA_SERVICE_ENDPOINT", server.URL)
+
+	creds, err := loadAWSCredentials(context.Background(), "us-east-1")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if creds.AccessKeyID != "ASIAROLE" || creds.SessionToken != "session" {
+		t.Errorf("loadAWSCredentials() = %+v, want the instance profile credentials", creds)
+	}
+}
+
+func TestNewS3GetRequestUnsignedWithoutCredentials(t *testing.T) {
+	isolateAWSCredentials(t)
+	if _, err := loadAWSCredentials(context.Background(), "us-east-1"); !errors.Is(err, errNoAWSCredentials) {
+		t.Fatalf("loadAWSCredentials() error = %v, want errNoAWSCredentials", err)
+	}
+
+	req, err := NewS3GetRequest(context.Background(), "public-bucket", "config.terse")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := req.Header.Get("Authorization"); got != "" {
+		t.Errorf("Authorization = %q, want an unsigned request", got)
+	}
+}
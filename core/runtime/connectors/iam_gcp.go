@@ -0,0 +1,187 @@
+package connectors
+
+import (
+	"context"
+	"crypto"
+	"crypto/rand"
+	"crypto/rsa"
+	"crypto/sha256"
+	"crypto/x509"
+	"encoding/base64"
+	"encoding/json"
+	"encoding/pem"
+	"errors"
+	"fmt"
+	"io/fs"
+	"net/http"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+)
+
+const (
+	// gcpSQLLoginScope is the OAuth scope Cloud SQL accepts for IAM database login
+	gcpSQLLoginScope = "https://www.googleapis.com/auth/sqlservice.login"
+
+	gcpTokenURL         = "https://oauth2.googleapis.com/token"
+	gcpMetadataTokenURL = "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token"
+)
+
+// gcpCredentialsFile is the subset of an Application Default Credentials file
+// used to obtain access tokens
+type gcpCredentialsFile struct {
+	Type string `json:"type"`
+
+	// service_account keys
+	ClientEmail  string `json:"client_email"`
+	PrivateKey   string `json:"private_key"`
+	PrivateKeyID string `json:"private_key_id"`
+	TokenURI     string `json:"token_uri"`
+
+	// authorized_user credentials from gcloud auth application-default login
+	ClientID     string `json:"client_id"`
+	ClientSecret string `json:"client_secret"`
+	RefreshToken string `json:"refresh_token"`
+}
+
+// fetchGCPAccessToken returns an access token from Application Default
+// Credentials: the file in GOOGLE_APPLICATION_CREDENTIALS or gcloud's default
+// location, otherwise the metadata server available on Google Cloud
+func fetchGCPAccessToken(ctx context.Context) (string, time.Time, error) {
+	path := os.Getenv("GOOGLE_APPLICATION_CREDENTIALS")
+	explicit := path != ""
+	if !explicit {
+		if home, err := os.UserHomeDir(); err == nil {
+			path = filepath.Join(home, ".config", "gcloud", "application_default_credentials.json")
+		}
+	}
+
+	if path != "" {
+		data, err := os.ReadFile(path)
+		switch {
+		case err == nil:
+			return gcpTokenFromCredentials(ctx, data)
+		case explicit || !errors.Is(err, fs.ErrNotExist):
+			return "", time.Time{}, fmt.Errorf("failed to read google credentials: %w", err)
+		}
+	}
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, gcpMetadataTokenURL+"?scopes="+url.QueryEscape(gcpSQLLoginScope), nil)
+	if err != nil {
+		return "", time.Time{}, err
+	}
+	req.Header.Set("Metadata-Flavor", "Google")
+	token, expires, err := requestGCPToken(req)
+	if err != nil {
+		return "", time.Time{}, fmt.Errorf("no google credentials found: set GOOGLE_APPLICATION_CREDENTIALS or run on Google Cloud: %w", err)
+	}
+	return token, expires, nil
+}
+
+// gcpTokenFromCredentials exchanges a credentials file for an access token
+func gcpTokenFromCredentials(ctx context.Context, data []byte) (string, time.Time, error) {
+	var creds gcpCredentialsFile
+	if err := json.Unmarshal(data, &creds); err != nil {
+		return "", time.Time{}, fmt.Errorf("failed to parse google credentials: %w", err)
+	}
+
+	var form url.Values
+	tokenURL := gcpTokenURL
+	switch creds.Type {
+	case "service_account":
+		if creds.TokenURI != "" {
+			tokenURL = creds.TokenURI
+		}
+		assertion, err := signGCPAssertion(creds, tokenURL, time.Now())
+		if err != nil {
+			return "", time.Time{}, err
+		}
+		form = url.Values{
+			"grant_type": {"urn:ietf:params:oauth:grant-type:jwt-bearer"},
+			"assertion":  {assertion},
+		}
+	case "authorized_user":
+		form = url.Values{
+			"grant_type":    {"refresh_token"},
+			"client_id":     {creds.ClientID},
+			"client_secret": {creds.ClientSecret},
+			"refresh_token": {creds.RefreshToken},
+		}
+	default:
+		return "", time.Time{}, fmt.Errorf("unsupported google credentials type '%s'", creds.Type)
+	}
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, tokenURL, strings.NewReader(form.Encode()))
+	if err != nil {
+		return "", time.Time{}, err
+	}
+	req.Header.Set("Content-Type", "application/x-www-form-urlencoded")
+	return requestGCPToken(req)
+}
+
+// signGCPAssertion returns the JWT a service account key presents to the token
+// endpoint, scoped for Cloud SQL login
+func signGCPAssertion(creds gcpCredentialsFile, audience string, now time.Time) (string, error) {
+	block, _ := pem.Decode([]byte(creds.PrivateKey))
+	if block == nil {
+		return "", fmt.Errorf("google service account private_key is not PEM encoded")
+	}
+	parsed, err := x509.ParsePKCS8PrivateKey(block.Bytes)
+	if err != nil {
+		return "", fmt.Errorf("failed to parse google service account private_key: %w", err)
+	}
+	key, ok := parsed.(*rsa.PrivateKey)
+	if !ok {
+		return "", fmt.Errorf("google service account private_key is not an RSA key")
+	}
+
+	header, err := json.Marshal(map[string]string{"alg": "RS256", "typ": "JWT", "kid": creds.PrivateKeyID})
+	if err != nil {
+		return "", err
+	}
+	claims, err := json.Marshal(map[string]any{
+		"iss":   creds.ClientEmail,
+		"scope": gcpSQLLoginScope,
+		"aud":   audience,
+		"iat":   now.Unix(),
+		"exp":   now.Add(time.Hour).Unix(),
+	})
+	if err != nil {
+		return "", err
+	}
+
+	unsigned := base64.RawURLEncoding.EncodeToString(header) + "." + base64.RawURLEncoding.EncodeToString(claims)
+	digest := sha256.Sum256([]byte(unsigned))
+	signature, err := rsa.SignPKCS1v15(rand.Reader, key, crypto.SHA256, digest[:])
+	if err != nil {
+		return "", fmt.Errorf("failed to sign google token request: %w", err)
+	}
+	return unsigned + "." + base64.RawURLEncoding.EncodeToString(signature), nil
+}
+
+// requestGCPToken sends req to a Google token endpoint and returns the access
+// token with its expiry
+func requestGCPToken(req *http.Request) (string, time.Time, error) {
+	resp, err := iamHTTPClient.Do(req)
+	if err != nil {
+		return "", time.Time{}, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return "", time.Time{}, fmt.Errorf("token request returned %s", resp.Status)
+	}
+
+	var token struct {
+		AccessToken string `json:"access_token"`
+		ExpiresIn   int    `json:"expires_in"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&token); err != nil {
+		return "", time.Time{}, fmt.Errorf("failed to decode token response: %w", err)
+	}
+	if token.AccessToken == "" {
+		return "", time.Time{}, fmt.Errorf("token response contained no access_token")
+	}
+	return token.AccessToken, time.Now().Add(time.Duration(token.ExpiresIn) * time.Second), nil
+}
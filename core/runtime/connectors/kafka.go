@@ -0,0 +1,212 @@
+//go:build cgo
+
+package connectors
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/confluentinc/confluent-kafka-go/v2/kafka"
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/observability"
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"go.opentelemetry.io/otel"
+	"go.opentelemetry.io/otel/attribute"
+	"go.opentelemetry.io/otel/codes"
+)
+
+const (
+	kafkaMetadataTimeoutMs = 10000
+	kafkaFlushTimeoutMs    = 10000
+)
+
+// KafkaConnector implements the Connector interface for publishing messages to
+// Kafka. Each statement produces one message and returns its delivery report.
+type KafkaConnector struct {
+	producer *kafka.Producer
+}
+
+// NewKafkaConnector creates a new Kafka producer.
+// The connection string is a comma-separated list of bootstrap brokers, optionally
+// prefixed with "kafka://". Options are passed through as librdkafka configuration
+// properties (e.g. "security.protocol", "sasl.mechanisms", "compression.type").
+func NewKafkaConnector(def *protoconnectors.ConnectorDef) (*KafkaConnector, error) {
+	options := def.GetOptions()
+
+	if !def.GetConfig().GetJsonStatements() {
+		return nil, fmt.Errorf("json_statements must be true for kafka")
+	}
+
+	brokers := strings.TrimPrefix(def.GetConnectionString(), "kafka://")
+	if brokers == "" {
+		return nil, fmt.Errorf("kafka connection string must list at least one broker")
+	}
+
+	// Wait for all in-sync replicas by default so a successful response means the
+	// message is durable. Options may override this.
+	config := kafka.ConfigMap{
+		"bootstrap.servers": brokers,
+		"acks":              "all",
+	}
+	for key, value := range options {
+		config[key] = value
+	}
+
+	log := logger.New("connector:kafka")
+	log.Debugf("Creating Kafka producer")
+
+	producer, err := kafka.NewProducer(&config)
+	if err != nil {
+		return nil, fmt.Errorf("failed to create kafka producer: %w", err)
+	}
+
+	// Test the connection
+	log.Debugf("Fetching cluster metadata")
+	if _, err := producer.GetMetadata(nil, false, kafkaMetadataTimeoutMs); err != nil {
+		producer.Close()
+		return nil, fmt.Errorf("failed to connect to kafka brokers: %w", err)
+	}
+
+	// Delivery reports go to per-message channels; the shared events channel only
+	// carries client-level errors, and must be drained so the producer never blocks.
+	go func() {
+		for event := range producer.Events() {
+			if kafkaErr, ok := event.(kafka.Error); ok {
+				log.Warnf("Kafka producer error: %v", kafkaErr)
+			}
+		}
+	}()
+
+	log.Debugf("Kafka producer created successfully")
+	return &KafkaConnector{producer: producer}, nil
+}
+
+// kafkaStatement is the JSON statement accepted by the kafka connector
+type kafkaStatement struct {
+	Topic     string            `json:"topic"`
+	Key       *string           `json:"key"`
+	Value     json.RawMessage   `json:"value"`
+	Headers   map[string]string `json:"headers"`
+	Partition *int32            `json:"partition"`
+}
+
+// Execute publishes a message and waits for the broker to acknowledge it.
+// The statement must be JSON with a "topic" and a "value". A JSON string value is
+// sent as-is; any other JSON value is sent as its compact JSON encoding.
+//
+// Example statement:
+//
+//	{ "topic": "orders", "key": "{{ inputs.order_id }}", "value": { "id": "{{ inputs.order_id }}", "total": {{ inputs.total }} } }
+//
+// The single result row contains the topic, partition, offset and timestamp assigned
+// to the message.
+func (k *KafkaConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	start := time.Now()
+	tracer := otel.Tracer("runtime/connectors/kafka")
+	ctx, span := tracer.Start(ctx, "connector.kafka.execute")
+	defer span.End()
+	span.SetAttributes(attribute.String(observability.AttrConnectorType, "kafka"))
+
+	fail := func(status string, err error) ([]map[string]any, error) {
+		span.SetStatus(codes.Error, status)
+		observability.RecordConnectorOperation(ctx, "", "kafka", "execute", false, float64(time.Since(start).Milliseconds()))
+		return nil, err
+	}
+
+	var stmt kafkaStatement
+	if err := json.Unmarshal([]byte(statement), &stmt); err != nil {
+		return fail("invalid_statement", fmt.Errorf("kafka statement must be valid JSON: %w", err))
+	}
+	if stmt.Topic == "" {
+		return fail("missing_topic", fmt.Errorf("kafka statement must include topic"))
+	}
+	if len(stmt.Value) == 0 {
+		return fail("missing_value", fmt.Errorf("kafka statement must include value"))
+	}
+
+	value, err := kafkaMessageValue(stmt.Value)
+	if err != nil {
+		return fail("invalid_statement", err)
+	}
+
+	message := &kafka.Message{
+		TopicPartition: kafka.TopicPartition{Topic: &stmt.Topic, Partition: kafka.PartitionAny},
+		Value:          value,
+	}
+	if stmt.Partition != nil {
+		message.TopicPartition.Partition = *stmt.Partition
+	}
+	if stmt.Key != nil {
+		message.Key = []byte(*stmt.Key)
+	}
+	for name, headerValue := range stmt.Headers {
+		message.Headers = append(message.Headers, kafka.Header{Key: name, Value: []byte(headerValue)})
+	}
+
+	// Buffered so the producer can still deliver the report if the request is cancelled
+	deliveryChan := make(chan kafka.Event, 1)
+	if err := k.producer.Produce(message, deliveryChan); err != nil {
+		return fail("produce_failed", fmt.Errorf("failed to produce kafka message: %w", err))
+	}
+
+	var delivered *kafka.Message
+	select {
+	case event := <-deliveryChan:
+		delivered = event.(*kafka.Message)
+	case <-ctx.Done():
+		return fail("delivery_cancelled", fmt.Errorf("kafka delivery was not acknowledged before the request ended: %w", ctx.Err()))
+	}
+	if delivered.TopicPartition.Error != nil {
+		return fail("delivery_failed", fmt.Errorf("kafka delivery failed: %w", delivered.TopicPartition.Error))
+	}
+
+	span.SetAttributes(attribute.String("messaging.destination.name", stmt.Topic))
+	observability.RecordConnectorOperation(ctx, "", "kafka", "execute", true, float64(time.Since(start).Milliseconds()))
+	return []map[string]any{{
+		"topic":     *delivered.TopicPartition.Topic,
+		"partition": delivered.TopicPartition.Partition,
+		"offset":    int64(delivered.TopicPartition.Offset),
+		"timestamp": delivered.Timestamp,
+	}}, nil
+}
+
+// Ping verifies the brokers are reachable by fetching cluster metadata
+func (k *KafkaConnector) Ping(ctx context.Context) error {
+	timeoutMs := kafkaMetadataTimeoutMs
+	if deadline, ok := ctx.Deadline(); ok {
+		timeoutMs = int(time.Until(deadline).Milliseconds())
+	}
+	_, err := k.producer.GetMetadata(nil, false, timeoutMs)
+	return err
+}
+
+// Close flushes pending messages and closes the producer
+func (k *KafkaConnector) Close() error {
+	if k.producer != nil {
+		log := logger.New("connector:kafka")
+		log.Debugf("Flushing Kafka producer")
+		if remaining := k.producer.Flush(kafkaFlushTimeoutMs); remaining > 0 {
+			log.Warnf("%d Kafka message(s) were not delivered before shutdown", remaining)
+		}
+		k.producer.Close()
+		log.Debugf("Kafka producer closed")
+	}
+	return nil
+}
+
+// kafkaMessageValue returns the message payload for a statement value
+func kafkaMessageValue(raw json.RawMessage) ([]byte, error) {
+	var text string
+	if err := json.Unmarshal(raw, &text); err == nil {
+		return []byte(text), nil
+	}
+	var compacted bytes.Buffer
+	if err := json.Compact(&compacted, raw); err != nil {
+		return nil, fmt.Errorf("kafka statement value is not valid JSON: %w", err)
+	}
+	return compacted.Bytes(), nil
+}
@@ -0,0 +1,28 @@
+//go:build !cgo
+
+package connectors
+
+import (
+	"context"
+	"fmt"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// KafkaConnector is unavailable in builds without cgo, which librdkafka requires.
+type KafkaConnector struct{}
+
+// NewKafkaConnector reports that Kafka support was not compiled in
+func NewKafkaConnector(def *protoconnectors.ConnectorDef) (*KafkaConnector, error) {
+	return nil, fmt.Errorf("kafka connector is not available in this build: rebuild with CGO_ENABLED=1")
+}
+
+// Execute is never reached since the connector cannot be constructed
+func (k *KafkaConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	return nil, fmt.Errorf("kafka connector is not available in this build")
+}
+
+// Close is a no-op
+func (k *KafkaConnector) Close() error {
+	return nil
+}
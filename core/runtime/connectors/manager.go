@@ -5,24 +5,41 @@ import (
 	"errors"
 	"fmt"
 	"maps"
+	"sort"
 	"sync"
+	"time"
 
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
 	"golang.org/x/sync/errgroup"
 )
 
+// readyRetryInterval is how long InitializeAllWithin waits between attempts to
+// connect adapters that are not ready yet
+const readyRetryInterval = 2 * time.Second
+
 // ConnectorManager manages database connectors with parallel initialization and shutdown.
 // New connectors automatically benefit from parallel operations by implementing the Connector interface.
 type ConnectorManager struct {
 	connectors map[string]Connector
 	mu         sync.RWMutex
+
+	// ctx is canceled by CloseAll to stop credential watchers
+	ctx  context.Context
+	stop context.CancelFunc
+
+	// stats counts the statements executed on each adapter
+	stats *statsRegistry
 }
 
 // NewConnectorManager creates a new ConnectorManager instance
 func NewConnectorManager() *ConnectorManager {
+	ctx, stop := context.WithCancel(context.Background())
 	return &ConnectorManager{
 		connectors: make(map[string]Connector),
+		ctx:        ctx,
+		stop:       stop,
+		stats:      newStatsRegistry(),
 	}
 }
 
@@ -47,27 +64,10 @@ func (m *ConnectorManager) InitializeAll(adapters []*hyperterse.Adapter) error {
 			default:
 			}
 
-			connectorTag := fmt.Sprintf("connector:%s", adapter.Name)
-			connLog := logger.New(connectorTag)
-
-			connLog.Debugf("Initializing connector")
-			connLog.Debugf("Connector type: %s", adapter.Connector.String())
-
-			// Log connector-specific options if present (masked)
-			if adapter.Options != nil && len(adapter.Options.Options) > 0 {
-				connLog.Debugf("Options provided: %d option(s)", len(adapter.Options.Options))
-			}
-
-			conn, err := NewConnector(adapter)
-			if err != nil {
+			connLog := logger.New(fmt.Sprintf("connector:%s", adapter.Name))
+			if err := m.initialize(adapter); err != nil {
 				return connLog.Errorf("%w", err)
 			}
-
-			m.mu.Lock()
-			m.connectors[adapter.Name] = conn
-			m.mu.Unlock()
-
-			connLog.Debugf("Connector initialized successfully")
 			return nil
 		})
 	}
@@ -82,8 +82,118 @@ func (m *ConnectorManager) InitializeAll(adapters []*hyperterse.Adapter) error {
 	return nil
 }
 
+// InitializeAllWithin creates all connectors like InitializeAll, but retries
+// adapters that fail to connect until timeout has elapsed, logging which ones
+// are still blocking readiness. If any adapter is still failing at the
+// deadline, all successfully created connectors are closed.
+func (m *ConnectorManager) InitializeAllWithin(adapters []*hyperterse.Adapter, timeout time.Duration) error {
+	log := logger.New("connector")
+	deadline := time.Now().Add(timeout)
+	pending := adapters
+
+	for len(pending) > 0 {
+		log.Debugf("Initializing %d adapter(s)", len(pending))
+
+		var mu sync.Mutex
+		var wg sync.WaitGroup
+		failures := make(map[string]error)
+		for _, adapter := range pending {
+			wg.Add(1)
+			go func(adapter *hyperterse.Adapter) {
+				defer wg.Done()
+				if err := m.initialize(adapter); err != nil {
+					mu.Lock()
+					failures[adapter.Name] = err
+					mu.Unlock()
+				}
+			}(adapter)
+		}
+		wg.Wait()
+
+		if len(failures) == 0 {
+			break
+		}
+
+		names := make([]string, 0, len(failures))
+		for name := range failures {
+			names = append(names, name)
+		}
+		sort.Strings(names)
+
+		if time.Now().After(deadline) {
+			m.CloseAll()
+			blocking := make(adapterErrors, len(names))
+			for i, name := range names {
+				blocking[i] = &AdapterError{Adapter: name, Err: failures[name]}
+			}
+			return log.Errorf("adapters not ready after %s: %w", timeout, blocking)
+		}
+		for _, name := range names {
+			log.Warnf("Waiting for adapter '%s': %v", name, failures[name])
+		}
+
+		var retry []*hyperterse.Adapter
+		for _, adapter := range pending {
+			if _, failed := failures[adapter.Name]; failed {
+				retry = append(retry, adapter)
+			}
+		}
+		pending = retry
+		time.Sleep(min(readyRetryInterval, time.Until(deadline)))
+	}
+
+	log.Debugf("All connectors initialized successfully")
+	return nil
+}
+
+// initialize creates the adapter's connector and registers it
+func (m *ConnectorManager) initialize(adapter *hyperterse.Adapter) error {
+	connLog := logger.New(fmt.Sprintf("connector:%s", adapter.Name))
+
+	connLog.Debugf("Initializing connector")
+	connLog.Debugf("Connector type: %s", adapter.Connector.String())
+
+	// Log connector-specific options if present (masked)
+	if adapter.Options != nil && len(adapter.Options.Options) > 0 {
+		connLog.Debugf("Options provided: %d option(s)", len(adapter.Options.Options))
+	}
+
+	// Adapters with refreshable credentials connect with the provider's
+	// current value and are then watched for changes
+	resolved := adapter
+	var credential string
+	if adapter.Credentials != nil {
+		var err error
+		if credential, err = resolveCredential(m.ctx, adapter.Credentials); err != nil {
+			return err
+		}
+		if resolved, err = withCredential(adapter, credential); err != nil {
+			return err
+		}
+	}
+
+	conn, err := NewConnector(resolved)
+	if err != nil {
+		return err
+	}
+
+	m.mu.Lock()
+	m.connectors[adapter.Name] = conn
+	m.mu.Unlock()
+
+	if adapter.Credentials != nil {
+		go m.watchCredentials(adapter, credential)
+	}
+
+	connLog.Debugf("Connector initialized successfully")
+	return nil
+}
+
 // CloseAll closes all connectors in parallel, collecting and returning all errors.
+// Credential watchers are stopped first.
 func (m *ConnectorManager) CloseAll() error {
+	m.stop()
+
 	m.mu.RLock()
 	connectorCount := len(m.connectors)
 	if connectorCount == 0 {
@@ -148,6 +258,34 @@ func (m *ConnectorManager) Count() int {
 	return len(m.connectors)
 }
 
+// HealthCheckAll pings every connector that supports health checks in
+// parallel, bounding each ping by timeout. It returns each checked adapter's
+// ping error, nil when healthy; connectors without health checks are omitted.
+func (m *ConnectorManager) HealthCheckAll(ctx context.Context, timeout time.Duration) map[string]error {
+	var mu sync.Mutex
+	results := make(map[string]error)
+
+	var wg sync.WaitGroup
+	for name, conn := range m.GetAll() {
+		checker, ok := conn.(HealthChecker)
+		if !ok {
+			continue
+		}
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			pingCtx, cancel := context.WithTimeout(ctx, timeout)
+			err := checker.Ping(pingCtx)
+			cancel()
+			mu.Lock()
+			results[name] = err
+			mu.Unlock()
+		}()
+	}
+	wg.Wait()
+	return results
+}
+
 // Register adds or replaces a connector by name.
 // Primarily useful for tests and custom runtime wiring.
 func (m *ConnectorManager) Register(name string, conn Connector) {
@@ -1,3 +1,5 @@
+//go:build !no_mongodb
+
 package connectors
 
 import (
@@ -12,6 +14,7 @@ import (
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/observability"
 	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
 	"go.mongodb.org/mongo-driver/v2/bson"
 	"go.mongodb.org/mongo-driver/v2/mongo"
 	mongoOptions "go.mongodb.org/mongo-driver/v2/mongo/options"
@@ -24,6 +27,9 @@ import (
 // MongoDBConnector implements the Connector interface for MongoDB
 type MongoDBConnector struct {
 	client *mongo.Client
+	// extendedJSON is "relaxed" or "canonical" to return documents as MongoDB
+	// Extended JSON, or empty to return plain JSON values
+	extendedJSON string
 }
 
 // NewMongoDBConnector creates a new MongoDB connector
@@ -38,6 +44,23 @@ func NewMongoDBConnector(def *protoconnectors.ConnectorDef) (*MongoDBConnector,
 	log := logger.New("connector:mongodb")
 	log.Debugf("Opening MongoDB connection")
 
+	// extended_json selects the result format and is not a driver option
+	extendedJSON := options["extended_json"]
+	switch extendedJSON {
+	case "", "relaxed", "canonical":
+	default:
+		return nil, fmt.Errorf("invalid mongodb option extended_json '%s': must be relaxed or canonical", extendedJSON)
+	}
+	if extendedJSON != "" {
+		uriOptions := make(map[string]string, len(options))
+		for key, value := range options {
+			if key != "extended_json" {
+				uriOptions[key] = value
+			}
+		}
+		options = uriOptions
+	}
+
 	// Append all options to connection string if provided
 	if len(options) > 0 {
 		if strings.HasPrefix(connectionString, "mongodb://") || strings.HasPrefix(connectionString, "mongodb+srv://") {
@@ -55,6 +78,14 @@ func NewMongoDBConnector(def *protoconnectors.ConnectorDef) (*MongoDBConnector,
 	}
 
 	opts := mongoOptions.Client().ApplyURI(connectionString)
+	if settings := def.GetTls(); settings != nil {
+		// Overrides any tls setting in the connection string
+		tlsConfig, err := buildTLSConfig(settings)
+		if err != nil {
+			return nil, fmt.Errorf("invalid mongodb tls settings: %w", err)
+		}
+		opts.TLSConfig = tlsConfig
+	}
 	client, err := mongo.Connect(opts)
 	if err != nil {
 		return nil, fmt.Errorf("failed to connect to mongodb: %w", err)
@@ -70,19 +101,13 @@ func NewMongoDBConnector(def *protoconnectors.ConnectorDef) (*MongoDBConnector,
 	}
 
 	log.Debugf("MongoDB connection opened successfully")
-	return &MongoDBConnector{client: client}, nil
-}
-
-// mongoStatement represents the JSON structure for a MongoDB command.
-// Command is kept as json.RawMessage so we can parse it into an ordered bson.D,
-// which is required by RunCommand (the command name must be the first key).
-type mongoStatement struct {
-	Database string          `json:"database"`
-	Command  json.RawMessage `json:"command"`
+	return &MongoDBConnector{client: client, extendedJSON: extendedJSON}, nil
 }
 
 // Execute runs a raw MongoDB command via RunCommand.
-// The statement must be JSON with "database" and "command" fields.
+// The statement must be JSON with "database" and "command" fields. The command
+// is parsed as MongoDB Extended JSON, so values such as {"$date": ...},
+// {"$numberLong": ...}, {"$oid": ...} and {"$regularExpression": ...} keep their BSON types.
 //
 // Example statements:
 //
@@ -100,22 +125,17 @@ func (m *MongoDBConnector) Execute(ctx context.Context, statement string, params
 	defer span.End()
 	span.SetAttributes(attribute.String(observability.AttrConnectorType, "mongodb"))
 
-	var stmt mongoStatement
-	if err := json.Unmarshal([]byte(statement), &stmt); err != nil {
+	stmt, err := utils.ParseMongoStatement(statement)
+	if err != nil {
 		span.SetStatus(codes.Error, "invalid_statement")
 		observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", false, float64(time.Since(start).Milliseconds()))
-		return nil, fmt.Errorf("mongodb statement must be valid JSON: %w", err)
+		return nil, err
 	}
 
-	if stmt.Database == "" {
-		span.SetStatus(codes.Error, "missing_database")
-		observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", false, float64(time.Since(start).Milliseconds()))
-		return nil, fmt.Errorf("mongodb statement must include database")
-	}
-	if len(stmt.Command) == 0 {
-		span.SetStatus(codes.Error, "missing_command")
+	if err := utils.ValidateMongoCommand(stmt.Command); err != nil {
+		span.SetStatus(codes.Error, "unsupported_command")
 		observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", false, float64(time.Since(start).Milliseconds()))
-		return nil, fmt.Errorf("mongodb statement must include command")
+		return nil, err
 	}
 
 	cmd, err := commandToBsonD(stmt.Command)
@@ -141,8 +161,14 @@ func (m *MongoDBConnector) Execute(ctx context.Context, statement string, params
 				if docs, ok := firstBatch.(bson.A); ok {
 					results := make([]map[string]any, 0, len(docs))
 					for _, doc := range docs {
-						if m, ok := doc.(bson.M); ok {
-							results = append(results, bsonMToMap(m))
+						if d, ok := doc.(bson.M); ok {
+							row, err := m.toRow(d)
+							if err != nil {
+								span.SetStatus(codes.Error, "encode_failed")
+								observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", false, float64(time.Since(start).Milliseconds()))
+								return nil, err
+							}
+							results = append(results, row)
 						}
 					}
 					observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", true, float64(time.Since(start).Milliseconds()))
@@ -153,70 +179,50 @@ func (m *MongoDBConnector) Execute(ctx context.Context, statement string, params
 	}
 
 	// For non-cursor results (insert, update, delete, count, etc.), return the raw result
+	row, err := m.toRow(result)
+	if err != nil {
+		span.SetStatus(codes.Error, "encode_failed")
+		observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", false, float64(time.Since(start).Milliseconds()))
+		return nil, err
+	}
 	observability.RecordConnectorOperation(ctx, "", "mongodb", "execute", true, float64(time.Since(start).Milliseconds()))
-	return []map[string]any{bsonMToMap(result)}, nil
+	return []map[string]any{row}, nil
 }
 
-// commandToBsonD parses command JSON into bson.D preserving key order.
+// commandToBsonD parses command Extended JSON into bson.D preserving key order.
 // RunCommand requires the command name (e.g. "find") to be the first key.
 func commandToBsonD(raw json.RawMessage) (bson.D, error) {
-	dec := json.NewDecoder(bytes.NewReader(raw))
-
-	// expect opening {
-	t, err := dec.Token()
-	if err != nil {
-		return nil, err
-	}
-	if delim, ok := t.(json.Delim); !ok || delim != '{' {
+	if trimmed := bytes.TrimSpace(raw); len(trimmed) == 0 || trimmed[0] != '{' {
 		return nil, fmt.Errorf("command must be a JSON object")
 	}
 
+	// Relaxed mode accepts both relaxed and canonical Extended JSON
 	var d bson.D
-	for dec.More() {
-		t, err := dec.Token()
-		if err != nil {
-			return nil, err
-		}
-		key := t.(string)
-
-		var val any
-		if err := dec.Decode(&val); err != nil {
-			return nil, err
-		}
-		d = append(d, bson.E{Key: key, Value: toBSONValue(val)})
+	if err := bson.UnmarshalExtJSON(raw, false, &d); err != nil {
+		return nil, err
 	}
-
 	return d, nil
 }
 
-// toBSON converts map[string]any to bson.M (handles nested maps and slices)
-func toBSON(m map[string]any) bson.M {
-	if m == nil {
-		return nil
-	}
-	out := make(bson.M, len(m))
-	for k, v := range m {
-		out[k] = toBSONValue(v)
+// toRow converts a result document to a row, as plain JSON values or as
+// Extended JSON when the adapter asks for it
+func (m *MongoDBConnector) toRow(doc bson.M) (map[string]any, error) {
+	if m.extendedJSON == "" {
+		return bsonMToMap(doc), nil
 	}
-	return out
-}
 
-func toBSONValue(v any) any {
-	switch val := v.(type) {
-	case map[string]any:
-		if oid, ok := objectIDFromMap(val); ok {
-			return oid
-		}
-		return toBSON(val)
-	case []any:
-		arr := make(bson.A, len(val))
-		for i, item := range val {
-			arr[i] = toBSONValue(item)
-		}
-		return arr
-	default:
-		return v
+	encoded, err := bson.MarshalExtJSON(doc, m.extendedJSON == "canonical", false)
+	if err != nil {
+		return nil, fmt.Errorf("failed to encode mongodb result as extended json: %w", err)
 	}
+	// Keep numbers as written so 64-bit integers survive in relaxed mode
+	dec := json.NewDecoder(bytes.NewReader(encoded))
+	dec.UseNumber()
+	var row map[string]any
+	if err := dec.Decode(&row); err != nil {
+		return nil, fmt.Errorf("failed to decode mongodb extended json result: %w", err)
+	}
+	return row, nil
 }
 
 func bsonMToMap(doc bson.M) map[string]any {
@@ -264,23 +270,9 @@ func bsonDToMap(doc bson.D) map[string]any {
 	return out
 }
 
-func objectIDFromMap(m map[string]any) (bson.ObjectID, bool) {
-	if len(m) != 1 {
-		return bson.ObjectID{}, false
-	}
-	raw, ok := m["$oid"]
-	if !ok {
-		return bson.ObjectID{}, false
-	}
-	s, ok := raw.(string)
-	if !ok {
-		return bson.ObjectID{}, false
-	}
-	oid, err := bson.ObjectIDFromHex(s)
-	if err != nil {
-		return bson.ObjectID{}, false
-	}
-	return oid, true
+// Ping verifies the primary is reachable
+func (m *MongoDBConnector) Ping(ctx context.Context) error {
+	return m.client.Ping(ctx, readpref.Primary())
 }
 
 // Close closes the MongoDB connection
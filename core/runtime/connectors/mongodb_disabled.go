@@ -0,0 +1,28 @@
+//go:build no_mongodb
+
+package connectors
+
+import (
+	"context"
+	"fmt"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// MongoDBConnector is unavailable in builds with the no_mongodb tag.
+type MongoDBConnector struct{}
+
+// NewMongoDBConnector reports that MongoDB support was not compiled in
+func NewMongoDBConnector(def *protoconnectors.ConnectorDef) (*MongoDBConnector, error) {
+	return nil, fmt.Errorf("mongodb connector is not available in this build: rebuild without the no_mongodb tag")
+}
+
+// Execute is never reached since the connector cannot be constructed
+func (m *MongoDBConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	return nil, fmt.Errorf("mongodb connector is not available in this build")
+}
+
+// Close is a no-op
+func (m *MongoDBConnector) Close() error {
+	return nil
+}
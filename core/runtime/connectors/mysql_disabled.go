@@ -0,0 +1,28 @@
+//go:build no_mysql
+
+package connectors
+
+import (
+	"context"
+	"fmt"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// MySQLConnector is unavailable in builds with the no_mysql tag.
+type MySQLConnector struct{}
+
+// NewMySQLConnector reports that MySQL support was not compiled in
+func NewMySQLConnector(def *protoconnectors.ConnectorDef) (*MySQLConnector, error) {
+	return nil, fmt.Errorf("mysql connector is not available in this build: rebuild without the no_mysql tag")
+}
+
+// Execute is never reached since the connector cannot be constructed
+func (m *MySQLConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	return nil, fmt.Errorf("mysql connector is not available in this build")
+}
+
+// Close is a no-op
+func (m *MySQLConnector) Close() error {
+	return nil
+}
@@ -0,0 +1,25 @@
+package connectors
+
+import (
+	"net/http"
+	"net/url"
+	"testing"
+	"time"
+)
+
+func TestSignS3RequestSendsSignedPath(t *testing.T) {
+	target := &url.URL{Scheme: "https", Host: "bucket.s3.us-east-1.amazonaws.com", Path: "/reports/a b+c=d:e.csv"}
+	req, err := http.NewRequest(http.MethodGet, target.String(), nil)
+	if err != nil {
+		t.Fatal(err)
+	}
+	signS3Request(req, "us-east-1", awsCredentials{AccessKeyID: "AKID", SecretAccessKey: "secret"}, time.Unix(0, 0).UTC())
+
+	want := "/reports/a%20b%2Bc%3Dd%3Ae.csv"
+	if got := req.URL.EscapedPath(); got != want {
+		t.Errorf("EscapedPath() = %q, want %q", got, want)
+	}
+	if got := req.URL.RequestURI(); got != want {
+		t.Errorf("RequestURI() = %q, want %q", got, want)
+	}
+}
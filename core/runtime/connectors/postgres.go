@@ -1,3 +1,5 @@
+//go:build !no_postgres
+
 package connectors
 
 import (
@@ -11,6 +13,7 @@ import (
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/observability"
 	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
 	_ "github.com/lib/pq"
 	"go.opentelemetry.io/otel"
 	"go.opentelemetry.io/otel/attribute"
@@ -25,7 +28,7 @@ type PostgresConnector struct {
 // NewPostgresConnector creates a new PostgreSQL connector
 func NewPostgresConnector(def *protoconnectors.ConnectorDef) (*PostgresConnector, error) {
 	connectionString := def.GetConnectionString()
-	options := def.GetOptions()
+	options := postgresTLSOptions(def.GetOptions(), def.GetTls())
 
 	if def.GetConfig().GetJsonStatements() {
 		return nil, fmt.Errorf("json_statements is not supported for postgres")
@@ -69,10 +72,20 @@ func NewPostgresConnector(def *protoconnectors.ConnectorDef) (*PostgresConnector
 		}
 	}
 
+	// With IAM auth each new connection gets a freshly generated token as its
+	// password
+	var dsnFor func(context.Context) (string, error)
+	if auth := def.GetIamAuth(); auth != nil {
+		var err error
+		if dsnFor, err = postgresIAMDSN(connectionString, auth); err != nil {
+			return nil, fmt.Errorf("invalid postgres iam auth settings: %w", err)
+		}
+	}
+
 	log := logger.New("connector:postgres")
 	log.Debugf("Opening PostgreSQL connection pool")
 
-	db, err := sql.Open("postgres", connectionString)
+	db, err := openSQLPool("postgres", connectionString, def.GetOnConnect(), dsnFor)
 	if err != nil {
 		return nil, fmt.Errorf("failed to open postgres connection: %w", err)
 	}
@@ -83,6 +96,7 @@ func NewPostgresConnector(def *protoconnectors.ConnectorDef) (*PostgresConnector
 		db.Close()
 		return nil, fmt.Errorf("failed to ping postgres database: %w", err)
 	}
+	configureSQLPool(db, def.GetPool(), log)
 
 	log.Debugf("PostgreSQL connection pool opened successfully")
 	return &PostgresConnector{db: db}, nil
@@ -118,16 +132,21 @@ func (p *PostgresConnector) Execute(ctx context.Context, statement string, param
 		return nil, fmt.Errorf("failed to get columns: %w", err)
 	}
 
-	// Build result slice
+	// JSON columns are decoded so they are returned as JSON rather than text
+	var jsonColumns []bool
+	if types, err := rows.ColumnTypes(); err == nil {
+		jsonColumns = utils.JSONColumns(types)
+	}
+
+	// Build result slice. Scan buffers are reused across rows since every value is
+	// copied into the row map, and column names are shared by all row maps.
 	var results []map[string]any
+	values := make([]any, len(columns))
+	valuePtrs := make([]any, len(columns))
+	for i := range values {
+		valuePtrs[i] = &values[i]
+	}
 	for rows.Next() {
-		// Create slice to hold values
-		values := make([]any, len(columns))
-		valuePtrs := make([]any, len(columns))
-		for i := range values {
-			valuePtrs[i] = &values[i]
-		}
-
 		// Scan row into values
 		if err := rows.Scan(valuePtrs...); err != nil {
 			span.SetStatus(codes.Error, "scan_failed")
@@ -136,12 +155,18 @@ func (p *PostgresConnector) Execute(ctx context.Context, statement string, param
 		}
 
 		// Create map for this row
-		rowMap := make(map[string]any)
+		rowMap := make(map[string]any, len(columns))
 		for i, col := range columns {
 			val := values[i]
 			// Convert []byte to string for better JSON serialization
 			if b, ok := val.([]byte); ok {
-				rowMap[col] = string(b)
+				if jsonColumns != nil && jsonColumns[i] {
+					rowMap[col] = utils.DecodeJSONColumn(b)
+				} else {
+					rowMap[col] = string(b)
+				}
+			} else if s, ok := val.(string); ok && jsonColumns != nil && jsonColumns[i] {
+				rowMap[col] = utils.DecodeJSONColumn([]byte(s))
 			} else {
 				rowMap[col] = val
 			}
@@ -160,6 +185,21 @@ func (p *PostgresConnector) Execute(ctx context.Context, statement string, param
 	return results, nil
 }
 
+// Ping verifies the database is reachable
+func (p *PostgresConnector) Ping(ctx context.Context) error {
+	return p.db.PingContext(ctx)
+}
+
+// PoolStats returns the connection pool's statistics
+func (p *PostgresConnector) PoolStats() sql.DBStats {
+	return p.db.Stats()
+}
+
+// DB returns the underlying connection pool
+func (p *PostgresConnector) DB() *sql.DB {
+	return p.db
+}
+
 // Close closes the database connection
 func (p *PostgresConnector) Close() error {
 	if p.db != nil {
@@ -0,0 +1,28 @@
+//go:build no_postgres
+
+package connectors
+
+import (
+	"context"
+	"fmt"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// PostgresConnector is unavailable in builds with the no_postgres tag.
+type PostgresConnector struct{}
+
+// NewPostgresConnector reports that PostgreSQL support was not compiled in
+func NewPostgresConnector(def *protoconnectors.ConnectorDef) (*PostgresConnector, error) {
+	return nil, fmt.Errorf("postgres connector is not available in this build: rebuild without the no_postgres tag")
+}
+
+// Execute is never reached since the connector cannot be constructed
+func (p *PostgresConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	return nil, fmt.Errorf("postgres connector is not available in this build")
+}
+
+// Close is a no-op
+func (p *PostgresConnector) Close() error {
+	return nil
+}
@@ -1,3 +1,5 @@
+//go:build !no_redis
+
 package connectors
 
 import (
@@ -122,6 +124,11 @@ func (r *RedisConnector) Execute(ctx context.Context, statement string, params m
 	return []map[string]any{result}, nil
 }
 
+// Ping verifies the server is reachable
+func (r *RedisConnector) Ping(ctx context.Context) error {
+	return r.client.Ping(ctx).Err()
+}
+
 // Close closes the Redis connection
 func (r *RedisConnector) Close() error {
 	if r.client != nil {
@@ -0,0 +1,28 @@
+//go:build no_redis
+
+package connectors
+
+import (
+	"context"
+	"fmt"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// RedisConnector is unavailable in builds with the no_redis tag.
+type RedisConnector struct{}
+
+// NewRedisConnector reports that Redis support was not compiled in
+func NewRedisConnector(def *protoconnectors.ConnectorDef) (*RedisConnector, error) {
+	return nil, fmt.Errorf("redis connector is not available in this build: rebuild without the no_redis tag")
+}
+
+// Execute is never reached since the connector cannot be constructed
+func (r *RedisConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	return nil, fmt.Errorf("redis connector is not available in this build")
+}
+
+// Close is a no-op
+func (r *RedisConnector) Close() error {
+	return nil
+}
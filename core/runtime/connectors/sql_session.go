@@ -0,0 +1,141 @@
+package connectors
+
+import (
+	"context"
+	"database/sql"
+	"database/sql/driver"
+	"fmt"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// poolWarmTimeout bounds opening the connections of a warm pool at startup
+const poolWarmTimeout = 30 * time.Second
+
+// openSQLPool opens a database/sql pool. When onConnect is set, its statements
+// run on every new connection before the pool hands it out, so session settings
+// such as search_path apply to all queries. When dsnFor is set, it supplies the
+// DSN for each new connection in place of dsn, so short-lived credentials are
+// current whenever the pool reconnects.
+func openSQLPool(driverName, dsn string, onConnect []string, dsnFor func(context.Context) (string, error)) (*sql.DB, error) {
+	db, err := sql.Open(driverName, dsn)
+	if err != nil || (len(onConnect) == 0 && dsnFor == nil) {
+		return db, err
+	}
+
+	drv := db.Driver()
+	db.Close()
+
+	driverCtx, ok := drv.(driver.DriverContext)
+	if !ok {
+		return nil, fmt.Errorf("%s driver does not support on_connect or iam auth", driverName)
+	}
+
+	var connector driver.Connector = &dynamicConnector{driver: drv, driverCtx: driverCtx, dsn: dsnFor}
+	if dsnFor == nil {
+		if connector, err = driverCtx.OpenConnector(dsn); err != nil {
+			return nil, err
+		}
+	}
+	return sql.OpenDB(&sessionConnector{Connector: connector, statements: onConnect}), nil
+}
+
+// dynamicConnector opens each connection with a DSN built when it connects
+type dynamicConnector struct {
+	driver    driver.Driver
+	driverCtx driver.DriverContext
+	dsn       func(context.Context) (string, error)
+}
+
+// Connect builds the DSN and establishes a connection with it
+func (c *dynamicConnector) Connect(ctx context.Context) (driver.Conn, error) {
+	dsn, err := c.dsn(ctx)
+	if err != nil {
+		return nil, err
+	}
+	connector, err := c.driverCtx.OpenConnector(dsn)
+	if err != nil {
+		return nil, err
+	}
+	return connector.Connect(ctx)
+}
+
+// Driver returns the underlying driver
+func (c *dynamicConnector) Driver() driver.Driver {
+	return c.driver
+}
+
+// sessionConnector runs session statements on each connection it establishes
+type sessionConnector struct {
+	driver.Connector
+	statements []string
+}
+
+// Connect establishes a connection and runs the session statements on it
+func (c *sessionConnector) Connect(ctx context.Context) (driver.Conn, error) {
+	conn, err := c.Connector.Connect(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	for _, stmt := range c.statements {
+		if err := execSessionStatement(ctx, conn, stmt); err != nil {
+			conn.Close()
+			return nil, fmt.Errorf("on_connect statement '%s' failed: %w", stmt, err)
+		}
+	}
+	return conn, nil
+}
+
+func execSessionStatement(ctx context.Context, conn driver.Conn, stmt string) error {
+	execer, ok := conn.(driver.ExecerContext)
+	if !ok {
+		return fmt.Errorf("driver connection cannot execute statements directly")
+	}
+	_, err := execer.ExecContext(ctx, stmt, nil)
+	return err
+}
+
+// configureSQLPool applies pool settings to db. max_connections caps the open
+// connections, min_connections sets how many idle connections the pool keeps,
+// and warm opens them now so the first queries after startup don't wait for
+// connections. A pool that can't be fully warmed is still used, opening the
+// remaining connections on demand.
+func configureSQLPool(db *sql.DB, pool *protoconnectors.PoolConfig, log *logger.Logger) {
+	if pool == nil {
+		return
+	}
+	if pool.MaxConnections > 0 {
+		db.SetMaxOpenConns(int(pool.MaxConnections))
+	}
+	if pool.MinConnections <= 0 {
+		return
+	}
+	db.SetMaxIdleConns(int(pool.MinConnections))
+	if !pool.Warm {
+		return
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), poolWarmTimeout)
+	defer cancel()
+
+	// Connections are held until all are open, otherwise the pool would hand
+	// back the same idle connection each time
+	conns := make([]*sql.Conn, 0, pool.MinConnections)
+	defer func() {
+		for _, conn := range conns {
+			conn.Close()
+		}
+	}()
+	for range pool.MinConnections {
+		conn, err := db.Conn(ctx)
+		if err != nil {
+			log.Warnf("Warmed %d of %d pool connections: %v", len(conns), pool.MinConnections, err)
+			return
+		}
+		conns = append(conns, conn)
+	}
+	log.Debugf("Warmed %d pool connections", len(conns))
+}
@@ -0,0 +1,53 @@
+package connectors
+
+import (
+	"context"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/observability"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"go.opentelemetry.io/otel"
+	"go.opentelemetry.io/otel/attribute"
+	"go.opentelemetry.io/otel/codes"
+)
+
+// StaticConnector implements the Connector interface for adapters without a
+// backend. Each statement holds its own rows as JSON, so enums, feature lists
+// and fixed payloads are served like any other query.
+type StaticConnector struct{}
+
+// NewStaticConnector creates a static connector. It has no connection.
+func NewStaticConnector() *StaticConnector {
+	return &StaticConnector{}
+}
+
+// Execute returns the rows written in the statement, keeping only rows whose
+// columns equal the statement's filter values.
+//
+// Example statements:
+//
+//	[{ "code": "us", "name": "United States" }, { "code": "de", "name": "Germany" }]
+//	{ "rows": [{ "plan": "free", "seats": 1 }, { "plan": "team", "seats": 10 }], "filter": { "plan": "{{ inputs.plan }}" } }
+func (s *StaticConnector) Execute(ctx context.Context, statement string, params map[string]any) ([]map[string]any, error) {
+	start := time.Now()
+	tracer := otel.Tracer("runtime/connectors/static")
+	ctx, span := tracer.Start(ctx, "connector.static.execute")
+	defer span.End()
+	span.SetAttributes(attribute.String(observability.AttrConnectorType, "static"))
+
+	parsed, err := utils.ParseStaticStatement(statement)
+	if err != nil {
+		span.SetStatus(codes.Error, "invalid_statement")
+		observability.RecordConnectorOperation(ctx, "", "static", "execute", false, float64(time.Since(start).Milliseconds()))
+		return nil, err
+	}
+
+	rows := parsed.FilterRows()
+	observability.RecordConnectorOperation(ctx, "", "static", "execute", true, float64(time.Since(start).Milliseconds()))
+	return rows, nil
+}
+
+// Close is a no-op
+func (s *StaticConnector) Close() error {
+	return nil
+}
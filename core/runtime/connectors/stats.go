@@ -0,0 +1,138 @@
+package connectors
+
+import (
+	"context"
+	"slices"
+	"sync"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/observability"
+)
+
+// statsSampleSize is how many recent executions of each adapter its latency
+// percentiles are computed from
+const statsSampleSize = 1024
+
+// AdapterStats is a snapshot of the statements an adapter executed. Cache hits
+// never reach the adapter and are not counted; a coalesced execution counts
+// once. Percentiles cover the latest statsSampleSize executions.
+type AdapterStats struct {
+	Queries      int64   `json:"queries"`
+	Errors       int64   `json:"errors"`
+	Rows         int64   `json:"rows"`
+	Bytes        int64   `json:"bytes"` // Estimated in-memory size of the rows returned
+	AvgLatencyMs float64 `json:"avg_latency_ms"`
+	P50LatencyMs float64 `json:"p50_latency_ms"`
+	P95LatencyMs float64 `json:"p95_latency_ms"`
+	P99LatencyMs float64 `json:"p99_latency_ms"`
+}
+
+// adapterStats holds the counters of a single adapter
+type adapterStats struct {
+	mu           sync.Mutex
+	queries      int64
+	errors       int64
+	rows         int64
+	bytes        int64
+	totalLatency time.Duration
+	samples      []time.Duration // Ring buffer of recent latencies
+	next         int
+}
+
+// statsRegistry holds the counters of every adapter. Reloads hand it to the
+// new manager, so counters cover the whole life of the server.
+type statsRegistry struct {
+	mu       sync.Mutex
+	adapters map[string]*adapterStats
+	since    time.Time
+}
+
+func newStatsRegistry() *statsRegistry {
+	return &statsRegistry{adapters: make(map[string]*adapterStats), since: time.Now()}
+}
+
+// RecordExecution counts a statement executed on adapter, with the rows and
+// estimated bytes it returned, and exports it as metrics
+func (m *ConnectorManager) RecordExecution(ctx context.Context, adapter string, latency time.Duration, rows int, bytes int64, err error) {
+	observability.RecordAdapterExecution(ctx, adapter, err == nil, float64(latency.Microseconds())/1000, int64(rows), bytes)
+
+	m.stats.mu.Lock()
+	stats, ok := m.stats.adapters[adapter]
+	if !ok {
+		stats = &adapterStats{}
+		m.stats.adapters[adapter] = stats
+	}
+	m.stats.mu.Unlock()
+
+	stats.mu.Lock()
+	defer stats.mu.Unlock()
+	stats.queries++
+	stats.totalLatency += latency
+	if err != nil {
+		stats.errors++
+	} else {
+		stats.rows += int64(rows)
+		stats.bytes += bytes
+	}
+	if len(stats.samples) < statsSampleSize {
+		stats.samples = append(stats.samples, latency)
+	} else {
+		stats.samples[stats.next] = latency
+		stats.next = (stats.next + 1) % statsSampleSize
+	}
+}
+
+// Stats returns a snapshot of each adapter's counters, and the time counting
+// started
+func (m *ConnectorManager) Stats() (map[string]AdapterStats, time.Time) {
+	m.stats.mu.Lock()
+	adapters := make(map[string]*adapterStats, len(m.stats.adapters))
+	for name, stats := range m.stats.adapters {
+		adapters[name] = stats
+	}
+	since := m.stats.since
+	m.stats.mu.Unlock()
+
+	result := make(map[string]AdapterStats, len(adapters))
+	for name, stats := range adapters {
+		stats.mu.Lock()
+		snapshot := AdapterStats{
+			Queries: stats.queries,
+			Errors:  stats.errors,
+			Rows:    stats.rows,
+			Bytes:   stats.bytes,
+		}
+		if stats.queries > 0 {
+			snapshot.AvgLatencyMs = milliseconds(stats.totalLatency / time.Duration(stats.queries))
+		}
+		samples := slices.Clone(stats.samples)
+		stats.mu.Unlock()
+
+		slices.Sort(samples)
+		snapshot.P50LatencyMs = milliseconds(percentile(samples, 50))
+		snapshot.P95LatencyMs = milliseconds(percentile(samples, 95))
+		snapshot.P99LatencyMs = milliseconds(percentile(samples, 99))
+		result[name] = snapshot
+	}
+	return result, since
+}
+
+// InheritStats continues counting from the counters of previous, so adapter
+// statistics survive a reload
+func (m *ConnectorManager) InheritStats(previous *ConnectorManager) {
+	m.stats = previous.stats
+}
+
+// percentile returns the p-th percentile of sorted samples by the nearest-rank
+// method, zero when there are none
+func percentile(sorted []time.Duration, p int) time.Duration {
+	if len(sorted) == 0 {
+		return 0
+	}
+	rank := (p*len(sorted) + 99) / 100
+	return sorted[max(rank, 1)-1]
+}
+
+func milliseconds(d time.Duration) float64 {
+	return float64(d.Microseconds()) / 1000
+}
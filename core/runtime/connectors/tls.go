@@ -0,0 +1,109 @@
+package connectors
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"maps"
+	"os"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// SSL modes accepted by an adapter's ssl_mode
+const (
+	sslModeDisable    = "disable"
+	sslModeRequire    = "require"
+	sslModeVerifyCA   = "verify-ca"
+	sslModeVerifyFull = "verify-full"
+)
+
+// sslMode returns the effective mode of settings, verify-full when unset
+func sslMode(settings *protoconnectors.TLSConfig) string {
+	if settings.GetSslMode() == "" {
+		return sslModeVerifyFull
+	}
+	return settings.GetSslMode()
+}
+
+// buildTLSConfig returns the client TLS configuration for settings, or nil when
+// TLS is disabled. The server name is left empty for the driver to fill in from
+// the host it connects to.
+func buildTLSConfig(settings *protoconnectors.TLSConfig) (*tls.Config, error) {
+	mode := sslMode(settings)
+	if mode == sslModeDisable {
+		return nil, nil
+	}
+
+	cfg := &tls.Config{MinVersion: tls.VersionTLS12}
+
+	if settings.GetCaCertFile() != "" {
+		pem, err := os.ReadFile(settings.GetCaCertFile())
+		if err != nil {
+			return nil, fmt.Errorf("failed to read ca_cert_file: %w", err)
+		}
+		pool := x509.NewCertPool()
+		if !pool.AppendCertsFromPEM(pem) {
+			return nil, fmt.Errorf("ca_cert_file '%s' contains no PEM certificates", settings.GetCaCertFile())
+		}
+		cfg.RootCAs = pool
+	}
+
+	if settings.GetClientCertFile() != "" {
+		cert, err := tls.LoadX509KeyPair(settings.GetClientCertFile(), settings.GetClientKeyFile())
+		if err != nil {
+			return nil, fmt.Errorf("failed to load client_cert_file and client_key_file: %w", err)
+		}
+		cfg.Certificates = []tls.Certificate{cert}
+	}
+
+	if mode == sslModeRequire && cfg.RootCAs != nil {
+		// As with libpq, a CA file upgrades require to verify-ca
+		mode = sslModeVerifyCA
+	}
+
+	switch mode {
+	case sslModeRequire:
+		// Encrypt without verifying the server, as libpq does
+		cfg.InsecureSkipVerify = true
+	case sslModeVerifyCA:
+		// Verify the chain but not the host name, which crypto/tls only
+		// supports by skipping its own verification and checking here
+		cfg.InsecureSkipVerify = true
+		roots := cfg.RootCAs
+		cfg.VerifyConnection = func(state tls.ConnectionState) error {
+			if len(state.PeerCertificates) == 0 {
+				return fmt.Errorf("server presented no certificate")
+			}
+			intermediates := x509.NewCertPool()
+			for _, cert := range state.PeerCertificates[1:] {
+				intermediates.AddCert(cert)
+			}
+			_, err := state.PeerCertificates[0].Verify(x509.VerifyOptions{
+				Roots:         roots,
+				Intermediates: intermediates,
+			})
+			return err
+		}
+	}
+	return cfg, nil
+}
+
+// postgresTLSOptions returns options with the libpq TLS parameters for
+// settings added, leaving options itself unchanged
+func postgresTLSOptions(options map[string]string, settings *protoconnectors.TLSConfig) map[string]string {
+	if settings == nil {
+		return options
+	}
+	merged := make(map[string]string, len(options)+4)
+	maps.Copy(merged, options)
+	merged["sslmode"] = sslMode(settings)
+	if settings.GetCaCertFile() != "" {
+		merged["sslrootcert"] = settings.GetCaCertFile()
+	}
+	if settings.GetClientCertFile() != "" {
+		merged["sslcert"] = settings.GetClientCertFile()
+		merged["sslkey"] = settings.GetClientKeyFile()
+	}
+	return merged
+}
@@ -0,0 +1,216 @@
+package dashboard
+
+import (
+	_ "embed"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+//go:embed assets/index.html
+var indexHTML []byte
+
+// Dashboard status states
+const (
+	StateOK        = "ok"
+	StateReloading = "reloading"
+	StateError     = "error"
+)
+
+// Status describes the development server's reload/validation state
+type Status struct {
+	State   string    `json:"state"`
+	Message string    `json:"message,omitempty"`
+	Time    time.Time `json:"time"`
+}
+
+// StatusBroker fans out status updates to connected dashboards.
+// It outlives individual runtimes so reload and validation failures can be
+// reported while the previous server keeps running.
+type StatusBroker struct {
+	mu          sync.Mutex
+	last        Status
+	subscribers map[chan Status]struct{}
+}
+
+// NewStatusBroker creates a new StatusBroker
+func NewStatusBroker() *StatusBroker {
+	return &StatusBroker{
+		last:        Status{State: StateOK, Time: time.Now()},
+		subscribers: make(map[chan Status]struct{}),
+	}
+}
+
+// Publish records a new status and sends it to all subscribers
+func (b *StatusBroker) Publish(state, message string) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+
+	b.last = Status{State: state, Message: message, Time: time.Now()}
+	for ch := range b.subscribers {
+		select {
+		case ch <- b.last:
+		default:
+			// Slow subscriber, drop the update rather than block reloads
+		}
+	}
+}
+
+// Subscribe registers a subscriber and returns its channel along with an unsubscribe function.
+// The current status is delivered immediately.
+func (b *StatusBroker) Subscribe() (<-chan Status, func()) {
+	ch := make(chan Status, 8)
+
+	b.mu.Lock()
+	b.subscribers[ch] = struct{}{}
+	ch <- b.last
+	b.mu.Unlock()
+
+	return ch, func() {
+		b.mu.Lock()
+		delete(b.subscribers, ch)
+		b.mu.Unlock()
+	}
+}
+
+// Register mounts the development dashboard routes under /_dev
+func Register(mux *http.ServeMux, model *hyperterse.Model, exec *executor.Executor, broker *StatusBroker, shutdown <-chan struct{}) {
+	mux.HandleFunc("/_dev", func(w http.ResponseWriter, req *http.Request) {
+		if req.Method != http.MethodGet {
+			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+		w.Header().Set("Content-Type", "text/html; charset=utf-8")
+		w.Write(indexHTML)
+	})
+
+	mux.HandleFunc("/_dev/api/queries", func(w http.ResponseWriter, req *http.Request) {
+		if req.Method != http.MethodGet {
+			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+		writeJSON(w, http.StatusOK, describeQueries(model))
+	})
+
+	mux.HandleFunc("/_dev/api/execute/", func(w http.ResponseWriter, req *http.Request) {
+		if req.Method != http.MethodPost {
+			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+		queryName := strings.TrimPrefix(req.URL.Path, "/_dev/api/execute/")
+
+		var inputs map[string]any
+		if err := json.NewDecoder(req.Body).Decode(&inputs); err != nil {
+			writeJSON(w, http.StatusBadRequest, map[string]any{
+				"success": false,
+				"error":   "Invalid JSON",
+				"results": []any{},
+			})
+			return
+		}
+
+		start := time.Now()
+		result, err := exec.Execute(req.Context(), queryName, inputs)
+		durationMS := float64(time.Since(start).Microseconds()) / 1000
+		if err != nil {
+			writeJSON(w, http.StatusOK, map[string]any{
+				"success":     false,
+				"error":       err.Error(),
+				"results":     []any{},
+				"duration_ms": durationMS,
+			})
+			return
+		}
+		results := result.Rows
+		if results == nil {
+			results = []map[string]any{}
+		}
+		writeJSON(w, http.StatusOK, map[string]any{
+			"success":     true,
+			"error":       "",
+			"results":     results,
+			"truncated":   result.Truncated,
+			"cache_hit":   result.CacheHit,
+			"duration_ms": durationMS,
+		})
+	})
+
+	mux.HandleFunc("/_dev/events", func(w http.ResponseWriter, req *http.Request) {
+		if req.Method != http.MethodGet {
+			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+		flusher, ok := w.(http.Flusher)
+		if !ok {
+			http.Error(w, "Streaming not supported", http.StatusInternalServerError)
+			return
+		}
+
+		w.Header().Set("Content-Type", "text/event-stream")
+		w.Header().Set("Cache-Control", "no-cache")
+		w.Header().Set("Connection", "keep-alive")
+		flusher.Flush()
+
+		updates, unsubscribe := broker.Subscribe()
+		defer unsubscribe()
+
+		ticker := time.NewTicker(10 * time.Second)
+		defer ticker.Stop()
+
+		for {
+			select {
+			case <-req.Context().Done():
+				return
+			case <-shutdown:
+				return
+			case status := <-updates:
+				payload, _ := json.Marshal(status)
+				fmt.Fprintf(w, "event: status\ndata: %s\n\n", payload)
+				flusher.Flush()
+			case <-ticker.C:
+				fmt.Fprintf(w, ": keep-alive\n\n")
+				flusher.Flush()
+			}
+		}
+	})
+
+	logger.New("dashboard").Debugf("Development dashboard registered at /_dev")
+}
+
+// describeQueries builds the query listing used to render input forms
+func describeQueries(model *hyperterse.Model) []map[string]any {
+	queries := make([]map[string]any, 0, len(model.Queries))
+	for _, query := range model.Queries {
+		inputs := make([]map[string]any, 0, len(query.Inputs))
+		for _, input := range query.Inputs {
+			inputs = append(inputs, map[string]any{
+				"name":        input.Name,
+				"type":        types.PrimitiveEnumToString(input.Type),
+				"description": input.Description,
+				"optional":    input.Optional,
+				"default":     input.DefaultValue,
+			})
+		}
+		queries = append(queries, map[string]any{
+			"name":        query.Name,
+			"description": query.Description,
+			"use":         query.Use,
+			"inputs":      inputs,
+		})
+	}
+	return queries
+}
+
+func writeJSON(w http.ResponseWriter, statusCode int, v any) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(statusCode)
+	json.NewEncoder(w).Encode(v)
+}
@@ -0,0 +1,93 @@
+package events
+
+import (
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+// dropWarnInterval is the minimum time between warnings about dropped events,
+// so a stalled subscriber does not flood the log with one line per event
+const dropWarnInterval = 10 * time.Second
+
+// Event is a notification published by the runtime. Payload holds one of the
+// event types in this package; subscribers switch on its concrete type.
+type Event struct {
+	Time    time.Time
+	Payload RuntimeEvent
+}
+
+// Type returns the type of the event's payload
+func (e Event) Type() Type {
+	return e.Payload.Type()
+}
+
+// Bus fans events out to subscribers. Publishing never blocks: a subscriber
+// that falls behind by more than its buffer misses events rather than stalling
+// the request path. A nil Bus discards everything published to it.
+type Bus struct {
+	mu          sync.RWMutex
+	subscribers map[int]chan Event
+	nextID      int
+
+	dropped  atomic.Int64 // Events dropped since the last warning
+	warnedAt atomic.Int64 // Unix nanoseconds of the last warning
+}
+
+// NewBus creates an event bus with no subscribers
+func NewBus() *Bus {
+	return &Bus{subscribers: make(map[int]chan Event)}
+}
+
+// Publish sends an event to every subscriber
+func (b *Bus) Publish(payload RuntimeEvent) {
+	if b == nil {
+		return
+	}
+	event := Event{Time: time.Now(), Payload: payload}
+
+	b.mu.RLock()
+	defer b.mu.RUnlock()
+	for _, ch := range b.subscribers {
+		select {
+		case ch <- event:
+		default:
+			b.recordDrop(event)
+		}
+	}
+}
+
+// recordDrop counts an event a subscriber missed and warns with the number of
+// events dropped since the previous warning, at most once per dropWarnInterval
+func (b *Bus) recordDrop(event Event) {
+	b.dropped.Add(1)
+	last := b.warnedAt.Load()
+	if event.Time.UnixNano()-last < int64(dropWarnInterval) || !b.warnedAt.CompareAndSwap(last, event.Time.UnixNano()) {
+		return
+	}
+	logger.New("events").Warnf("Event subscriber is falling behind, dropped %d event(s) since the last warning, the latest a %s event", b.dropped.Swap(0), event.Type())
+}
+
+// Subscribe returns a channel receiving every event published from now on, and
+// a function that unsubscribes and closes the channel
+func (b *Bus) Subscribe(buffer int) (<-chan Event, func()) {
+	ch := make(chan Event, buffer)
+
+	b.mu.Lock()
+	id := b.nextID
+	b.nextID++
+	b.subscribers[id] = ch
+	b.mu.Unlock()
+
+	var once sync.Once
+	return ch, func() {
+		once.Do(func() {
+			b.mu.Lock()
+			delete(b.subscribers, id)
+			b.mu.Unlock()
+			close(ch)
+		})
+	}
+}
@@ -0,0 +1,73 @@
+package events
+
+import "time"
+
+// Type identifies a runtime event
+type Type string
+
+// Runtime event types
+const (
+	TypeServerStarted          Type = "server.started"
+	TypeQueryStarted           Type = "query.started"
+	TypeQueryFinished          Type = "query.finished"
+	TypeConnectorHealthChanged Type = "connector.health_changed"
+	TypeConfigReloaded         Type = "config.reloaded"
+)
+
+// RuntimeEvent is implemented by every event payload
+type RuntimeEvent interface {
+	Type() Type
+}
+
+// ServerStarted is published once the HTTP server is listening
+type ServerStarted struct {
+	Port string
+}
+
+// QueryStarted is published when the executor begins running a query.
+// Headers holds the request's passthrough header values, keyed by lowercase name.
+type QueryStarted struct {
+	Query   string
+	Adapter string
+	Headers map[string]string
+}
+
+// QueryFinished is published when a query completes, successfully or not.
+// InvalidInput marks failures caused by the caller's inputs rather than the
+// query or its connector. ErrorClass is the connectors.ErrorClass of Err, and
+// Transient marks failures that may succeed if retried, such as timeouts and
+// dropped connections. Err keeps the driver error in its chain for errors.As.
+type QueryFinished struct {
+	Query        string
+	Adapter      string
+	Headers      map[string]string
+	Duration     time.Duration
+	Rows         int
+	CacheHit     bool
+	Err          error
+	InvalidInput bool
+	ErrorClass   string
+	Transient    bool
+}
+
+// ConnectorHealthChanged is published when a connector health check starts
+// failing or recovers. Err holds the failing check's error while unhealthy.
+type ConnectorHealthChanged struct {
+	Adapter string
+	Healthy bool
+	Err     error
+}
+
+// ConfigReloaded is published after a configuration reload. On failure Err is
+// set and the previous configuration keeps serving.
+type ConfigReloaded struct {
+	Adapters int
+	Queries  int
+	Err      error
+}
+
+func (ServerStarted) Type() Type          { return TypeServerStarted }
+func (QueryStarted) Type() Type           { return TypeQueryStarted }
+func (QueryFinished) Type() Type          { return TypeQueryFinished }
+func (ConnectorHealthChanged) Type() Type { return TypeConnectorHealthChanged }
+func (ConfigReloaded) Type() Type         { return TypeConfigReloaded }
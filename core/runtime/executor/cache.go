@@ -54,6 +54,15 @@ func (c *queryCache) Get(key string) ([]map[string]any, bool) {
 	return cloneRows(rows), true
 }
 
+// ExpiresAt returns when the cached rows under key expire
+func (c *queryCache) ExpiresAt(key string) (time.Time, bool) {
+	ttl, ok := c.store.GetTTL(key)
+	if !ok || ttl <= 0 {
+		return time.Time{}, false
+	}
+	return time.Now().Add(ttl), true
+}
+
 func (c *queryCache) Set(key string, rows []map[string]any, ttl time.Duration) {
 	if ttl <= 0 {
 		return
@@ -94,6 +103,15 @@ func cloneRows(rows []map[string]any) []map[string]any {
 	return out
 }
 
+// estimateRowsBytes returns the estimated in-memory size of rows, zero when
+// there are none
+func estimateRowsBytes(rows []map[string]any) int64 {
+	if len(rows) == 0 {
+		return 0
+	}
+	return estimateRowsCost(rows)
+}
+
 func estimateRowsCost(rows []map[string]any) int64 {
 	if len(rows) == 0 {
 		return 1
@@ -0,0 +1,30 @@
+package executor
+
+// ExecutionError is an error returned by the adapter while running a query, as
+// opposed to an error in the request or in the query's configuration. Its
+// message is the database's own and can reveal statements, schema or values.
+type ExecutionError struct {
+	Err error
+}
+
+func (e *ExecutionError) Error() string {
+	return e.Err.Error()
+}
+
+func (e *ExecutionError) Unwrap() error {
+	return e.Err
+}
+
+// InputError is an error in the request, such as an invalid input, found before
+// the query runs. It is the caller's fault, unlike an ExecutionError.
+type InputError struct {
+	Err error
+}
+
+func (e *InputError) Error() string {
+	return e.Err.Error()
+}
+
+func (e *InputError) Unwrap() error {
+	return e.Err
+}
@@ -2,17 +2,24 @@ package executor
 
 import (
 	"context"
+	"fmt"
+	"slices"
 	"time"
 
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/observability"
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
 	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/events"
 	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/runtime/failures"
+	"github.com/hyperterse/hyperterse/core/runtime/fixtures"
 	runtimeutils "github.com/hyperterse/hyperterse/core/runtime/utils"
 	"go.opentelemetry.io/otel"
 	"go.opentelemetry.io/otel/attribute"
 	"go.opentelemetry.io/otel/codes"
+	"golang.org/x/sync/singleflight"
 )
 
 const defaultCacheTTLSeconds = int32(120)
@@ -22,20 +29,85 @@ type Executor struct {
 	connectorManager *connectors.ConnectorManager
 	model            *hyperterse.Model
 	cache            *queryCache
+	inflight         singleflight.Group
+	events           *events.Bus
+	// Time zone of each adapter with a timezone setting
+	timezones map[string]*time.Location
+	// Concurrency scheduler of each adapter with a max_concurrency setting
+	schedulers map[string]*scheduler
+	// Records or replays connector results, nil when neither is enabled
+	fixtures *fixtures.Store
+	// Keeps debug bundles of failed executions, nil when disabled
+	failures *failures.Store
 }
 
 // NewExecutor creates a new query executor
 func NewExecutor(model *hyperterse.Model, manager *connectors.ConnectorManager) *Executor {
+	timezones := make(map[string]*time.Location)
+	schedulers := make(map[string]*scheduler)
+	for _, adapter := range model.Adapters {
+		if adapter.MaxConcurrency > 0 {
+			schedulers[adapter.Name] = newScheduler(int(adapter.MaxConcurrency))
+		}
+		if adapter.Timezone == "" {
+			continue
+		}
+		// The validator has already rejected unknown time zones
+		if loc, err := time.LoadLocation(adapter.Timezone); err == nil {
+			timezones[adapter.Name] = loc
+		}
+	}
+
 	return &Executor{
 		connectorManager: manager,
 		model:            model,
 		cache:            newQueryCache(),
+		timezones:        timezones,
+		schedulers:       schedulers,
 	}
 }
 
+// SetEventBus publishes query started and finished events to bus.
+// Without a bus nothing is published.
+func (e *Executor) SetEventBus(bus *events.Bus) {
+	e.events = bus
+}
+
+// SetFixtures records connector results to store or serves queries from it,
+// depending on its mode. Pass nil to always run queries on their adapters.
+func (e *Executor) SetFixtures(store *fixtures.Store) {
+	e.fixtures = store
+}
+
+// SetFailureCapture saves a debug bundle of each failed execution to store.
+// Pass nil to disable capture.
+func (e *Executor) SetFailureCapture(store *failures.Store) {
+	e.failures = store
+}
+
+// QueryResult holds the rows returned by a query along with execution details
+type QueryResult struct {
+	Rows       []map[string]any
+	Truncated  bool          // Rows were cut off at the configured max_rows
+	CacheHit   bool          // Rows were served from the query cache
+	Coalesced  bool          // Rows were shared from a concurrent identical execution
+	Duration   time.Duration // Time spent in the executor
+	ExpiresAt  time.Time     // When the cached rows expire; zero when the query is not cached
+	NextCursor string        // Cursor of the next page, set when a query with a cursor has more rows
+}
+
 // ExecuteQuery executes a query by name with the provided inputs and context.
 // The context allows for request cancellation and timeout propagation.
 func (e *Executor) ExecuteQuery(ctx context.Context, queryName string, userInputs map[string]any) ([]map[string]any, error) {
+	result, err := e.Execute(ctx, queryName, userInputs)
+	if err != nil {
+		return nil, err
+	}
+	return result.Rows, nil
+}
+
+// Execute executes a query by name like ExecuteQuery, but returns the full QueryResult
+func (e *Executor) Execute(ctx context.Context, queryName string, userInputs map[string]any) (result *QueryResult, err error) {
 	log := logger.New("executor")
 	start := time.Now()
 	tracer := otel.Tracer("runtime/executor")
@@ -58,104 +130,498 @@ func (e *Executor) ExecuteQuery(ctx context.Context, queryName string, userInput
 		return nil, log.Errorf("query '%s' not found", queryName)
 	}
 
-	log.InfofCtx(ctx, map[string]any{
+	// Attach passthrough request headers so traffic can be segmented by caller
+	headers := RequestHeaders(ctx)
+	logAttrs := map[string]any{
 		observability.AttrQueryName: queryName,
-	}, "Executing query: %s", queryName)
+	}
+	for name, value := range headers {
+		key := observability.AttrHTTPRequestHeaderPrefix + name
+		value = observability.RedactAttributeValue(name, value)
+		span.SetAttributes(attribute.String(key, value))
+		logAttrs[key] = value
+	}
+
+	log.InfofCtx(ctx, logAttrs, "Executing query: %s", queryName)
+
+	// Authenticated admin requests may run the query on one of its allowed_adapters
+	override := AdapterOverride(ctx)
+	if override != "" && !slices.Contains(query.AllowedAdapters, override) {
+		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
+		span.SetStatus(codes.Error, "adapter_override_rejected")
+		return nil, log.Errorf("%w", &InputError{Err: fmt.Errorf("adapter '%s' is not in allowed_adapters of query '%s'", override, queryName)})
+	}
+
+	// Every execution of a known query publishes a started and a finished event
+	var eventAdapter string
+	if override != "" {
+		eventAdapter = override
+	} else if len(query.Use) > 0 {
+		eventAdapter = query.Use[0]
+	}
+	invalidInput := false
+	e.events.Publish(events.QueryStarted{Query: queryName, Adapter: eventAdapter, Headers: headers})
+	defer func() {
+		class := connectors.ClassifyError(err)
+		finished := events.QueryFinished{
+			Query:        queryName,
+			Adapter:      eventAdapter,
+			Headers:      headers,
+			Duration:     time.Since(start),
+			Err:          err,
+			InvalidInput: invalidInput,
+			ErrorClass:   string(class),
+			Transient:    class.Retryable(),
+		}
+		if result != nil {
+			finished.Rows = len(result.Rows)
+			finished.CacheHit = result.CacheHit
+		}
+		e.events.Publish(finished)
+	}()
 
 	// Validate inputs
 	log.Debugf("Validating inputs")
+	// A cursor stands in for the key value of the query's cursor input
+	userInputs, err = utils.ApplyCursor(query, userInputs)
+	if err != nil {
+		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
+		span.SetStatus(codes.Error, "input_validation_failed")
+		invalidInput = true
+		return nil, log.Errorf("input validation failed: %w", &InputError{Err: err})
+	}
 	validatedInputs, err := utils.ValidateInputs(query, userInputs)
 	if err != nil {
 		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
 		span.SetStatus(codes.Error, "input_validation_failed")
-		return nil, log.Errorf("input validation failed: %w", err)
+		invalidInput = true
+		return nil, log.Errorf("input validation failed: %w", &InputError{Err: err})
 	}
 	log.Debugf("Input validation successful, %d input(s)", len(validatedInputs))
 
-	// Build input type map for proper formatting
-	inputTypeMap := make(map[string]string)
-	for _, input := range query.Inputs {
-		inputTypeMap[input.Name] = input.Type.String()
-	}
-
-	// Substitute environment variables in statement at runtime (before input substitution)
-	log.Debugf("Substituting environment variables")
-	statementWithEnvVars, err := runtimeutils.SubstituteEnvVars(query.Statement)
-	if err != nil {
+	// Rewrite datetime inputs into the adapter's canonical format and time zone
+	layout, loc := e.datetimeFormat(query)
+	if err := utils.NormalizeDatetimes(query, validatedInputs, layout, loc); err != nil {
 		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
-		span.SetStatus(codes.Error, "env_substitution_failed")
-		return nil, log.Errorf("query '%s': failed to substitute environment variables in statement: %w", queryName, err)
+		span.SetStatus(codes.Error, "input_validation_failed")
+		invalidInput = true
+		return nil, log.Errorf("input validation failed: %w", &InputError{Err: err})
 	}
 
-	// Substitute inputs in statement
-	log.Debugf("Substituting inputs")
-	finalStatement, err := utils.SubstituteInputs(statementWithEnvVars, validatedInputs, inputTypeMap)
+	// Render the statement, or the statement of each source of a federated query
+	var (
+		finalStatement string
+		sources        []sourceStatement
+		status         string
+	)
+	statement := query.Statement
+	if query.Federated != nil {
+		sources, status, err = e.renderFederatedStatements(query, validatedInputs, headers)
+		finalStatement = federatedCacheStatement(sources)
+	} else if statement, err = softDeleteStatement(query); err != nil {
+		status = "soft_delete_filter_failed"
+	} else {
+		finalStatement, status, err = e.renderStatement(query, statement, validatedInputs, headers)
+	}
 	if err != nil {
 		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
-		span.SetStatus(codes.Error, "template_substitution_failed")
-		return nil, log.Errorf("template substitution failed: %w", err)
+		span.SetStatus(codes.Error, status)
+		return nil, log.Errorf("%w", err)
+	}
+	// Input, header and environment values stay out of logs
+	redactedStatement := func() string {
+		if query.Federated != nil {
+			return e.redactFederatedStatements(query, validatedInputs)
+		}
+		return utils.RedactStatement(statement, validatedInputs, e.identifierQuoter(query))
+	}
+	if logger.GetLogLevel() >= logger.LogLevelDebug {
+		log.Debugf("Final statement: %s", redactedStatement())
 	}
-	log.Debugf("Final statement: %s", finalStatement)
+
+	maxRows := e.resolveMaxRows(query)
 
 	cacheEnabled, cacheTTL := e.resolveCachePolicy(query)
+	if cacheEnabled && e.usesProducerConnector(query) {
+		// Serving a cached acknowledgment would silently drop the message
+		log.Debugf("Cache skipped for query '%s': adapter publishes messages", queryName)
+		cacheEnabled = false
+	}
+	if cacheEnabled && override != "" {
+		// Cached results are keyed by statement, not by the adapter that produced them
+		log.Debugf("Cache skipped for query '%s': adapter override", queryName)
+		cacheEnabled = false
+	}
 	if cacheEnabled {
 		cacheKey := buildCacheKey(queryName, finalStatement)
 		if cachedResults, found := e.cache.Get(cacheKey); found {
 			log.Debugf("Cache hit for query: %s", queryName)
 			log.Infof("Query execution completed (cache hit)")
-			return cachedResults, nil
+			rows, truncated := utils.TruncateRows(cachedResults, maxRows)
+			expiresAt, _ := e.cache.ExpiresAt(cacheKey)
+			nextCursor := utils.NextCursor(query, rows, truncated)
+			rows = utils.RenameKeys(rows, e.resultKeyCase())
+			return &QueryResult{Rows: rows, Truncated: truncated, CacheHit: true, Duration: time.Since(start), ExpiresAt: expiresAt, NextCursor: nextCursor}, nil
 		}
 		log.Debugf("Cache miss for query: %s", queryName)
 	}
 
-	// Get the connector(s) for this query
-	if len(query.Use) == 0 {
-		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
-		span.SetStatus(codes.Error, "adapter_missing")
-		return nil, log.Errorf("query '%s' has no adapter specified", queryName)
-	}
+	// Get the connector for this query. Federated queries get the connector
+	// of each source when they run.
+	var (
+		conn              connectors.Connector
+		adapterName       string
+		executedStatement = finalStatement
+	)
+	if query.Federated == nil {
+		if len(query.Use) == 0 {
+			observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
+			span.SetStatus(codes.Error, "adapter_missing")
+			return nil, log.Errorf("query '%s' has no adapter specified", queryName)
+		}
 
-	// Use the first adapter (supporting multiple adapters can be added later)
-	adapterName := query.Use[0]
-	conn, exists := e.connectorManager.Get(adapterName)
-	if !exists {
-		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
-		span.SetStatus(codes.Error, "adapter_not_found")
-		return nil, log.Errorf("adapter '%s' not found", adapterName)
+		// Use the first adapter (supporting multiple adapters can be added later)
+		adapterName = query.Use[0]
+		if override != "" {
+			log.Warnf("Adapter override: running query '%s' on '%s' instead of '%s'", queryName, override, adapterName)
+			span.SetAttributes(attribute.String(observability.AttrAdapterOverride, override))
+			adapterName = override
+		}
+		var exists bool
+		conn, exists = e.connectorManager.Get(adapterName)
+		if !exists && !e.fixtures.Replaying() {
+			observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
+			span.SetStatus(codes.Error, "adapter_not_found")
+			return nil, log.Errorf("adapter '%s' not found", adapterName)
+		}
+
+		// Find the adapter to get connector type
+		var adapter *hyperterse.Adapter
+		for _, a := range e.model.Adapters {
+			if a.Name == adapterName {
+				adapter = a
+				break
+			}
+		}
+
+		if adapter != nil {
+			log.Infof("Using adapter: %s (%s)", adapterName, adapter.Connector.String())
+		} else {
+			log.Infof("Using adapter: %s", adapterName)
+		}
+
+		// Push the row limit down to SQL databases where the statement allows it
+		if maxRows > 0 && adapter != nil && utils.StatementKindOf(adapter.Connector) == utils.StatementSQL {
+			if limited, ok := utils.ApplyRowLimit(finalStatement, maxRows); ok {
+				log.Debugf("Applied row limit of %d to statement", maxRows)
+				executedStatement = limited
+			}
+		}
 	}
 
-	// Find the adapter to get connector type
-	var adapter *hyperterse.Adapter
-	for _, a := range e.model.Adapters {
-		if a.Name == adapterName {
-			adapter = a
-			break
+	// Execute the query with context for cancellation support
+	execute := func(ctx context.Context) ([]map[string]any, error) {
+		// Wait for a slot on adapters that limit concurrent queries
+		if sched := e.schedulers[adapterName]; sched != nil {
+			wait, err := sched.acquire(ctx, queryName, query.Weight)
+			observability.RecordQueueWait(ctx, adapterName, queryName, float64(wait.Milliseconds()))
+			span.SetAttributes(attribute.Int64(observability.AttrQueueWaitMS, wait.Milliseconds()))
+			if err != nil {
+				return nil, fmt.Errorf("gave up waiting for a free slot on adapter '%s' after %s: %w", adapterName, wait.Round(time.Millisecond), err)
+			}
+			defer sched.release()
+		}
+
+		var results []map[string]any
+		var err error
+		switch {
+		case e.fixtures.Replaying():
+			results, err = e.fixtures.Load(queryName, fixtureInputs(userInputs, validatedInputs))
+		case query.Federated != nil:
+			results, err = e.executeFederated(ctx, query, sources)
+		default:
+			executeStart := time.Now()
+			results, err = conn.Execute(ctx, executedStatement, validatedInputs)
+			e.connectorManager.RecordExecution(ctx, adapterName, time.Since(executeStart), len(results), estimateRowsBytes(results), err)
+		}
+		if err != nil {
+			return nil, err
+		}
+		if e.fixtures.Recording() {
+			// A failed write must not fail the query it recorded
+			if err := e.fixtures.Save(queryName, fixtureInputs(userInputs, validatedInputs), results); err != nil {
+				log.Warnf("Failed to record fixture for query '%s': %v", queryName, err)
+			}
+		}
+
+		// Shape missing and null columns before rows are cached
+		utils.ApplyNulls(results, query.Data, query.Nulls)
+
+		// Keep one row beyond the limit so cached results still report truncation
+		if maxRows > 0 {
+			results, _ = utils.TruncateRows(results, maxRows+1)
 		}
+
+		if cacheEnabled {
+			cacheKey := buildCacheKey(queryName, finalStatement)
+			e.cache.Set(cacheKey, results, cacheTTL)
+		}
+		return results, nil
 	}
 
-	if adapter != nil {
-		log.Infof("Using adapter: %s (%s)", adapterName, adapter.Connector.String())
+	var results []map[string]any
+	coalesced := false
+	if query.Coalesce && override == "" && !e.usesProducerConnector(query) {
+		results, coalesced, err = e.executeCoalesced(ctx, buildCacheKey(queryName, finalStatement), execute)
+		span.SetAttributes(attribute.Bool(observability.AttrQueryCoalesced, coalesced))
+		if coalesced {
+			log.Debugf("Shared in-flight execution of query: %s", queryName)
+		}
 	} else {
-		log.Infof("Using adapter: %s", adapterName)
+		results, err = execute(ctx)
+	}
+	if slow := e.slowQueryThreshold(); slow > 0 && !coalesced {
+		if elapsed := time.Since(start); elapsed >= slow {
+			log.Warnf("Slow query '%s' took %s: %s", queryName, elapsed.Round(time.Millisecond), redactedStatement())
+		}
 	}
-
-	// Execute the query with context for cancellation support
-	results, err := conn.Execute(ctx, finalStatement, validatedInputs)
 	if err != nil {
 		observability.RecordQueryExecution(ctx, queryName, false, float64(time.Since(start).Milliseconds()))
 		span.SetStatus(codes.Error, "query_execution_failed")
-		return nil, log.Errorf("query execution failed: %w", err)
+		e.captureFailure(ctx, queryName, adapterName, validatedInputs, redactedStatement(), time.Since(start), err)
+		return nil, log.Errorf("query execution failed: %w", &ExecutionError{Err: err})
 	}
 
-	if cacheEnabled {
-		cacheKey := buildCacheKey(queryName, finalStatement)
-		e.cache.Set(cacheKey, results, cacheTTL)
+	rows, truncated := utils.TruncateRows(results, maxRows)
+	if truncated {
+		log.Warnf("Query '%s' returned more than %d row(s), results truncated", queryName, maxRows)
 	}
+	// The cursor key names a column as returned by the adapter, before renaming
+	nextCursor := utils.NextCursor(query, rows, truncated)
+	rows = utils.RenameKeys(rows, e.resultKeyCase())
 
-	log.Debugf("Query executed successfully, %d result(s)", len(results))
+	log.Debugf("Query executed successfully, %d result(s)", len(rows))
 	log.Infof("Query execution completed")
 	observability.RecordQueryExecution(ctx, queryName, true, float64(time.Since(start).Milliseconds()))
-	return results, nil
+	result = &QueryResult{Rows: rows, Truncated: truncated, Coalesced: coalesced, Duration: time.Since(start), NextCursor: nextCursor}
+	if cacheEnabled {
+		result.ExpiresAt = time.Now().Add(cacheTTL)
+	}
+	return result, nil
+}
+
+// softDeleteStatement returns the query's statement with its soft_delete_filter
+// added to the WHERE clause. The filter is added before inputs are substituted,
+// so input values cannot change where it goes.
+func softDeleteStatement(query *hyperterse.Query) (string, error) {
+	if query.SoftDeleteFilter == "" {
+		return query.Statement, nil
+	}
+	statement, err := utils.ApplySoftDeleteFilter(query.Statement, query.SoftDeleteFilter)
+	if err != nil {
+		return "", fmt.Errorf("query '%s': %w", query.Name, err)
+	}
+	return statement, nil
+}
+
+// renderStatement substitutes environment variables, template functions,
+// identifiers, LIKE patterns, inputs and headers into statement, escaped for
+// the query's adapter. On failure it also returns the span status to report.
+func (e *Executor) renderStatement(query *hyperterse.Query, statement string, inputs map[string]any, headers map[string]string) (string, string, error) {
+	log := logger.New("executor")
+
+	// Build input type map for proper formatting
+	inputTypeMap := make(map[string]string)
+	for _, input := range query.Inputs {
+		inputTypeMap[input.Name] = input.Type.String()
+	}
+
+	// Substitute environment variables in statement at runtime (before input substitution)
+	log.Debugf("Substituting environment variables")
+	statementWithEnvVars, err := runtimeutils.SubstituteEnvVars(statement)
+	if err != nil {
+		return "", "env_substitution_failed", fmt.Errorf("query '%s': failed to substitute environment variables in statement: %w", query.Name, err)
+	}
+
+	// Evaluate template functions before inputs so input values are never evaluated
+	log.Debugf("Evaluating template functions")
+	statementWithFunctions, err := utils.SubstituteFunctions(statementWithEnvVars, inputs, e.functionEscaper(query))
+	if err != nil {
+		return "", "function_evaluation_failed", fmt.Errorf("template function evaluation failed: %w", err)
+	}
+
+	// Quote identifier inputs, whose values the validator limits to their enum
+	statementWithIdents := statementWithFunctions
+	if quote := e.identifierQuoter(query); quote != nil {
+		statementWithIdents, err = utils.SubstituteIdentifiers(statementWithFunctions, inputs, quote)
+		if err != nil {
+			return "", "template_substitution_failed", fmt.Errorf("template substitution failed: %w", err)
+		}
+	}
+
+	// Build quoted LIKE patterns from escaped input values
+	statementWithLike := statementWithIdents
+	if literal := e.likeLiteral(query); literal != nil {
+		statementWithLike, err = utils.SubstituteLike(statementWithIdents, inputs, literal)
+		if err != nil {
+			return "", "template_substitution_failed", fmt.Errorf("template substitution failed: %w", err)
+		}
+	}
+
+	// Substitute inputs in statement
+	log.Debugf("Substituting inputs")
+	finalStatement, err := utils.SubstituteInputs(statementWithLike, utils.EscapeLikeInputs(query, inputs), inputTypeMap)
+	if err != nil {
+		return "", "template_substitution_failed", fmt.Errorf("template substitution failed: %w", err)
+	}
+	return utils.SubstituteHeaders(finalStatement, headers, e.functionEscaper(query)), "", nil
+}
+
+// executeCoalesced runs execute once for all concurrent callers with the same
+// key and reports whether the rows were shared with another caller. The
+// execution is detached from the cancellation of whichever caller started it,
+// so one client disconnecting does not fail the others; each caller still
+// stops waiting when its own context is done.
+func (e *Executor) executeCoalesced(ctx context.Context, key string, execute func(context.Context) ([]map[string]any, error)) ([]map[string]any, bool, error) {
+	ch := e.inflight.DoChan(key, func() (any, error) {
+		return execute(context.WithoutCancel(ctx))
+	})
+	select {
+	case <-ctx.Done():
+		return nil, false, ctx.Err()
+	case res := <-ch:
+		if res.Err != nil {
+			return nil, res.Shared, res.Err
+		}
+		rows := res.Val.([]map[string]any)
+		if res.Shared {
+			// Every caller gets its own copy of the shared rows
+			rows = cloneRows(rows)
+		}
+		return rows, res.Shared, nil
+	}
+}
+
+// resolveMaxRows returns the effective row limit for a query (0 = unlimited)
+func (e *Executor) resolveMaxRows(query *hyperterse.Query) int32 {
+	if query != nil && query.MaxRows > 0 {
+		return query.MaxRows
+	}
+	if e.model != nil && e.model.Server != nil {
+		return e.model.Server.MaxRows
+	}
+	return 0
+}
+
+// slowQueryThreshold returns server.request_log.slow_ms as a duration, zero when unset
+func (e *Executor) slowQueryThreshold() time.Duration {
+	if e.model != nil && e.model.Server != nil && e.model.Server.RequestLog != nil {
+		return time.Duration(e.model.Server.RequestLog.SlowMs) * time.Millisecond
+	}
+	return 0
+}
+
+// resultKeyCase returns the configured case for result keys
+func (e *Executor) resultKeyCase() string {
+	if e.model != nil && e.model.Server != nil {
+		return e.model.Server.ResultKeyCase
+	}
+	return ""
+}
+
+// fixtureInputs returns the validated values of the inputs the caller gave,
+// which identify a recorded execution. Defaults are left out so inputs
+// generated per request, such as auto UUIDs, do not change the fixture.
+func fixtureInputs(userInputs, validatedInputs map[string]any) map[string]any {
+	inputs := make(map[string]any, len(userInputs))
+	for name := range userInputs {
+		if value, ok := validatedInputs[name]; ok {
+			inputs[name] = value
+		}
+	}
+	return inputs
+}
+
+// usesProducerConnector reports whether the query's adapter publishes messages,
+// which must happen on every execution and so is never cached
+func (e *Executor) usesProducerConnector(query *hyperterse.Query) bool {
+	if len(query.Use) == 0 {
+		return false
+	}
+	for _, a := range e.model.Adapters {
+		if a.Name == query.Use[0] {
+			return a.Connector == protoconnectors.Connector_CONNECTOR_KAFKA
+		}
+	}
+	return false
+}
+
+// adapterFor returns the definition of the adapter the query runs on, or nil
+func (e *Executor) adapterFor(query *hyperterse.Query) *hyperterse.Adapter {
+	if len(query.Use) == 0 {
+		return nil
+	}
+	for _, a := range e.model.Adapters {
+		if a.Name == query.Use[0] {
+			return a
+		}
+	}
+	return nil
+}
+
+// functionEscaper returns the escaping applied to template function results and
+// header values for the statement language of the query's adapter
+func (e *Executor) functionEscaper(query *hyperterse.Query) func(string) string {
+	adapter := e.adapterFor(query)
+	if adapter == nil {
+		return nil
+	}
+	return utils.StringEscaper(adapter.Connector)
+}
+
+// identifierQuoter returns the quoting applied to {{ ident.name }} values for
+// the query's adapter, or nil for adapters without SQL identifiers
+func (e *Executor) identifierQuoter(query *hyperterse.Query) func(string) string {
+	adapter := e.adapterFor(query)
+	if adapter == nil {
+		return nil
+	}
+	return utils.IdentifierQuoter(adapter.Connector)
+}
+
+// likeLiteral returns how {{ like.name(...) }} patterns are quoted for the
+// query's adapter, or nil for adapters without LIKE
+func (e *Executor) likeLiteral(query *hyperterse.Query) func(string) string {
+	adapter := e.adapterFor(query)
+	if adapter == nil {
+		return nil
+	}
+	return utils.LikeQuoter(adapter.Connector)
+}
+
+// datetimeFormat returns the layout and time zone datetime inputs are normalized
+// to for the query's adapter. Values are converted to the adapter's time zone so
+// naive timestamp columns receive its wall time.
+func (e *Executor) datetimeFormat(query *hyperterse.Query) (string, *time.Location) {
+	adapter := e.adapterFor(query)
+	if adapter == nil {
+		return time.RFC3339Nano, time.UTC
+	}
+	loc := time.UTC
+	if tz, ok := e.timezones[adapter.Name]; ok {
+		loc = tz
+	}
+	return utils.DatetimeLayout(adapter.Connector), loc
+}
+
+// CacheTTL returns how long results of query are cached, or 0 when they are
+// not cached
+func (e *Executor) CacheTTL(query *hyperterse.Query) time.Duration {
+	enabled, ttl := e.resolveCachePolicy(query)
+	if !enabled || e.usesProducerConnector(query) {
+		return 0
+	}
+	return ttl
 }
 
 func (e *Executor) resolveCachePolicy(query *hyperterse.Query) (bool, time.Duration) {
@@ -0,0 +1,49 @@
+package executor
+
+import (
+	"context"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/runtime/failures"
+	"go.opentelemetry.io/otel/trace"
+)
+
+// captureFailure saves a debug bundle of a failed execution when failure
+// capture is enabled. Input values are hashed, and scrubbed from the error
+// messages; statement must already be redacted. A failed write is logged and never changes the query's error.
+func (e *Executor) captureFailure(ctx context.Context, queryName, adapterName string, inputs map[string]any, statement string, duration time.Duration, err error) {
+	if e.failures == nil {
+		return
+	}
+	hashed := make(map[string]string, len(inputs))
+	for name, value := range inputs {
+		hashed[name] = utils.RedactValue(value)
+	}
+	scrub := func(message string) string {
+		return utils.RedactText(message, inputs)
+	}
+	capture := failures.Capture{
+		Time:       time.Now().UTC(),
+		Query:      queryName,
+		Adapter:    adapterName,
+		DurationMs: float64(duration.Microseconds()) / 1000,
+		Inputs:     hashed,
+		Statement:  statement,
+		ErrorClass: string(connectors.ClassifyError(err)),
+		Errors:     failures.Chain(err, scrub),
+	}
+	if spanContext := trace.SpanContextFromContext(ctx); spanContext.HasTraceID() {
+		capture.TraceID = spanContext.TraceID().String()
+	}
+
+	log := logger.New("executor")
+	id, saveErr := e.failures.Save(capture)
+	if saveErr != nil {
+		log.Warnf("Failed to capture failure of query '%s': %v", queryName, saveErr)
+		return
+	}
+	log.Infof("Captured failure of query '%s' as %s", queryName, id)
+}
@@ -0,0 +1,134 @@
+package executor
+
+import (
+	"context"
+	"fmt"
+	"maps"
+	"strings"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/observability"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"go.opentelemetry.io/otel"
+	"go.opentelemetry.io/otel/attribute"
+	"go.opentelemetry.io/otel/codes"
+	"golang.org/x/sync/errgroup"
+)
+
+// sourceStatement is the rendered statement of one source of a federated query
+type sourceStatement struct {
+	source    *hyperterse.FederatedSource
+	statement string
+	// Inputs normalized for the source's adapter
+	inputs map[string]any
+}
+
+// sourceQuery returns a query that runs a federated query's source on its own
+// adapter, so the source's statement is escaped for that adapter
+func sourceQuery(query *hyperterse.Query, source *hyperterse.FederatedSource) *hyperterse.Query {
+	return &hyperterse.Query{
+		Name:      query.Name,
+		Use:       []string{source.Use},
+		Statement: source.Statement,
+		Inputs:    query.Inputs,
+		Weight:    query.Weight,
+	}
+}
+
+// renderFederatedStatements renders the statement of each source of a federated
+// query. On failure it also returns the span status to report.
+func (e *Executor) renderFederatedStatements(query *hyperterse.Query, inputs map[string]any, headers map[string]string) ([]sourceStatement, string, error) {
+	sources := make([]sourceStatement, 0, len(query.Federated.Sources))
+	for _, source := range query.Federated.Sources {
+		sub := sourceQuery(query, source)
+
+		// Adapters differ in the datetime format they accept
+		sourceInputs := maps.Clone(inputs)
+		layout, loc := e.datetimeFormat(sub)
+		if err := utils.NormalizeDatetimes(sub, sourceInputs, layout, loc); err != nil {
+			return nil, "input_validation_failed", fmt.Errorf("source '%s': input validation failed: %w", source.Name, err)
+		}
+
+		statement, status, err := e.renderStatement(sub, source.Statement, sourceInputs, headers)
+		if err != nil {
+			return nil, status, fmt.Errorf("source '%s': %w", source.Name, err)
+		}
+		sources = append(sources, sourceStatement{source: source, statement: statement, inputs: sourceInputs})
+	}
+	return sources, "", nil
+}
+
+// federatedCacheStatement returns the statement federated query results are
+// cached and coalesced under
+func federatedCacheStatement(sources []sourceStatement) string {
+	statements := make([]string, len(sources))
+	for i, source := range sources {
+		statements[i] = source.statement
+	}
+	return strings.Join(statements, "\n")
+}
+
+// redactFederatedStatements returns the statement of each source with input
+// values left out, for logs
+func (e *Executor) redactFederatedStatements(query *hyperterse.Query, inputs map[string]any) string {
+	statements := make([]string, len(query.Federated.Sources))
+	for i, source := range query.Federated.Sources {
+		redacted := utils.RedactStatement(source.Statement, inputs, e.identifierQuoter(sourceQuery(query, source)))
+		statements[i] = fmt.Sprintf("[%s] %s", source.Name, redacted)
+	}
+	return strings.Join(statements, " ")
+}
+
+// executeFederated runs the sources of a federated query concurrently, each on
+// its own adapter, and merges their rows. The query fails if any source fails.
+func (e *Executor) executeFederated(ctx context.Context, query *hyperterse.Query, sources []sourceStatement) ([]map[string]any, error) {
+	tracer := otel.Tracer("runtime/executor")
+	rows := make([][]map[string]any, len(sources))
+
+	g, ctx := errgroup.WithContext(ctx)
+	for i, source := range sources {
+		g.Go(func() error {
+			ctx, span := tracer.Start(ctx, "executor.execute_federated_source")
+			span.SetAttributes(
+				attribute.String(observability.AttrQueryName, query.Name),
+				attribute.String(observability.AttrQuerySource, source.source.Name),
+				attribute.String(observability.AttrAdapterName, source.source.Use),
+			)
+			defer span.End()
+
+			adapterName := source.source.Use
+			conn, exists := e.connectorManager.Get(adapterName)
+			if !exists {
+				span.SetStatus(codes.Error, "adapter_not_found")
+				return fmt.Errorf("source '%s': adapter '%s' not found", source.source.Name, adapterName)
+			}
+
+			// Wait for a slot on adapters that limit concurrent queries
+			if sched := e.schedulers[adapterName]; sched != nil {
+				wait, err := sched.acquire(ctx, query.Name, query.Weight)
+				observability.RecordQueueWait(ctx, adapterName, query.Name, float64(wait.Milliseconds()))
+				span.SetAttributes(attribute.Int64(observability.AttrQueueWaitMS, wait.Milliseconds()))
+				if err != nil {
+					span.SetStatus(codes.Error, "queue_wait_failed")
+					return fmt.Errorf("source '%s': gave up waiting for a free slot on adapter '%s' after %s: %w", source.source.Name, adapterName, wait.Round(time.Millisecond), err)
+				}
+				defer sched.release()
+			}
+
+			executeStart := time.Now()
+			results, err := conn.Execute(ctx, source.statement, source.inputs)
+			e.connectorManager.RecordExecution(ctx, adapterName, time.Since(executeStart), len(results), estimateRowsBytes(results), err)
+			if err != nil {
+				span.SetStatus(codes.Error, "query_execution_failed")
+				return fmt.Errorf("source '%s': %w", source.source.Name, err)
+			}
+			rows[i] = results
+			return nil
+		})
+	}
+	if err := g.Wait(); err != nil {
+		return nil, err
+	}
+	return utils.MergeFederatedRows(query.Federated, rows), nil
+}
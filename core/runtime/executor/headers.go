@@ -0,0 +1,38 @@
+package executor
+
+import (
+	"context"
+	"net/http"
+	"strings"
+)
+
+type requestHeadersKey struct{}
+
+// WithRequestHeaders returns a context carrying the values of the configured
+// passthrough headers. Names are stored in lowercase.
+func WithRequestHeaders(ctx context.Context, headers map[string]string) context.Context {
+	return context.WithValue(ctx, requestHeadersKey{}, headers)
+}
+
+// RequestHeaders returns the passthrough header values carried by ctx, or nil
+func RequestHeaders(ctx context.Context) map[string]string {
+	headers, _ := ctx.Value(requestHeadersKey{}).(map[string]string)
+	return headers
+}
+
+// CaptureHeaders collects the named headers present on header, keyed by their
+// lowercase name. It returns nil when none of them are set.
+func CaptureHeaders(header http.Header, names []string) map[string]string {
+	var captured map[string]string
+	for _, name := range names {
+		value := header.Get(name)
+		if value == "" {
+			continue
+		}
+		if captured == nil {
+			captured = make(map[string]string, len(names))
+		}
+		captured[strings.ToLower(name)] = value
+	}
+	return captured
+}
@@ -0,0 +1,18 @@
+package executor
+
+import "context"
+
+type adapterOverrideKey struct{}
+
+// WithAdapterOverride returns a context that runs queries on adapter instead
+// of their own. Callers must have authenticated the request; queries still
+// reject adapters missing from their allowed_adapters.
+func WithAdapterOverride(ctx context.Context, adapter string) context.Context {
+	return context.WithValue(ctx, adapterOverrideKey{}, adapter)
+}
+
+// AdapterOverride returns the adapter override carried by ctx, or ""
+func AdapterOverride(ctx context.Context) string {
+	adapter, _ := ctx.Value(adapterOverrideKey{}).(string)
+	return adapter
+}
@@ -0,0 +1,138 @@
+package executor
+
+import (
+	"context"
+	"sync"
+	"time"
+)
+
+// starvationAge is how long a request may wait before it is admitted ahead of
+// the weighted order, so a low-weight query is never starved by busy ones
+const starvationAge = 5 * time.Second
+
+// scheduler limits how many queries run on an adapter at once. When every
+// slot is taken, waiting requests are admitted in weighted fair order: each
+// query receives slots in proportion to its weight, so one busy query cannot
+// take all of the adapter's connections.
+type scheduler struct {
+	mu      sync.Mutex
+	slots   int
+	active  int
+	waiting int
+	classes map[string]*queryClass
+	// vtime is the pass of the last admitted class. Classes that were idle
+	// start from it rather than from their old pass, so idling earns no credit.
+	vtime float64
+}
+
+// queryClass holds the waiting requests of one query. pass advances by
+// 1/weight per admission, and the waiting class with the lowest pass goes next.
+type queryClass struct {
+	weight  int32
+	pass    float64
+	waiters []*schedulerWaiter
+}
+
+type schedulerWaiter struct {
+	ready    chan struct{}
+	enqueued time.Time
+	admitted bool
+}
+
+func newScheduler(slots int) *scheduler {
+	return &scheduler{
+		slots:   slots,
+		classes: make(map[string]*queryClass),
+	}
+}
+
+// acquire waits for a slot for query and returns how long it waited. The slot
+// must be given back with release. If ctx is done first, its error is returned
+// and no slot is held.
+func (s *scheduler) acquire(ctx context.Context, query string, weight int32) (time.Duration, error) {
+	s.mu.Lock()
+	if s.active < s.slots && s.waiting == 0 {
+		s.active++
+		s.mu.Unlock()
+		return 0, nil
+	}
+
+	class, ok := s.classes[query]
+	if !ok {
+		class = &queryClass{}
+		s.classes[query] = class
+	}
+	class.weight = max(weight, 1)
+	if len(class.waiters) == 0 {
+		class.pass = max(class.pass, s.vtime)
+	}
+	waiter := &schedulerWaiter{ready: make(chan struct{}), enqueued: time.Now()}
+	class.waiters = append(class.waiters, waiter)
+	s.waiting++
+	s.mu.Unlock()
+
+	select {
+	case <-waiter.ready:
+		return time.Since(waiter.enqueued), nil
+	case <-ctx.Done():
+		s.mu.Lock()
+		if waiter.admitted {
+			// Admitted while giving up; hand the slot to the next waiter
+			s.active--
+			s.dispatch()
+		} else {
+			class.waiters = removeWaiter(class.waiters, waiter)
+			s.waiting--
+		}
+		s.mu.Unlock()
+		return time.Since(waiter.enqueued), ctx.Err()
+	}
+}
+
+// release gives back a slot taken by acquire
+func (s *scheduler) release() {
+	s.mu.Lock()
+	s.active--
+	s.dispatch()
+	s.mu.Unlock()
+}
+
+// dispatch admits waiters while slots are free. The caller holds s.mu.
+func (s *scheduler) dispatch() {
+	now := time.Now()
+	for s.active < s.slots && s.waiting > 0 {
+		var next, oldest *queryClass
+		for _, class := range s.classes {
+			if len(class.waiters) == 0 {
+				continue
+			}
+			if next == nil || class.pass < next.pass {
+				next = class
+			}
+			if oldest == nil || class.waiters[0].enqueued.Before(oldest.waiters[0].enqueued) {
+				oldest = class
+			}
+		}
+		if now.Sub(oldest.waiters[0].enqueued) >= starvationAge {
+			next = oldest
+		}
+
+		waiter := next.waiters[0]
+		next.waiters = next.waiters[1:]
+		s.vtime = next.pass
+		next.pass += 1 / float64(next.weight)
+		s.waiting--
+		s.active++
+		waiter.admitted = true
+		close(waiter.ready)
+	}
+}
+
+func removeWaiter(waiters []*schedulerWaiter, waiter *schedulerWaiter) []*schedulerWaiter {
+	for i, w := range waiters {
+		if w == waiter {
+			return append(waiters[:i], waiters[i+1:]...)
+		}
+	}
+	return waiters
+}
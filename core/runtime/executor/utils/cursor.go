@@ -0,0 +1,100 @@
+package utils
+
+import (
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+// CursorParam is the request field, and GET query string parameter, that
+// carries the next_cursor of a previous response to queries with a cursor
+const CursorParam = "cursor"
+
+// cursorPayload is the content of a cursor before it is encoded. The query
+// name keeps a cursor from being replayed against another query.
+type cursorPayload struct {
+	Query string `json:"q"`
+	Key   string `json:"k"`
+}
+
+// EncodeCursor returns the opaque cursor of the page of queryName that starts
+// after a row whose key column holds value
+func EncodeCursor(queryName string, value any) string {
+	key := valueToString(value)
+	if t, ok := value.(time.Time); ok {
+		key = t.Format(time.RFC3339Nano)
+	}
+	payload, _ := json.Marshal(cursorPayload{Query: queryName, Key: key})
+	return base64.RawURLEncoding.EncodeToString(payload)
+}
+
+// DecodeCursor returns the key value encoded in cursor, which must have been
+// issued by queryName
+func DecodeCursor(queryName, cursor string) (string, error) {
+	raw, err := base64.RawURLEncoding.DecodeString(cursor)
+	if err != nil {
+		return "", fmt.Errorf("cursor is not valid")
+	}
+	var payload cursorPayload
+	if err := json.Unmarshal(raw, &payload); err != nil {
+		return "", fmt.Errorf("cursor is not valid")
+	}
+	if payload.Query != queryName {
+		return "", fmt.Errorf("cursor was issued by another query")
+	}
+	return payload.Key, nil
+}
+
+// ApplyCursor returns inputs with the cursor given in CursorParam replaced by
+// its key value under the query's cursor input, which ValidateInputs then
+// checks like any other value. Inputs are returned unchanged for queries
+// without a cursor or requests without one.
+func ApplyCursor(query *hyperterse.Query, inputs map[string]any) (map[string]any, error) {
+	raw, ok := inputs[CursorParam]
+	if query.Cursor == nil || !ok {
+		return inputs, nil
+	}
+	cursor, ok := raw.(string)
+	if !ok {
+		return nil, &ValidationError{Field: CursorParam, Message: "cursor must be a string"}
+	}
+	if _, exists := inputs[query.Cursor.Input]; exists {
+		return nil, &ValidationError{
+			Field:   CursorParam,
+			Message: fmt.Sprintf("cursor cannot be combined with input '%s'", query.Cursor.Input),
+		}
+	}
+
+	applied := make(map[string]any, len(inputs))
+	for name, value := range inputs {
+		if name != CursorParam {
+			applied[name] = value
+		}
+	}
+	if cursor == "" {
+		// An empty cursor asks for the first page
+		return applied, nil
+	}
+	key, err := DecodeCursor(query.Name, cursor)
+	if err != nil {
+		return nil, &ValidationError{Field: CursorParam, Message: err.Error()}
+	}
+	applied[query.Cursor.Input] = key
+	return applied, nil
+}
+
+// NextCursor returns the cursor of the page after rows, or "" when rows is the
+// last page. More rows exist only when the results were truncated at max_rows.
+func NextCursor(query *hyperterse.Query, rows []map[string]any, truncated bool) string {
+	if query.Cursor == nil || !truncated || len(rows) == 0 {
+		return ""
+	}
+	value, ok := rows[len(rows)-1][query.Cursor.Key]
+	if !ok || value == nil {
+		return ""
+	}
+	return EncodeCursor(query.Name, value)
+}
@@ -0,0 +1,64 @@
+package utils
+
+import (
+	"testing"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+func TestCursorRoundTrip(t *testing.T) {
+	query := &hyperterse.Query{
+		Name:   "list-orders",
+		Cursor: &hyperterse.CursorConfig{Key: "id", Input: "after"},
+	}
+	rows := []map[string]any{{"id": int64(41)}, {"id": int64(42)}}
+
+	if got := NextCursor(query, rows, false); got != "" {
+		t.Errorf("NextCursor() on the last page = %q, want none", got)
+	}
+	cursor := NextCursor(query, rows, true)
+	if cursor == "" {
+		t.Fatal("NextCursor() = \"\", want a cursor")
+	}
+
+	inputs, err := ApplyCursor(query, map[string]any{CursorParam: cursor, "status": "open"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if inputs["after"] != "42" || inputs["status"] != "open" {
+		t.Errorf("ApplyCursor() = %v, want after 42 and status open", inputs)
+	}
+	if _, ok := inputs[CursorParam]; ok {
+		t.Error("ApplyCursor() kept the cursor field")
+	}
+}
+
+func TestApplyCursorErrors(t *testing.T) {
+	query := &hyperterse.Query{
+		Name:   "list-orders",
+		Cursor: &hyperterse.CursorConfig{Key: "id", Input: "after"},
+	}
+	tests := []map[string]any{
+		{CursorParam: "not a cursor"},
+		{CursorParam: EncodeCursor("list-users", 1)},
+		{CursorParam: EncodeCursor("list-orders", 1), "after": 5},
+		{CursorParam: 12},
+	}
+	for _, inputs := range tests {
+		if _, err := ApplyCursor(query, inputs); err == nil {
+			t.Errorf("ApplyCursor(%v) succeeded, want an error", inputs)
+		}
+	}
+}
+
+func TestEncodeCursorTime(t *testing.T) {
+	at := time.Date(2026, 1, 2, 3, 4, 5, 6000, time.UTC)
+	key, err := DecodeCursor("q", EncodeCursor("q", at))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if key != "2026-01-02T03:04:05.000006Z" {
+		t.Errorf("DecodeCursor() = %q, want RFC 3339", key)
+	}
+}
@@ -0,0 +1,60 @@
+package utils
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// datetimeLayouts are the accepted datetime input formats, tried in order.
+// Fractional seconds are accepted after the seconds field in every layout.
+var datetimeLayouts = []string{
+	time.RFC3339,
+	"2006-01-02 15:04:05Z07:00",
+	"2006-01-02T15:04:05",
+	"2006-01-02 15:04:05",
+	dateLayout,
+}
+
+const dateLayout = "2006-01-02"
+
+// acceptedDatetimeFormats describes datetimeLayouts in validation errors
+const acceptedDatetimeFormats = "2024-01-02T10:00:00Z, 2024-01-02T10:00:00+02:00, 2024-01-02 10:00:00 (interpreted in the adapter's timezone) or 2024-01-02"
+
+// parseDatetime parses value in one of the accepted formats. Values without an
+// offset are interpreted in loc. The bool reports a date without a time.
+func parseDatetime(value string, loc *time.Location) (time.Time, bool, error) {
+	for _, layout := range datetimeLayouts {
+		if t, err := time.ParseInLocation(layout, value, loc); err == nil {
+			return t, layout == dateLayout, nil
+		}
+	}
+	return time.Time{}, false, fmt.Errorf("cannot parse '%s' as datetime; accepted formats: %s", value, acceptedDatetimeFormats)
+}
+
+// NormalizeDatetimes rewrites the query's datetime inputs to layout in loc, so
+// that values sent with different offsets reach the database in one canonical
+// form. Date-only values stay dates.
+func NormalizeDatetimes(query *hyperterse.Query, inputs map[string]any, layout string, loc *time.Location) error {
+	for _, input := range query.Inputs {
+		if types.PrimitiveEnumToString(input.Type) != "datetime" {
+			continue
+		}
+		value, ok := inputs[input.Name].(string)
+		if !ok {
+			continue
+		}
+		t, dateOnly, err := parseDatetime(value, loc)
+		if err != nil {
+			return &ValidationError{Field: input.Name, Message: err.Error()}
+		}
+		if dateOnly {
+			inputs[input.Name] = t.Format(dateLayout)
+			continue
+		}
+		inputs[input.Name] = t.In(loc).Format(layout)
+	}
+	return nil
+}
@@ -0,0 +1,29 @@
+package utils
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	runtimeutils "github.com/hyperterse/hyperterse/core/runtime/utils"
+)
+
+// QueryEnabled reports whether a query is served, resolving the
+// {{ env.VARIABLE_NAME }} placeholders of its enabled field. Queries without
+// the field are enabled. Values that do not resolve to true or false return an
+// error, and callers should treat the query as disabled.
+func QueryEnabled(query *hyperterse.Query) (bool, error) {
+	if query.Enabled == "" {
+		return true, nil
+	}
+	value, err := runtimeutils.SubstituteEnvVars(query.Enabled)
+	if err != nil {
+		return false, err
+	}
+	enabled, err := strconv.ParseBool(strings.TrimSpace(value))
+	if err != nil {
+		return false, fmt.Errorf("enabled must be true or false, got '%s'", value)
+	}
+	return enabled, nil
+}
@@ -0,0 +1,105 @@
+package utils
+
+import (
+	"fmt"
+	"maps"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+// Merge modes accepted by a federated query's merge option
+const (
+	FederatedMergeJoin   = "join"
+	FederatedMergeConcat = "concat"
+)
+
+// FederatedMerges lists every federated merge mode
+var FederatedMerges = []string{FederatedMergeJoin, FederatedMergeConcat}
+
+// Join types accepted by a federated query's join.type option
+const (
+	FederatedJoinLeft  = "left"
+	FederatedJoinInner = "inner"
+)
+
+// FederatedJoinTypes lists every federated join type
+var FederatedJoinTypes = []string{FederatedJoinLeft, FederatedJoinInner}
+
+// MergeFederatedRows combines the rows returned by each source of a federated
+// query, given in source order. "concat" appends them; "join" hash joins every
+// other source onto the first on their join keys.
+func MergeFederatedRows(federated *hyperterse.FederatedConfig, rows [][]map[string]any) []map[string]any {
+	if federated.Merge == FederatedMergeConcat {
+		total := 0
+		for _, sourceRows := range rows {
+			total += len(sourceRows)
+		}
+		merged := make([]map[string]any, 0, total)
+		for _, sourceRows := range rows {
+			merged = append(merged, sourceRows...)
+		}
+		return merged
+	}
+	return hashJoinRows(federated, rows)
+}
+
+// hashJoinRows joins each source after the first onto the first, one source at
+// a time. A base row matching several rows of a source yields one row per
+// match, and the base row's own columns win when both have a column.
+func hashJoinRows(federated *hyperterse.FederatedConfig, rows [][]map[string]any) []map[string]any {
+	if len(rows) == 0 {
+		return nil
+	}
+	keys := federated.GetJoin().GetKeys()
+	inner := federated.GetJoin().GetType() == FederatedJoinInner
+	baseKey := keys[federated.Sources[0].Name]
+
+	joined := rows[0]
+	for i := 1; i < len(rows); i++ {
+		sourceKey := keys[federated.Sources[i].Name]
+		index := make(map[string][]map[string]any, len(rows[i]))
+		for _, row := range rows[i] {
+			if key, ok := joinKey(row[sourceKey]); ok {
+				index[key] = append(index[key], row)
+			}
+		}
+
+		next := make([]map[string]any, 0, len(joined))
+		for _, row := range joined {
+			var matches []map[string]any
+			if key, ok := joinKey(row[baseKey]); ok {
+				matches = index[key]
+			}
+			if len(matches) == 0 {
+				if !inner {
+					next = append(next, row)
+				}
+				continue
+			}
+			for _, match := range matches {
+				merged := make(map[string]any, len(row)+len(match))
+				maps.Copy(merged, match)
+				maps.Copy(merged, row)
+				next = append(next, merged)
+			}
+		}
+		joined = next
+	}
+	return joined
+}
+
+// joinKey returns the form join values are compared in. Values are compared as
+// strings, so an integer ID from a SQL database matches the same ID read from
+// Redis as a string. Null and missing values never match.
+func joinKey(value any) (string, bool) {
+	switch v := value.(type) {
+	case nil:
+		return "", false
+	case string:
+		return v, true
+	case []byte:
+		return string(v), true
+	default:
+		return fmt.Sprint(v), true
+	}
+}
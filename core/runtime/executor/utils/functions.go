@@ -0,0 +1,164 @@
+package utils
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"regexp"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+var (
+	// Function placeholder: {{ fn.<anything> }}, parsed by ParseFunctionCall
+	functionPattern = regexp.MustCompile(`\{\{\s*fn\.([^}]*)\}\}`)
+	// Function call: name(arg, ...)
+	functionCallPattern = regexp.MustCompile(`^([a-zA-Z_][a-zA-Z0-9_]*)\s*\((.*)\)$`)
+	// Function argument: inputs.fieldName
+	functionArgPattern = regexp.MustCompile(`^inputs\.(\w+)$`)
+)
+
+// templateFunction is a built-in function callable from statements
+type templateFunction struct {
+	arity int
+	eval  func(args []string) string
+}
+
+// nowLayout is ISO 8601 with microseconds and an explicit offset, accepted by
+// every supported database
+const nowLayout = "2006-01-02T15:04:05.000000-07:00"
+
+var templateFunctions = map[string]templateFunction{
+	"now": {arity: 0, eval: func([]string) string {
+		return time.Now().UTC().Format(nowLayout)
+	}},
+	"uuid": {arity: 0, eval: func([]string) string {
+		return uuid.NewString()
+	}},
+	"sha256": {arity: 1, eval: func(args []string) string {
+		sum := sha256.Sum256([]byte(args[0]))
+		return hex.EncodeToString(sum[:])
+	}},
+}
+
+// FunctionNames returns the names of the built-in template functions, sorted
+func FunctionNames() []string {
+	names := make([]string, 0, len(templateFunctions))
+	for name := range templateFunctions {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// FunctionCall is a parsed {{ fn.name(args) }} placeholder
+type FunctionCall struct {
+	Placeholder string
+	Name        string
+	// Inputs holds the input name passed as each argument
+	Inputs []string
+}
+
+// ParseFunctionCalls returns every function placeholder in statement, or an
+// error naming the first unknown function, wrong argument count or malformed call
+func ParseFunctionCalls(statement string) ([]FunctionCall, error) {
+	var calls []FunctionCall
+	for _, match := range functionPattern.FindAllStringSubmatch(statement, -1) {
+		call, err := parseFunctionCall(match[0], strings.TrimSpace(match[1]))
+		if err != nil {
+			return nil, err
+		}
+		calls = append(calls, call)
+	}
+	return calls, nil
+}
+
+func parseFunctionCall(placeholder, expr string) (FunctionCall, error) {
+	parts := functionCallPattern.FindStringSubmatch(expr)
+	if parts == nil {
+		return FunctionCall{}, fmt.Errorf("'%s' is not a valid function call; expected {{ fn.name(arguments) }}", placeholder)
+	}
+	call := FunctionCall{Placeholder: placeholder, Name: parts[1]}
+
+	fn, ok := templateFunctions[call.Name]
+	if !ok {
+		return FunctionCall{}, fmt.Errorf("unknown function 'fn.%s'; must be one of: %s", call.Name, strings.Join(FunctionNames(), ", "))
+	}
+
+	if args := strings.TrimSpace(parts[2]); args != "" {
+		for _, arg := range strings.Split(args, ",") {
+			arg = strings.TrimSpace(arg)
+			ref := functionArgPattern.FindStringSubmatch(arg)
+			if ref == nil {
+				return FunctionCall{}, fmt.Errorf("fn.%s: argument '%s' must be an input reference like inputs.name", call.Name, arg)
+			}
+			call.Inputs = append(call.Inputs, ref[1])
+		}
+	}
+	if len(call.Inputs) != fn.arity {
+		return FunctionCall{}, fmt.Errorf("fn.%s takes %d argument(s), got %d", call.Name, fn.arity, len(call.Inputs))
+	}
+	return call, nil
+}
+
+// SubstituteFunctions evaluates {{ fn.name(args) }} placeholders and replaces
+// them with their results passed through escape. Each distinct placeholder is
+// evaluated once per statement, so repeated calls share a value. Functions are
+// substituted before inputs so input values are never evaluated as templates.
+func SubstituteFunctions(statement string, inputs map[string]any, escape func(string) string) (string, error) {
+	log := logger.New("executor")
+
+	calls, err := ParseFunctionCalls(statement)
+	if err != nil {
+		return "", err
+	}
+	log.Debugf("Found %d function call(s) to evaluate", len(calls))
+
+	result := statement
+	seen := make(map[string]bool)
+	for _, call := range calls {
+		if seen[call.Placeholder] {
+			continue
+		}
+		seen[call.Placeholder] = true
+
+		args := make([]string, len(call.Inputs))
+		for i, name := range call.Inputs {
+			value, exists := inputs[name]
+			if !exists {
+				return "", fmt.Errorf("input '%s' not found for fn.%s", name, call.Name)
+			}
+			args[i] = valueToString(value)
+		}
+
+		log.Debugf("Evaluating 'fn.%s'", call.Name)
+		value := templateFunctions[call.Name].eval(args)
+		if escape != nil {
+			value = escape(value)
+		}
+		result = strings.ReplaceAll(result, call.Placeholder, value)
+	}
+	return result, nil
+}
+
+// EscapeSQLString escapes a value for use inside a single-quoted SQL string
+func EscapeSQLString(value string) string {
+	return strings.ReplaceAll(value, "'", "''")
+}
+
+// EscapeMySQLString escapes a value for use inside a quoted MySQL string,
+// where backslashes are escape characters by default
+func EscapeMySQLString(value string) string {
+	return EscapeSQLString(strings.ReplaceAll(value, `\`, `\\`))
+}
+
+// EscapeJSONString escapes a value for use inside a double-quoted JSON string
+func EscapeJSONString(value string) string {
+	encoded, _ := json.Marshal(value)
+	return string(encoded[1 : len(encoded)-1])
+}
@@ -0,0 +1,33 @@
+package utils
+
+import (
+	"regexp"
+	"strings"
+)
+
+// headerPattern matches {{ headers.name }} references to passthrough headers
+var headerPattern = regexp.MustCompile(`\{\{\s*headers\.([a-zA-Z0-9-]+)\s*\}\}`)
+
+// HeaderReferences returns the header names referenced by statement, lowercased
+func HeaderReferences(statement string) []string {
+	var names []string
+	for _, match := range headerPattern.FindAllStringSubmatch(statement, -1) {
+		names = append(names, strings.ToLower(match[1]))
+	}
+	return names
+}
+
+// SubstituteHeaders replaces {{ headers.name }} placeholders with the request's
+// header values passed through escape. Headers the request did not send are
+// replaced with an empty string. Headers are substituted after inputs so their
+// values are never evaluated as templates.
+func SubstituteHeaders(statement string, headers map[string]string, escape func(string) string) string {
+	return headerPattern.ReplaceAllStringFunc(statement, func(placeholder string) string {
+		name := strings.ToLower(headerPattern.FindStringSubmatch(placeholder)[1])
+		value := headers[name]
+		if escape != nil {
+			value = escape(value)
+		}
+		return value
+	})
+}
@@ -0,0 +1,66 @@
+package utils
+
+import (
+	"fmt"
+	"regexp"
+	"slices"
+	"strings"
+)
+
+// identPattern matches {{ ident.name }} references to inputs substituted as identifiers
+var identPattern = regexp.MustCompile(`\{\{\s*ident\.(\w+)\s*\}\}`)
+
+// IdentifierPattern is the form the enum values of identifier inputs take: a
+// column or table name, optionally qualified as table.column
+var IdentifierPattern = regexp.MustCompile(`^[A-Za-z_][A-Za-z0-9_]*(\.[A-Za-z_][A-Za-z0-9_]*)?$`)
+
+// IdentReferences returns the unique input names referenced by {{ ident.name }} in statement
+func IdentReferences(statement string) []string {
+	var names []string
+	for _, match := range identPattern.FindAllStringSubmatch(statement, -1) {
+		if !slices.Contains(names, match[1]) {
+			names = append(names, match[1])
+		}
+	}
+	return names
+}
+
+// SubstituteIdentifiers replaces {{ ident.name }} placeholders with the value of
+// the input, quoted as an identifier by quote. The validator restricts these
+// inputs to the identifiers listed in their enum, and ValidateInputs rejects any
+// other value, so a caller can choose a column but never inject SQL.
+func SubstituteIdentifiers(statement string, inputs map[string]any, quote func(string) string) (string, error) {
+	var missing error
+	result := identPattern.ReplaceAllStringFunc(statement, func(placeholder string) string {
+		name := identPattern.FindStringSubmatch(placeholder)[1]
+		value, ok := inputs[name]
+		if !ok {
+			missing = fmt.Errorf("input '%s' not found for substitution", name)
+			return placeholder
+		}
+		return quote(valueToString(value))
+	})
+	if missing != nil {
+		return "", missing
+	}
+	return result, nil
+}
+
+// QuoteSQLIdentifier quotes an identifier with double quotes, as PostgreSQL and
+// DuckDB expect. Each part of a qualified table.column name is quoted separately.
+func QuoteSQLIdentifier(name string) string {
+	return quoteIdentifierParts(name, `"`)
+}
+
+// QuoteMySQLIdentifier quotes an identifier with backticks
+func QuoteMySQLIdentifier(name string) string {
+	return quoteIdentifierParts(name, "`")
+}
+
+func quoteIdentifierParts(name, quote string) string {
+	parts := strings.Split(name, ".")
+	for i, part := range parts {
+		parts[i] = quote + strings.ReplaceAll(part, quote, quote+quote) + quote
+	}
+	return strings.Join(parts, ".")
+}
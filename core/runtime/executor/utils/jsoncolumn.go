@@ -0,0 +1,38 @@
+package utils
+
+import (
+	"bytes"
+	"database/sql"
+	"encoding/json"
+	"strings"
+)
+
+// JSONColumns reports which columns of a SQL result hold JSON, by their
+// database type name (JSON on MySQL, JSON and JSONB on PostgreSQL). It returns
+// nil when the driver does not report column types.
+func JSONColumns(types []*sql.ColumnType) []bool {
+	var columns []bool
+	for i, t := range types {
+		switch strings.ToUpper(t.DatabaseTypeName()) {
+		case "JSON", "JSONB":
+			if columns == nil {
+				columns = make([]bool, len(types))
+			}
+			columns[i] = true
+		}
+	}
+	return columns
+}
+
+// DecodeJSONColumn decodes the value of a SQL JSON column, so it is returned as
+// JSON rather than as a string and result_key_case renames its keys. Numbers
+// keep their precision. Values that are not valid JSON are returned as text.
+func DecodeJSONColumn(b []byte) any {
+	decoder := json.NewDecoder(bytes.NewReader(b))
+	decoder.UseNumber()
+	var value any
+	if err := decoder.Decode(&value); err != nil || decoder.More() {
+		return string(b)
+	}
+	return value
+}
@@ -0,0 +1,145 @@
+package utils
+
+import (
+	"strings"
+	"unicode"
+)
+
+// Result key cases accepted by server.result_key_case
+const (
+	KeyCaseOriginal = "original"
+	KeyCaseCamel    = "camel"
+	KeyCaseSnake    = "snake"
+)
+
+// KeyCases lists every result key case
+var KeyCases = []string{KeyCaseOriginal, KeyCaseCamel, KeyCaseSnake}
+
+// RenameKeys returns rows with their keys, and the keys of nested objects,
+// converted to keyCase. Rows are copied so cached results are never modified.
+// SQL JSON columns reach it decoded (see DecodeJSONColumn), so their keys are
+// renamed too.
+func RenameKeys(rows []map[string]any, keyCase string) []map[string]any {
+	if keyCase != KeyCaseCamel && keyCase != KeyCaseSnake {
+		return rows
+	}
+
+	// Rows share their column names, so each name is converted once
+	names := make(map[string]string)
+	renamed := make([]map[string]any, len(rows))
+	for i, row := range rows {
+		renamed[i] = renameMap(row, keyCase, names)
+	}
+	return renamed
+}
+
+// renameMap converts the keys of m. When several keys convert to the same name,
+// as user_id and userId do in camel case, the one already in that case takes
+// the name and the others keep their original key, so no value is lost.
+func renameMap(m map[string]any, keyCase string, names map[string]string) map[string]any {
+	if m == nil {
+		return nil
+	}
+	targets := make(map[string]int, len(m))
+	for key := range m {
+		name, ok := names[key]
+		if !ok {
+			name = ConvertKey(key, keyCase)
+			names[key] = name
+		}
+		targets[name]++
+	}
+	out := make(map[string]any, len(m))
+	for key, value := range m {
+		name := names[key]
+		if targets[name] > 1 && name != key {
+			name = key
+		}
+		out[name] = renameValue(value, keyCase, names)
+	}
+	return out
+}
+
+func renameValue(value any, keyCase string, names map[string]string) any {
+	switch v := value.(type) {
+	case map[string]any:
+		return renameMap(v, keyCase, names)
+	case []any:
+		arr := make([]any, len(v))
+		for i, item := range v {
+			arr[i] = renameValue(item, keyCase, names)
+		}
+		return arr
+	default:
+		return value
+	}
+}
+
+// ConvertKey converts a single key to keyCase. Leading underscores, as in
+// MongoDB's _id, are kept.
+func ConvertKey(key, keyCase string) string {
+	trimmed := strings.TrimLeft(key, "_")
+	prefix := key[:len(key)-len(trimmed)]
+
+	words := splitWords(trimmed)
+	if len(words) == 0 {
+		return key
+	}
+
+	var sb strings.Builder
+	sb.WriteString(prefix)
+	switch keyCase {
+	case KeyCaseCamel:
+		for i, word := range words {
+			runes := []rune(strings.ToLower(word))
+			if i > 0 {
+				runes[0] = unicode.ToUpper(runes[0])
+			}
+			sb.WriteString(string(runes))
+		}
+	case KeyCaseSnake:
+		for i, word := range words {
+			if i > 0 {
+				sb.WriteByte('_')
+			}
+			sb.WriteString(strings.ToLower(word))
+		}
+	default:
+		return key
+	}
+	return sb.String()
+}
+
+// splitWords splits a key on underscores, hyphens, spaces and case changes, so
+// "user_id", "userId" and "UserID" all yield "user" and "id"
+func splitWords(key string) []string {
+	var words []string
+	runes := []rune(key)
+	start := -1
+	for i, r := range runes {
+		if r == '_' || r == '-' || r == ' ' {
+			if start >= 0 {
+				words = append(words, string(runes[start:i]))
+				start = -1
+			}
+			continue
+		}
+		if start < 0 {
+			start = i
+			continue
+		}
+		// A new word starts at an upper-case letter after a lower-case letter or
+		// digit, or at the last upper-case letter of an acronym ("HTTPStatus")
+		prev := runes[i-1]
+		boundary := unicode.IsUpper(r) && (unicode.IsLower(prev) || unicode.IsDigit(prev) ||
+			(unicode.IsUpper(prev) && i+1 < len(runes) && unicode.IsLower(runes[i+1])))
+		if boundary {
+			words = append(words, string(runes[start:i]))
+			start = i
+		}
+	}
+	if start >= 0 {
+		words = append(words, string(runes[start:]))
+	}
+	return words
+}
@@ -0,0 +1,94 @@
+package utils
+
+import (
+	"encoding/json"
+	"reflect"
+	"testing"
+)
+
+func TestConvertKey(t *testing.T) {
+	tests := []struct {
+		key, keyCase, want string
+	}{
+		{"user_id", KeyCaseCamel, "userId"},
+		{"createdAt", KeyCaseCamel, "createdAt"},
+		{"UserID", KeyCaseCamel, "userId"},
+		{"HTTPStatus", KeyCaseSnake, "http_status"},
+		{"createdAt", KeyCaseSnake, "created_at"},
+		{"user_id", KeyCaseSnake, "user_id"},
+		{"_id", KeyCaseCamel, "_id"},
+		{"_created_at", KeyCaseCamel, "_createdAt"},
+		{"user_id", KeyCaseOriginal, "user_id"},
+	}
+	for _, tt := range tests {
+		if got := ConvertKey(tt.key, tt.keyCase); got != tt.want {
+			t.Errorf("ConvertKey(%q, %q) = %q, want %q", tt.key, tt.keyCase, got, tt.want)
+		}
+	}
+}
+
+func TestRenameKeysNested(t *testing.T) {
+	rows := []map[string]any{{
+		"user_id": 1,
+		"profile": map[string]any{
+			"display_name": "Ada",
+			"tags":         []any{map[string]any{"tag_name": "admin"}},
+		},
+	}}
+	want := []map[string]any{{
+		"userId": 1,
+		"profile": map[string]any{
+			"displayName": "Ada",
+			"tags":        []any{map[string]any{"tagName": "admin"}},
+		},
+	}}
+	if got := RenameKeys(rows, KeyCaseCamel); !reflect.DeepEqual(got, want) {
+		t.Errorf("RenameKeys() = %v, want %v", got, want)
+	}
+	if _, ok := rows[0]["user_id"]; !ok {
+		t.Error("RenameKeys() modified the input rows")
+	}
+}
+
+func TestRenameKeysJSONColumn(t *testing.T) {
+	// SQL JSON and JSONB columns are decoded by the connectors before renaming
+	column := DecodeJSONColumn([]byte(`{"street_name": "Main", "geo_point": {"lat_deg": 1.5}}`))
+	rows := []map[string]any{{"home_address": column}}
+
+	got := RenameKeys(rows, KeyCaseCamel)
+	encoded, err := json.Marshal(got)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := `[{"homeAddress":{"geoPoint":{"latDeg":1.5},"streetName":"Main"}}]`
+	if string(encoded) != want {
+		t.Errorf("RenameKeys() = %s, want %s", encoded, want)
+	}
+}
+
+func TestRenameKeysCollision(t *testing.T) {
+	rows := []map[string]any{{"user_id": 1, "userId": 2, "name": "a"}}
+	want := []map[string]any{{"user_id": 1, "userId": 2, "name": "a"}}
+	if got := RenameKeys(rows, KeyCaseCamel); !reflect.DeepEqual(got, want) {
+		t.Errorf("RenameKeys() = %v, want %v", got, want)
+	}
+
+	rows = []map[string]any{{"user_id": 1, "userId": 2}}
+	want = []map[string]any{{"user_id": 1, "userId": 2}}
+	if got := RenameKeys(rows, KeyCaseSnake); !reflect.DeepEqual(got, want) {
+		t.Errorf("RenameKeys() = %v, want %v", got, want)
+	}
+}
+
+func TestDecodeJSONColumn(t *testing.T) {
+	if got := DecodeJSONColumn([]byte(`not json`)); got != "not json" {
+		t.Errorf("DecodeJSONColumn() = %v, want the text", got)
+	}
+	if got := DecodeJSONColumn([]byte(`{"a": 1} {"b": 2}`)); got != `{"a": 1} {"b": 2}` {
+		t.Errorf("DecodeJSONColumn() = %v, want the text", got)
+	}
+	got := DecodeJSONColumn([]byte(`{"id": 9007199254740993}`))
+	if id := got.(map[string]any)["id"]; id != json.Number("9007199254740993") {
+		t.Errorf("DecodeJSONColumn() id = %v, want 9007199254740993", id)
+	}
+}
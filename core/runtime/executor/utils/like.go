@@ -0,0 +1,124 @@
+package utils
+
+import (
+	"fmt"
+	"maps"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+var (
+	// LIKE helper placeholder: {{ like.<anything> }}, parsed by ParseLikeCalls
+	likePattern = regexp.MustCompile(`\{\{\s*like\.([^}]*)\}\}`)
+	// LIKE helper call: name(inputs.fieldName)
+	likeCallPattern = regexp.MustCompile(`^([a-zA-Z_][a-zA-Z0-9_]*)\s*\(\s*inputs\.(\w+)\s*\)$`)
+)
+
+// likeHelpers build a LIKE pattern around an escaped value
+var likeHelpers = map[string]func(string) string{
+	"contains":    func(value string) string { return "%" + value + "%" },
+	"starts_with": func(value string) string { return value + "%" },
+	"ends_with":   func(value string) string { return "%" + value },
+}
+
+// LikeHelperNames returns the names of the LIKE helpers, sorted
+func LikeHelperNames() []string {
+	names := make([]string, 0, len(likeHelpers))
+	for name := range likeHelpers {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// LikeCall is a parsed {{ like.name(inputs.field) }} placeholder
+type LikeCall struct {
+	Placeholder string
+	Name        string
+	Input       string
+}
+
+// ParseLikeCalls returns every LIKE helper placeholder in statement, or an
+// error naming the first unknown helper or malformed call
+func ParseLikeCalls(statement string) ([]LikeCall, error) {
+	var calls []LikeCall
+	for _, match := range likePattern.FindAllStringSubmatch(statement, -1) {
+		parts := likeCallPattern.FindStringSubmatch(strings.TrimSpace(match[1]))
+		if parts == nil {
+			return nil, fmt.Errorf("'%s' is not a valid LIKE helper; expected {{ like.name(inputs.field) }}", match[0])
+		}
+		if _, ok := likeHelpers[parts[1]]; !ok {
+			return nil, fmt.Errorf("unknown LIKE helper 'like.%s'; must be one of: %s", parts[1], strings.Join(LikeHelperNames(), ", "))
+		}
+		calls = append(calls, LikeCall{Placeholder: match[0], Name: parts[1], Input: parts[2]})
+	}
+	return calls, nil
+}
+
+// EscapeLike escapes the LIKE wildcards % and _, and the backslash escape
+// character itself, so a value only matches literally
+func EscapeLike(value string) string {
+	return strings.NewReplacer(`\`, `\\`, `%`, `\%`, `_`, `\_`).Replace(value)
+}
+
+// EscapeLikeInputs returns inputs with the values of escape_like inputs passed
+// through EscapeLike, for substitution into {{ inputs.x }} placeholders
+func EscapeLikeInputs(query *hyperterse.Query, inputs map[string]any) map[string]any {
+	var escaped map[string]any
+	for _, input := range query.Inputs {
+		value, ok := inputs[input.Name].(string)
+		if !input.EscapeLike || !ok {
+			continue
+		}
+		if escaped == nil {
+			escaped = maps.Clone(inputs)
+		}
+		escaped[input.Name] = EscapeLike(value)
+	}
+	if escaped == nil {
+		return inputs
+	}
+	return escaped
+}
+
+// SubstituteLike replaces {{ like.name(inputs.field) }} placeholders with a
+// quoted LIKE pattern built from the input's escaped value by literal. Helpers
+// are substituted before inputs so input values are never evaluated as templates.
+func SubstituteLike(statement string, inputs map[string]any, literal func(pattern string) string) (string, error) {
+	calls, err := ParseLikeCalls(statement)
+	if err != nil {
+		return "", err
+	}
+
+	result := statement
+	for _, call := range calls {
+		value, exists := inputs[call.Input]
+		if !exists {
+			return "", fmt.Errorf("input '%s' not found for like.%s", call.Input, call.Name)
+		}
+		pattern := likeHelpers[call.Name](EscapeLike(valueToString(value)))
+		result = strings.ReplaceAll(result, call.Placeholder, literal(pattern))
+	}
+	return result, nil
+}
+
+// SQLLikeLiteral quotes a LIKE pattern for PostgreSQL, where backslash is the
+// default LIKE escape character
+func SQLLikeLiteral(pattern string) string {
+	return "'" + EscapeSQLString(pattern) + "'"
+}
+
+// MySQLLikeLiteral quotes a LIKE pattern for MySQL. Backslashes are doubled in
+// the string literal so the pattern keeps its escapes.
+func MySQLLikeLiteral(pattern string) string {
+	return "'" + EscapeMySQLString(pattern) + "'"
+}
+
+// DuckDBLikeLiteral quotes a LIKE pattern for DuckDB, which has no default
+// LIKE escape character and so needs an ESCAPE clause
+func DuckDBLikeLiteral(pattern string) string {
+	return SQLLikeLiteral(pattern) + ` ESCAPE '\'`
+}
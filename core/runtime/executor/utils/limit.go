@@ -0,0 +1,48 @@
+package utils
+
+import (
+	"fmt"
+	"regexp"
+	"strings"
+)
+
+var (
+	// Statements that can safely receive a trailing LIMIT clause
+	selectPattern = regexp.MustCompile(`(?is)^\s*(select|with)\b`)
+	// Any existing row-limiting clause means the statement is left untouched
+	limitPattern = regexp.MustCompile(`(?i)\b(limit|fetch\s+first|fetch\s+next|top)\b`)
+	// A locking clause must follow LIMIT, so statements with one are left untouched
+	lockingPattern = regexp.MustCompile(`(?i)\b(for\s+(update|share|no\s+key\s+update|key\s+share)|lock\s+in\s+share\s+mode)\b`)
+)
+
+// ApplyRowLimit appends "LIMIT maxRows+1" to a single SQL SELECT statement that has no
+// row-limiting clause of its own. The extra row lets the caller detect truncation.
+// Statements that cannot be rewritten safely are returned unchanged; the caller is
+// expected to truncate the results instead.
+func ApplyRowLimit(statement string, maxRows int32) (string, bool) {
+	if maxRows <= 0 {
+		return statement, false
+	}
+
+	trimmed := strings.TrimRight(strings.TrimSpace(statement), "; \t\r\n")
+	if !selectPattern.MatchString(trimmed) {
+		return statement, false
+	}
+	// Multiple statements, comments and existing limits could change the meaning of an appended clause
+	if strings.Contains(trimmed, ";") || strings.Contains(trimmed, "--") || strings.Contains(trimmed, "/*") {
+		return statement, false
+	}
+	if limitPattern.MatchString(trimmed) || lockingPattern.MatchString(trimmed) {
+		return statement, false
+	}
+
+	return fmt.Sprintf("%s LIMIT %d", trimmed, int64(maxRows)+1), true
+}
+
+// TruncateRows caps results at maxRows, reporting whether any rows were dropped
+func TruncateRows(results []map[string]any, maxRows int32) ([]map[string]any, bool) {
+	if maxRows <= 0 || len(results) <= int(maxRows) {
+		return results, false
+	}
+	return results[:maxRows], true
+}
@@ -0,0 +1,29 @@
+package utils
+
+import "testing"
+
+func TestApplyRowLimit(t *testing.T) {
+	tests := []struct {
+		statement string
+		want      string
+		applied   bool
+	}{
+		{"SELECT * FROM users", "SELECT * FROM users LIMIT 11", true},
+		{"SELECT * FROM users;", "SELECT * FROM users LIMIT 11", true},
+		{"WITH u AS (SELECT 1) SELECT * FROM u", "WITH u AS (SELECT 1) SELECT * FROM u LIMIT 11", true},
+		{"SELECT * FROM users LIMIT 5", "SELECT * FROM users LIMIT 5", false},
+		{"SELECT * FROM jobs FOR UPDATE", "SELECT * FROM jobs FOR UPDATE", false},
+		{"SELECT * FROM jobs FOR UPDATE SKIP LOCKED", "SELECT * FROM jobs FOR UPDATE SKIP LOCKED", false},
+		{"SELECT * FROM jobs FOR NO KEY UPDATE", "SELECT * FROM jobs FOR NO KEY UPDATE", false},
+		{"SELECT * FROM jobs for share", "SELECT * FROM jobs for share", false},
+		{"SELECT * FROM jobs LOCK IN SHARE MODE", "SELECT * FROM jobs LOCK IN SHARE MODE", false},
+		{"DELETE FROM users", "DELETE FROM users", false},
+		{"SELECT 1; SELECT 2", "SELECT 1; SELECT 2", false},
+	}
+	for _, tt := range tests {
+		got, applied := ApplyRowLimit(tt.statement, 10)
+		if got != tt.want || applied != tt.applied {
+			t.Errorf("ApplyRowLimit(%q) = %q, %v, want %q, %v", tt.statement, got, applied, tt.want, tt.applied)
+		}
+	}
+}
@@ -0,0 +1,207 @@
+package utils
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strings"
+)
+
+// MongoStatement is the statement of a query on a MongoDB adapter: a database
+// command run against a database. Command is kept raw so the connector can
+// parse it as Extended JSON in key order, since MongoDB requires the command
+// name to be the first key.
+type MongoStatement struct {
+	Database string          `json:"database"`
+	Command  json.RawMessage `json:"command"`
+}
+
+// MongoCommands are the database commands MongoDB statements may run. Cursor
+// commands return their first batch, and other commands their reply document;
+// getMore and killCursors are left out since cursors are not kept between
+// executions.
+var MongoCommands = map[string]bool{
+	"find":            true,
+	"aggregate":       true,
+	"count":           true,
+	"distinct":        true,
+	"insert":          true,
+	"update":          true,
+	"delete":          true,
+	"findAndModify":   true,
+	"bulkWrite":       true,
+	"mapReduce":       true,
+	"listCollections": true,
+	"listIndexes":     true,
+	"listDatabases":   true,
+	"create":          true,
+	"drop":            true,
+	"createIndexes":   true,
+	"dropIndexes":     true,
+	"collMod":         true,
+	"collStats":       true,
+	"dbStats":         true,
+	"serverStatus":    true,
+	"buildInfo":       true,
+	"hello":           true,
+	"isMaster":        true,
+	"explain":         true,
+	"ping":            true,
+}
+
+// mongoPlaceholder stands in for template placeholders written outside JSON
+// strings, such as "limit": {{ inputs.limit }}, while a statement is validated
+const mongoPlaceholder = `"{{}}"`
+
+// ParseMongoStatement parses a MongoDB statement, which must have a database
+// and a command
+func ParseMongoStatement(statement string) (*MongoStatement, error) {
+	var parsed MongoStatement
+	if err := json.Unmarshal([]byte(statement), &parsed); err != nil {
+		return nil, fmt.Errorf("mongodb statement must be valid JSON: %w", err)
+	}
+	if parsed.Database == "" {
+		return nil, fmt.Errorf("mongodb statement must include database")
+	}
+	if len(parsed.Command) == 0 {
+		return nil, fmt.Errorf("mongodb statement must include command")
+	}
+	return &parsed, nil
+}
+
+// ValidateMongoStatement checks a MongoDB statement as written in the
+// configuration. Placeholders outside JSON strings are replaced before the
+// statement is parsed, and the command name must be one of MongoCommands
+// unless it comes from a placeholder.
+func ValidateMongoStatement(statement string) error {
+	parsed, err := ParseMongoStatement(normalizeMongoPlaceholders(statement))
+	if err != nil {
+		return err
+	}
+	if string(bytes.TrimSpace(parsed.Command)) == mongoPlaceholder {
+		return nil
+	}
+	name, err := mongoCommandName(parsed.Command)
+	if err != nil {
+		return err
+	}
+	if strings.Contains(name, "{{") {
+		return nil
+	}
+	return checkMongoCommand(name)
+}
+
+// ValidateMongoCommand checks that the command of a MongoDB statement, once
+// its placeholders are substituted, is one of MongoCommands. The connector
+// runs it before every command, since a command name can come from an input.
+func ValidateMongoCommand(command json.RawMessage) error {
+	name, err := mongoCommandName(command)
+	if err != nil {
+		return err
+	}
+	return checkMongoCommand(name)
+}
+
+// checkMongoCommand reports an error naming the supported commands unless
+// name is one of MongoCommands
+func checkMongoCommand(name string) error {
+	if MongoCommands[name] {
+		return nil
+	}
+	supported := make([]string, 0, len(MongoCommands))
+	for command := range MongoCommands {
+		supported = append(supported, command)
+	}
+	sort.Strings(supported)
+	return fmt.Errorf("mongodb command '%s' is not supported. Must be one of: %s", name, strings.Join(supported, ", "))
+}
+
+// normalizeMongoPlaceholders replaces the {{ ... }} placeholders of statement
+// that are not inside JSON strings with a JSON string, so the statement parses
+// before its values are known
+func normalizeMongoPlaceholders(statement string) string {
+	var sb strings.Builder
+	inString, escaped := false, false
+	for i := 0; i < len(statement); i++ {
+		c := statement[i]
+		if inString {
+			switch {
+			case escaped:
+				escaped = false
+			case c == '\\':
+				escaped = true
+			case c == '"':
+				inString = false
+			}
+			sb.WriteByte(c)
+			continue
+		}
+		if c == '"' {
+			inString = true
+		} else if strings.HasPrefix(statement[i:], "{{") {
+			if end := strings.Index(statement[i:], "}}"); end >= 0 {
+				sb.WriteString(mongoPlaceholder)
+				i += end + 1
+				continue
+			}
+		}
+		sb.WriteByte(c)
+	}
+	return sb.String()
+}
+
+// mongoCommandName returns the first key of a command object, which MongoDB
+// takes as the command name
+func mongoCommandName(command json.RawMessage) (string, error) {
+	decoder := json.NewDecoder(bytes.NewReader(command))
+	if token, err := decoder.Token(); err != nil || token != json.Delim('{') {
+		return "", fmt.Errorf("mongodb command must be a JSON object")
+	}
+	token, err := decoder.Token()
+	if err != nil {
+		return "", fmt.Errorf("mongodb command must be a JSON object: %w", err)
+	}
+	name, ok := token.(string)
+	if !ok {
+		return "", fmt.Errorf("mongodb command must not be empty")
+	}
+	return name, nil
+}
+
+// mongoReadCommands are the MongoDB commands that never modify data
+var mongoReadCommands = map[string]bool{
+	"find":            true,
+	"aggregate":       true,
+	"count":           true,
+	"distinct":        true,
+	"listCollections": true,
+	"listIndexes":     true,
+	"listDatabases":   true,
+	"collStats":       true,
+	"dbStats":         true,
+	"serverStatus":    true,
+	"buildInfo":       true,
+	"hello":           true,
+	"isMaster":        true,
+	"explain":         true,
+	"ping":            true,
+}
+
+// isReadOnlyMongoStatement reports whether a MongoDB statement only reads.
+// aggregate writes when its pipeline has an $out or $merge stage, and a
+// command name read from a placeholder counts as a write.
+func isReadOnlyMongoStatement(statement string) bool {
+	parsed, err := ParseMongoStatement(normalizeMongoPlaceholders(statement))
+	if err != nil {
+		return false
+	}
+	name, err := mongoCommandName(parsed.Command)
+	if err != nil || !mongoReadCommands[name] {
+		return false
+	}
+	if name == "aggregate" && (strings.Contains(statement, `"$out"`) || strings.Contains(statement, `"$merge"`)) {
+		return false
+	}
+	return true
+}
@@ -0,0 +1,53 @@
+package utils
+
+import (
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// Null handling modes accepted by a query's nulls option
+const (
+	NullsKeep = "keep"
+	NullsDrop = "drop"
+	NullsFill = "fill"
+)
+
+// NullModes lists every null handling mode
+var NullModes = []string{NullsKeep, NullsDrop, NullsFill}
+
+// ConvertDataDefault converts a data field's declared default to its type
+func ConvertDataDefault(data *hyperterse.Data) (any, error) {
+	if data.DefaultValue == "" {
+		return nil, nil
+	}
+	return convertAndValidateValue(data.DefaultValue, types.PrimitiveEnumToString(data.Type))
+}
+
+// ApplyNulls shapes rows according to mode. "drop" removes columns whose value
+// is null; "fill" adds every declared data column that is missing or null,
+// using its default or null when it has none. Rows are modified in place.
+func ApplyNulls(rows []map[string]any, data []*hyperterse.Data, mode string) {
+	switch mode {
+	case NullsDrop:
+		for _, row := range rows {
+			for key, value := range row {
+				if value == nil {
+					delete(row, key)
+				}
+			}
+		}
+	case NullsFill:
+		// Defaults are checked when the configuration loads
+		defaults := make(map[string]any, len(data))
+		for _, d := range data {
+			defaults[d.Name], _ = ConvertDataDefault(d)
+		}
+		for _, row := range rows {
+			for name, value := range defaults {
+				if row[name] == nil {
+					row[name] = value
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,101 @@
+package utils
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/url"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// InputParamPrefix prefixes query string parameters that carry query inputs,
+// as in GET /query/get-user?inputs.id=42
+const InputParamPrefix = "inputs."
+
+// InputsFromQueryParams builds query inputs from the inputs.* parameters of a
+// query string, parsing int, float and boolean inputs from their text.
+// Parameters without the prefix are ignored. Unknown inputs are passed through
+// as strings so ValidateInputs reports them, and inputs with coerce enabled are
+// left as strings so their coercion still applies.
+func InputsFromQueryParams(query *hyperterse.Query, params url.Values) (map[string]any, error) {
+	inputDefs := make(map[string]*hyperterse.Input, len(query.Inputs))
+	for _, input := range query.Inputs {
+		inputDefs[input.Name] = input
+	}
+
+	inputs := make(map[string]any)
+	for key, values := range params {
+		name, ok := strings.CutPrefix(key, InputParamPrefix)
+		if !ok {
+			continue
+		}
+		if len(values) > 1 {
+			return nil, &ValidationError{
+				Field:   name,
+				Message: fmt.Sprintf("query parameter '%s' was given %d times, but inputs take a single value", key, len(values)),
+			}
+		}
+
+		// Strings, datetimes and UUIDs stay strings and are checked by ValidateInputs
+		inputDef, exists := inputDefs[name]
+		typ := types.PrimitiveEnumToString(inputDef.GetType())
+		if !exists || inputDef.Coerce || (typ != "int" && typ != "float" && typ != "boolean") {
+			inputs[name] = values[0]
+			continue
+		}
+		value, err := convertAndValidateValue(values[0], typ)
+		if err != nil {
+			return nil, &ValidationError{
+				Field:   name,
+				Message: fmt.Sprintf("type validation failed: %v", err),
+			}
+		}
+		inputs[name] = value
+	}
+	return inputs, nil
+}
+
+// DecodeJSONInputs decodes a JSON object of query inputs. Unlike decoding into
+// a map, a key given more than once is an error rather than silently keeping
+// the last value.
+func DecodeJSONInputs(r io.Reader) (map[string]any, error) {
+	decoder := json.NewDecoder(r)
+	token, err := decoder.Token()
+	if err != nil {
+		return nil, err
+	}
+	if token == nil {
+		// A null body has no inputs
+		return map[string]any{}, nil
+	}
+	if delim, ok := token.(json.Delim); !ok || delim != '{' {
+		return nil, fmt.Errorf("request body must be a JSON object")
+	}
+
+	inputs := make(map[string]any)
+	for decoder.More() {
+		token, err := decoder.Token()
+		if err != nil {
+			return nil, err
+		}
+		key := token.(string)
+		var value any
+		if err := decoder.Decode(&value); err != nil {
+			return nil, err
+		}
+		if _, exists := inputs[key]; exists {
+			return nil, &ValidationError{
+				Field:   key,
+				Message: fmt.Sprintf("input '%s' was given more than once, but inputs take a single value", key),
+			}
+		}
+		inputs[key] = value
+	}
+	if _, err := decoder.Token(); err != nil {
+		return nil, err
+	}
+	return inputs, nil
+}
@@ -0,0 +1,79 @@
+package utils
+
+import (
+	"regexp"
+	"strings"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+var (
+	// readOnlySQLPattern matches statements that start as a read
+	readOnlySQLPattern = regexp.MustCompile(`(?is)^\s*(select|with|show|describe|desc|values|table)\b`)
+
+	// writeSQLPattern matches keywords that make a statement write or lock rows,
+	// such as a data-modifying CTE, SELECT INTO or FOR UPDATE. Column names that
+	// happen to be one of them make the statement count as a write, which errs
+	// on the safe side.
+	writeSQLPattern = regexp.MustCompile(`(?i)\b(insert|update|delete|merge|upsert|replace|truncate|drop|alter|create|grant|revoke|into|call|lock|share|copy)\b`)
+)
+
+// redisReadCommands are the Redis commands that never modify data
+var redisReadCommands = map[string]bool{
+	"GET": true, "MGET": true, "STRLEN": true, "GETRANGE": true, "EXISTS": true,
+	"TTL": true, "PTTL": true, "TYPE": true, "KEYS": true, "SCAN": true,
+	"HGET": true, "HMGET": true, "HGETALL": true, "HKEYS": true, "HVALS": true, "HLEN": true, "HEXISTS": true, "HSCAN": true,
+	"LRANGE": true, "LINDEX": true, "LLEN": true,
+	"SMEMBERS": true, "SISMEMBER": true, "SCARD": true, "SSCAN": true,
+	"ZRANGE": true, "ZRANGEBYSCORE": true, "ZREVRANGE": true, "ZSCORE": true, "ZCARD": true, "ZRANK": true, "ZCOUNT": true, "ZSCAN": true,
+	"XRANGE": true, "XREVRANGE": true, "XLEN": true,
+	"PING": true, "DBSIZE": true,
+}
+
+// IsReadOnlyStatement reports whether statement only reads data on an adapter
+// of connector. It is conservative: statements it cannot classify count as
+// writes.
+func IsReadOnlyStatement(connector protoconnectors.Connector, statement string) bool {
+	switch StatementKindOf(connector) {
+	case StatementSQL:
+		trimmed := strings.TrimSuffix(strings.TrimSpace(statement), ";")
+		return readOnlySQLPattern.MatchString(trimmed) &&
+			!writeSQLPattern.MatchString(trimmed) &&
+			!strings.Contains(trimmed, ";")
+	case StatementRedisCommand:
+		parts := strings.Fields(statement)
+		return len(parts) > 0 && redisReadCommands[strings.ToUpper(parts[0])]
+	case StatementMongoJSON:
+		return isReadOnlyMongoStatement(statement)
+	case StatementJSON:
+		// Object store and static statements read; Kafka statements publish
+		return connector != protoconnectors.Connector_CONNECTOR_KAFKA
+	}
+	return false
+}
+
+// IsReadOnlyQuery reports whether every statement query runs only reads data.
+// Federated queries are read-only when each of their sources is.
+func IsReadOnlyQuery(model *hyperterse.Model, query *hyperterse.Query) bool {
+	connectorOf := func(name string) protoconnectors.Connector {
+		for _, adapter := range model.Adapters {
+			if adapter.Name == name {
+				return adapter.Connector
+			}
+		}
+		return protoconnectors.Connector_CONNECTOR_UNSPECIFIED
+	}
+	if query.Federated != nil {
+		for _, source := range query.Federated.Sources {
+			if !IsReadOnlyStatement(connectorOf(source.Use), source.Statement) {
+				return false
+			}
+		}
+		return len(query.Federated.Sources) > 0
+	}
+	if len(query.Use) == 0 {
+		return false
+	}
+	return IsReadOnlyStatement(connectorOf(query.Use[0]), query.Statement)
+}
@@ -0,0 +1,83 @@
+package utils
+
+import (
+	"crypto/hmac"
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"regexp"
+	"sort"
+	"strings"
+)
+
+// placeholderPattern matches any {{ ... }} placeholder, capturing its expression
+var placeholderPattern = regexp.MustCompile(`\{\{\s*([^}]*?)\s*\}\}`)
+
+// redactKey keys the HMAC of redacted values. It is drawn once per process, so
+// hashes cannot be reversed by hashing a dictionary of likely values, and only
+// compare within one run of the server.
+var redactKey = newRedactKey()
+
+// redactHashSize is the length in bytes of the hash of a redacted value
+const redactHashSize = 16
+
+func newRedactKey() []byte {
+	key := make([]byte, 32)
+	if _, err := rand.Read(key); err != nil {
+		panic(fmt.Sprintf("failed to generate redaction key: %v", err))
+	}
+	return key
+}
+
+// RedactStatement formats statement for logs without the values substituted
+// into it. Identifier placeholders are quoted by quote, since the validator
+// limits them to the names in their enum. Input placeholders become
+// <inputs.name:hash>, where hash identifies the value without revealing it, and
+// every other placeholder - functions, LIKE helpers, headers and environment
+// variables - keeps only its expression, as in <env.DB_SCHEMA>.
+func RedactStatement(statement string, inputs map[string]any, quote func(string) string) string {
+	if quote != nil {
+		if quoted, err := SubstituteIdentifiers(statement, inputs, quote); err == nil {
+			statement = quoted
+		}
+	}
+	return placeholderPattern.ReplaceAllStringFunc(statement, func(placeholder string) string {
+		expr := placeholderPattern.FindStringSubmatch(placeholder)[1]
+		if match := templatePattern.FindStringSubmatch(placeholder); match != nil {
+			if value, ok := inputs[match[1]]; ok {
+				return fmt.Sprintf("<%s:%s>", expr, RedactValue(value))
+			}
+		}
+		return "<" + expr + ">"
+	})
+}
+
+// RedactValue returns a keyed hash of value, so logs can show whether two
+// requests used the same value without showing the value itself
+func RedactValue(value any) string {
+	mac := hmac.New(sha256.New, redactKey)
+	mac.Write([]byte(valueToString(value)))
+	return hex.EncodeToString(mac.Sum(nil)[:redactHashSize])
+}
+
+// RedactText replaces the values of inputs found in text, such as a database
+// error echoing a duplicate key, with <inputs.name:hash> as RedactStatement
+// writes them. Longer values are replaced first so a value inside another is
+// not split.
+func RedactText(text string, inputs map[string]any) string {
+	type redaction struct{ value, replacement string }
+	redactions := make([]redaction, 0, len(inputs))
+	for name, value := range inputs {
+		if str := valueToString(value); str != "" {
+			redactions = append(redactions, redaction{str, fmt.Sprintf("<inputs.%s:%s>", name, RedactValue(value))})
+		}
+	}
+	sort.Slice(redactions, func(i, j int) bool { return len(redactions[i].value) > len(redactions[j].value) })
+
+	pairs := make([]string, 0, 2*len(redactions))
+	for _, r := range redactions {
+		pairs = append(pairs, r.value, r.replacement)
+	}
+	return strings.NewReplacer(pairs...).Replace(text)
+}
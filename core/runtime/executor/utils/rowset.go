@@ -0,0 +1,226 @@
+package utils
+
+import (
+	"encoding/json"
+	"math"
+	"sort"
+	"strconv"
+	"unicode/utf8"
+)
+
+// absent marks a column that a row does not have, as opposed to a null value
+type absent struct{}
+
+// RowSet holds result rows as one column header shared by every row and a
+// single slice of values laid out row after row in header order. Encoding a
+// RowSet produces the same JSON as encoding the rows it was built from, without
+// sorting and escaping the keys of every row again.
+type RowSet struct {
+	columns []string
+	keys    [][]byte // Encoded `"column":` prefix of each column
+	values  []any
+	rows    int
+	isNil   bool
+}
+
+// NewRowSet returns the columnar form of rows. Columns are the union of the
+// keys of every row, sorted as encoding/json sorts map keys; rows that lack a
+// column keep lacking it when encoded.
+func NewRowSet(rows []map[string]any) *RowSet {
+	set := &RowSet{rows: len(rows), isNil: rows == nil}
+
+	index := make(map[string]int)
+	for _, row := range rows {
+		for key := range row {
+			if _, ok := index[key]; !ok {
+				index[key] = len(set.columns)
+				set.columns = append(set.columns, key)
+			}
+		}
+	}
+	sort.Strings(set.columns)
+	set.keys = make([][]byte, len(set.columns))
+	for i, column := range set.columns {
+		index[column] = i
+		set.keys[i] = append(appendJSONString(nil, column), ':')
+	}
+
+	set.values = make([]any, len(rows)*len(set.columns))
+	for r, row := range rows {
+		values := set.values[r*len(set.columns) : (r+1)*len(set.columns)]
+		for i := range values {
+			values[i] = absent{}
+		}
+		for key, value := range row {
+			values[index[key]] = value
+		}
+	}
+	return set
+}
+
+// Columns returns the column header shared by every row
+func (s *RowSet) Columns() []string {
+	return s.columns
+}
+
+// Len returns the number of rows
+func (s *RowSet) Len() int {
+	return s.rows
+}
+
+// MarshalJSON encodes the rows as a JSON array of objects, or null when the
+// set was built from nil rows, exactly as json.Marshal encodes the rows
+func (s *RowSet) MarshalJSON() ([]byte, error) {
+	if s.isNil {
+		return []byte("null"), nil
+	}
+	// Leave room for punctuation and short values before growing
+	buf := make([]byte, 0, 2+s.rows*(2+len(s.columns)*16))
+	buf = append(buf, '[')
+	for r := 0; r < s.rows; r++ {
+		if r > 0 {
+			buf = append(buf, ',')
+		}
+		buf = append(buf, '{')
+		first := true
+		for i, value := range s.values[r*len(s.columns) : (r+1)*len(s.columns)] {
+			if _, ok := value.(absent); ok {
+				continue
+			}
+			if !first {
+				buf = append(buf, ',')
+			}
+			first = false
+			buf = append(buf, s.keys[i]...)
+			var err error
+			if buf, err = AppendJSONValue(buf, value); err != nil {
+				return nil, err
+			}
+		}
+		buf = append(buf, '}')
+	}
+	return append(buf, ']'), nil
+}
+
+// AppendJSONValue appends the JSON encoding of value to dst. Scalars that
+// database drivers return are written directly; other values are encoded by
+// json.Marshal, which produces the same output for every type.
+func AppendJSONValue(dst []byte, value any) ([]byte, error) {
+	switch v := value.(type) {
+	case nil:
+		return append(dst, "null"...), nil
+	case string:
+		return appendJSONString(dst, v), nil
+	case bool:
+		return strconv.AppendBool(dst, v), nil
+	case int:
+		return strconv.AppendInt(dst, int64(v), 10), nil
+	case int8:
+		return strconv.AppendInt(dst, int64(v), 10), nil
+	case int16:
+		return strconv.AppendInt(dst, int64(v), 10), nil
+	case int32:
+		return strconv.AppendInt(dst, int64(v), 10), nil
+	case int64:
+		return strconv.AppendInt(dst, v, 10), nil
+	case uint:
+		return strconv.AppendUint(dst, uint64(v), 10), nil
+	case uint8:
+		return strconv.AppendUint(dst, uint64(v), 10), nil
+	case uint16:
+		return strconv.AppendUint(dst, uint64(v), 10), nil
+	case uint32:
+		return strconv.AppendUint(dst, uint64(v), 10), nil
+	case uint64:
+		return strconv.AppendUint(dst, v, 10), nil
+	case float64:
+		if !math.IsNaN(v) && !math.IsInf(v, 0) {
+			return appendJSONFloat(dst, v, 64), nil
+		}
+	case float32:
+		if f := float64(v); !math.IsNaN(f) && !math.IsInf(f, 0) {
+			return appendJSONFloat(dst, f, 32), nil
+		}
+	}
+	encoded, err := json.Marshal(value)
+	if err != nil {
+		return nil, err
+	}
+	return append(dst, encoded...), nil
+}
+
+// appendJSONFloat formats f as encoding/json does, using exponents only for
+// very small and very large magnitudes
+func appendJSONFloat(dst []byte, f float64, bits int) []byte {
+	format := byte('f')
+	if abs := math.Abs(f); abs != 0 {
+		if bits == 64 && (abs < 1e-6 || abs >= 1e21) || bits == 32 && (float32(abs) < 1e-6 || float32(abs) >= 1e21) {
+			format = 'e'
+		}
+	}
+	dst = strconv.AppendFloat(dst, f, format, -1, bits)
+	if format == 'e' {
+		// Clean up e-09 to e-9
+		if n := len(dst); n >= 4 && dst[n-4] == 'e' && dst[n-3] == '-' && dst[n-2] == '0' {
+			dst[n-2] = dst[n-1]
+			dst = dst[:n-1]
+		}
+	}
+	return dst
+}
+
+const hexDigits = "0123456789abcdef"
+
+// appendJSONString appends s as a JSON string with the escaping encoding/json
+// applies by default, including HTML characters and invalid UTF-8
+func appendJSONString(dst []byte, s string) []byte {
+	dst = append(dst, '"')
+	start := 0
+	for i := 0; i < len(s); {
+		if b := s[i]; b < utf8.RuneSelf {
+			if b >= 0x20 && b != '"' && b != '\\' && b != '<' && b != '>' && b != '&' {
+				i++
+				continue
+			}
+			dst = append(dst, s[start:i]...)
+			switch b {
+			case '"', '\\':
+				dst = append(dst, '\\', b)
+			case '\b':
+				dst = append(dst, '\\', 'b')
+			case '\f':
+				dst = append(dst, '\\', 'f')
+			case '\n':
+				dst = append(dst, '\\', 'n')
+			case '\r':
+				dst = append(dst, '\\', 'r')
+			case '\t':
+				dst = append(dst, '\\', 't')
+			default:
+				dst = append(dst, '\\', 'u', '0', '0', hexDigits[b>>4], hexDigits[b&0xF])
+			}
+			i++
+			start = i
+			continue
+		}
+		r, size := utf8.DecodeRuneInString(s[i:])
+		if r == utf8.RuneError && size == 1 {
+			dst = append(dst, s[start:i]...)
+			dst = append(dst, `\ufffd`...)
+			i += size
+			start = i
+			continue
+		}
+		// U+2028 and U+2029 are valid JSON but end lines in JavaScript
+		if r == '\u2028' || r == '\u2029' {
+			dst = append(dst, s[start:i]...)
+			dst = append(dst, '\\', 'u', '2', '0', '2', hexDigits[r&0xF])
+			i += size
+			start = i
+			continue
+		}
+		i += size
+	}
+	dst = append(dst, s[start:]...)
+	return append(dst, '"')
+}
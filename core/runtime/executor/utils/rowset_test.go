@@ -0,0 +1,83 @@
+package utils
+
+import (
+	"encoding/json"
+	"fmt"
+	"math"
+	"testing"
+	"time"
+)
+
+func TestRowSetMatchesJSONMarshal(t *testing.T) {
+	tests := [][]map[string]any{
+		nil,
+		{},
+		{{}},
+		{{"id": int64(1), "name": "Ada", "score": 9.5}, {"id": int64(2), "name": nil, "score": float32(0.1)}},
+		{{"a": 1}, {"b": 2}, {"a": 3, "c": true}},
+		{{"text": "<b>\"tabs\"\tand\nlines & \\ \x01 \u2028 \u2029 \xff é 日本"}},
+		{{"small": 1e-7, "large": 1e21, "float32": float32(1e-7), "zero": 0.0, "negative": -2.5e-9}},
+		{{"uint": uint64(math.MaxUint64), "int8": int8(-8), "uint16": uint16(16), "int": -1}},
+		{{"at": time.Date(2026, 1, 2, 3, 4, 5, 0, time.UTC), "number": json.Number("9007199254740993")}},
+		{{"nested": map[string]any{"b": []any{1, "<x>"}, "a": nil}, "list": []string{"a"}}},
+		{{"<key>": 1, "key\n": 2}},
+	}
+	for _, rows := range tests {
+		want, err := json.Marshal(rows)
+		if err != nil {
+			t.Fatal(err)
+		}
+		got, err := json.Marshal(NewRowSet(rows))
+		if err != nil {
+			t.Fatalf("json.Marshal(NewRowSet(%v)) failed: %v", rows, err)
+		}
+		if string(got) != string(want) {
+			t.Errorf("NewRowSet(%v) encodes as %s, want %s", rows, got, want)
+		}
+	}
+}
+
+func TestRowSetUnsupportedValue(t *testing.T) {
+	if _, err := NewRowSet([]map[string]any{{"x": math.NaN()}}).MarshalJSON(); err == nil {
+		t.Error("MarshalJSON() with NaN succeeded, want an error")
+	}
+}
+
+// benchmarkRows returns rows shaped like a typical SQL result
+func benchmarkRows(n int) []map[string]any {
+	rows := make([]map[string]any, n)
+	for i := range rows {
+		rows[i] = map[string]any{
+			"id":         int64(i),
+			"email":      fmt.Sprintf("user%d@example.com", i),
+			"name":       "Ada Lovelace",
+			"active":     i%2 == 0,
+			"balance":    float64(i) * 1.25,
+			"created_at": "2026-01-02T03:04:05Z",
+			"team_id":    nil,
+			"plan":       "pro",
+		}
+	}
+	return rows
+}
+
+func BenchmarkEncodeRows(b *testing.B) {
+	rows := benchmarkRows(1000)
+
+	b.Run("maps", func(b *testing.B) {
+		b.ReportAllocs()
+		for b.Loop() {
+			if _, err := json.Marshal(rows); err != nil {
+				b.Fatal(err)
+			}
+		}
+	})
+	b.Run("rowset", func(b *testing.B) {
+		b.ReportAllocs()
+		for b.Loop() {
+			if _, err := NewRowSet(rows).MarshalJSON(); err != nil {
+				b.Fatal(err)
+			}
+		}
+	})
+}
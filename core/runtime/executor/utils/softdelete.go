@@ -0,0 +1,161 @@
+package utils
+
+import (
+	"fmt"
+	"slices"
+	"strings"
+)
+
+// Keywords that end a SELECT's WHERE clause, or that follow FROM when there is
+// no WHERE clause
+var whereEndKeywords = []string{"group", "having", "window", "qualify", "order", "limit", "offset", "fetch", "for", "lock"}
+
+// Keywords combining SELECTs, whose WHERE clauses would each need the filter
+var setOperatorKeywords = []string{"union", "intersect", "except"}
+
+// sqlWord is a keyword-like word outside quotes and parentheses
+type sqlWord struct {
+	word  string // lowercase
+	start int
+	end   int
+}
+
+// ApplySoftDeleteFilter adds filter to the WHERE clause of a SELECT statement,
+// so rows it excludes are never returned. An existing WHERE condition is kept
+// in parentheses and combined with AND; otherwise a WHERE clause is inserted
+// after FROM and its joins, before GROUP BY, ORDER BY, LIMIT and similar
+// clauses. Only the outermost SELECT is changed, so subqueries and CTEs are
+// left alone.
+//
+// Statements the filter cannot be added to safely are rejected rather than run
+// without it: statements other than SELECT, several statements, comments,
+// dollar-quoted strings and UNION, INTERSECT or EXCEPT.
+func ApplySoftDeleteFilter(statement, filter string) (string, error) {
+	trimmed := strings.TrimRight(strings.TrimSpace(statement), "; \t\r\n")
+	if !selectPattern.MatchString(trimmed) {
+		return "", fmt.Errorf("soft_delete_filter only applies to SELECT statements")
+	}
+	if strings.Contains(trimmed, "--") || strings.Contains(trimmed, "/*") || strings.Contains(trimmed, "$$") {
+		return "", fmt.Errorf("soft_delete_filter cannot be applied to statements with comments or dollar-quoted strings")
+	}
+
+	words, err := topLevelWords(trimmed)
+	if err != nil {
+		return "", err
+	}
+
+	from := -1
+	for i, w := range words {
+		switch {
+		case w.word == ";":
+			return "", fmt.Errorf("soft_delete_filter cannot be applied to multiple statements")
+		case slices.Contains(setOperatorKeywords, w.word):
+			return "", fmt.Errorf("soft_delete_filter cannot be applied to statements with %s; add the predicate to each SELECT", strings.ToUpper(w.word))
+		case w.word == "from" && from == -1:
+			from = i
+		}
+	}
+	if from == -1 {
+		return "", fmt.Errorf("soft_delete_filter requires a SELECT statement with a FROM clause")
+	}
+
+	// The WHERE clause, if any, runs from WHERE to the next clause keyword
+	where := -1
+	end := len(trimmed)
+	for _, w := range words[from+1:] {
+		if w.word == "where" && where == -1 {
+			where = w.end
+			continue
+		}
+		if slices.Contains(whereEndKeywords, w.word) {
+			end = w.start
+			break
+		}
+	}
+
+	predicate := "(" + strings.TrimSpace(filter) + ")"
+	if where != -1 {
+		condition := strings.TrimSpace(trimmed[where:end])
+		return joinClauses(trimmed[:where], "("+condition+") AND "+predicate, trimmed[end:]), nil
+	}
+	return joinClauses(trimmed[:end], "WHERE "+predicate, trimmed[end:]), nil
+}
+
+// ValidateSoftDeleteFilter checks a filter is a single predicate that cannot
+// end the WHERE clause it is added to
+func ValidateSoftDeleteFilter(filter string) error {
+	if strings.TrimSpace(filter) == "" {
+		return fmt.Errorf("must not be empty")
+	}
+	if strings.Contains(filter, "--") || strings.Contains(filter, "/*") || strings.Contains(filter, "$$") {
+		return fmt.Errorf("must not contain comments or dollar-quoted strings")
+	}
+	words, err := topLevelWords(filter)
+	if err != nil {
+		return err
+	}
+	for _, w := range words {
+		if w.word == ";" {
+			return fmt.Errorf("must be a single predicate without ';'")
+		}
+	}
+	return nil
+}
+
+// joinClauses joins statement parts with single spaces
+func joinClauses(before, clause, after string) string {
+	result := strings.TrimSpace(before) + " " + clause
+	if after = strings.TrimSpace(after); after != "" {
+		result += " " + after
+	}
+	return result
+}
+
+// topLevelWords returns the words and semicolons of statement outside quotes
+// and parentheses, with their byte offsets
+func topLevelWords(statement string) ([]sqlWord, error) {
+	var words []sqlWord
+	depth := 0
+	for i := 0; i < len(statement); {
+		c := statement[i]
+		switch {
+		case c == '\'' || c == '"' || c == '`':
+			// Quotes are escaped by doubling, which reads as two adjacent literals
+			closing := strings.IndexByte(statement[i+1:], c)
+			if closing == -1 {
+				return nil, fmt.Errorf("unterminated %c quote", c)
+			}
+			i += closing + 2
+		case c == '(':
+			depth++
+			i++
+		case c == ')':
+			depth--
+			if depth < 0 {
+				return nil, fmt.Errorf("unbalanced parentheses")
+			}
+			i++
+		case c == ';' && depth == 0:
+			words = append(words, sqlWord{word: ";", start: i, end: i + 1})
+			i++
+		case isWordByte(c):
+			start := i
+			for i < len(statement) && isWordByte(statement[i]) {
+				i++
+			}
+			if depth == 0 {
+				words = append(words, sqlWord{word: strings.ToLower(statement[start:i]), start: start, end: i})
+			}
+		default:
+			i++
+		}
+	}
+	if depth != 0 {
+		return nil, fmt.Errorf("unbalanced parentheses")
+	}
+	return words, nil
+}
+
+func isWordByte(c byte) bool {
+	return c == '_' || c == '.' || c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z' || c >= '0' && c <= '9'
+}
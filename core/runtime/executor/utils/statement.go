@@ -0,0 +1,131 @@
+package utils
+
+import (
+	"strings"
+	"time"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+)
+
+// StatementKind is the language of the statements an adapter runs, derived from
+// its connector. The validator, the executor and the connectors use it to agree
+// on how a statement is parsed, escaped and validated.
+type StatementKind int
+
+const (
+	// StatementUnknown is the kind of statements on an unspecified connector
+	StatementUnknown StatementKind = iota
+	// StatementSQL statements are SQL, run on postgres, mysql and duckdb adapters
+	StatementSQL
+	// StatementRedisCommand statements are Redis commands, one per statement
+	StatementRedisCommand
+	// StatementMongoJSON statements are JSON objects of a database and a command
+	StatementMongoJSON
+	// StatementJSON statements are JSON documents, run on objectstore, kafka and
+	// static adapters
+	StatementJSON
+)
+
+// StatementKindOf returns the kind of the statements run on connector
+func StatementKindOf(connector protoconnectors.Connector) StatementKind {
+	switch connector {
+	case protoconnectors.Connector_CONNECTOR_POSTGRES,
+		protoconnectors.Connector_CONNECTOR_MYSQL,
+		protoconnectors.Connector_CONNECTOR_DUCKDB:
+		return StatementSQL
+	case protoconnectors.Connector_CONNECTOR_REDIS:
+		return StatementRedisCommand
+	case protoconnectors.Connector_CONNECTOR_MONGODB:
+		return StatementMongoJSON
+	case protoconnectors.Connector_CONNECTOR_OBJECTSTORE,
+		protoconnectors.Connector_CONNECTOR_KAFKA,
+		protoconnectors.Connector_CONNECTOR_STATIC:
+		return StatementJSON
+	}
+	return StatementUnknown
+}
+
+// SQLServer reports whether connector runs SQL on a database server, which
+// unlike embedded DuckDB has sessions, connection pools and login credentials
+func SQLServer(connector protoconnectors.Connector) bool {
+	return StatementKindOf(connector) == StatementSQL && connector != protoconnectors.Connector_CONNECTOR_DUCKDB
+}
+
+// JSONStatements reports whether statements run on connector are JSON
+func JSONStatements(connector protoconnectors.Connector) bool {
+	kind := StatementKindOf(connector)
+	return kind == StatementMongoJSON || kind == StatementJSON
+}
+
+// StringEscaper returns the escaping applied to template function results and
+// header values in statements run on connector, or nil when they are inserted
+// as they are
+func StringEscaper(connector protoconnectors.Connector) func(string) string {
+	switch StatementKindOf(connector) {
+	case StatementSQL:
+		if connector == protoconnectors.Connector_CONNECTOR_MYSQL {
+			return EscapeMySQLString
+		}
+		return EscapeSQLString
+	case StatementMongoJSON, StatementJSON:
+		return EscapeJSONString
+	}
+	return nil
+}
+
+// IdentifierQuoter returns the quoting applied to {{ ident.name }} values in
+// statements run on connector, or nil when they have no SQL identifiers
+func IdentifierQuoter(connector protoconnectors.Connector) func(string) string {
+	if StatementKindOf(connector) != StatementSQL {
+		return nil
+	}
+	if connector == protoconnectors.Connector_CONNECTOR_MYSQL {
+		return QuoteMySQLIdentifier
+	}
+	return QuoteSQLIdentifier
+}
+
+// LikeQuoter returns how {{ like.name(...) }} patterns are quoted in statements
+// run on connector, or nil when they have no LIKE
+func LikeQuoter(connector protoconnectors.Connector) func(string) string {
+	switch connector {
+	case protoconnectors.Connector_CONNECTOR_POSTGRES:
+		return SQLLikeLiteral
+	case protoconnectors.Connector_CONNECTOR_MYSQL:
+		return MySQLLikeLiteral
+	case protoconnectors.Connector_CONNECTOR_DUCKDB:
+		return DuckDBLikeLiteral
+	}
+	return nil
+}
+
+// DatetimeLayout returns the layout datetime inputs are written in for
+// statements run on connector. PostgreSQL and DuckDB get the offset for
+// timestamptz columns, while MySQL rejects offsets before 8.0.19.
+func DatetimeLayout(connector protoconnectors.Connector) string {
+	switch connector {
+	case protoconnectors.Connector_CONNECTOR_POSTGRES, protoconnectors.Connector_CONNECTOR_DUCKDB:
+		return "2006-01-02 15:04:05.999999-07:00"
+	case protoconnectors.Connector_CONNECTOR_MYSQL:
+		return "2006-01-02 15:04:05.999999"
+	}
+	return time.RFC3339Nano
+}
+
+// ValidateStatement checks a statement as written in the configuration for
+// the language of connector. MongoDB statements are checked with their
+// placeholders in place; static statements with placeholders can only be
+// parsed once they are substituted.
+func ValidateStatement(connector protoconnectors.Connector, statement string) error {
+	if statement == "" {
+		return nil
+	}
+	switch {
+	case StatementKindOf(connector) == StatementMongoJSON:
+		return ValidateMongoStatement(statement)
+	case connector == protoconnectors.Connector_CONNECTOR_STATIC && !strings.Contains(statement, "{{"):
+		_, err := ParseStaticStatement(statement)
+		return err
+	}
+	return nil
+}
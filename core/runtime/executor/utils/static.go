@@ -0,0 +1,66 @@
+package utils
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+)
+
+// StaticStatement is the statement of a query on a static adapter: literal rows,
+// optionally filtered by inputs
+type StaticStatement struct {
+	Rows []map[string]any `json:"rows"`
+	// Filter maps a column to the value it must equal, usually an input
+	// placeholder. Null and empty values do not filter, so optional inputs
+	// without a value return every row.
+	Filter map[string]any `json:"filter"`
+}
+
+// ParseStaticStatement parses a static statement, written either as a JSON
+// array of rows or as an object with rows and filter
+func ParseStaticStatement(statement string) (*StaticStatement, error) {
+	trimmed := bytes.TrimSpace([]byte(statement))
+	decoder := json.NewDecoder(bytes.NewReader(trimmed))
+	decoder.UseNumber()
+
+	var parsed StaticStatement
+	if len(trimmed) > 0 && trimmed[0] == '[' {
+		if err := decoder.Decode(&parsed.Rows); err != nil {
+			return nil, fmt.Errorf("static statement must be a JSON array of rows or an object with rows: %w", err)
+		}
+	} else {
+		decoder.DisallowUnknownFields()
+		if err := decoder.Decode(&parsed); err != nil {
+			return nil, fmt.Errorf("static statement must be a JSON array of rows or an object with rows: %w", err)
+		}
+	}
+	for i, row := range parsed.Rows {
+		if row == nil {
+			return nil, fmt.Errorf("static statement row %d must be an object", i)
+		}
+	}
+	return &parsed, nil
+}
+
+// FilterRows returns the rows whose columns equal the filter values. Values
+// are compared as text, like federated join keys, so "42" matches 42.
+func (s *StaticStatement) FilterRows() []map[string]any {
+	rows := make([]map[string]any, 0, len(s.Rows))
+	for _, row := range s.Rows {
+		matches := true
+		for column, value := range s.Filter {
+			want, ok := joinKey(value)
+			if !ok || want == "" {
+				continue
+			}
+			if got, ok := joinKey(row[column]); !ok || got != want {
+				matches = false
+				break
+			}
+		}
+		if matches {
+			rows = append(rows, row)
+		}
+	}
+	return rows
+}
@@ -0,0 +1,109 @@
+package utils
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// Transform names accepted in an input's transform list
+var TransformNames = []string{"lowercase", "trim", "truncate(n)"}
+
+var truncatePattern = regexp.MustCompile(`^truncate\(\s*([0-9]+)\s*\)$`)
+
+// Transform rewrites a string input value before type checking
+type Transform func(string) string
+
+// ParseTransform parses a transform such as "lowercase", "trim" or "truncate(32)"
+func ParseTransform(spec string) (Transform, error) {
+	spec = strings.TrimSpace(spec)
+	switch spec {
+	case "lowercase":
+		return strings.ToLower, nil
+	case "trim":
+		return strings.TrimSpace, nil
+	}
+
+	if match := truncatePattern.FindStringSubmatch(spec); match != nil {
+		n, err := strconv.Atoi(match[1])
+		if err != nil || n <= 0 {
+			return nil, fmt.Errorf("truncate length must be a positive integer, got '%s'", match[1])
+		}
+		return func(value string) string {
+			runes := []rune(value)
+			if len(runes) <= n {
+				return value
+			}
+			return string(runes[:n])
+		}, nil
+	}
+
+	return nil, fmt.Errorf("unknown transform '%s'; must be one of: %s", spec, strings.Join(TransformNames, ", "))
+}
+
+// applyTransforms runs the input's transforms, in order, over string values.
+// Other values are returned unchanged.
+func applyTransforms(value any, specs []string) (any, error) {
+	str, ok := value.(string)
+	if !ok || len(specs) == 0 {
+		return value, nil
+	}
+	for _, spec := range specs {
+		transform, err := ParseTransform(spec)
+		if err != nil {
+			return nil, err
+		}
+		str = transform(str)
+	}
+	return str, nil
+}
+
+// coerceValue loosens the accepted representations of a value for inputs with
+// coerce enabled, returning a value the regular type conversion accepts
+func coerceValue(value any, expectedType string) any {
+	if str, ok := value.(string); ok {
+		value = strings.TrimSpace(str)
+	}
+
+	switch expectedType {
+	case "int":
+		// "42.0" and 42.0 are accepted when they hold a whole number
+		if str, ok := value.(string); ok {
+			if f, err := strconv.ParseFloat(str, 64); err == nil && f == float64(int64(f)) {
+				return int64(f)
+			}
+		}
+	case "boolean":
+		if str, ok := value.(string); ok {
+			switch strings.ToLower(str) {
+			case "yes", "y", "on":
+				return true
+			case "no", "n", "off", "":
+				return false
+			}
+		}
+	case "datetime":
+		// Numbers and numeric strings are milliseconds since the Unix epoch
+		var millis int64
+		switch v := value.(type) {
+		case string:
+			parsed, err := strconv.ParseInt(v, 10, 64)
+			if err != nil {
+				return value
+			}
+			millis = parsed
+		case float64:
+			millis = int64(v)
+		case int:
+			millis = int64(v)
+		case int64:
+			millis = v
+		default:
+			return value
+		}
+		return time.UnixMilli(millis).UTC().Format(time.RFC3339Nano)
+	}
+	return value
+}
@@ -3,14 +3,21 @@ package utils
 import (
 	"encoding/json"
 	"fmt"
+	"slices"
 	"strconv"
+	"strings"
 	"time"
 
+	"github.com/google/uuid"
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
 	"github.com/hyperterse/hyperterse/core/types"
 )
 
+// DefaultAuto is the default value that generates a fresh value on every
+// execution: a random v4 UUID for uuid inputs, the current time for datetime
+const DefaultAuto = types.DefaultAuto
+
 // ValidationError represents an input validation error
 type ValidationError struct {
 	Field   string
@@ -63,6 +70,25 @@ func ValidateInputs(query *hyperterse.Query, userInputs map[string]any) (map[str
 		}
 
 		log.Debugf("Validating input '%s' (type: %s)", key, types.PrimitiveEnumToString(inputDef.Type))
+		// Every input type holds one value; lists are never split across it
+		if list, ok := value.([]any); ok {
+			return nil, &ValidationError{
+				Field:   key,
+				Message: fmt.Sprintf("expected a single %s value but got a list of %d", types.PrimitiveEnumToString(inputDef.Type), len(list)),
+			}
+		}
+		// Apply transforms and coercion before type checking
+		value, err := applyTransforms(value, inputDef.Transforms)
+		if err != nil {
+			return nil, &ValidationError{
+				Field:   key,
+				Message: err.Error(),
+			}
+		}
+		if inputDef.Coerce {
+			value = coerceValue(value, types.PrimitiveEnumToString(inputDef.Type))
+		}
+
 		// Convert and validate the value
 		convertedValue, err := convertAndValidateValue(value, types.PrimitiveEnumToString(inputDef.Type))
 		if err != nil {
@@ -72,6 +98,12 @@ func ValidateInputs(query *hyperterse.Query, userInputs map[string]any) (map[str
 				Message: fmt.Sprintf("type validation failed: %v", err),
 			}
 		}
+		if len(inputDef.Enum) > 0 && !slices.Contains(inputDef.Enum, valueToString(convertedValue)) {
+			return nil, &ValidationError{
+				Field:   key,
+				Message: fmt.Sprintf("value '%s' is not allowed, must be one of: %s", valueToString(convertedValue), strings.Join(inputDef.Enum, ", ")),
+			}
+		}
 
 		validated[key] = convertedValue
 	}
@@ -79,7 +111,10 @@ func ValidateInputs(query *hyperterse.Query, userInputs map[string]any) (map[str
 	// Apply default values for optional inputs that weren't provided
 	for _, input := range query.Inputs {
 		if _, exists := validated[input.Name]; !exists {
-			if input.DefaultValue != "" {
+			if input.DefaultValue == DefaultAuto {
+				log.Debugf("Generating default value for optional input '%s'", input.Name)
+				validated[input.Name] = generateDefault(types.PrimitiveEnumToString(input.Type))
+			} else if input.DefaultValue != "" {
 				log.Debugf("Applying default value for optional input '%s'", input.Name)
 				convertedValue, err := convertAndValidateValue(input.DefaultValue, types.PrimitiveEnumToString(input.Type))
 				if err != nil {
@@ -98,6 +133,11 @@ func ValidateInputs(query *hyperterse.Query, userInputs map[string]any) (map[str
 	return validated, nil
 }
 
+// ConvertInputExample converts an example value of an input to its type
+func ConvertInputExample(input *hyperterse.Input, example string) (any, error) {
+	return convertAndValidateValue(example, types.PrimitiveEnumToString(input.Type))
+}
+
 // convertAndValidateValue converts a value to the expected type and validates it
 func convertAndValidateValue(value any, expectedType string) (any, error) {
 	switch expectedType {
@@ -111,6 +151,8 @@ func convertAndValidateValue(value any, expectedType string) (any, error) {
 		return convertToBoolean(value)
 	case "datetime":
 		return convertToDatetime(value)
+	case "uuid":
+		return convertToUUID(value)
 	default:
 		return nil, fmt.Errorf("unsupported type '%s'", expectedType)
 	}
@@ -237,30 +279,38 @@ func convertToBoolean(value any) (bool, error) {
 	}
 }
 
+// generateDefault returns the value of a "default: auto" input
+func generateDefault(typ string) any {
+	if typ == "datetime" {
+		return time.Now().UTC().Format(time.RFC3339Nano)
+	}
+	return uuid.NewString()
+}
+
+func convertToUUID(value any) (string, error) {
+	str, ok := value.(string)
+	if !ok {
+		return "", fmt.Errorf("cannot convert %T to uuid", value)
+	}
+	parsed, err := uuid.Parse(str)
+	if err != nil {
+		return "", fmt.Errorf("cannot parse '%s' as uuid", str)
+	}
+	// Use the canonical lowercase, hyphenated form
+	return parsed.String(), nil
+}
+
 func convertToDatetime(value any) (string, error) {
 	switch v := value.(type) {
 	case string:
-		// Try to parse as RFC3339
-		_, err := time.Parse(time.RFC3339, v)
-		if err != nil {
-			// Try other common formats
-			formats := []string{
-				time.RFC3339Nano,
-				"2006-01-02T15:04:05Z07:00",
-				"2006-01-02 15:04:05",
-				"2006-01-02",
-			}
-			for _, format := range formats {
-				if _, err := time.Parse(format, v); err == nil {
-					return v, nil
-				}
-			}
-			return "", fmt.Errorf("cannot parse '%s' as datetime", v)
+		// Values are normalized for the adapter once it is known
+		if _, _, err := parseDatetime(v, time.UTC); err != nil {
+			return "", err
 		}
 		return v, nil
 	case time.Time:
-		return v.Format(time.RFC3339), nil
+		return v.Format(time.RFC3339Nano), nil
 	default:
-		return "", fmt.Errorf("cannot convert %T to datetime", value)
+		return "", fmt.Errorf("cannot convert %T to datetime; expected a string in one of: %s", value, acceptedDatetimeFormats)
 	}
 }
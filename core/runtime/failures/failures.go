@@ -0,0 +1,188 @@
+package failures
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"slices"
+	"strings"
+	"sync"
+	"time"
+)
+
+// DefaultMax is how many captures are kept when server.failure_capture.max is unset
+const DefaultMax = 100
+
+// ErrNotFound is returned by Load for an unknown capture ID
+var ErrNotFound = errors.New("capture not found")
+
+// idPattern matches capture IDs, so Load never reads outside the directory
+var idPattern = regexp.MustCompile(`^[0-9]+-[a-zA-Z0-9_-]+$`)
+
+// Capture is the debug bundle of a failed query execution. It holds no input
+// values: each input is replaced by a short hash, and the statement is
+// redacted the same way as in logs.
+type Capture struct {
+	ID         string            `json:"id"`
+	Time       time.Time         `json:"time"`
+	Query      string            `json:"query"`
+	Adapter    string            `json:"adapter,omitempty"`
+	TraceID    string            `json:"trace_id,omitempty"`
+	DurationMs float64           `json:"duration_ms"`
+	Inputs     map[string]string `json:"inputs"` // Hash of each input value
+	Statement  string            `json:"statement"`
+	ErrorClass string            `json:"error_class"`
+	Errors     []ErrorLink       `json:"errors"` // Error chain, outermost first
+}
+
+// ErrorLink is one error of a chain
+type ErrorLink struct {
+	Type    string `json:"type"`
+	Message string `json:"message"`
+}
+
+// Chain returns the links of err's chain, outermost first. Joined errors
+// contribute each of their errors. Messages are passed through scrub, when
+// set, since database errors can echo the values of a statement.
+func Chain(err error, scrub func(string) string) []ErrorLink {
+	var links []ErrorLink
+	queue := []error{err}
+	for len(queue) > 0 {
+		current := queue[0]
+		queue = queue[1:]
+		if current == nil {
+			continue
+		}
+		message := current.Error()
+		if scrub != nil {
+			message = scrub(message)
+		}
+		links = append(links, ErrorLink{Type: fmt.Sprintf("%T", current), Message: message})
+		switch wrapped := current.(type) {
+		case interface{ Unwrap() error }:
+			queue = append(queue, wrapped.Unwrap())
+		case interface{ Unwrap() []error }:
+			queue = append(queue, wrapped.Unwrap()...)
+		}
+	}
+	return links
+}
+
+// Store keeps the latest captures as JSON files in a directory, removing the
+// oldest once there are more than max
+type Store struct {
+	dir string
+	max int
+	mu  sync.Mutex
+}
+
+// NewStore creates a store keeping up to limit captures in dir, or DefaultMax
+// when limit is not positive
+func NewStore(dir string, limit int) *Store {
+	if limit <= 0 {
+		limit = DefaultMax
+	}
+	return &Store{dir: dir, max: limit}
+}
+
+// Dir returns the directory captures are kept in
+func (s *Store) Dir() string {
+	return s.dir
+}
+
+// Save writes capture, assigning its ID from its time and query, and returns
+// the ID
+func (s *Store) Save(capture Capture) (string, error) {
+	capture.ID = fmt.Sprintf("%d-%s", capture.Time.UnixNano(), capture.Query)
+	data, err := json.MarshalIndent(capture, "", "  ")
+	if err != nil {
+		return "", err
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if err := os.MkdirAll(s.dir, 0700); err != nil {
+		return "", err
+	}
+	if err := os.WriteFile(filepath.Join(s.dir, capture.ID+".json"), append(data, '\n'), 0600); err != nil {
+		return "", err
+	}
+
+	ids, err := s.ids()
+	if err != nil {
+		return capture.ID, err
+	}
+	for len(ids) > s.max {
+		if err := os.Remove(filepath.Join(s.dir, ids[len(ids)-1]+".json")); err != nil && !errors.Is(err, os.ErrNotExist) {
+			return capture.ID, err
+		}
+		ids = ids[:len(ids)-1]
+	}
+	return capture.ID, nil
+}
+
+// List returns every kept capture, newest first
+func (s *Store) List() ([]Capture, error) {
+	s.mu.Lock()
+	ids, err := s.ids()
+	s.mu.Unlock()
+	if err != nil {
+		return nil, err
+	}
+	captures := make([]Capture, 0, len(ids))
+	for _, id := range ids {
+		capture, err := s.Load(id)
+		if errors.Is(err, ErrNotFound) {
+			continue // Removed since it was listed
+		}
+		if err != nil {
+			return nil, err
+		}
+		captures = append(captures, capture)
+	}
+	return captures, nil
+}
+
+// Load returns the capture with id
+func (s *Store) Load(id string) (Capture, error) {
+	var capture Capture
+	if !idPattern.MatchString(id) {
+		return capture, ErrNotFound
+	}
+	data, err := os.ReadFile(filepath.Join(s.dir, id+".json"))
+	if errors.Is(err, os.ErrNotExist) {
+		return capture, ErrNotFound
+	}
+	if err != nil {
+		return capture, err
+	}
+	if err := json.Unmarshal(data, &capture); err != nil {
+		return capture, fmt.Errorf("invalid capture %s: %w", id, err)
+	}
+	return capture, nil
+}
+
+// ids returns the IDs of the kept captures, newest first. IDs start with the
+// capture time in nanoseconds, which has the same number of digits until 2286,
+// so they sort by time.
+func (s *Store) ids() ([]string, error) {
+	entries, err := os.ReadDir(s.dir)
+	if errors.Is(err, os.ErrNotExist) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	var ids []string
+	for _, entry := range entries {
+		if id, ok := strings.CutSuffix(entry.Name(), ".json"); ok && idPattern.MatchString(id) {
+			ids = append(ids, id)
+		}
+	}
+	slices.Sort(ids)
+	slices.Reverse(ids)
+	return ids, nil
+}
@@ -0,0 +1,174 @@
+package fixtures
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+)
+
+// Environment variables that select the fixture mode and directory
+const (
+	RecordEnv = "HYPERTERSE_RECORD"
+	ReplayEnv = "HYPERTERSE_REPLAY"
+	DirEnv    = "HYPERTERSE_FIXTURES"
+)
+
+// DefaultDir is where fixtures are kept when HYPERTERSE_FIXTURES is not set
+const DefaultDir = ".hyperterse/fixtures"
+
+// Mode is what the executor does with fixtures
+type Mode int
+
+const (
+	// ModeRecord saves the results of every query execution
+	ModeRecord Mode = iota + 1
+	// ModeReplay serves saved results without connecting to adapters
+	ModeReplay
+)
+
+func (m Mode) String() string {
+	switch m {
+	case ModeRecord:
+		return "record"
+	case ModeReplay:
+		return "replay"
+	default:
+		return "off"
+	}
+}
+
+// ErrNotFound is returned by Load when no fixture was recorded for a query and its inputs
+var ErrNotFound = errors.New("fixture not found")
+
+// Store reads and writes fixtures under a directory. Each fixture is a JSON
+// file at <dir>/<query>/<key>.json, where key is a hash of the inputs.
+type Store struct {
+	Mode Mode
+	dir  string
+}
+
+// fixture is the file format of a recorded query execution. The inputs are
+// kept so fixtures can be told apart and edited by hand.
+type fixture struct {
+	Query   string           `json:"query"`
+	Inputs  map[string]any   `json:"inputs"`
+	Results []map[string]any `json:"results"`
+}
+
+// FromEnv returns the store selected by HYPERTERSE_RECORD or HYPERTERSE_REPLAY,
+// or nil when neither is set to 1.
+func FromEnv() (*Store, error) {
+	record := os.Getenv(RecordEnv) == "1"
+	replay := os.Getenv(ReplayEnv) == "1"
+	if record && replay {
+		return nil, fmt.Errorf("%s and %s cannot both be set", RecordEnv, ReplayEnv)
+	}
+
+	dir := os.Getenv(DirEnv)
+	if dir == "" {
+		dir = DefaultDir
+	}
+	switch {
+	case record:
+		return NewStore(ModeRecord, dir), nil
+	case replay:
+		return NewStore(ModeReplay, dir), nil
+	default:
+		return nil, nil
+	}
+}
+
+// NewStore creates a store for fixtures under dir
+func NewStore(mode Mode, dir string) *Store {
+	return &Store{Mode: mode, dir: dir}
+}
+
+// Dir returns the directory fixtures are kept in
+func (s *Store) Dir() string {
+	return s.dir
+}
+
+// Replaying reports whether queries are served from fixtures. It is safe to
+// call on a nil store.
+func (s *Store) Replaying() bool {
+	return s != nil && s.Mode == ModeReplay
+}
+
+// Recording reports whether query results are saved as fixtures. It is safe
+// to call on a nil store.
+func (s *Store) Recording() bool {
+	return s != nil && s.Mode == ModeRecord
+}
+
+// Load returns the results recorded for query with inputs
+func (s *Store) Load(query string, inputs map[string]any) ([]map[string]any, error) {
+	path, err := s.path(query, inputs)
+	if err != nil {
+		return nil, err
+	}
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return nil, fmt.Errorf("%w for query '%s' at %s", ErrNotFound, query, path)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	var f fixture
+	if err := json.Unmarshal(data, &f); err != nil {
+		return nil, fmt.Errorf("invalid fixture %s: %w", path, err)
+	}
+	if f.Results == nil {
+		f.Results = []map[string]any{}
+	}
+	return f.Results, nil
+}
+
+// Save records the results of query with inputs, replacing any earlier fixture
+func (s *Store) Save(query string, inputs map[string]any, results []map[string]any) error {
+	path, err := s.path(query, inputs)
+	if err != nil {
+		return err
+	}
+	data, err := json.MarshalIndent(fixture{Query: query, Inputs: inputs, Results: results}, "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+
+	// Write to a temporary file first so a concurrent Load never reads half a fixture
+	tmp, err := os.CreateTemp(filepath.Dir(path), ".fixture-*")
+	if err != nil {
+		return err
+	}
+	if _, err := tmp.Write(append(data, '\n')); err != nil {
+		tmp.Close()
+		os.Remove(tmp.Name())
+		return err
+	}
+	if err := tmp.Close(); err != nil {
+		os.Remove(tmp.Name())
+		return err
+	}
+	return os.Rename(tmp.Name(), path)
+}
+
+// path returns the fixture file of query with inputs. Maps are encoded with
+// sorted keys, so equal inputs always hash to the same file.
+func (s *Store) path(query string, inputs map[string]any) (string, error) {
+	if inputs == nil {
+		inputs = map[string]any{}
+	}
+	encoded, err := json.Marshal(inputs)
+	if err != nil {
+		return "", fmt.Errorf("failed to encode inputs of query '%s': %w", query, err)
+	}
+	sum := sha256.Sum256(encoded)
+	return filepath.Join(s.dir, query, hex.EncodeToString(sum[:8])+".json"), nil
+}
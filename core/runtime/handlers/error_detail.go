@@ -0,0 +1,32 @@
+package handlers
+
+import (
+	"errors"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// ErrorMessage returns the message reported to clients for a failed query.
+// With server.error_detail: full it is the error itself. Otherwise errors
+// raised by the database are replaced with a message naming only their class,
+// while errors in the request or the query's configuration are kept.
+func ErrorMessage(model *hyperterse.Model, err error) string {
+	if model.Server != nil && model.Server.ErrorDetail == types.ErrorDetailFull {
+		return err.Error()
+	}
+	var execErr *executor.ExecutionError
+	if !errors.As(err, &execErr) {
+		return err.Error()
+	}
+	switch connectors.ClassifyError(err) {
+	case connectors.ErrorClassTimeout:
+		return "query execution failed: timed out"
+	case connectors.ErrorClassTransient, connectors.ErrorClassUnavailable:
+		return "query execution failed: database unavailable"
+	default:
+		return "query execution failed"
+	}
+}
@@ -5,24 +5,28 @@ import (
 	"encoding/json"
 	"fmt"
 	"strings"
+	"time"
 
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/observability"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
 	"github.com/hyperterse/hyperterse/core/proto/runtime"
 	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
 	"github.com/hyperterse/hyperterse/core/types"
 )
 
 // QueryServiceHandler implements the QueryService
 type QueryServiceHandler struct {
 	executor *executor.Executor
+	model    *hyperterse.Model
 }
 
 // NewQueryServiceHandler creates a new QueryService handler
-func NewQueryServiceHandler(exec *executor.Executor) *QueryServiceHandler {
+func NewQueryServiceHandler(exec *executor.Executor, model *hyperterse.Model) *QueryServiceHandler {
 	return &QueryServiceHandler{
 		executor: exec,
+		model:    model,
 	}
 }
 
@@ -51,29 +55,29 @@ func (h *QueryServiceHandler) ExecuteQuery(ctx context.Context, req *runtime.Exe
 	}
 
 	// Execute the query with context for cancellation support
-	results, err := h.executor.ExecuteQuery(ctx, req.QueryName, inputs)
+	result, err := h.executor.Execute(ctx, req.QueryName, inputs)
 	if err != nil {
 		log.WarnfCtx(ctx, map[string]any{
 			observability.AttrQueryName: req.QueryName,
 		}, "Query execution failed: %v", err)
 		return &runtime.ExecuteQueryResponse{
 			Success: false,
-			Error:   err.Error(),
+			Error:   ErrorMessage(h.model, err),
 			Results: nil,
 		}, nil
 	}
 
 	log.DebugfCtx(ctx, map[string]any{
 		observability.AttrQueryName: req.QueryName,
-	}, "Query executed successfully, converting %d result(s) to proto format", len(results))
+	}, "Query executed successfully, converting %d result(s) to proto format", len(result.Rows))
 
 	// Convert results to proto format
-	protoResults := make([]*runtime.ResultRow, len(results))
-	for i, row := range results {
+	protoResults := make([]*runtime.ResultRow, len(result.Rows))
+	for i, row := range result.Rows {
 		fields := make(map[string]string)
 		for key, value := range row {
 			// Convert value to JSON string
-			valueJSON, err := json.Marshal(value)
+			valueJSON, err := utils.AppendJSONValue(nil, value)
 			if err != nil {
 				valueJSON = fmt.Appendf(nil, "%v", value)
 			}
@@ -87,17 +91,37 @@ func (h *QueryServiceHandler) ExecuteQuery(ctx context.Context, req *runtime.Exe
 	log.InfofCtx(ctx, map[string]any{
 		observability.AttrQueryName: req.QueryName,
 	}, "Query execution completed successfully")
-	return &runtime.ExecuteQueryResponse{
-		Success: true,
-		Error:   "",
-		Results: protoResults,
-	}, nil
+	response := &runtime.ExecuteQueryResponse{
+		Success:    true,
+		Error:      "",
+		Results:    protoResults,
+		Truncated:  result.Truncated,
+		NextCursor: result.NextCursor,
+	}
+	response.Meta = BuildResponseMeta(h.model, req.QueryName, result)
+	return response, nil
+}
+
+// BuildResponseMeta returns execution metadata for a query result, or nil when
+// server.include_meta is disabled
+func BuildResponseMeta(model *hyperterse.Model, queryName string, result *executor.QueryResult) *runtime.ResponseMeta {
+	if model == nil || model.Server == nil || !model.Server.IncludeMeta {
+		return nil
+	}
+	return &runtime.ResponseMeta{
+		QueryName:  queryName,
+		DurationMs: float64(result.Duration.Microseconds()) / 1000,
+		RowCount:   int32(len(result.Rows)),
+		CacheHit:   result.CacheHit,
+	}
 }
 
 // MCPServiceHandler implements the MCPService
 type MCPServiceHandler struct {
-	executor *executor.Executor
-	model    *hyperterse.Model
+	executor    *executor.Executor
+	model       *hyperterse.Model
+	logging     *MCPLogging
+	maintenance *Maintenance
 }
 
 // NewMCPServiceHandler creates a new MCPService handler
@@ -108,6 +132,17 @@ func NewMCPServiceHandler(exec *executor.Executor, model *hyperterse.Model) *MCP
 	}
 }
 
+// SetLogging enables the MCP logging capability, delivering log notifications
+// through logging
+func (h *MCPServiceHandler) SetLogging(logging *MCPLogging) {
+	h.logging = logging
+}
+
+// SetMaintenance makes tool calls fail while maintenance mode is on
+func (h *MCPServiceHandler) SetMaintenance(maintenance *Maintenance) {
+	h.maintenance = maintenance
+}
+
 // ListTools returns all available queries as MCP tools
 func (h *MCPServiceHandler) ListTools(ctx context.Context, req *runtime.ListToolsRequest) (*runtime.ListToolsResponse, error) {
 	log := logger.New("mcp")
@@ -125,6 +160,15 @@ func (h *MCPServiceHandler) ListTools(ctx context.Context, req *runtime.ListTool
 				Description:  input.Description,
 				Optional:     input.Optional,
 				DefaultValue: input.DefaultValue,
+				Enum:         input.Enum,
+				Examples:     input.Examples,
+			}
+		}
+		if query.Cursor != nil {
+			toolInputs[utils.CursorParam] = &runtime.ToolInput{
+				Type:        "string",
+				Description: fmt.Sprintf("next_cursor of the previous page, in place of %s", query.Cursor.Input),
+				Optional:    true,
 			}
 		}
 
@@ -132,6 +176,7 @@ func (h *MCPServiceHandler) ListTools(ctx context.Context, req *runtime.ListTool
 			Name:        query.Name,
 			Description: query.Description,
 			Inputs:      toolInputs,
+			CacheTtl:    int32(h.executor.CacheTTL(query).Seconds()),
 		})
 		log.DebugfCtx(ctx, map[string]any{
 			observability.AttrQueryName: query.Name,
@@ -154,6 +199,17 @@ func (h *MCPServiceHandler) CallTool(ctx context.Context, req *runtime.CallToolR
 		observability.AttrQueryName: req.Name,
 	}, "Argument count: %d", len(req.Arguments))
 
+	if status := h.maintenance.Status(); status.Enabled {
+		log.WarnfCtx(ctx, map[string]any{
+			observability.AttrQueryName: req.Name,
+		}, "Tool call refused in maintenance mode")
+		errorJSON, _ := json.Marshal(map[string]string{"error": status.Message})
+		return &runtime.CallToolResponse{
+			Content: string(errorJSON),
+			IsError: true,
+		}, nil
+	}
+
 	// Parse arguments from JSON strings
 	// Arguments are stored as JSON-encoded strings (e.g., "\"pending\"" for string "pending")
 	inputs := make(map[string]any)
@@ -185,12 +241,12 @@ func (h *MCPServiceHandler) CallTool(ctx context.Context, req *runtime.CallToolR
 	}
 
 	// Execute the query with context for cancellation support
-	results, err := h.executor.ExecuteQuery(ctx, req.Name, inputs)
+	result, err := h.executor.Execute(ctx, req.Name, inputs)
 	if err != nil {
 		log.WarnfCtx(ctx, map[string]any{
 			observability.AttrQueryName: req.Name,
 		}, "Tool execution failed: %v", err)
-		errorJSON, _ := json.Marshal(map[string]string{"error": err.Error()})
+		errorJSON, _ := json.Marshal(map[string]string{"error": ErrorMessage(h.model, err)})
 		return &runtime.CallToolResponse{
 			Content: string(errorJSON),
 			IsError: true,
@@ -199,10 +255,10 @@ func (h *MCPServiceHandler) CallTool(ctx context.Context, req *runtime.CallToolR
 
 	log.DebugfCtx(ctx, map[string]any{
 		observability.AttrQueryName: req.Name,
-	}, "Tool executed successfully, marshaling %d result(s)", len(results))
+	}, "Tool executed successfully, marshaling %d result(s)", len(result.Rows))
 
 	// Convert results to JSON
-	resultsJSON, err := json.Marshal(results)
+	resultsJSON, err := utils.NewRowSet(result.Rows).MarshalJSON()
 	if err != nil {
 		log.WarnfCtx(ctx, map[string]any{
 			observability.AttrQueryName: req.Name,
@@ -220,8 +276,13 @@ func (h *MCPServiceHandler) CallTool(ctx context.Context, req *runtime.CallToolR
 	log.InfofCtx(ctx, map[string]any{
 		observability.AttrQueryName: req.Name,
 	}, "MCP tool call completed successfully")
-	return &runtime.CallToolResponse{
-		Content: string(resultsJSON),
-		IsError: false,
-	}, nil
+	response := &runtime.CallToolResponse{
+		Content:    string(resultsJSON),
+		IsError:    false,
+		NextCursor: result.NextCursor,
+	}
+	if !result.ExpiresAt.IsZero() {
+		response.ExpiresAt = result.ExpiresAt.UTC().Format(time.RFC3339)
+	}
+	return response, nil
 }
@@ -0,0 +1,169 @@
+package handlers
+
+import (
+	"context"
+	"encoding/json"
+	"reflect"
+	"sort"
+	"testing"
+
+	"github.com/hyperterse/hyperterse/core/parser"
+	"github.com/hyperterse/hyperterse/core/proto/runtime"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+)
+
+const schemaParityConfig = `
+name: schema-parity
+adapters:
+  main:
+    connector: postgres
+    connection_string: postgres://localhost/app
+queries:
+  list-orders:
+    use: main
+    description: Orders of a customer
+    statement: |
+      SELECT id, status FROM orders
+      WHERE customer_id = {{ inputs.customerId }} AND status = {{ inputs.status }}
+        AND placed_at > {{ inputs.since }} AND id > {{ inputs.after }}
+        AND total > {{ inputs.minTotal }} AND gift = {{ inputs.gift }}
+        AND ref = {{ inputs.ref }}
+      ORDER BY id
+    max_rows: 50
+    cursor:
+      key: id
+      input: after
+    inputs:
+      customerId:
+        type: int
+        description: Customer identifier
+        examples: ['42']
+      status:
+        type: string
+        optional: true
+        default: open
+        enum: [open, closed]
+      since:
+        type: datetime
+        optional: true
+      after:
+        type: int
+        optional: true
+        default: '0'
+      minTotal:
+        type: float
+        optional: true
+      gift:
+        type: boolean
+        optional: true
+      ref:
+        type: uuid
+        optional: true
+  ping:
+    use: main
+    description: Check the database
+    statement: SELECT 1
+`
+
+// withoutExamples returns a schema property with its examples removed, since
+// OpenAPI adds an example of the type to inputs that declare none
+func withoutExamples(prop any) any {
+	m, ok := prop.(map[string]any)
+	if !ok {
+		return prop
+	}
+	stripped := make(map[string]any, len(m))
+	for key, value := range m {
+		if key != "example" && key != "examples" {
+			stripped[key] = value
+		}
+	}
+	return stripped
+}
+
+// roundTrip returns v as decoded from its JSON encoding
+func roundTrip(t *testing.T, v any) map[string]any {
+	t.Helper()
+	encoded, err := json.Marshal(v)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var decoded map[string]any
+	if err := json.Unmarshal(encoded, &decoded); err != nil {
+		t.Fatal(err)
+	}
+	return decoded
+}
+
+func sortedStrings(v any) []string {
+	list, _ := v.([]any)
+	out := make([]string, 0, len(list))
+	for _, item := range list {
+		out = append(out, item.(string))
+	}
+	sort.Strings(out)
+	return out
+}
+
+func TestToolInputSchemaMatchesOpenAPIRequestSchema(t *testing.T) {
+	model, err := parser.ParseYAML([]byte(schemaParityConfig))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := parser.Validate(model); err != nil {
+		t.Fatal(err)
+	}
+
+	specJSON, err := GenerateOpenAPISpec(model, "http://localhost:8080", OpenAPIVersion31)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var spec map[string]any
+	if err := json.Unmarshal(specJSON, &spec); err != nil {
+		t.Fatal(err)
+	}
+
+	tools, err := NewMCPServiceHandler(executor.NewExecutor(model, nil), model).ListTools(context.Background(), &runtime.ListToolsRequest{})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(tools.Tools) != len(model.Queries) {
+		t.Fatalf("ListTools() returned %d tools, want %d", len(tools.Tools), len(model.Queries))
+	}
+
+	for _, tool := range tools.Tools {
+		mcpSchema := roundTrip(t, toolInputSchema(tool))
+
+		post := spec["paths"].(map[string]any)["/query/"+tool.Name].(map[string]any)["post"].(map[string]any)
+		openAPISchema := post["requestBody"].(map[string]any)["content"].(map[string]any)["application/json"].(map[string]any)["schema"].(map[string]any)
+
+		if mcpSchema["type"] != openAPISchema["type"] {
+			t.Errorf("%s: type = %v, OpenAPI has %v", tool.Name, mcpSchema["type"], openAPISchema["type"])
+		}
+		if mcp, openAPI := sortedStrings(mcpSchema["required"]), sortedStrings(openAPISchema["required"]); !reflect.DeepEqual(mcp, openAPI) {
+			t.Errorf("%s: required = %v, OpenAPI has %v", tool.Name, mcp, openAPI)
+		}
+
+		mcpProps := mcpSchema["properties"].(map[string]any)
+		openAPIProps := openAPISchema["properties"].(map[string]any)
+		if len(mcpProps) != len(openAPIProps) {
+			t.Errorf("%s: %d properties, OpenAPI has %d", tool.Name, len(mcpProps), len(openAPIProps))
+		}
+		for name, openAPIProp := range openAPIProps {
+			mcpProp, ok := mcpProps[name]
+			if !ok {
+				t.Errorf("%s: property %s is missing from the MCP schema", tool.Name, name)
+				continue
+			}
+			if !reflect.DeepEqual(withoutExamples(mcpProp), withoutExamples(openAPIProp)) {
+				t.Errorf("%s: property %s = %v, OpenAPI has %v", tool.Name, name, mcpProp, openAPIProp)
+			}
+			// Declared examples appear in both
+			if examples, ok := mcpProp.(map[string]any)["examples"]; ok {
+				if openAPIExamples := openAPIProp.(map[string]any)["examples"]; !reflect.DeepEqual(examples, openAPIExamples) {
+					t.Errorf("%s: property %s examples = %v, OpenAPI has %v", tool.Name, name, examples, openAPIExamples)
+				}
+			}
+		}
+	}
+}
@@ -4,11 +4,11 @@ import (
 	"context"
 	"encoding/json"
 	"fmt"
-	"strconv"
 
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/observability"
 	"github.com/hyperterse/hyperterse/core/proto/runtime"
+	"github.com/hyperterse/hyperterse/core/types"
 )
 
 // JSONRPCRequest represents a JSON-RPC 2.0 request
@@ -43,34 +43,6 @@ const (
 	JSONRPCInternalError  = -32603
 )
 
-// parseDefaultValueForMCP parses a default value string to the appropriate JSON type
-// This ensures default values are properly typed in the MCP tool schema (e.g., strings are quoted, numbers are numeric)
-func parseDefaultValueForMCP(valueStr, typ string) any {
-	switch typ {
-	case "int":
-		if val, err := strconv.ParseInt(valueStr, 10, 64); err == nil {
-			return val
-		}
-		// If parsing fails, return as string (fallback)
-		return valueStr
-	case "float":
-		if val, err := strconv.ParseFloat(valueStr, 64); err == nil {
-			return val
-		}
-		return valueStr
-	case "boolean":
-		if val, err := strconv.ParseBool(valueStr); err == nil {
-			return val
-		}
-		return valueStr
-	default:
-		// For string types, return as-is
-		// The value should already be a valid string (quoted or unquoted)
-		// When JSON marshaled, it will be properly quoted
-		return valueStr
-	}
-}
-
 // HandleJSONRPC handles JSON-RPC 2.0 requests for MCP protocol
 func HandleJSONRPC(ctx context.Context, mcpHandler *MCPServiceHandler, requestBody []byte) ([]byte, error) {
 	log := logger.New("mcp")
@@ -171,11 +143,15 @@ func HandleJSONRPC(ctx context.Context, mcpHandler *MCPServiceHandler, requestBo
 		log.DebugfCtx(ctx, nil, "Using protocol version: %s", protocolVersion)
 
 		// Return server capabilities
+		capabilities := map[string]any{
+			"tools": map[string]any{},
+		}
+		if mcpHandler.logging != nil {
+			capabilities["logging"] = map[string]any{}
+		}
 		result = map[string]any{
 			"protocolVersion": protocolVersion,
-			"capabilities": map[string]any{
-				"tools": map[string]any{},
-			},
+			"capabilities":    capabilities,
 			"serverInfo": map[string]any{
 				"name":    "hyperterse",
 				"version": "1.0.0",
@@ -216,42 +192,17 @@ func HandleJSONRPC(ctx context.Context, mcpHandler *MCPServiceHandler, requestBo
 					"description": tool.Description,
 				}
 
-				// Convert inputs to MCP format
-				// inputSchema must always be present as an object (even if empty)
-				inputsSchema := map[string]any{
-					"type":       "object",
-					"properties": make(map[string]any),
-					"required":   []string{},
-				}
+				// Always include inputSchema (required by MCP spec)
+				toolMap["inputSchema"] = toolInputSchema(tool)
 
-				if len(tool.Inputs) > 0 {
-					properties := inputsSchema["properties"].(map[string]any)
-					required := inputsSchema["required"].([]string)
-
-					for name, input := range tool.Inputs {
-						prop := map[string]any{
-							"type":        input.Type,
-							"description": input.Description,
-						}
-						if input.DefaultValue != "" {
-							// Parse default value according to type to ensure valid JSON
-							// This prevents issues where unquoted strings like "pending" become invalid JSON
-							// input.Type is already a string like "int", "string", etc. (from PrimitiveEnumToString)
-							parsedDefault := parseDefaultValueForMCP(input.DefaultValue, input.Type)
-							prop["default"] = parsedDefault
-						}
-						properties[name] = prop
-
-						if !input.Optional {
-							required = append(required, name)
-						}
+				// Cached queries hint how long a result stays fresh, so clients
+				// can skip repeating a call within that time
+				if tool.CacheTtl > 0 {
+					toolMap["annotations"] = map[string]any{
+						"cacheTtlSeconds": tool.CacheTtl,
 					}
-					inputsSchema["required"] = required
 				}
 
-				// Always include inputSchema (required by MCP spec)
-				toolMap["inputSchema"] = inputsSchema
-
 				tools[i] = toolMap
 			}
 			result = map[string]any{
@@ -340,12 +291,57 @@ func HandleJSONRPC(ctx context.Context, mcpHandler *MCPServiceHandler, requestBo
 				},
 			}
 
-			result = map[string]any{
+			toolResult := map[string]any{
 				"content": content,
 				"isError": resp.IsError,
 			}
+			if resp.ExpiresAt != "" {
+				toolResult["expiresAt"] = resp.ExpiresAt
+			}
+			if resp.NextCursor != "" {
+				toolResult["nextCursor"] = resp.NextCursor
+			}
+			result = toolResult
 		}
 
+	case "logging/setLevel":
+		var params struct {
+			Level string `json:"level"`
+		}
+		if err := json.Unmarshal(req.Params, &params); err != nil {
+			log.DebugfCtx(ctx, nil, "Failed to parse logging/setLevel params: %v", err)
+			jsonrpcErr = &JSONRPCError{
+				Code:    JSONRPCInvalidParams,
+				Message: "Invalid params",
+				Data:    err.Error(),
+			}
+			break
+		}
+		if mcpHandler.logging == nil {
+			jsonrpcErr = &JSONRPCError{
+				Code:    JSONRPCMethodNotFound,
+				Message: fmt.Sprintf("Method not found: %s", req.Method),
+			}
+			break
+		}
+		sessionID := mcpSession(ctx)
+		if sessionID == "" {
+			jsonrpcErr = &JSONRPCError{
+				Code:    JSONRPCInvalidRequest,
+				Message: "Invalid Request: logging/setLevel requires an Mcp-Session-Id header",
+			}
+			break
+		}
+		if err := mcpHandler.logging.SetLevel(sessionID, params.Level); err != nil {
+			jsonrpcErr = &JSONRPCError{
+				Code:    JSONRPCInvalidParams,
+				Message: "Invalid params: " + err.Error(),
+			}
+			break
+		}
+		log.DebugfCtx(ctx, nil, "MCP log level set to %s", params.Level)
+		result = map[string]any{}
+
 	case "initialized":
 		// This is a notification (no response expected if no ID)
 		// According to MCP spec, after initialize response, client sends initialized notification
@@ -384,3 +380,27 @@ func HandleJSONRPC(ctx context.Context, mcpHandler *MCPServiceHandler, requestBo
 
 	return json.Marshal(response)
 }
+
+// toolInputSchema converts the inputs of a tool to the JSON Schema of its MCP
+// inputSchema, which must always be present as an object (even if empty)
+func toolInputSchema(tool *runtime.Tool) map[string]any {
+	properties := make(map[string]any, len(tool.Inputs))
+	required := []string{}
+	for name, input := range tool.Inputs {
+		// input.Type is already a string like "int", "string", etc. (from PrimitiveEnumToString)
+		prop := types.InputSchema(input.Type, input.Description, input.DefaultValue, input.Enum)
+		if examples := types.ExampleValues(input.Examples, input.Type); len(examples) > 0 {
+			prop["examples"] = examples
+		}
+		properties[name] = prop
+
+		if !input.Optional {
+			required = append(required, name)
+		}
+	}
+	return map[string]any{
+		"type":       "object",
+		"properties": properties,
+		"required":   required,
+	}
+}
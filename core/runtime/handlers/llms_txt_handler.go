@@ -1,17 +1,21 @@
 package handlers
 
 import (
+	"encoding/json"
 	"fmt"
 	"net/http"
 	"strings"
 
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
 	"github.com/hyperterse/hyperterse/core/types"
 )
 
 // GenerateLLMDocumentation generates markdown documentation for LLMs
 func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 	var sb strings.Builder
+	// Every route is mounted under server.base_path
+	basePath := model.GetServer().GetBasePath()
 
 	// Header
 	sb.WriteString("# Hyperterse Runtime API Documentation\n\n")
@@ -29,18 +33,18 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 	if len(model.Queries) > 0 {
 		sb.WriteString("#### Query Endpoints\n\n")
 		for _, query := range model.Queries {
-			endpointPath := fmt.Sprintf("/query/%s", query.Name)
+			endpointPath := fmt.Sprintf("%s/query/%s", basePath, query.Name)
 			sb.WriteString(fmt.Sprintf("- **POST** `%s` - %s\n", endpointPath, query.Description))
 		}
 		sb.WriteString("\n")
 	}
 
 	sb.WriteString("#### Utility Endpoints\n\n")
-	sb.WriteString("- **POST** `/mcp` - MCP Streamable HTTP endpoint for JSON-RPC 2.0 requests (requires `MCP-Protocol-Version` header)\n")
-	sb.WriteString("- **GET** `/mcp` - MCP Streamable HTTP endpoint for receiving server-initiated messages via SSE (requires `Accept: text/event-stream` header)\n")
-	sb.WriteString("- **DELETE** `/mcp` - MCP Streamable HTTP endpoint for session termination (requires `Mcp-Session-Id` header)\n")
-	sb.WriteString("- **GET** `/llms.txt` - This documentation\n")
-	sb.WriteString("- **GET** `/docs` - OpenAPI 3.0 specification\n\n")
+	sb.WriteString(fmt.Sprintf("- **POST** `%s/mcp` - MCP Streamable HTTP endpoint for JSON-RPC 2.0 requests (requires `MCP-Protocol-Version` header)\n", basePath))
+	sb.WriteString(fmt.Sprintf("- **GET** `%s/mcp` - MCP Streamable HTTP endpoint for receiving server-initiated messages via SSE (requires `Accept: text/event-stream` header)\n", basePath))
+	sb.WriteString(fmt.Sprintf("- **DELETE** `%s/mcp` - MCP Streamable HTTP endpoint for session termination (requires `Mcp-Session-Id` header)\n", basePath))
+	sb.WriteString(fmt.Sprintf("- **GET** `%s/llms.txt` - This documentation\n", basePath))
+	sb.WriteString(fmt.Sprintf("- **GET** `%s/docs` - OpenAPI 3.0 specification\n\n", basePath))
 
 	// Queries Section
 	sb.WriteString("## Available Queries\n\n")
@@ -91,6 +95,10 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 				sb.WriteString("| Name | Type | Description |\n")
 				sb.WriteString("|------|------|-------------|\n")
 
+				keyCase := ""
+				if model.Server != nil {
+					keyCase = model.Server.ResultKeyCase
+				}
 				for _, data := range query.Data {
 					description := "-"
 					if data.Description != "" {
@@ -103,13 +111,13 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 					}
 
 					sb.WriteString(fmt.Sprintf("| `%s` | `%s` | %s%s |\n",
-						data.Name, types.PrimitiveEnumToString(data.Type), description, mapTo))
+						utils.ConvertKey(data.Name, keyCase), types.PrimitiveEnumToString(data.Type), description, mapTo))
 				}
 				sb.WriteString("\n")
 			}
 
 			// Endpoint Information
-			endpointPath := fmt.Sprintf("/query/%s", query.Name)
+			endpointPath := fmt.Sprintf("%s/query/%s", basePath, query.Name)
 			sb.WriteString(fmt.Sprintf("**Endpoint:** `POST %s%s`\n\n", baseURL, endpointPath))
 
 			// Usage Example
@@ -122,7 +130,7 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 			if len(query.Inputs) > 0 {
 				exampleInputs := make([]string, 0)
 				for _, input := range query.Inputs {
-					exampleValue := getExampleValue(input.Type.String())
+					exampleValue := getExampleValue(input)
 					exampleInputs = append(exampleInputs, fmt.Sprintf("\"%s\": %s", input.Name, exampleValue))
 				}
 				sb.WriteString("{" + strings.Join(exampleInputs, ", ") + "}")
@@ -139,7 +147,7 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 			if len(query.Inputs) > 0 {
 				exampleInputs := make([]string, 0)
 				for _, input := range query.Inputs {
-					exampleValue := getExampleValue(input.Type.String())
+					exampleValue := getExampleValue(input)
 					exampleInputs = append(exampleInputs, fmt.Sprintf("  \"%s\": %s", input.Name, exampleValue))
 				}
 				sb.WriteString(strings.Join(exampleInputs, ",\n"))
@@ -160,14 +168,14 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 
 	if len(model.Queries) > 0 {
 		exampleQuery := model.Queries[0]
-		examplePath := fmt.Sprintf("/query/%s", exampleQuery.Name)
+		examplePath := fmt.Sprintf("%s/query/%s", basePath, exampleQuery.Name)
 		sb.WriteString("**Example:**\n\n")
 		sb.WriteString("```bash\n")
 		sb.WriteString(fmt.Sprintf("curl -X POST %s%s \\\n", baseURL, examplePath))
 		sb.WriteString("  -H \"Content-Type: application/json\" \\\n")
 		if len(exampleQuery.Inputs) > 0 {
 			exampleInput := exampleQuery.Inputs[0]
-			exampleValue := getExampleValue(exampleInput.Type.String())
+			exampleValue := getExampleValue(exampleInput)
 			sb.WriteString(fmt.Sprintf("  -d '{\"%s\": %s}'\n", exampleInput.Name, exampleValue))
 		} else {
 			sb.WriteString("  -d '{}'\n")
@@ -177,38 +185,27 @@ func GenerateLLMDocumentation(model *hyperterse.Model, baseURL string) string {
 
 	sb.WriteString("### Using MCP Protocol (Streamable HTTP Transport)\n\n")
 	sb.WriteString("The MCP protocol uses Streamable HTTP transport for JSON-RPC 2.0 messages.\n")
-	sb.WriteString("Client sends JSON-RPC requests via POST to `/mcp`, server responds with JSON or SSE stream.\n")
-	sb.WriteString("Server-initiated messages are received via GET request to `/mcp` (SSE stream).\n\n")
+	sb.WriteString(fmt.Sprintf("Client sends JSON-RPC requests via POST to `%s/mcp`, server responds with JSON or SSE stream.\n", basePath))
+	sb.WriteString(fmt.Sprintf("Server-initiated messages are received via GET request to `%s/mcp` (SSE stream).\n\n", basePath))
 	sb.WriteString("```bash\n")
 	sb.WriteString("# Send initialize request\n")
-	sb.WriteString("curl -X POST http://localhost:8080/mcp \\\n")
+	sb.WriteString(fmt.Sprintf("curl -X POST http://localhost:8080%s/mcp \\\n", basePath))
 	sb.WriteString("  -H \"Content-Type: application/json\" \\\n")
 	sb.WriteString("  -H \"MCP-Protocol-Version: 2025-03-26\" \\\n")
 	sb.WriteString("  -H \"Accept: application/json, text/event-stream\" \\\n")
 	sb.WriteString("  -d '{\"jsonrpc\": \"2.0\", \"method\": \"initialize\", \"params\": {\"protocolVersion\": \"2025-03-26\", \"capabilities\": {}, \"clientInfo\": {\"name\": \"client\", \"version\": \"1.0.0\"}}, \"id\": 1}'\n\n")
 	sb.WriteString("# Open SSE stream for server-initiated messages\n")
-	sb.WriteString("curl -N -H \"Accept: text/event-stream\" http://localhost:8080/mcp\n")
+	sb.WriteString(fmt.Sprintf("curl -N -H \"Accept: text/event-stream\" http://localhost:8080%s/mcp\n", basePath))
 	sb.WriteString("```\n\n")
 
 	return sb.String()
 }
 
-// getExampleValue returns an example JSON value for a given type
-func getExampleValue(typ string) string {
-	switch typ {
-	case "string":
-		return `"example"`
-	case "int":
-		return "42"
-	case "float":
-		return "3.14"
-	case "boolean":
-		return "true"
-	case "datetime":
-		return `"2024-01-01T00:00:00Z"`
-	default:
-		return `"example"`
-	}
+// getExampleValue returns an example JSON value for an input, preferring its
+// first declared example
+func getExampleValue(input *hyperterse.Input) string {
+	example, _ := json.Marshal(types.InputExample(input.Examples, types.PrimitiveEnumToString(input.Type)))
+	return string(example)
 }
 
 // LLMTxtHandler handles requests to /llms.txt
@@ -0,0 +1,50 @@
+package handlers
+
+import (
+	"sync/atomic"
+)
+
+// DefaultMaintenanceMessage is the error returned in maintenance mode when
+// server.maintenance.message is unset
+const DefaultMaintenanceMessage = "service is under maintenance, please retry later"
+
+// MaintenanceStatus is the state of maintenance mode
+type MaintenanceStatus struct {
+	Enabled    bool   `json:"enabled"`
+	Message    string `json:"message,omitempty"`
+	RetryAfter int32  `json:"retry_after,omitempty"` // Seconds sent in the Retry-After header
+}
+
+// Maintenance switches maintenance mode, in which query endpoints and MCP
+// tool calls are refused while health checks and docs keep serving. One
+// instance is shared by every serving state, so the mode survives reloads.
+type Maintenance struct {
+	status atomic.Pointer[MaintenanceStatus]
+}
+
+// NewMaintenance creates a maintenance switch, initially off
+func NewMaintenance() *Maintenance {
+	return &Maintenance{}
+}
+
+// Set replaces the maintenance state. An enabled state without a message uses
+// DefaultMaintenanceMessage.
+func (m *Maintenance) Set(status MaintenanceStatus) {
+	if !status.Enabled {
+		status = MaintenanceStatus{}
+	} else if status.Message == "" {
+		status.Message = DefaultMaintenanceMessage
+	}
+	m.status.Store(&status)
+}
+
+// Status returns the current maintenance state
+func (m *Maintenance) Status() MaintenanceStatus {
+	if m == nil {
+		return MaintenanceStatus{}
+	}
+	if status := m.status.Load(); status != nil {
+		return *status
+	}
+	return MaintenanceStatus{}
+}
@@ -0,0 +1,195 @@
+package handlers
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"slices"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// DefaultMCPLogLevel is the minimum level sent to sessions that have not called
+// logging/setLevel when server.mcp_log_level is unset
+const DefaultMCPLogLevel = "warning"
+
+// mcpLogLoggerName is the logger reported in notifications/message params
+const mcpLogLoggerName = "hyperterse"
+
+// mcpLogBuffer is how many log notifications may queue for a stream before
+// further ones are dropped
+const mcpLogBuffer = 64
+
+// mcpSessionKey is the context key holding the request's MCP session ID
+type mcpSessionKey struct{}
+
+// WithMCPSession returns a context carrying the MCP session ID of a request
+func WithMCPSession(ctx context.Context, sessionID string) context.Context {
+	return context.WithValue(ctx, mcpSessionKey{}, sessionID)
+}
+
+// mcpSession returns the MCP session ID stored by WithMCPSession, if any
+func mcpSession(ctx context.Context) string {
+	sessionID, _ := ctx.Value(mcpSessionKey{}).(string)
+	return sessionID
+}
+
+// mcpLogSeverity returns the position of level in types.MCPLogLevels
+func mcpLogSeverity(level string) (int, bool) {
+	severity := slices.Index(types.MCPLogLevels, level)
+	return severity, severity >= 0
+}
+
+// MCPLogging sends notifications/message log notifications to MCP sessions
+// with an open GET stream. Each session receives messages at or above the
+// level it set with logging/setLevel, or the default minimum level. Sessions
+// outlive model reloads, so a single MCPLogging belongs to the runtime.
+type MCPLogging struct {
+	mu           sync.Mutex
+	defaultLevel int
+	idleTTL      time.Duration
+	levels       map[string]*mcpLogSession
+	streams      map[chan []byte]string
+}
+
+// mcpLogSession is the level a session set with logging/setLevel, and when the
+// session was last seen
+type mcpLogSession struct {
+	level    int
+	lastSeen time.Time
+}
+
+// NewMCPLogging creates an MCPLogging with the given default minimum level,
+// or DefaultMCPLogLevel when level is empty. The level set by a session ends
+// with the session: when it is terminated, or once it has had no open stream
+// and made no request for idleTTL, the idle expiry of the session store.
+func NewMCPLogging(level string, idleTTL time.Duration) *MCPLogging {
+	l := &MCPLogging{
+		idleTTL: idleTTL,
+		levels:  make(map[string]*mcpLogSession),
+		streams: make(map[chan []byte]string),
+	}
+	l.SetDefaultLevel(level)
+	return l
+}
+
+// SetDefaultLevel changes the minimum level of sessions that have not called
+// logging/setLevel. An empty or unknown level resets it to DefaultMCPLogLevel.
+func (l *MCPLogging) SetDefaultLevel(level string) {
+	severity, ok := mcpLogSeverity(level)
+	if !ok {
+		severity, _ = mcpLogSeverity(DefaultMCPLogLevel)
+	}
+	l.mu.Lock()
+	l.defaultLevel = severity
+	l.mu.Unlock()
+}
+
+// SetLevel sets the minimum level of messages sent to a session
+func (l *MCPLogging) SetLevel(sessionID, level string) error {
+	severity, ok := mcpLogSeverity(level)
+	if !ok {
+		return fmt.Errorf("unknown log level '%s'; must be one of: %s", level, strings.Join(types.MCPLogLevels, ", "))
+	}
+	now := time.Now()
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	l.levels[sessionID] = &mcpLogSession{level: severity, lastSeen: now}
+	l.pruneLocked(now)
+	return nil
+}
+
+// Touch records activity of a session, keeping the level it set
+func (l *MCPLogging) Touch(sessionID string) {
+	l.mu.Lock()
+	if session, ok := l.levels[sessionID]; ok {
+		session.lastSeen = time.Now()
+	}
+	l.mu.Unlock()
+}
+
+// Subscribe returns a channel receiving the encoded notifications for a
+// session, and a function that unsubscribes and closes the channel
+func (l *MCPLogging) Subscribe(sessionID string) (<-chan []byte, func()) {
+	ch := make(chan []byte, mcpLogBuffer)
+	l.mu.Lock()
+	l.streams[ch] = sessionID
+	l.mu.Unlock()
+
+	var once sync.Once
+	return ch, func() {
+		once.Do(func() {
+			now := time.Now()
+			l.mu.Lock()
+			delete(l.streams, ch)
+			if session, ok := l.levels[sessionID]; ok {
+				session.lastSeen = now
+			}
+			l.pruneLocked(now)
+			l.mu.Unlock()
+			close(ch)
+		})
+	}
+}
+
+// pruneLocked drops the levels of sessions that have been idle for idleTTL
+// without an open stream. l.mu must be held.
+func (l *MCPLogging) pruneLocked(now time.Time) {
+	open := make(map[string]bool, len(l.streams))
+	for _, sessionID := range l.streams {
+		open[sessionID] = true
+	}
+	for sessionID, session := range l.levels {
+		if !open[sessionID] && now.Sub(session.lastSeen) > l.idleTTL {
+			delete(l.levels, sessionID)
+		}
+	}
+}
+
+// Forget drops the level set by a terminated session
+func (l *MCPLogging) Forget(sessionID string) {
+	l.mu.Lock()
+	delete(l.levels, sessionID)
+	l.mu.Unlock()
+}
+
+// Log sends a notifications/message notification to every subscribed session
+// whose minimum level is at or below level. Streams that have fallen behind
+// miss the message rather than blocking the caller.
+func (l *MCPLogging) Log(level string, data any) {
+	severity, ok := mcpLogSeverity(level)
+	if !ok {
+		return
+	}
+	message, err := json.Marshal(map[string]any{
+		"jsonrpc": "2.0",
+		"method":  "notifications/message",
+		"params": map[string]any{
+			"level":  level,
+			"logger": mcpLogLoggerName,
+			"data":   data,
+		},
+	})
+	if err != nil {
+		return
+	}
+
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	for ch, sessionID := range l.streams {
+		minimum := l.defaultLevel
+		if session, ok := l.levels[sessionID]; ok {
+			minimum = session.level
+		}
+		if severity < minimum {
+			continue
+		}
+		select {
+		case ch <- message:
+		default:
+		}
+	}
+}
@@ -3,16 +3,90 @@ package handlers
 import (
 	"encoding/json"
 	"fmt"
+	"maps"
 	"net/http"
-	"strconv"
+	"slices"
 	"strings"
 
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/types"
 	"github.com/pb33f/libopenapi"
 )
 
-// GenerateOpenAPISpec generates a complete OpenAPI 3.0 specification using libopenapi
-func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error) {
+// OpenAPI versions the spec can be generated for
+const (
+	OpenAPIVersion30 = "3.0"
+	OpenAPIVersion31 = "3.1"
+)
+
+// openAPIDialect writes schema keywords in the form an OpenAPI version expects.
+// 3.0 uses its own Schema Object, while 3.1 schemas are JSON Schema 2020-12:
+// examples is a list, single values use const, and null is a type.
+type openAPIDialect struct {
+	v31 bool
+}
+
+// example sets the example value of schema
+func (d openAPIDialect) example(schema map[string]any, value any) map[string]any {
+	if d.v31 {
+		schema["examples"] = []any{value}
+	} else {
+		schema["example"] = value
+	}
+	return schema
+}
+
+// examples sets several example values of schema. 3.0 schemas hold a single
+// example, so only the first is kept.
+func (d openAPIDialect) examples(schema map[string]any, values []any) map[string]any {
+	if d.v31 {
+		schema["examples"] = values
+	} else {
+		schema["example"] = values[0]
+	}
+	return schema
+}
+
+// constant restricts schema to a single value
+func (d openAPIDialect) constant(schema map[string]any, value any) map[string]any {
+	if d.v31 {
+		schema["const"] = value
+	} else {
+		schema["enum"] = []any{value}
+	}
+	return schema
+}
+
+// nullable allows null in addition to the types of schema
+func (d openAPIDialect) nullable(schema map[string]any) map[string]any {
+	if !d.v31 {
+		schema["nullable"] = true
+		return schema
+	}
+	switch typ := schema["type"].(type) {
+	case string:
+		schema["type"] = []any{typ, "null"}
+	case []any:
+		if !slices.Contains(typ, any("null")) {
+			schema["type"] = append(typ, "null")
+		}
+	}
+	return schema
+}
+
+// GenerateOpenAPISpec generates a complete OpenAPI specification using
+// libopenapi. version is OpenAPIVersion30 or OpenAPIVersion31; empty means 3.0.
+func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string, version string) ([]byte, error) {
+	var dialect openAPIDialect
+	switch version {
+	case "", OpenAPIVersion30:
+	case OpenAPIVersion31:
+		dialect.v31 = true
+	default:
+		return nil, fmt.Errorf("unsupported OpenAPI version '%s', use %s or %s", version, OpenAPIVersion30, OpenAPIVersion31)
+	}
+
 	// Build the OpenAPI spec as a map structure
 	spec := map[string]any{
 		"openapi": "3.0.0",
@@ -23,12 +97,16 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 		},
 		"servers": []map[string]any{
 			{
-				"url":         baseURL,
+				"url":         baseURL + model.GetServer().GetBasePath(),
 				"description": "Hyperterse Runtime Server",
 			},
 		},
 		"paths": make(map[string]any),
 	}
+	if dialect.v31 {
+		spec["openapi"] = "3.1.0"
+		spec["jsonSchemaDialect"] = "https://spec.openapis.org/oas/3.1/dialect/base"
+	}
 
 	paths := spec["paths"].(map[string]any)
 
@@ -41,17 +119,14 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 		required := []string{}
 
 		for _, input := range query.Inputs {
-			prop := map[string]any{
-				"type":        mapProtoTypeToOpenAPIType(input.Type.String()),
-				"description": input.Description,
-			}
-
-			// Add example value
-			prop["example"] = getExampleValueForOpenAPI(input.Type.String())
-
-			// Handle default value
-			if input.DefaultValue != "" {
-				prop["default"] = parseDefaultValue(input.DefaultValue, input.Type.String())
+			typ := types.PrimitiveEnumToString(input.Type)
+			prop := types.InputSchema(typ, input.Description, input.DefaultValue, input.Enum)
+
+			// Add example values, falling back to one for the type
+			if examples := types.ExampleValues(input.Examples, typ); len(examples) > 0 {
+				dialect.examples(prop, examples)
+			} else {
+				dialect.example(prop, types.SchemaForPrimitive(typ).Example)
 			}
 
 			properties[input.Name] = prop
@@ -62,6 +137,14 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 			}
 		}
 
+		// Queries with a cursor take the next_cursor of a previous page
+		if query.Cursor != nil {
+			properties[utils.CursorParam] = map[string]any{
+				"type":        "string",
+				"description": fmt.Sprintf("next_cursor of the previous page, in place of %s", query.Cursor.Input),
+			}
+		}
+
 		requestBodySchema := map[string]any{
 			"type":       "object",
 			"properties": properties,
@@ -75,73 +158,126 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 		responseSchema := map[string]any{
 			"type": "object",
 			"properties": map[string]any{
-				"success": map[string]any{
-					"type":    "boolean",
-					"example": true,
-				},
-				"error": map[string]any{
-					"type":    "string",
-					"example": "",
-				},
+				"success": dialect.example(map[string]any{"type": "boolean"}, true),
+				"error":   dialect.example(map[string]any{"type": "string"}, ""),
 				"results": map[string]any{
 					"type": "array",
 					"items": map[string]any{
-						"type": "object",
-						"additionalProperties": map[string]any{
-							"type": "string",
-						},
+						"type":                 "object",
+						"additionalProperties": dialect.nullable(map[string]any{"type": "string"}),
 					},
 				},
+				"truncated": map[string]any{
+					"type":        "boolean",
+					"description": "Present and true when results were cut off at the configured max_rows",
+				},
 			},
 		}
+		if query.Cursor != nil {
+			responseSchema["properties"].(map[string]any)["next_cursor"] = map[string]any{
+				"type":        "string",
+				"description": "Present when more rows follow; pass it as cursor to fetch the next page",
+			}
+		}
+		if model.Server != nil && model.Server.IncludeMeta {
+			responseSchema["properties"].(map[string]any)["meta"] = map[string]any{
+				"type":        "object",
+				"description": "Execution metadata",
+				"properties": map[string]any{
+					"query_name":  dialect.example(map[string]any{"type": "string"}, query.Name),
+					"duration_ms": map[string]any{"type": "number", "description": "Executor time in milliseconds"},
+					"row_count":   map[string]any{"type": "integer", "description": "Number of rows returned"},
+					"cache_hit":   map[string]any{"type": "boolean", "description": "Whether results were served from the query cache"},
+				},
+			}
+		}
 
 		// Add endpoint definition
-		paths[endpointPath] = map[string]any{
-			"post": map[string]any{
-				"summary":     query.Description,
-				"description": fmt.Sprintf("Execute the '%s' query. %s", query.Name, query.Description),
-				"operationId": "execute" + toPascalCase(query.Name),
-				"requestBody": map[string]any{
-					"required": true,
+		post := map[string]any{
+			"summary":     query.Description,
+			"description": fmt.Sprintf("Execute the '%s' query. %s", query.Name, query.Description),
+			"operationId": "execute" + toPascalCase(query.Name),
+			"requestBody": map[string]any{
+				"required": true,
+				"content": map[string]any{
+					"application/json": map[string]any{
+						"schema": requestBodySchema,
+					},
+				},
+			},
+			"responses": map[string]any{
+				"200": map[string]any{
+					"description": "Query executed successfully",
 					"content": map[string]any{
 						"application/json": map[string]any{
-							"schema": requestBodySchema,
+							"schema": responseSchema,
 						},
 					},
 				},
-				"responses": map[string]any{
-					"200": map[string]any{
-						"description": "Query executed successfully",
-						"content": map[string]any{
-							"application/json": map[string]any{
-								"schema": responseSchema,
-							},
-						},
-					},
-					"400": map[string]any{
-						"description": "Bad request - invalid input parameters",
-						"content": map[string]any{
-							"application/json": map[string]any{
-								"schema": map[string]any{
-									"type": "object",
-									"properties": map[string]any{
-										"success": map[string]any{"type": "boolean", "example": false},
-										"error":   map[string]any{"type": "string", "example": "validation error"},
-										"results": map[string]any{
-											"type":    "array",
-											"example": []any{},
-										},
-									},
+				"400": map[string]any{
+					"description": "Bad request - invalid input parameters",
+					"content": map[string]any{
+						"application/json": map[string]any{
+							"schema": map[string]any{
+								"type": "object",
+								"properties": map[string]any{
+									"success": dialect.example(map[string]any{"type": "boolean"}, false),
+									"error":   dialect.example(map[string]any{"type": "string"}, "validation error"),
+									"results": dialect.example(map[string]any{"type": "array"}, []any{}),
 								},
 							},
 						},
 					},
-					"500": map[string]any{
-						"description": "Internal server error",
-					},
+				},
+				"409": map[string]any{
+					"description": "Conflict - a write violated a unique constraint",
+				},
+				"422": map[string]any{
+					"description": "Unprocessable entity - a write violated a foreign key, not-null or check constraint",
+				},
+				"500": map[string]any{
+					"description": "Internal server error",
+				},
+				"503": map[string]any{
+					"description": "Service unavailable - the database is unreachable or the query hit a conflict; may succeed if retried",
+				},
+				"504": map[string]any{
+					"description": "Gateway timeout - the query timed out",
 				},
 			},
 		}
+
+		operations := map[string]any{"post": post}
+		paths[endpointPath] = operations
+
+		// GET takes the same inputs as inputs.* query parameters, and is only
+		// served for queries that read
+		if utils.IsReadOnlyQuery(model, query) {
+			parameters := []map[string]any{}
+			for _, input := range query.Inputs {
+				parameters = append(parameters, map[string]any{
+					"name":        utils.InputParamPrefix + input.Name,
+					"in":          "query",
+					"required":    !input.Optional,
+					"description": input.Description,
+					"schema":      properties[input.Name],
+				})
+			}
+			if query.Cursor != nil {
+				parameters = append(parameters, map[string]any{
+					"name":        utils.CursorParam,
+					"in":          "query",
+					"required":    false,
+					"description": "next_cursor of the previous page",
+					"schema":      properties[utils.CursorParam],
+				})
+			}
+			get := maps.Clone(post)
+			delete(get, "requestBody")
+			get["operationId"] = "get" + toPascalCase(query.Name)
+			get["parameters"] = parameters
+			operations["get"] = get
+		}
 	}
 
 	// Add MCP endpoint - Streamable HTTP transport
@@ -187,11 +323,10 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 						"schema": map[string]any{
 							"type": "object",
 							"properties": map[string]any{
-								"jsonrpc": map[string]any{
+								"jsonrpc": dialect.constant(map[string]any{
 									"type":        "string",
 									"description": "JSON-RPC version (must be '2.0')",
-									"enum":        []string{"2.0"},
-								},
+								}, "2.0"),
 								"method": map[string]any{
 									"type":        "string",
 									"description": "RPC method name",
@@ -200,10 +335,10 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 									"type":        "object",
 									"description": "Method parameters",
 								},
-								"id": map[string]any{
-									"type":        []any{"string", "number", "null"},
+								"id": dialect.nullable(map[string]any{
+									"type":        []any{"string", "number"},
 									"description": "Request ID (null or omitted for notifications)",
-								},
+								}),
 							},
 							"required": []string{"jsonrpc", "method"},
 						},
@@ -236,11 +371,10 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 					"in":          "header",
 					"required":    true,
 					"description": "Must be 'text/event-stream'",
-					"schema": map[string]any{
+					"schema": dialect.constant(map[string]any{
 						"type":    "string",
-						"enum":    []string{"text/event-stream"},
 						"default": "text/event-stream",
-					},
+					}, "text/event-stream"),
 				},
 			},
 			"responses": map[string]any{
@@ -304,15 +438,30 @@ func GenerateOpenAPISpec(model *hyperterse.Model, baseURL string) ([]byte, error
 	return specJSON, nil
 }
 
-// GenerateOpenAPISpecHandler returns an HTTP handler for the OpenAPI spec
+// GenerateOpenAPISpecHandler returns an HTTP handler for the OpenAPI spec. The
+// version query parameter overrides server.openapi_version.
 func GenerateOpenAPISpecHandler(model *hyperterse.Model, baseURL string) http.HandlerFunc {
+	defaultVersion := OpenAPIVersion30
+	if model.Server != nil && model.Server.OpenapiVersion != "" {
+		defaultVersion = model.Server.OpenapiVersion
+	}
+
 	return func(w http.ResponseWriter, req *http.Request) {
 		if req.Method != http.MethodGet {
 			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
 			return
 		}
 
-		specJSON, err := GenerateOpenAPISpec(model, baseURL)
+		version := req.URL.Query().Get("version")
+		if version == "" {
+			version = defaultVersion
+		}
+		if version != OpenAPIVersion30 && version != OpenAPIVersion31 {
+			http.Error(w, fmt.Sprintf("Unsupported OpenAPI version '%s', use %s or %s", version, OpenAPIVersion30, OpenAPIVersion31), http.StatusBadRequest)
+			return
+		}
+
+		specJSON, err := GenerateOpenAPISpec(model, baseURL, version)
 		if err != nil {
 			http.Error(w, fmt.Sprintf("Failed to generate OpenAPI spec: %v", err), http.StatusInternalServerError)
 			return
@@ -337,63 +486,6 @@ func GenerateOpenAPISpecHandler(model *hyperterse.Model, baseURL string) http.Ha
 	}
 }
 
-// mapProtoTypeToOpenAPIType converts a proto type to OpenAPI type
-func mapProtoTypeToOpenAPIType(protoType string) string {
-	switch protoType {
-	case "string", "datetime":
-		return "string"
-	case "int":
-		return "integer"
-	case "float":
-		return "number"
-	case "boolean":
-		return "boolean"
-	default:
-		return "string"
-	}
-}
-
-// getExampleValueForOpenAPI returns an example value for OpenAPI spec
-func getExampleValueForOpenAPI(typ string) any {
-	switch typ {
-	case "string":
-		return "example"
-	case "int":
-		return 42
-	case "float":
-		return 3.14
-	case "boolean":
-		return true
-	case "datetime":
-		return "2024-01-01T00:00:00Z"
-	default:
-		return "example"
-	}
-}
-
-// parseDefaultValue parses a default value string to the appropriate type
-func parseDefaultValue(valueStr, typ string) any {
-	switch typ {
-	case "int":
-		if val, err := strconv.ParseInt(valueStr, 10, 64); err == nil {
-			return val
-		}
-		return 0
-	case "float":
-		if val, err := strconv.ParseFloat(valueStr, 64); err == nil {
-			return val
-		}
-		return 0.0
-	case "boolean":
-		if val, err := strconv.ParseBool(valueStr); err == nil {
-			return val
-		}
-		return false
-	default:
-		return valueStr
-	}
-}
-
 // toPascalCase converts a string to PascalCase
 func toPascalCase(s string) string {
 	if s == "" {
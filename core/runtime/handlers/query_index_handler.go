@@ -0,0 +1,67 @@
+package handlers
+
+import (
+	"encoding/json"
+	"net/http"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// QueryIndexEntry describes one query in the GET /query index
+type QueryIndexEntry struct {
+	Name        string         `json:"name"`
+	Description string         `json:"description"`
+	Endpoint    string         `json:"endpoint"`
+	Methods     []string       `json:"methods"`
+	InputSchema map[string]any `json:"input_schema"`
+}
+
+// BuildQueryIndex describes every query with a JSON Schema of its inputs, in
+// the order the queries are defined
+func BuildQueryIndex(model *hyperterse.Model) []QueryIndexEntry {
+	entries := make([]QueryIndexEntry, 0, len(model.Queries))
+	for _, query := range model.Queries {
+		properties := make(map[string]any)
+		required := []string{}
+		for _, input := range query.Inputs {
+			typ := types.PrimitiveEnumToString(input.Type)
+			prop := types.InputSchema(typ, input.Description, input.DefaultValue, input.Enum)
+			if examples := types.ExampleValues(input.Examples, typ); len(examples) > 0 {
+				prop["examples"] = examples
+			}
+			properties[input.Name] = prop
+			if !input.Optional {
+				required = append(required, input.Name)
+			}
+		}
+
+		entries = append(entries, QueryIndexEntry{
+			Name:        query.Name,
+			Description: query.Description,
+			Endpoint:    model.GetServer().GetBasePath() + "/query/" + query.Name,
+			Methods:     []string{http.MethodGet, http.MethodPost},
+			InputSchema: map[string]any{
+				"type":                 "object",
+				"properties":           properties,
+				"required":             required,
+				"additionalProperties": false,
+			},
+		})
+	}
+	return entries
+}
+
+// QueryIndexHandler returns an HTTP handler listing every query and its inputs
+func QueryIndexHandler(model *hyperterse.Model) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		if req.Method != http.MethodGet {
+			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusOK)
+		json.NewEncoder(w).Encode(map[string]any{"queries": BuildQueryIndex(model)})
+	}
+}
@@ -0,0 +1,303 @@
+package hooks
+
+import (
+	"bytes"
+	"context"
+	"crypto/hmac"
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/observability"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/events"
+	"github.com/hyperterse/hyperterse/core/runtime/utils"
+	"github.com/hyperterse/hyperterse/core/types"
+)
+
+// Delivery defaults used when a hook leaves them unset
+const (
+	DefaultRetries = 3
+	DefaultTimeout = 10 * time.Second
+)
+
+// Request headers sent with every delivery
+const (
+	HeaderEvent     = "X-Hyperterse-Event"
+	HeaderDelivery  = "X-Hyperterse-Delivery"
+	HeaderSignature = "X-Hyperterse-Signature"
+)
+
+// eventBuffer is how many events may queue while deliveries are being started
+const eventBuffer = 256
+
+// hook is a webhook with its environment variables resolved
+type hook struct {
+	name             string
+	url              string
+	secret           string
+	events           map[string]bool
+	retries          int
+	timeout          time.Duration
+	failureThreshold int
+}
+
+// Payload is the JSON body POSTed to a webhook
+type Payload struct {
+	ID        string         `json:"id"`
+	Event     string         `json:"event"`
+	Timestamp time.Time      `json:"timestamp"`
+	Data      map[string]any `json:"data"`
+}
+
+// Dispatcher delivers runtime events to the configured webhooks. It lives as
+// long as the runtime; SetHooks swaps the hook list when the model reloads.
+type Dispatcher struct {
+	client      *http.Client
+	hooks       atomic.Pointer[[]*hook]
+	unsubscribe func()
+	done        chan struct{}
+	deliveries  sync.WaitGroup
+
+	// Consecutive failures per query, reset when the query succeeds
+	failures map[string]int
+}
+
+// NewDispatcher subscribes to bus and delivers events to hooks
+func NewDispatcher(bus *events.Bus, configs []*hyperterse.Hook) (*Dispatcher, error) {
+	d := &Dispatcher{
+		client:   &http.Client{},
+		done:     make(chan struct{}),
+		failures: make(map[string]int),
+	}
+	if err := d.SetHooks(configs); err != nil {
+		return nil, err
+	}
+
+	ch, unsubscribe := bus.Subscribe(eventBuffer)
+	d.unsubscribe = unsubscribe
+	go d.run(ch)
+	return d, nil
+}
+
+// SetHooks replaces the hooks events are delivered to. Deliveries already in
+// progress finish against the previous hooks.
+func (d *Dispatcher) SetHooks(configs []*hyperterse.Hook) error {
+	resolved := make([]*hook, 0, len(configs))
+	for _, config := range configs {
+		h, err := resolveHook(config)
+		if err != nil {
+			return err
+		}
+		resolved = append(resolved, h)
+	}
+	d.hooks.Store(&resolved)
+	return nil
+}
+
+// Close stops receiving events and waits for pending deliveries, including
+// retries, until ctx is done
+func (d *Dispatcher) Close(ctx context.Context) {
+	d.unsubscribe()
+	<-d.done
+
+	finished := make(chan struct{})
+	go func() {
+		d.deliveries.Wait()
+		close(finished)
+	}()
+	select {
+	case <-finished:
+	case <-ctx.Done():
+		logger.New("hooks").Warnf("Webhook deliveries still pending at shutdown were abandoned")
+	}
+}
+
+func (d *Dispatcher) run(ch <-chan events.Event) {
+	defer close(d.done)
+	for event := range ch {
+		name, data, consecutive := d.translate(event)
+		if name == "" {
+			continue
+		}
+
+		for _, h := range *d.hooks.Load() {
+			if !h.events[name] {
+				continue
+			}
+			// Send query.failed once every failure_threshold consecutive failures
+			if name == types.HookEventQueryFailed && consecutive%h.failureThreshold != 0 {
+				continue
+			}
+			d.deliveries.Add(1)
+			go func(h *hook, payload Payload) {
+				defer d.deliveries.Done()
+				d.deliver(h, payload)
+			}(h, Payload{ID: newDeliveryID(), Event: name, Timestamp: event.Time.UTC(), Data: data})
+		}
+	}
+}
+
+// translate maps a runtime event to a webhook event name and payload data,
+// returning an empty name for events that are not delivered. Failure streaks
+// are tracked even when no hook is listening, so a hook added on reload sees
+// the same counts.
+func (d *Dispatcher) translate(event events.Event) (string, map[string]any, int) {
+	switch e := event.Payload.(type) {
+	case events.ServerStarted:
+		return types.HookEventServerStarted, map[string]any{"port": e.Port}, 0
+	case events.QueryFinished:
+		if e.InvalidInput {
+			return "", nil, 0
+		}
+		if e.Err == nil {
+			delete(d.failures, e.Query)
+			return "", nil, 0
+		}
+		d.failures[e.Query]++
+		consecutive := d.failures[e.Query]
+		data := map[string]any{
+			"query":                e.Query,
+			"adapter":              e.Adapter,
+			"error":                e.Err.Error(),
+			"consecutive_failures": consecutive,
+			"error_class":          e.ErrorClass,
+			"transient":            e.Transient,
+		}
+		if len(e.Headers) > 0 {
+			headers := make(map[string]string, len(e.Headers))
+			for name, value := range e.Headers {
+				headers[name] = observability.RedactAttributeValue(name, value)
+			}
+			data["headers"] = headers
+		}
+		return types.HookEventQueryFailed, data, consecutive
+	case events.ConfigReloaded:
+		if e.Err != nil {
+			return types.HookEventReloadFailed, map[string]any{"error": e.Err.Error()}, 0
+		}
+		return types.HookEventReloadSucceeded, map[string]any{"adapters": e.Adapters, "queries": e.Queries}, 0
+	case events.ConnectorHealthChanged:
+		data := map[string]any{"adapter": e.Adapter, "healthy": e.Healthy}
+		if e.Err != nil {
+			data["error"] = e.Err.Error()
+		}
+		return types.HookEventConnectorHealthChanged, data, 0
+	}
+	return "", nil, 0
+}
+
+// deliver POSTs the payload, retrying with exponential backoff on network
+// errors, 429 and 5xx responses
+func (d *Dispatcher) deliver(h *hook, payload Payload) {
+	log := logger.New("hooks")
+
+	body, err := json.Marshal(payload)
+	if err != nil {
+		log.Warnf("Hook '%s': failed to encode %s event: %v", h.name, payload.Event, err)
+		return
+	}
+
+	backoff := time.Second
+	for attempt := 0; attempt <= h.retries; attempt++ {
+		if attempt > 0 {
+			time.Sleep(backoff)
+			backoff *= 2
+		}
+
+		retryable, err := d.send(h, payload, body)
+		if err == nil {
+			log.Debugf("Hook '%s': delivered %s event", h.name, payload.Event)
+			return
+		}
+		if !retryable || attempt == h.retries {
+			log.Warnf("Hook '%s': failed to deliver %s event after %d attempt(s): %v", h.name, payload.Event, attempt+1, err)
+			return
+		}
+		log.Debugf("Hook '%s': attempt %d failed, retrying: %v", h.name, attempt+1, err)
+	}
+}
+
+func (d *Dispatcher) send(h *hook, payload Payload, body []byte) (bool, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), h.timeout)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, h.url, bytes.NewReader(body))
+	if err != nil {
+		return false, err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set("User-Agent", "hyperterse-hooks")
+	req.Header.Set(HeaderEvent, payload.Event)
+	req.Header.Set(HeaderDelivery, payload.ID)
+	if h.secret != "" {
+		req.Header.Set(HeaderSignature, Sign(h.secret, body))
+	}
+
+	resp, err := d.client.Do(req)
+	if err != nil {
+		return true, err
+	}
+	resp.Body.Close()
+
+	if resp.StatusCode >= 200 && resp.StatusCode < 300 {
+		return false, nil
+	}
+	retryable := resp.StatusCode == http.StatusTooManyRequests || resp.StatusCode >= 500
+	return retryable, fmt.Errorf("endpoint responded with status %d", resp.StatusCode)
+}
+
+// Sign returns the signature header value for body: "sha256=" followed by the
+// hex-encoded HMAC-SHA256 of the body keyed with secret
+func Sign(secret string, body []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write(body)
+	return "sha256=" + hex.EncodeToString(mac.Sum(nil))
+}
+
+func resolveHook(config *hyperterse.Hook) (*hook, error) {
+	url, err := utils.SubstituteEnvVars(config.Url)
+	if err != nil {
+		return nil, fmt.Errorf("hooks.%s.url: %w", config.Name, err)
+	}
+	secret, err := utils.SubstituteEnvVars(config.Secret)
+	if err != nil {
+		return nil, fmt.Errorf("hooks.%s.secret: %w", config.Name, err)
+	}
+
+	h := &hook{
+		name:             config.Name,
+		url:              url,
+		secret:           secret,
+		events:           make(map[string]bool, len(config.Events)),
+		retries:          DefaultRetries,
+		timeout:          DefaultTimeout,
+		failureThreshold: 1,
+	}
+	for _, name := range config.Events {
+		h.events[name] = true
+	}
+	if config.HasRetries {
+		h.retries = int(config.Retries)
+	}
+	if config.Timeout > 0 {
+		h.timeout = time.Duration(config.Timeout) * time.Second
+	}
+	if config.FailureThreshold > 0 {
+		h.failureThreshold = int(config.FailureThreshold)
+	}
+	return h, nil
+}
+
+func newDeliveryID() string {
+	b := make([]byte, 16)
+	rand.Read(b)
+	return hex.EncodeToString(b)
+}
@@ -0,0 +1,225 @@
+package migrations
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strconv"
+	"time"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/seed"
+)
+
+// Table records the applied migrations of a database
+const Table = "hyperterse_migrations"
+
+// filePattern matches migration files such as 001_create_users.up.sql
+var filePattern = regexp.MustCompile(`^([0-9]+)_([A-Za-z0-9_-]+)\.(up|down)\.sql$`)
+
+// Migration is a numbered schema change with the files that apply and revert it
+type Migration struct {
+	Version int64
+	Name    string
+	Up      string // Path of the .up.sql file
+	Down    string // Path of the .down.sql file, empty when the migration cannot be reverted
+}
+
+// Status is a migration and when it was applied
+type Status struct {
+	Migration
+	Applied   bool
+	AppliedAt string
+}
+
+// Load reads the migrations of dir in version order. Files that do not match
+// <version>_<name>.up.sql or <version>_<name>.down.sql are ignored.
+func Load(dir string) ([]Migration, error) {
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read migrations directory: %w", err)
+	}
+
+	byVersion := make(map[int64]*Migration)
+	for _, entry := range entries {
+		match := filePattern.FindStringSubmatch(entry.Name())
+		if entry.IsDir() || match == nil {
+			continue
+		}
+		version, err := strconv.ParseInt(match[1], 10, 64)
+		if err != nil {
+			return nil, fmt.Errorf("migration %s: invalid version: %w", entry.Name(), err)
+		}
+		migration, ok := byVersion[version]
+		if !ok {
+			migration = &Migration{Version: version, Name: match[2]}
+			byVersion[version] = migration
+		} else if migration.Name != match[2] {
+			return nil, fmt.Errorf("migration version %d is used by both '%s' and '%s'", version, migration.Name, match[2])
+		}
+
+		path := filepath.Join(dir, entry.Name())
+		if match[3] == "up" {
+			migration.Up = path
+		} else {
+			migration.Down = path
+		}
+	}
+
+	migrations := make([]Migration, 0, len(byVersion))
+	for _, migration := range byVersion {
+		if migration.Up == "" {
+			return nil, fmt.Errorf("migration %d_%s has no .up.sql file", migration.Version, migration.Name)
+		}
+		migrations = append(migrations, *migration)
+	}
+	sort.Slice(migrations, func(i, j int) bool {
+		return migrations[i].Version < migrations[j].Version
+	})
+	return migrations, nil
+}
+
+// Runner applies and reverts migrations on a database, recording them in Table
+type Runner struct {
+	db         *sql.DB
+	connector  protoconnectors.Connector
+	migrations []Migration
+}
+
+// NewRunner creates a runner for migrations on db, an adapter of connector
+func NewRunner(db *sql.DB, connector protoconnectors.Connector, migrations []Migration) *Runner {
+	return &Runner{db: db, connector: connector, migrations: migrations}
+}
+
+// Status returns every migration with whether it has been applied
+func (r *Runner) Status(ctx context.Context) ([]Status, error) {
+	applied, err := r.applied(ctx)
+	if err != nil {
+		return nil, err
+	}
+	statuses := make([]Status, len(r.migrations))
+	for i, migration := range r.migrations {
+		appliedAt, ok := applied[migration.Version]
+		statuses[i] = Status{Migration: migration, Applied: ok, AppliedAt: appliedAt}
+	}
+	return statuses, nil
+}
+
+// Up applies pending migrations in version order, at most steps of them when
+// steps is positive, and returns the migrations it applied
+func (r *Runner) Up(ctx context.Context, steps int) ([]Migration, error) {
+	applied, err := r.applied(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	var done []Migration
+	for _, migration := range r.migrations {
+		if _, ok := applied[migration.Version]; ok {
+			continue
+		}
+		if steps > 0 && len(done) == steps {
+			break
+		}
+		record := fmt.Sprintf("INSERT INTO %s (version, name, applied_at) VALUES (%s, %s, %s)",
+			Table, r.placeholder(1), r.placeholder(2), r.placeholder(3))
+		if err := r.run(ctx, migration.Up, record, migration.Version, migration.Name, time.Now().UTC()); err != nil {
+			return done, fmt.Errorf("migration %d_%s: %w", migration.Version, migration.Name, err)
+		}
+		done = append(done, migration)
+	}
+	return done, nil
+}
+
+// Down reverts the most recently applied migrations, steps of them, and
+// returns the migrations it reverted
+func (r *Runner) Down(ctx context.Context, steps int) ([]Migration, error) {
+	applied, err := r.applied(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	var done []Migration
+	for i := len(r.migrations) - 1; i >= 0 && len(done) < steps; i-- {
+		migration := r.migrations[i]
+		if _, ok := applied[migration.Version]; !ok {
+			continue
+		}
+		if migration.Down == "" {
+			return done, fmt.Errorf("migration %d_%s has no .down.sql file", migration.Version, migration.Name)
+		}
+		record := fmt.Sprintf("DELETE FROM %s WHERE version = %s", Table, r.placeholder(1))
+		if err := r.run(ctx, migration.Down, record, migration.Version); err != nil {
+			return done, fmt.Errorf("migration %d_%s: %w", migration.Version, migration.Name, err)
+		}
+		done = append(done, migration)
+	}
+	return done, nil
+}
+
+// run executes the statements of file and the tracking statement record in
+// one transaction. Databases that commit DDL implicitly, such as MySQL, can
+// still leave a failed migration partly applied.
+func (r *Runner) run(ctx context.Context, file string, record string, args ...any) error {
+	data, err := os.ReadFile(file)
+	if err != nil {
+		return err
+	}
+	statements, err := seed.Statements(r.connector, data)
+	if err != nil {
+		return err
+	}
+
+	tx, err := r.db.BeginTx(ctx, nil)
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback()
+	for n, statement := range statements {
+		if _, err := tx.ExecContext(ctx, statement); err != nil {
+			return fmt.Errorf("%s: statement %d failed: %w", filepath.Base(file), n+1, err)
+		}
+	}
+	if _, err := tx.ExecContext(ctx, record, args...); err != nil {
+		return fmt.Errorf("failed to update %s: %w", Table, err)
+	}
+	return tx.Commit()
+}
+
+// applied creates the tracking table if needed and returns when each applied
+// version was applied
+func (r *Runner) applied(ctx context.Context) (map[int64]string, error) {
+	create := fmt.Sprintf("CREATE TABLE IF NOT EXISTS %s (version BIGINT PRIMARY KEY, name VARCHAR(255) NOT NULL, applied_at TIMESTAMP NOT NULL)", Table)
+	if _, err := r.db.ExecContext(ctx, create); err != nil {
+		return nil, fmt.Errorf("failed to create %s: %w", Table, err)
+	}
+
+	rows, err := r.db.QueryContext(ctx, fmt.Sprintf("SELECT version, applied_at FROM %s", Table))
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", Table, err)
+	}
+	defer rows.Close()
+
+	applied := make(map[int64]string)
+	for rows.Next() {
+		var version int64
+		var appliedAt string
+		if err := rows.Scan(&version, &appliedAt); err != nil {
+			return nil, fmt.Errorf("failed to read %s: %w", Table, err)
+		}
+		applied[version] = appliedAt
+	}
+	return applied, rows.Err()
+}
+
+// placeholder returns the nth bind parameter in the connector's syntax
+func (r *Runner) placeholder(n int) string {
+	if r.connector == protoconnectors.Connector_CONNECTOR_POSTGRES {
+		return "$" + strconv.Itoa(n)
+	}
+	return "?"
+}
@@ -0,0 +1,103 @@
+package ratelimit
+
+import (
+	"context"
+	"fmt"
+	"sync/atomic"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/utils"
+)
+
+// Supported limiter backends
+const (
+	StoreMemory = "memory"
+	StoreRedis  = "redis"
+)
+
+// DefaultWindow is the rate limit window used when none is configured
+const DefaultWindow = time.Minute
+
+// Decision is the outcome of a rate limit check
+type Decision struct {
+	Allowed    bool
+	Limit      int
+	Remaining  int
+	ResetAfter time.Duration // Time until the current window ends
+}
+
+// Limiter counts requests per key in fixed windows. Implementations must be
+// safe for concurrent use.
+type Limiter interface {
+	// Allow records a request for key and reports whether it is within the limit
+	Allow(ctx context.Context, key string) (Decision, error)
+	// Close releases resources held by the limiter
+	Close() error
+}
+
+// NewLimiter creates the limiter described by config.
+// A nil config or a zero request count returns a nil limiter, meaning no limit.
+func NewLimiter(config *hyperterse.RateLimitConfig) (Limiter, error) {
+	if config == nil || config.Requests <= 0 {
+		return nil, nil
+	}
+
+	window := DefaultWindow
+	if config.Window > 0 {
+		window = time.Duration(config.Window) * time.Second
+	}
+	limit := int(config.Requests)
+
+	switch config.Store {
+	case "", StoreMemory:
+		return NewMemoryLimiter(limit, window), nil
+	case StoreRedis:
+		url, err := utils.SubstituteEnvVars(config.Url)
+		if err != nil {
+			return nil, fmt.Errorf("server.rate_limit.url: %w", err)
+		}
+		limiter, err := NewRedisLimiter(url, limit, window)
+		if err != nil {
+			return nil, err
+		}
+		return WithFallback(limiter, NewMemoryLimiter(limit, window)), nil
+	default:
+		return nil, fmt.Errorf("unsupported rate limit store '%s'", config.Store)
+	}
+}
+
+// fallbackLimiter uses a shared limiter and falls back to a local one while the
+// shared store is unavailable, so an outage degrades to per-replica limits
+// instead of rejecting or admitting all traffic. Switching between the two is
+// logged once each way rather than on every request.
+type fallbackLimiter struct {
+	primary  Limiter
+	fallback Limiter
+	degraded atomic.Bool
+}
+
+// WithFallback wraps primary so that errors are answered by fallback instead
+func WithFallback(primary, fallback Limiter) Limiter {
+	return &fallbackLimiter{primary: primary, fallback: fallback}
+}
+
+func (l *fallbackLimiter) Allow(ctx context.Context, key string) (Decision, error) {
+	decision, err := l.primary.Allow(ctx, key)
+	if err == nil {
+		if l.degraded.CompareAndSwap(true, false) {
+			logger.New("ratelimit").Infof("Shared rate limit store recovered, using shared limits")
+		}
+		return decision, nil
+	}
+	if l.degraded.CompareAndSwap(false, true) {
+		logger.New("ratelimit").Warnf("Shared rate limit store unavailable, using local limits: %v", err)
+	}
+	return l.fallback.Allow(ctx, key)
+}
+
+func (l *fallbackLimiter) Close() error {
+	l.fallback.Close()
+	return l.primary.Close()
+}
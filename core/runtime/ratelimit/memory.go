@@ -0,0 +1,67 @@
+package ratelimit
+
+import (
+	"context"
+	"sync"
+	"time"
+)
+
+type window struct {
+	start time.Time
+	count int
+}
+
+// MemoryLimiter enforces limits within a single process
+type MemoryLimiter struct {
+	mu      sync.Mutex
+	limit   int
+	window  time.Duration
+	windows map[string]*window
+}
+
+// NewMemoryLimiter creates an in-process fixed-window limiter
+func NewMemoryLimiter(limit int, windowSize time.Duration) *MemoryLimiter {
+	return &MemoryLimiter{
+		limit:   limit,
+		window:  windowSize,
+		windows: make(map[string]*window),
+	}
+}
+
+// Allow records a request for key and reports whether it is within the limit
+func (l *MemoryLimiter) Allow(ctx context.Context, key string) (Decision, error) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	now := time.Now()
+	w, ok := l.windows[key]
+	if !ok || now.Sub(w.start) >= l.window {
+		if !ok && len(l.windows) > 0 {
+			l.evictExpired(now)
+		}
+		w = &window{start: now}
+		l.windows[key] = w
+	}
+	w.count++
+
+	return Decision{
+		Allowed:    w.count <= l.limit,
+		Limit:      l.limit,
+		Remaining:  max(l.limit-w.count, 0),
+		ResetAfter: l.window - now.Sub(w.start),
+	}, nil
+}
+
+// Close is a no-op for the in-memory limiter
+func (l *MemoryLimiter) Close() error {
+	return nil
+}
+
+// evictExpired drops finished windows; callers must hold l.mu
+func (l *MemoryLimiter) evictExpired(now time.Time) {
+	for key, w := range l.windows {
+		if now.Sub(w.start) >= l.window {
+			delete(l.windows, key)
+		}
+	}
+}
@@ -0,0 +1,27 @@
+//go:build no_redis
+
+package ratelimit
+
+import (
+	"context"
+	"fmt"
+	"time"
+)
+
+// RedisLimiter is unavailable in builds with the no_redis tag.
+type RedisLimiter struct{}
+
+// NewRedisLimiter reports that Redis support was not compiled in
+func NewRedisLimiter(url string, limit int, window time.Duration) (*RedisLimiter, error) {
+	return nil, fmt.Errorf("redis rate limit store is not available in this build: rebuild without the no_redis tag")
+}
+
+// Allow is never reached since the limiter cannot be constructed
+func (l *RedisLimiter) Allow(ctx context.Context, key string) (Decision, error) {
+	return Decision{}, fmt.Errorf("redis rate limit store is not available in this build")
+}
+
+// Close is a no-op
+func (l *RedisLimiter) Close() error {
+	return nil
+}
@@ -1,6 +1,8 @@
 package runtime
 
 import (
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/ratelimit"
 	"github.com/hyperterse/hyperterse/core/runtime/server"
 )
 
@@ -11,3 +13,81 @@ type Runtime = server.Runtime
 // NewRuntime creates a new runtime instance
 // This is the main constructor function for the runtime package
 var NewRuntime = server.NewRuntime
+
+// portAuto is the server.port value that picks the next free port from 8080
+const portAuto = "auto"
+
+// Builder configures a runtime for services that embed Hyperterse. The model
+// is typically built with the builder package or loaded by the parser; it is
+// not validated again here.
+type Builder struct {
+	model          *hyperterse.Model
+	port           string
+	portAuto       bool
+	serviceVersion string
+	limiter        ratelimit.Limiter
+	reload         func() (*hyperterse.Model, error)
+}
+
+// NewBuilder starts configuring a runtime that serves the given model
+func NewBuilder(model *hyperterse.Model) *Builder {
+	return &Builder{model: model, serviceVersion: "embedded"}
+}
+
+// Port sets the listening port, overriding server.port (default: 8080)
+func (b *Builder) Port(port string) *Builder {
+	b.port = port
+	return b
+}
+
+// PortAuto moves on to the next free port when the port is in use
+func (b *Builder) PortAuto(enabled bool) *Builder {
+	b.portAuto = enabled
+	return b
+}
+
+// ServiceVersion sets the version reported to observability tools
+func (b *Builder) ServiceVersion(version string) *Builder {
+	b.serviceVersion = version
+	return b
+}
+
+// RateLimiter replaces the limiter built from server.rate_limit
+func (b *Builder) RateLimiter(limiter ratelimit.Limiter) *Builder {
+	b.limiter = limiter
+	return b
+}
+
+// Reloader sets the function that provides a new model when the runtime is
+// asked to reload, e.g. on SIGHUP
+func (b *Builder) Reloader(reload func() (*hyperterse.Model, error)) *Builder {
+	b.reload = reload
+	return b
+}
+
+// Build creates the runtime, connecting its adapters. Call Start or
+// StartAsync on the result to begin serving.
+func (b *Builder) Build() (*Runtime, error) {
+	port := b.port
+	if port == "" && b.model.Server != nil {
+		port = b.model.Server.Port
+	}
+	portAutoEnabled := b.portAuto
+	if port == portAuto {
+		port = "8080"
+		portAutoEnabled = true
+	}
+
+	rt, err := server.NewRuntime(b.model, port, b.serviceVersion)
+	if err != nil {
+		return nil, err
+	}
+	rt.SetPortAuto(portAutoEnabled)
+	if b.limiter != nil {
+		rt.SetRateLimiter(b.limiter)
+	}
+	if b.reload != nil {
+		rt.SetReloader(b.reload)
+	}
+	return rt, nil
+}
@@ -0,0 +1,219 @@
+package seed
+
+import (
+	"bufio"
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strings"
+
+	protoconnectors "github.com/hyperterse/hyperterse/core/proto/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+)
+
+// mongoBatchSize caps the documents sent in one insert command, keeping each
+// command well below MongoDB's message size limit
+const mongoBatchSize = 1000
+
+// Statements splits a seed file into the statements to execute, in order, on an
+// adapter of the given connector:
+//
+//   - postgres, mysql and duckdb take a SQL script of statements separated by semicolons
+//   - mongodb takes a JSON object of a database and the documents to insert into each collection
+//   - redis takes one command per line; blank lines and lines starting with # are skipped
+func Statements(connector protoconnectors.Connector, data []byte) ([]string, error) {
+	switch utils.StatementKindOf(connector) {
+	case utils.StatementSQL:
+		return splitSQL(string(data)), nil
+	case utils.StatementMongoJSON:
+		return mongoInserts(data)
+	case utils.StatementRedisCommand:
+		return redisCommands(data)
+	default:
+		return nil, fmt.Errorf("seeding is not supported for the %s connector", connectorName(connector))
+	}
+}
+
+func connectorName(connector protoconnectors.Connector) string {
+	return strings.ToLower(strings.TrimPrefix(connector.String(), "CONNECTOR_"))
+}
+
+// splitSQL splits a script on semicolons outside of quotes, comments and
+// PostgreSQL dollar-quoted bodies. Statements made only of comments are dropped.
+func splitSQL(script string) []string {
+	var statements []string
+	var current strings.Builder
+	hasContent := false
+
+	flush := func() {
+		if hasContent {
+			statements = append(statements, strings.TrimSpace(current.String()))
+		}
+		current.Reset()
+		hasContent = false
+	}
+
+	for i := 0; i < len(script); i++ {
+		c := script[i]
+		switch {
+		case c == ';':
+			flush()
+			continue
+		case c == '-' && strings.HasPrefix(script[i:], "--"):
+			end := strings.IndexByte(script[i:], '\n')
+			if end < 0 {
+				end = len(script) - i
+			}
+			current.WriteString(script[i : i+end])
+			i += end - 1
+			continue
+		case c == '/' && strings.HasPrefix(script[i:], "/*"):
+			end := strings.Index(script[i+2:], "*/")
+			if end < 0 {
+				end = len(script) - i
+			} else {
+				end += 4
+			}
+			current.WriteString(script[i : i+end])
+			i += end - 1
+			continue
+		case c == '\'' || c == '"' || c == '`':
+			end := closingQuote(script, i)
+			current.WriteString(script[i:end])
+			hasContent = true
+			i = end - 1
+			continue
+		case c == '$':
+			if tag, ok := dollarTag(script[i:]); ok {
+				end := strings.Index(script[i+len(tag):], tag)
+				if end < 0 {
+					end = len(script) - i
+				} else {
+					end += 2 * len(tag)
+				}
+				current.WriteString(script[i : i+end])
+				hasContent = true
+				i += end - 1
+				continue
+			}
+		}
+		current.WriteByte(c)
+		if c != ' ' && c != '\t' && c != '\n' && c != '\r' {
+			hasContent = true
+		}
+	}
+	flush()
+	return statements
+}
+
+// closingQuote returns the index just past the quoted section starting at
+// start. A doubled quote or a backslash escapes the quote character.
+func closingQuote(script string, start int) int {
+	quote := script[start]
+	for i := start + 1; i < len(script); i++ {
+		switch script[i] {
+		case '\\':
+			i++
+		case quote:
+			if i+1 < len(script) && script[i+1] == quote {
+				i++
+				continue
+			}
+			return i + 1
+		}
+	}
+	return len(script)
+}
+
+// dollarTag returns the $tag$ opening a dollar-quoted string at the start of s
+func dollarTag(s string) (string, bool) {
+	for i := 1; i < len(s); i++ {
+		c := s[i]
+		if c == '$' {
+			return s[:i+1], true
+		}
+		if !(c == '_' || c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z' || i > 1 && c >= '0' && c <= '9') {
+			return "", false
+		}
+	}
+	return "", false
+}
+
+// mongoSeed is the seed file format of MongoDB adapters:
+//
+//	{ "database": "shop", "collections": { "orders": [{ "id": "1", "total": 100 }] } }
+type mongoSeed struct {
+	Database    string                       `json:"database"`
+	Collections map[string][]json.RawMessage `json:"collections"`
+}
+
+// mongoInserts builds insert commands for the documents of each collection,
+// the equivalent of insertMany, in the statement format of the MongoDB connector
+func mongoInserts(data []byte) ([]string, error) {
+	var seed mongoSeed
+	if err := json.Unmarshal(data, &seed); err != nil {
+		return nil, fmt.Errorf("mongodb seed file must be a JSON object: %w", err)
+	}
+	if seed.Database == "" {
+		return nil, fmt.Errorf("mongodb seed file must include database")
+	}
+
+	names := make([]string, 0, len(seed.Collections))
+	for name := range seed.Collections {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	var statements []string
+	for _, name := range names {
+		documents := seed.Collections[name]
+		for start := 0; start < len(documents); start += mongoBatchSize {
+			batch := documents[start:min(start+mongoBatchSize, len(documents))]
+			statement, err := json.Marshal(map[string]any{
+				"database": seed.Database,
+				"command":  json.RawMessage(mongoInsertCommand(name, batch)),
+			})
+			if err != nil {
+				return nil, fmt.Errorf("collection '%s': %w", name, err)
+			}
+			statements = append(statements, string(statement))
+		}
+	}
+	return statements, nil
+}
+
+// mongoInsertCommand encodes an insert command by hand, since the command name
+// must be the first key and encoding a map would sort it after "documents"
+func mongoInsertCommand(collection string, documents []json.RawMessage) []byte {
+	name, _ := json.Marshal(collection)
+	var buf bytes.Buffer
+	buf.WriteString(`{"insert":`)
+	buf.Write(name)
+	buf.WriteString(`,"documents":[`)
+	for i, doc := range documents {
+		if i > 0 {
+			buf.WriteByte(',')
+		}
+		buf.Write(doc)
+	}
+	buf.WriteString(`]}`)
+	return buf.Bytes()
+}
+
+// redisCommands returns the commands of a seed file, one per line
+func redisCommands(data []byte) ([]string, error) {
+	var commands []string
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		commands = append(commands, line)
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, err
+	}
+	return commands, nil
+}
@@ -0,0 +1,113 @@
+package server
+
+import (
+	"crypto/subtle"
+	"encoding/json"
+	"net/http"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	"github.com/hyperterse/hyperterse/core/runtime/utils"
+)
+
+const (
+	// adapterOverrideHeader names the adapter an admin request runs its query on
+	adapterOverrideHeader = "X-Hyperterse-Adapter"
+	// adminTokenHeader carries server.admin_token on admin requests
+	adminTokenHeader = "X-Hyperterse-Admin-Token"
+)
+
+// withAdapterOverride attaches the adapter named by adapterOverrideHeader to
+// the request's context. Unless the request carries the configured admin
+// token, it writes 403 Forbidden and reports false.
+func withAdapterOverride(state *servingState, w http.ResponseWriter, req *http.Request) (*http.Request, bool) {
+	adapter := req.Header.Get(adapterOverrideHeader)
+	if adapter == "" {
+		return req, true
+	}
+
+	if !validAdminToken(state, req) {
+		logger.New("admin").Warnf("Rejected %s header without a valid admin token", adapterOverrideHeader)
+		writeAdminForbidden(w, "adapter override requires a valid admin token")
+		return nil, false
+	}
+	return req.WithContext(executor.WithAdapterOverride(req.Context(), adapter)), true
+}
+
+// validAdminToken reports whether a request carries server.admin_token in
+// adminTokenHeader. Without a configured token, no request is an admin request.
+func validAdminToken(state *servingState, req *http.Request) bool {
+	if state.model.Server == nil || state.model.Server.AdminToken == "" {
+		return false
+	}
+	token, err := utils.SubstituteEnvVars(state.model.Server.AdminToken)
+	if err != nil {
+		logger.New("admin").Warnf("Failed to resolve server.admin_token: %v", err)
+		return false
+	}
+	provided := req.Header.Get(adminTokenHeader)
+	return token != "" && subtle.ConstantTimeCompare([]byte(provided), []byte(token)) == 1
+}
+
+// writeAdminForbidden writes a 403 Forbidden response in the documented error format
+func writeAdminForbidden(w http.ResponseWriter, message string) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(http.StatusForbidden)
+	json.NewEncoder(w).Encode(map[string]any{
+		"success": false,
+		"error":   message,
+		"results": []any{},
+	})
+}
+
+// registerAdminRoutes registers the admin API on the admin route group's mux
+// when server.admin_token is set. Every admin request must carry the token.
+func (r *Runtime) registerAdminRoutes(state *servingState, mux *http.ServeMux) []string {
+	if state.model.Server == nil || state.model.Server.AdminToken == "" {
+		return nil
+	}
+
+	// Lists every query with its enabled state, including queries turned off
+	// and therefore hidden from the query index, docs and MCP
+	mux.HandleFunc("GET /admin/queries", r.instrumentEndpoint("/admin/queries", adminOnly(state, func(w http.ResponseWriter, req *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		json.NewEncoder(w).Encode(map[string]any{"queries": state.queries})
+	})))
+
+	// Reports and switches maintenance mode
+	mux.HandleFunc("/admin/maintenance", r.instrumentEndpoint("/admin/maintenance", adminOnly(state, r.serveMaintenance(state))))
+
+	// Reports the statements executed on each adapter since the server started
+	mux.HandleFunc("GET /admin/stats", r.instrumentEndpoint("/admin/stats", adminOnly(state, func(w http.ResponseWriter, req *http.Request) {
+		adapters, since := state.connectorManager.Stats()
+		w.Header().Set("Content-Type", "application/json")
+		w.Header().Set("Cache-Control", "no-store")
+		json.NewEncoder(w).Encode(map[string]any{
+			"since":    since.UTC().Format(time.RFC3339),
+			"adapters": adapters,
+		})
+	})))
+	routes := []string{"GET /admin/queries", "GET|POST /admin/maintenance", "GET /admin/stats"}
+
+	// Lists and returns the debug bundles of failed executions
+	if state.failures != nil {
+		mux.HandleFunc("GET /admin/failures", r.instrumentEndpoint("/admin/failures", adminOnly(state, serveFailures(state))))
+		mux.HandleFunc("GET /admin/failures/{id}", r.instrumentEndpoint("/admin/failures/{id}", adminOnly(state, serveFailure(state))))
+		routes = append(routes, "GET /admin/failures", "GET /admin/failures/{id}")
+	}
+	return routes
+}
+
+// adminOnly wraps an admin API handler so requests without the admin token
+// get 403 Forbidden
+func adminOnly(state *servingState, next http.HandlerFunc) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		if !validAdminToken(state, req) {
+			logger.New("admin").Warnf("Rejected admin request without a valid admin token")
+			writeAdminForbidden(w, "admin API requires a valid admin token")
+			return
+		}
+		next(w, req)
+	}
+}
@@ -0,0 +1,175 @@
+package server
+
+import (
+	"context"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/observability"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/events"
+	"github.com/hyperterse/hyperterse/core/runtime/handlers"
+	"github.com/hyperterse/hyperterse/core/runtime/sessions"
+)
+
+const (
+	// defaultHealthCheckInterval is how often connectors are pinged when
+	// server.health_check_interval is unset
+	defaultHealthCheckInterval = 30 * time.Second
+	// healthCheckTimeout bounds a single connector ping
+	healthCheckTimeout = 5 * time.Second
+	// metricsEventBuffer is how many events may queue for the metrics subscriber
+	metricsEventBuffer = 256
+	// mcpLogEventBuffer is how many events may queue for the MCP log forwarder
+	mcpLogEventBuffer = 256
+)
+
+// Subscribe returns a channel receiving every runtime event published from now
+// on, and a function that unsubscribes and closes the channel. Subscribe before
+// StartAsync to observe the server.started event.
+//
+// Publishing never blocks the runtime: a subscriber that falls more than buffer
+// events behind misses events until it catches up.
+func (r *Runtime) Subscribe(buffer int) (<-chan events.Event, func()) {
+	return r.events.Subscribe(buffer)
+}
+
+// recordEventMetrics exports metrics for events that are not already recorded
+// where they happen
+func recordEventMetrics(ch <-chan events.Event) {
+	ctx := context.Background()
+	for event := range ch {
+		switch e := event.Payload.(type) {
+		case events.ConnectorHealthChanged:
+			observability.RecordConnectorHealth(ctx, e.Adapter, e.Healthy)
+		case events.ConfigReloaded:
+			observability.RecordConfigReload(ctx, e.Err == nil)
+		}
+	}
+}
+
+// mcpLogLevel returns server.mcp_log_level, empty when unset
+func mcpLogLevel(model *hyperterse.Model) string {
+	if model.Server == nil {
+		return ""
+	}
+	return model.Server.McpLogLevel
+}
+
+// mcpSessionTTL returns server.sessions.ttl, or the default idle expiry of
+// sessions when unset
+func mcpSessionTTL(model *hyperterse.Model) time.Duration {
+	if ttl := model.GetServer().GetSessions().GetTtl(); ttl > 0 {
+		return time.Duration(ttl) * time.Second
+	}
+	return sessions.DefaultTTL
+}
+
+// forwardMCPLogs sends query failures, configuration reloads and connector
+// health changes to MCP sessions as log notifications. Failures caused by the
+// caller's inputs are logged as warnings rather than errors.
+func forwardMCPLogs(ch <-chan events.Event, logging *handlers.MCPLogging) {
+	for event := range ch {
+		data := map[string]any{"event": string(event.Type())}
+		switch e := event.Payload.(type) {
+		case events.QueryFinished:
+			if e.Err == nil {
+				continue
+			}
+			data["query"] = e.Query
+			data["adapter"] = e.Adapter
+			data["error"] = e.Err.Error()
+			if e.InvalidInput {
+				logging.Log("warning", data)
+			} else {
+				logging.Log("error", data)
+			}
+		case events.ConfigReloaded:
+			if e.Err != nil {
+				data["error"] = e.Err.Error()
+				logging.Log("error", data)
+				continue
+			}
+			data["adapters"] = e.Adapters
+			data["queries"] = e.Queries
+			logging.Log("info", data)
+		case events.ConnectorHealthChanged:
+			data["adapter"] = e.Adapter
+			data["healthy"] = e.Healthy
+			if e.Err != nil {
+				data["error"] = e.Err.Error()
+				logging.Log("error", data)
+			} else {
+				logging.Log("info", data)
+			}
+		}
+	}
+}
+
+// monitorConnectorHealth pings every connector that supports health checks
+// each health check interval, caches the results for /health/ready, and
+// publishes an event whenever a connector becomes unhealthy or recovers.
+// Connectors start out healthy since initialization already pinged them.
+func (r *Runtime) monitorConnectorHealth() {
+	log := logger.New("health")
+	timer := time.NewTimer(r.healthCheckInterval())
+	defer timer.Stop()
+
+	healthy := make(map[string]bool)
+	for {
+		select {
+		case <-r.shutdownCtx.Done():
+			return
+		case <-timer.C:
+		}
+
+		manager := r.state.Load().connectorManager
+		results := manager.HealthCheckAll(r.shutdownCtx, healthCheckTimeout)
+		if r.shutdownCtx.Err() != nil {
+			return
+		}
+		r.health.Store(&healthStatus{CheckedAt: time.Now(), Errors: results})
+
+		for name, conn := range manager.GetAll() {
+			if reporter, ok := conn.(connectors.PoolStatsReporter); ok {
+				stats := reporter.PoolStats()
+				observability.RecordPoolStats(r.shutdownCtx, name, stats.InUse, stats.Idle, stats.WaitCount, float64(stats.WaitDuration.Microseconds())/1000)
+			}
+		}
+
+		for name := range healthy {
+			if _, ok := results[name]; !ok {
+				delete(healthy, name)
+			}
+		}
+		for name, err := range results {
+			wasHealthy, seen := healthy[name]
+			if !seen {
+				wasHealthy = true
+			}
+			healthy[name] = err == nil
+			if healthy[name] == wasHealthy {
+				continue
+			}
+
+			if err != nil {
+				log.Warnf("Adapter '%s' is unhealthy: %v", name, err)
+			} else {
+				log.Infof("Adapter '%s' recovered", name)
+			}
+			r.events.Publish(events.ConnectorHealthChanged{Adapter: name, Healthy: err == nil, Err: err})
+		}
+
+		// Read the interval each time so reloads can change it
+		timer.Reset(r.healthCheckInterval())
+	}
+}
+
+// healthCheckInterval returns the configured time between health checks
+func (r *Runtime) healthCheckInterval() time.Duration {
+	if server := r.state.Load().model.Server; server != nil && server.HealthCheckInterval > 0 {
+		return time.Duration(server.HealthCheckInterval) * time.Second
+	}
+	return defaultHealthCheckInterval
+}
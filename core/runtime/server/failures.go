@@ -0,0 +1,82 @@
+package server
+
+import (
+	"encoding/json"
+	"errors"
+	"net/http"
+	"path/filepath"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/failures"
+)
+
+// failureCaptureStore returns the store of server.failure_capture, or nil when
+// capture is disabled
+func failureCaptureStore(model *hyperterse.Model) *failures.Store {
+	config := model.GetServer().GetFailureCapture()
+	if !config.GetEnabled() {
+		return nil
+	}
+	dir := config.GetDir()
+	if dir == "" {
+		dir = filepath.Join(logger.TempDir(), "failures", model.Name)
+	}
+	return failures.NewStore(dir, int(config.GetMax()))
+}
+
+// failureSummary is a capture as listed by GET /admin/failures
+type failureSummary struct {
+	ID      string `json:"id"`
+	Time    string `json:"time"`
+	Query   string `json:"query"`
+	Adapter string `json:"adapter,omitempty"`
+	Error   string `json:"error"`
+}
+
+// serveFailures lists the captured failures, newest first
+func serveFailures(state *servingState) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		captures, err := state.failures.List()
+		if err != nil {
+			logger.New("admin").Warnf("Failed to list captured failures: %v", err)
+			http.Error(w, "failed to list captured failures", http.StatusInternalServerError)
+			return
+		}
+		summaries := make([]failureSummary, 0, len(captures))
+		for _, capture := range captures {
+			summary := failureSummary{
+				ID:      capture.ID,
+				Time:    capture.Time.Format("2006-01-02T15:04:05.000Z07:00"),
+				Query:   capture.Query,
+				Adapter: capture.Adapter,
+			}
+			if len(capture.Errors) > 0 {
+				summary.Error = capture.Errors[0].Message
+			}
+			summaries = append(summaries, summary)
+		}
+		w.Header().Set("Content-Type", "application/json")
+		w.Header().Set("Cache-Control", "no-store")
+		json.NewEncoder(w).Encode(map[string]any{"failures": summaries})
+	}
+}
+
+// serveFailure returns the full debug bundle of one captured failure
+func serveFailure(state *servingState) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		capture, err := state.failures.Load(req.PathValue("id"))
+		if errors.Is(err, failures.ErrNotFound) {
+			http.NotFound(w, req)
+			return
+		}
+		if err != nil {
+			logger.New("admin").Warnf("Failed to load captured failure: %v", err)
+			http.Error(w, "failed to load captured failure", http.StatusInternalServerError)
+			return
+		}
+		w.Header().Set("Content-Type", "application/json")
+		w.Header().Set("Cache-Control", "no-store")
+		json.NewEncoder(w).Encode(capture)
+	}
+}
@@ -0,0 +1,83 @@
+package server
+
+import (
+	"encoding/json"
+	"net/http"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+)
+
+// healthStatus is the result of the latest background health check
+type healthStatus struct {
+	CheckedAt time.Time
+	Errors    map[string]error // Ping error of each checked adapter, nil when healthy
+}
+
+// readinessResponse is the JSON body returned by /health/ready
+type readinessResponse struct {
+	Ready       bool                        `json:"ready"`
+	CheckedAt   string                      `json:"checked_at"`
+	Adapters    map[string]adapterReadiness `json:"adapters"`
+	Maintenance bool                        `json:"maintenance,omitempty"` // Query endpoints are refused by maintenance mode
+}
+
+type adapterReadiness struct {
+	Healthy bool           `json:"healthy"`
+	Pool    *poolReadiness `json:"pool,omitempty"`
+}
+
+// poolReadiness is a connection pool's current state. The wait figures count
+// every time a query waited for a free connection since the pool opened.
+type poolReadiness struct {
+	InUse          int     `json:"in_use"`
+	Idle           int     `json:"idle"`
+	WaitCount      int64   `json:"wait_count"`
+	WaitDurationMs float64 `json:"wait_duration_ms"`
+}
+
+// serveReadiness reports whether every adapter passed the latest background
+// health check, answering from the cached status without pinging adapters.
+// Adapters added by a reload since the last check count as healthy, since
+// initialization pinged them.
+func (r *Runtime) serveReadiness(w http.ResponseWriter, req *http.Request) {
+	if req.Method != http.MethodGet {
+		http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+
+	status := r.health.Load()
+	if status == nil {
+		status = &healthStatus{CheckedAt: time.Now()}
+	}
+
+	response := readinessResponse{
+		Ready:       true,
+		CheckedAt:   status.CheckedAt.UTC().Format(time.RFC3339),
+		Adapters:    make(map[string]adapterReadiness),
+		Maintenance: r.maintenance.Status().Enabled,
+	}
+	for name, conn := range r.state.Load().connectorManager.GetAll() {
+		adapter := adapterReadiness{Healthy: status.Errors[name] == nil}
+		if reporter, ok := conn.(connectors.PoolStatsReporter); ok {
+			stats := reporter.PoolStats()
+			adapter.Pool = &poolReadiness{
+				InUse:          stats.InUse,
+				Idle:           stats.Idle,
+				WaitCount:      stats.WaitCount,
+				WaitDurationMs: float64(stats.WaitDuration.Microseconds()) / 1000,
+			}
+		}
+		response.Adapters[name] = adapter
+		response.Ready = response.Ready && adapter.Healthy
+	}
+
+	w.Header().Set("Content-Type", "application/json")
+	w.Header().Set("Cache-Control", "no-store")
+	if response.Ready {
+		w.WriteHeader(http.StatusOK)
+	} else {
+		w.WriteHeader(http.StatusServiceUnavailable)
+	}
+	json.NewEncoder(w).Encode(response)
+}
@@ -0,0 +1,97 @@
+package server
+
+import (
+	"encoding/json"
+	"net/http"
+	"strconv"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/handlers"
+)
+
+// SetMaintenance switches maintenance mode on or off. While it is on, query
+// endpoints return 503 Service Unavailable with status.Message and MCP tool
+// calls fail with it; health checks and docs keep serving. The mode lasts
+// until it is switched again or a reload changes server.maintenance.
+func (r *Runtime) SetMaintenance(status handlers.MaintenanceStatus) {
+	r.maintenance.Set(status)
+	log := logger.New("runtime")
+	if current := r.maintenance.Status(); current.Enabled {
+		log.Warnf("Maintenance mode on: %s", current.Message)
+	} else {
+		log.Infof("Maintenance mode off")
+	}
+}
+
+// Maintenance returns the current maintenance state
+func (r *Runtime) Maintenance() handlers.MaintenanceStatus {
+	return r.maintenance.Status()
+}
+
+// maintenanceFromConfig returns the maintenance state set by server.maintenance
+func maintenanceFromConfig(model *hyperterse.Model) handlers.MaintenanceStatus {
+	config := model.GetServer().GetMaintenance()
+	return handlers.MaintenanceStatus{
+		Enabled:    config.GetEnabled(),
+		Message:    config.GetMessage(),
+		RetryAfter: config.GetRetryAfter(),
+	}
+}
+
+// refuseInMaintenance wraps a query endpoint so it returns 503 Service
+// Unavailable while maintenance mode is on
+func (r *Runtime) refuseInMaintenance(next http.HandlerFunc) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		status := r.maintenance.Status()
+		if !status.Enabled {
+			next(w, req)
+			return
+		}
+		if status.RetryAfter > 0 {
+			w.Header().Set("Retry-After", strconv.Itoa(int(status.RetryAfter)))
+		}
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusServiceUnavailable)
+		json.NewEncoder(w).Encode(map[string]any{
+			"success": false,
+			"error":   status.Message,
+			"results": []any{},
+		})
+	}
+}
+
+// serveMaintenance reports maintenance mode on GET and switches it on POST,
+// with a body such as {"enabled": true, "message": "Back at 02:00 UTC"}.
+// Messages and retry_after left out of the body fall back to server.maintenance.
+func (r *Runtime) serveMaintenance(state *servingState) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		switch req.Method {
+		case http.MethodGet:
+		case http.MethodPost:
+			var body handlers.MaintenanceStatus
+			if err := json.NewDecoder(req.Body).Decode(&body); err != nil {
+				w.Header().Set("Content-Type", "application/json")
+				w.WriteHeader(http.StatusBadRequest)
+				json.NewEncoder(w).Encode(map[string]any{"success": false, "error": "Invalid JSON", "results": []any{}})
+				return
+			}
+			config := maintenanceFromConfig(state.model)
+			if body.Message == "" {
+				body.Message = config.Message
+			}
+			if body.RetryAfter <= 0 {
+				body.RetryAfter = config.RetryAfter
+			}
+			r.SetMaintenance(body)
+		default:
+			w.Header().Set("Allow", "GET, POST")
+			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+
+		w.Header().Set("Content-Type", "application/json")
+		w.Header().Set("Cache-Control", "no-store")
+		json.NewEncoder(w).Encode(r.maintenance.Status())
+	}
+}
@@ -0,0 +1,63 @@
+package server
+
+import (
+	"errors"
+	"fmt"
+	"net"
+	"os"
+	"path/filepath"
+	"strconv"
+	"syscall"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+const (
+	// portAutoAttempts is how many ports after the configured one are tried
+	// before falling back to an ephemeral port
+	portAutoAttempts = 20
+)
+
+// portFileDir holds one file per configuration name with the bound address
+func portFileDir() string {
+	return filepath.Join(logger.TempDir(), "ports")
+}
+
+// listen binds the server port. With port auto enabled, a port that is in use
+// is skipped for the next free one, falling back to an ephemeral port.
+func (r *Runtime) listen() (net.Listener, error) {
+	listener, err := net.Listen("tcp", ":"+r.port)
+	if err == nil || !r.portAuto || !errors.Is(err, syscall.EADDRINUSE) {
+		return listener, err
+	}
+
+	if base, convErr := strconv.Atoi(r.port); convErr == nil {
+		for p := base + 1; p <= base+portAutoAttempts && p <= 65535; p++ {
+			if listener, err = net.Listen("tcp", ":"+strconv.Itoa(p)); err == nil {
+				return listener, nil
+			}
+		}
+	}
+	return net.Listen("tcp", ":0")
+}
+
+// portFilePath returns the file the bound address is written to for tooling
+func (r *Runtime) portFilePath() string {
+	return filepath.Join(portFileDir(), r.state.Load().model.Name)
+}
+
+// writePortFile records the bound address so scripts can find a server whose
+// port was picked automatically
+func (r *Runtime) writePortFile() {
+	log := logger.New("server")
+	if err := os.MkdirAll(portFileDir(), 0755); err != nil {
+		log.Warnf("Failed to create port file directory: %v", err)
+		return
+	}
+	address := fmt.Sprintf("http://127.0.0.1:%s\n", r.port)
+	if err := os.WriteFile(r.portFilePath(), []byte(address), 0644); err != nil {
+		log.Warnf("Failed to write port file: %v", err)
+		return
+	}
+	log.Debugf("Wrote bound address to %s", r.portFilePath())
+}
@@ -0,0 +1,127 @@
+package server
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sort"
+	"strings"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+// queryNameHeader names the query a request was resolved to when its path
+// spelled the name differently
+const queryNameHeader = "X-Hyperterse-Query"
+
+// maxQuerySuggestions caps the close matches listed in a 404 body
+const maxQuerySuggestions = 3
+
+// queryNotFoundResponse is the JSON body returned for unknown /query/<name> paths
+type queryNotFoundResponse struct {
+	Success     bool     `json:"success"`
+	Error       string   `json:"error"`
+	Results     []any    `json:"results"`
+	Suggestions []string `json:"suggestions,omitempty"`
+	Available   []string `json:"available,omitempty"`
+}
+
+// serveUnmatchedQuery handles /query/ paths that name no query exactly. With
+// server.case_insensitive_queries, a name that matches a query once lowercased
+// and trimmed of spaces and slashes is served by that query's handler;
+// otherwise the response is a 404 suggesting the closest query names. With
+// server.query_index, the 404 also lists every query name. Queries turned off
+// by their enabled field get a 404 saying so.
+func serveUnmatchedQuery(state *servingState, queryHandlers map[string]http.HandlerFunc) http.HandlerFunc {
+	caseInsensitive := state.model.Server != nil && state.model.Server.CaseInsensitiveQueries
+	var available []string
+	if state.model.Server != nil && state.model.Server.QueryIndex {
+		for _, query := range state.model.Queries {
+			available = append(available, query.Name)
+		}
+	}
+	return func(w http.ResponseWriter, req *http.Request) {
+		name := strings.TrimPrefix(req.URL.Path, "/query/")
+		normalized := strings.ToLower(strings.Trim(name, " /"))
+
+		if caseInsensitive {
+			if handler, ok := queryHandlers[normalized]; ok {
+				logger.New("handler").Debugf("Resolved query '%s' to '%s'", name, normalized)
+				w.Header().Set(queryNameHeader, normalized)
+				handler(w, req)
+				return
+			}
+		}
+
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusNotFound)
+		if state.disabled[name] {
+			json.NewEncoder(w).Encode(queryNotFoundResponse{
+				Success: false,
+				Error:   fmt.Sprintf("query '%s' is disabled", name),
+				Results: []any{},
+			})
+			return
+		}
+		json.NewEncoder(w).Encode(queryNotFoundResponse{
+			Success:     false,
+			Error:       fmt.Sprintf("query '%s' not found", name),
+			Results:     []any{},
+			Suggestions: suggestQueryNames(normalized, state.model.Queries),
+			Available:   available,
+		})
+	}
+}
+
+// suggestQueryNames returns the query names closest to name by edit distance,
+// nearest first, leaving out names too far off to be a likely typo
+func suggestQueryNames(name string, queries []*hyperterse.Query) []string {
+	type candidate struct {
+		name     string
+		distance int
+	}
+	limit := max(2, len(name)/3)
+
+	var candidates []candidate
+	for _, query := range queries {
+		if distance := levenshtein(name, query.Name); distance <= limit {
+			candidates = append(candidates, candidate{query.Name, distance})
+		}
+	}
+	sort.Slice(candidates, func(i, j int) bool {
+		if candidates[i].distance != candidates[j].distance {
+			return candidates[i].distance < candidates[j].distance
+		}
+		return candidates[i].name < candidates[j].name
+	})
+
+	var names []string
+	for i := 0; i < len(candidates) && i < maxQuerySuggestions; i++ {
+		names = append(names, candidates[i].name)
+	}
+	return names
+}
+
+// levenshtein returns the number of single-character insertions, deletions
+// and substitutions needed to turn a into b
+func levenshtein(a, b string) int {
+	ar, br := []rune(a), []rune(b)
+	prev := make([]int, len(br)+1)
+	curr := make([]int, len(br)+1)
+	for j := range prev {
+		prev[j] = j
+	}
+	for i := 1; i <= len(ar); i++ {
+		curr[0] = i
+		for j := 1; j <= len(br); j++ {
+			cost := 1
+			if ar[i-1] == br[j-1] {
+				cost = 0
+			}
+			curr[j] = min(prev[j]+1, curr[j-1]+1, prev[j-1]+cost)
+		}
+		prev, curr = curr, prev
+	}
+	return prev[len(br)]
+}
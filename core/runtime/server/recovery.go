@@ -0,0 +1,74 @@
+package server
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"runtime/debug"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+// panicResponseWriter tracks whether a response has started so a recovered
+// panic only writes an error body when nothing has been sent yet
+type panicResponseWriter struct {
+	http.ResponseWriter
+	wroteHeader bool
+}
+
+func (w *panicResponseWriter) WriteHeader(statusCode int) {
+	w.wroteHeader = true
+	w.ResponseWriter.WriteHeader(statusCode)
+}
+
+func (w *panicResponseWriter) Write(b []byte) (int, error) {
+	w.wroteHeader = true
+	return w.ResponseWriter.Write(b)
+}
+
+// Flush keeps streaming (SSE) responses working through the wrapper
+func (w *panicResponseWriter) Flush() {
+	if flusher, ok := w.ResponseWriter.(http.Flusher); ok {
+		w.wroteHeader = true
+		flusher.Flush()
+	}
+}
+
+func (w *panicResponseWriter) Unwrap() http.ResponseWriter {
+	return w.ResponseWriter
+}
+
+// recoverPanics converts panics raised by handlers or connectors into a sanitized
+// 500 JSON response, logging the panic value and stack trace instead of letting
+// net/http reset the connection
+func recoverPanics(next http.Handler) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		pw := &panicResponseWriter{ResponseWriter: w}
+		defer func() {
+			recovered := recover()
+			if recovered == nil {
+				return
+			}
+			// ErrAbortHandler is net/http's sentinel for deliberately aborting a response
+			if recovered == http.ErrAbortHandler {
+				panic(recovered)
+			}
+
+			log := logger.New("server")
+			log.Error(fmt.Sprintf("Panic while handling %s %s: %v\n%s", req.Method, req.URL.Path, recovered, debug.Stack()))
+
+			if pw.wroteHeader {
+				// Too late to change the status; abort so the client sees an incomplete response
+				panic(http.ErrAbortHandler)
+			}
+			pw.Header().Set("Content-Type", "application/json")
+			pw.WriteHeader(http.StatusInternalServerError)
+			json.NewEncoder(pw).Encode(map[string]any{
+				"success": false,
+				"error":   "internal server error",
+				"results": []any{},
+			})
+		}()
+		next.ServeHTTP(pw, req)
+	})
+}
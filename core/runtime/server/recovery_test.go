@@ -0,0 +1,89 @@
+package server
+
+import (
+	"bytes"
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"strings"
+	"testing"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+)
+
+// captureLogs redirects log output to a buffer for the rest of the test
+func captureLogs(t *testing.T) *bytes.Buffer {
+	t.Helper()
+	var buf bytes.Buffer
+	logger.SetOutput(&buf)
+	t.Cleanup(func() { logger.SetOutput(os.Stdout) })
+	return &buf
+}
+
+func TestRecoverPanicsWritesInternalServerError(t *testing.T) {
+	logs := captureLogs(t)
+	handler := recoverPanics(http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		panic("kaboom")
+	}))
+
+	rec := httptest.NewRecorder()
+	handler.ServeHTTP(rec, httptest.NewRequest(http.MethodPost, "/query/get-user", nil))
+
+	if rec.Code != http.StatusInternalServerError {
+		t.Errorf("status = %d, want %d", rec.Code, http.StatusInternalServerError)
+	}
+	if got := rec.Header().Get("Content-Type"); got != "application/json" {
+		t.Errorf("Content-Type = %q, want application/json", got)
+	}
+	var body map[string]any
+	if err := json.Unmarshal(rec.Body.Bytes(), &body); err != nil {
+		t.Fatalf("response body is not JSON: %v", err)
+	}
+	if body["success"] != false || body["error"] != "internal server error" {
+		t.Errorf("body = %v, want a sanitized error", body)
+	}
+	if strings.Contains(rec.Body.String(), "kaboom") {
+		t.Error("response body leaks the panic value")
+	}
+
+	logged := logs.String()
+	if !strings.Contains(logged, "Panic while handling POST /query/get-user: kaboom") {
+		t.Errorf("logs = %q, want the panic value", logged)
+	}
+	if !strings.Contains(logged, "goroutine ") || !strings.Contains(logged, "recovery_test.go") {
+		t.Errorf("logs = %q, want a stack trace through the handler", logged)
+	}
+}
+
+func TestRecoverPanicsAbortsStartedResponse(t *testing.T) {
+	captureLogs(t)
+	handler := recoverPanics(http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		w.WriteHeader(http.StatusOK)
+		panic("kaboom")
+	}))
+
+	defer func() {
+		if recovered := recover(); recovered != http.ErrAbortHandler {
+			t.Errorf("recovered %v, want http.ErrAbortHandler", recovered)
+		}
+	}()
+	handler.ServeHTTP(httptest.NewRecorder(), httptest.NewRequest(http.MethodGet, "/", nil))
+}
+
+func TestRecoverPanicsPassesAbortHandlerThrough(t *testing.T) {
+	logs := captureLogs(t)
+	handler := recoverPanics(http.HandlerFunc(func(w http.ResponseWriter, req *http.Request) {
+		panic(http.ErrAbortHandler)
+	}))
+
+	defer func() {
+		if recovered := recover(); recovered != http.ErrAbortHandler {
+			t.Errorf("recovered %v, want http.ErrAbortHandler", recovered)
+		}
+		if logs.Len() != 0 {
+			t.Errorf("logs = %q, want nothing logged for a deliberate abort", logs.String())
+		}
+	}()
+	handler.ServeHTTP(httptest.NewRecorder(), httptest.NewRequest(http.MethodGet, "/", nil))
+}
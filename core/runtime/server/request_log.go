@@ -0,0 +1,76 @@
+package server
+
+import (
+	"net/http"
+	"sync/atomic"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/observability"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+)
+
+// requestSampler decides which requests to a query endpoint are logged.
+// Failed and slow requests are always logged; of the others, the first of
+// every n is.
+type requestSampler struct {
+	every uint64
+	slow  time.Duration
+	seen  atomic.Uint64
+}
+
+// newRequestSampler samples requests to query as set by server.request_log,
+// with query.log_sample overriding the sample rate
+func newRequestSampler(model *hyperterse.Model, query *hyperterse.Query) *requestSampler {
+	sampler := &requestSampler{every: 1}
+	if model.Server != nil && model.Server.RequestLog != nil {
+		if model.Server.RequestLog.Sample > 0 {
+			sampler.every = uint64(model.Server.RequestLog.Sample)
+		}
+		sampler.slow = time.Duration(model.Server.RequestLog.SlowMs) * time.Millisecond
+	}
+	if query.LogSample > 0 {
+		sampler.every = uint64(query.LogSample)
+	}
+	return sampler
+}
+
+// sample reports whether a request that finished with status after duration is logged
+func (s *requestSampler) sample(status int, duration time.Duration) bool {
+	if status >= http.StatusBadRequest || s.slow > 0 && duration >= s.slow {
+		return true
+	}
+	if s.every <= 1 {
+		return true
+	}
+	return s.seen.Add(1)%s.every == 1
+}
+
+// logRequests logs a line for each request to next that sampler selects:
+// a warning for failed or slow requests, info otherwise
+func logRequests(sampler *requestSampler, next http.HandlerFunc) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		start := time.Now()
+		recorder := &statusRecorder{ResponseWriter: w, statusCode: http.StatusOK}
+		next(recorder, req)
+		duration := time.Since(start)
+
+		if !sampler.sample(recorder.statusCode, duration) {
+			return
+		}
+		log := logger.New("handler")
+		fields := map[string]any{
+			observability.AttrHTTPMethod:     req.Method,
+			observability.AttrHTTPRoute:      req.URL.Path,
+			observability.AttrHTTPStatusCode: recorder.statusCode,
+		}
+		switch {
+		case recorder.statusCode >= http.StatusBadRequest:
+			log.WarnfCtx(req.Context(), fields, "%s %s %d in %s", req.Method, req.URL.Path, recorder.statusCode, duration.Round(time.Microsecond))
+		case sampler.slow > 0 && duration >= sampler.slow:
+			log.WarnfCtx(req.Context(), fields, "%s %s %d in %s (slow)", req.Method, req.URL.Path, recorder.statusCode, duration.Round(time.Microsecond))
+		default:
+			log.InfofCtx(req.Context(), fields, "%s %s %d in %s", req.Method, req.URL.Path, recorder.statusCode, duration.Round(time.Microsecond))
+		}
+	}
+}
@@ -0,0 +1,137 @@
+package server
+
+import (
+	"net"
+	"net/http"
+	"slices"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"go.opentelemetry.io/contrib/instrumentation/net/http/otelhttp"
+)
+
+// Route groups that server.routes can disable or move to another port
+const (
+	routeGroupQueries = "queries" // /query/<name> and the query index
+	routeGroupMCP     = "mcp"     // /mcp
+	routeGroupDocs    = "docs"    // /docs and /llms.txt
+	routeGroupHealth  = "health"  // /heartbeat and /health/ready
+	routeGroupAdmin   = "admin"   // /admin API
+)
+
+// managementRouteGroups are served on server.management_port, when set, unless
+// server.routes gives them a port of their own
+var managementRouteGroups = []string{routeGroupDocs, routeGroupHealth, routeGroupAdmin}
+
+// routeGroup returns the server.routes configuration of a group, or nil when
+// the group is not configured
+func routeGroup(model *hyperterse.Model, name string) *hyperterse.RouteGroupConfig {
+	if model.Server == nil || model.Server.Routes == nil {
+		return nil
+	}
+	routes := model.Server.Routes
+	switch name {
+	case routeGroupQueries:
+		return routes.Queries
+	case routeGroupMCP:
+		return routes.Mcp
+	case routeGroupDocs:
+		return routes.Docs
+	case routeGroupHealth:
+		return routes.Health
+	case routeGroupAdmin:
+		return routes.Admin
+	}
+	return nil
+}
+
+// movedRoutePort returns the port a route group is moved to, or an empty string
+// when it is served on the main port
+func movedRoutePort(model *hyperterse.Model, name string) string {
+	if group := routeGroup(model, name); group != nil && group.Port != "" {
+		return group.Port
+	}
+	if model.Server != nil && model.Server.ManagementPort != "" && slices.Contains(managementRouteGroups, name) {
+		return model.Server.ManagementPort
+	}
+	return ""
+}
+
+// routeGroupPorts returns the ports that route groups are moved to, sorted
+func routeGroupPorts(model *hyperterse.Model) []string {
+	var ports []string
+	for _, name := range []string{routeGroupQueries, routeGroupMCP, routeGroupDocs, routeGroupHealth, routeGroupAdmin} {
+		if group := routeGroup(model, name); group != nil && group.Disabled {
+			continue
+		}
+		if port := movedRoutePort(model, name); port != "" && !slices.Contains(ports, port) {
+			ports = append(ports, port)
+		}
+	}
+	slices.Sort(ports)
+	return ports
+}
+
+// routeGroupPort returns the port a route group is served on
+func (r *Runtime) routeGroupPort(model *hyperterse.Model, name string) string {
+	if port := movedRoutePort(model, name); port != "" {
+		return port
+	}
+	return r.port
+}
+
+// routeMux returns the mux a route group registers its routes on, or nil when
+// the group is disabled
+func (s *servingState) routeMux(name string) *http.ServeMux {
+	if group := routeGroup(s.model, name); group != nil && group.Disabled {
+		return nil
+	}
+	port := movedRoutePort(s.model, name)
+	if port == "" {
+		return s.mux
+	}
+	mux, ok := s.portMuxes[port]
+	if !ok {
+		mux = http.NewServeMux()
+		s.portMuxes[port] = mux
+	}
+	return mux
+}
+
+// startRouteGroupServers listens on every port that route groups were moved
+// to. The ports are fixed at startup; a reload that moves a group to another
+// port only takes effect after a restart.
+func (r *Runtime) startRouteGroupServers() error {
+	log := logger.New("server")
+	for _, port := range routeGroupPorts(r.state.Load().model) {
+		listener, err := net.Listen("tcp", ":"+port)
+		if err != nil {
+			r.stopRouteGroupServers()
+			return log.Errorf("failed to bind route group server on :%s: %w", port, err)
+		}
+		server := &http.Server{
+			Addr:        ":" + port,
+			Handler:     otelhttp.NewHandler(recoverPanics(r.servePort(port)), "hyperterse_http_server"),
+			ReadTimeout: 15 * time.Second,
+			IdleTimeout: 60 * time.Second,
+		}
+		r.groupServers = append(r.groupServers, server)
+
+		go func() {
+			log.Successf("Route groups listening on http://127.0.0.1:%s", port)
+			if err := server.Serve(listener); err != nil && err != http.ErrServerClosed {
+				log.Warnf("Route group server error: %v", err)
+			}
+		}()
+	}
+	return nil
+}
+
+// stopRouteGroupServers closes the route group listeners immediately
+func (r *Runtime) stopRouteGroupServers() {
+	for _, server := range r.groupServers {
+		server.Close()
+	}
+	r.groupServers = nil
+}
@@ -5,45 +5,67 @@ import (
 	"crypto/rand"
 	"encoding/base64"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"io"
+	"math"
 	"net"
 	"net/http"
 	"os"
 	"os/signal"
+	"slices"
+	"strconv"
 	"strings"
+	"sync"
+	"sync/atomic"
 	"syscall"
 	"time"
 
-	"connectrpc.com/connect"
 	"github.com/hyperterse/hyperterse/core/logger"
 	"github.com/hyperterse/hyperterse/core/observability"
 	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
-	"github.com/hyperterse/hyperterse/core/proto/runtime"
 	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/dashboard"
+	"github.com/hyperterse/hyperterse/core/runtime/events"
 	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/runtime/fixtures"
 	"github.com/hyperterse/hyperterse/core/runtime/handlers"
+	"github.com/hyperterse/hyperterse/core/runtime/hooks"
+	"github.com/hyperterse/hyperterse/core/runtime/ratelimit"
+	"github.com/hyperterse/hyperterse/core/runtime/sessions"
 	"go.opentelemetry.io/contrib/instrumentation/net/http/otelhttp"
 	"go.opentelemetry.io/otel"
 	"go.opentelemetry.io/otel/attribute"
 	"go.opentelemetry.io/otel/codes"
 	"go.opentelemetry.io/otel/trace"
+	"google.golang.org/protobuf/proto"
 )
 
 // Runtime represents the Hyperterse runtime server
 type Runtime struct {
-	model            *hyperterse.Model
-	executor         *executor.Executor
-	connectorManager *connectors.ConnectorManager
-	server           *http.Server
-	port             string
-	mux              *http.ServeMux
-	queryHandler     *handlers.QueryServiceHandler
-	mcpHandler       *handlers.MCPServiceHandler
-	shutdownCtx      context.Context
-	shutdownCancel   context.CancelFunc
-	observability    *observability.Providers
-	tracer           trace.Tracer
+	state          atomic.Pointer[servingState]
+	reloadMu       sync.Mutex
+	reloader       func() (*hyperterse.Model, error)
+	server         *http.Server
+	groupServers   []*http.Server
+	port           string
+	portAuto       bool
+	shutdownCtx    context.Context
+	shutdownCancel context.CancelFunc
+	observability  *observability.Providers
+	tracer         trace.Tracer
+	devStatus      *dashboard.StatusBroker
+	sessions       sessions.Store
+	limiter        ratelimit.Limiter
+	events         *events.Bus
+	hooks          *hooks.Dispatcher
+	stopMetrics    func()
+	mcpLogs        *handlers.MCPLogging
+	stopMCPLogs    func()
+	health         atomic.Pointer[healthStatus]
+	fixtures       *fixtures.Store
+	maintenance    *handlers.Maintenance
 }
 
 // NewRuntime creates a new runtime instance
@@ -62,33 +84,149 @@ func NewRuntime(model *hyperterse.Model, port string, serviceVersion string) (*R
 		return nil, log.Errorf("failed to initialize observability: %w", err)
 	}
 
+	// HYPERTERSE_RECORD and HYPERTERSE_REPLAY record or replay connector results
+	fixtureStore, err := fixtures.FromEnv()
+	if err != nil {
+		return nil, log.Errorf("invalid fixture settings: %w", err)
+	}
+
 	// Initialize connectors using ConnectorManager (parallel initialization)
+	// With a ready timeout, the listener only starts once every adapter connects
 	manager := connectors.NewConnectorManager()
-	if err := manager.InitializeAll(model.Adapters); err != nil {
+	if fixtureStore.Replaying() {
+		log.Warnf("Replay mode: serving queries from fixtures in %s without connecting to adapters", fixtureStore.Dir())
+	} else if model.Server != nil && model.Server.ReadyTimeout > 0 {
+		timeout := time.Duration(model.Server.ReadyTimeout) * time.Second
+		log.Infof("Waiting up to %s for adapters to become ready", timeout)
+		if err := manager.InitializeAllWithin(model.Adapters, timeout); err != nil {
+			return nil, err
+		}
+	} else if err := manager.InitializeAll(model.Adapters); err != nil {
 		return nil, err
 	}
 
 	if len(model.Adapters) == 0 {
 		log.Debugf("No adapters to initialize")
 	}
+	if fixtureStore.Recording() {
+		log.Warnf("Record mode: saving query results as fixtures in %s", fixtureStore.Dir())
+	}
 
-	// Create executor with connector manager
-	exec := executor.NewExecutor(model, manager)
-	log.Debugf("Executor created")
+	// MCP sessions outlive model reloads, so the store belongs to the runtime
+	var sessionStore sessions.Store
+	var limiter ratelimit.Limiter
+	if model.Server != nil {
+		sessionStore, err = sessions.NewStore(model.Server.Sessions)
+		if err != nil {
+			manager.CloseAll()
+			return nil, log.Errorf("failed to initialize session store: %w", err)
+		}
+		limiter, err = ratelimit.NewLimiter(model.Server.RateLimit)
+		if err != nil {
+			manager.CloseAll()
+			if sessionStore != nil {
+				sessionStore.Close()
+			}
+			return nil, log.Errorf("failed to initialize rate limiter: %w", err)
+		}
+	}
+
+	// The event bus outlives model reloads. Webhooks and metrics consume it
+	// through the same subscription API offered to embedders.
+	bus := events.NewBus()
+	dispatcher, err := hooks.NewDispatcher(bus, model.Hooks)
+	if err != nil {
+		manager.CloseAll()
+		if sessionStore != nil {
+			sessionStore.Close()
+		}
+		if limiter != nil {
+			limiter.Close()
+		}
+		return nil, log.Errorf("failed to initialize hooks: %w", err)
+	}
+
+	metricsEvents, unsubscribeMetrics := bus.Subscribe(metricsEventBuffer)
+	go recordEventMetrics(metricsEvents)
+
+	// Like sessions, MCP log levels and streams outlive model reloads
+	mcpLogs := handlers.NewMCPLogging(mcpLogLevel(model), mcpSessionTTL(model))
+	mcpLogEvents, unsubscribeMCPLogs := bus.Subscribe(mcpLogEventBuffer)
+	go forwardMCPLogs(mcpLogEvents, mcpLogs)
 
 	shutdownCtx, shutdownCancel := context.WithCancel(context.Background())
 
+	rt := &Runtime{
+		sessions:       sessionStore,
+		limiter:        limiter,
+		events:         bus,
+		hooks:          dispatcher,
+		stopMetrics:    unsubscribeMetrics,
+		mcpLogs:        mcpLogs,
+		stopMCPLogs:    unsubscribeMCPLogs,
+		port:           port,
+		shutdownCtx:    shutdownCtx,
+		shutdownCancel: shutdownCancel,
+		observability:  obsProviders,
+		tracer:         otel.Tracer("runtime"),
+		fixtures:       fixtureStore,
+		maintenance:    handlers.NewMaintenance(),
+	}
+	if status := maintenanceFromConfig(model); status.Enabled {
+		rt.SetMaintenance(status)
+	}
+
+	// Create executor and handlers with connector manager
+	rt.state.Store(newServingState(model, manager, bus, fixtureStore, mcpLogs, rt.maintenance))
+	log.Debugf("Executor created")
+
 	log.Infof("Runtime initialized successfully")
-	return &Runtime{
-		model:            model,
-		executor:         exec,
-		connectorManager: manager,
-		port:             port,
-		shutdownCtx:      shutdownCtx,
-		shutdownCancel:   shutdownCancel,
-		observability:    obsProviders,
-		tracer:           otel.Tracer("runtime"),
-	}, nil
+	return rt, nil
+}
+
+// EnableDevDashboard serves the development dashboard at /_dev, reporting
+// reload and validation status through the given broker.
+// Must be called before StartAsync.
+func (r *Runtime) EnableDevDashboard(broker *dashboard.StatusBroker) {
+	r.devStatus = broker
+}
+
+// SetReloader enables reloading the model on SIGHUP while Start is serving.
+// The function should load and validate the configuration; on error the
+// current model keeps serving.
+func (r *Runtime) SetReloader(reload func() (*hyperterse.Model, error)) {
+	r.reloader = reload
+}
+
+// SetRateLimiter replaces the limiter built from server.rate_limit, allowing
+// embedders to plug in their own backend. Pass nil to disable rate limiting.
+// The limiter it replaces is closed. Must be called before StartAsync.
+func (r *Runtime) SetRateLimiter(limiter ratelimit.Limiter) {
+	if r.limiter != nil {
+		if err := r.limiter.Close(); err != nil {
+			logger.New("server").Warnf("Error closing rate limiter: %v", err)
+		}
+	}
+	r.limiter = limiter
+}
+
+// SetPortAuto makes the runtime listen on the next free port, or an ephemeral
+// one, when its port is in use. The bound address is written to a port file.
+// Must be called before StartAsync.
+func (r *Runtime) SetPortAuto(enabled bool) {
+	r.portAuto = enabled
+}
+
+// Port returns the port the runtime listens on. With port auto enabled, this is
+// the port actually bound once StartAsync returns.
+func (r *Runtime) Port() string {
+	return r.port
+}
+
+// BasePath returns the server.base_path every route is mounted under, or an
+// empty string
+func (r *Runtime) BasePath() string {
+	return r.state.Load().model.GetServer().GetBasePath()
 }
 
 // Start starts the runtime server and blocks until SIGTERM/SIGINT
@@ -97,30 +235,74 @@ func (r *Runtime) Start() error {
 		return err
 	}
 
-	// Wait for interrupt signal
+	// Wait for interrupt signal, reloading on SIGHUP when a reloader is set
+	log := logger.New("engine")
 	quit := make(chan os.Signal, 1)
-	signal.Notify(quit, syscall.SIGINT, syscall.SIGTERM)
-	<-quit
+	signal.Notify(quit, syscall.SIGINT, syscall.SIGTERM, syscall.SIGHUP)
+	for sig := range quit {
+		if sig != syscall.SIGHUP {
+			break
+		}
+		if r.reloader == nil {
+			log.Debugf("Ignoring SIGHUP, reload not configured")
+			continue
+		}
+		log.Infof("Received SIGHUP, reloading configuration")
+		r.Reload()
+	}
 
 	return r.Stop()
 }
 
+// Reload loads a new model with the reloader set by SetReloader and swaps it
+// in, as SIGHUP does. On error the current model keeps serving and the error
+// is returned after being logged. It does nothing when no reloader is set.
+func (r *Runtime) Reload() error {
+	if r.reloader == nil {
+		return nil
+	}
+	log := logger.New("engine")
+	model, err := r.reloader()
+	if err != nil {
+		log.Warnf("Failed to load new config, keeping current model: %v", err)
+		r.events.Publish(events.ConfigReloaded{Err: err})
+		return err
+	}
+	if err := r.ReloadModel(model); err != nil {
+		log.Warnf("Reload failed, keeping current model: %v", err)
+		return err
+	}
+	return nil
+}
+
 // StartAsync starts the runtime server without blocking
 func (r *Runtime) StartAsync() error {
-	r.mux = http.NewServeMux()
 	log := logger.New("server")
 
 	log.Infof("Starting engine")
 	log.Debugf("Creating HTTP server on port %s", r.port)
 
-	r.queryHandler = handlers.NewQueryServiceHandler(r.executor)
-	r.mcpHandler = handlers.NewMCPServiceHandler(r.executor, r.model)
-	log.Debugf("Handlers created")
-	r.registerRoutes()
+	// Bind before registering routes, which embed the port in generated docs
+	requestedPort := r.port
+	listener, err := r.listen()
+	if err != nil {
+		return log.Errorf("failed to bind server on :%s: %w", r.port, err)
+	}
+	r.port = strconv.Itoa(listener.Addr().(*net.TCPAddr).Port)
+	if r.port != requestedPort && requestedPort != "0" {
+		log.Warnf("Port %s is in use, listening on port %s instead", requestedPort, r.port)
+	}
+	if r.portAuto {
+		r.writePortFile()
+	}
+
+	// Connectors were pinged during initialization, so they start out healthy
+	r.health.Store(&healthStatus{CheckedAt: time.Now()})
+	r.registerRoutes(r.state.Load())
 
 	r.server = &http.Server{
 		Addr:         ":" + r.port,
-		Handler:      otelhttp.NewHandler(r.mux, "hyperterse_http_server"),
+		Handler:      otelhttp.NewHandler(recoverPanics(http.HandlerFunc(r.serveHTTP)), "hyperterse_http_server"),
 		ReadTimeout:  15 * time.Second,
 		WriteTimeout: 0, // Disable write timeout for SSE connections (they're long-lived)
 		IdleTimeout:  60 * time.Second,
@@ -128,11 +310,6 @@ func (r *Runtime) StartAsync() error {
 
 	log.Debugf("Engine configuration: ReadTimeout=15s, WriteTimeout=0 (unlimited), IdleTimeout=60s")
 
-	listener, err := net.Listen("tcp", r.server.Addr)
-	if err != nil {
-		return log.Errorf("failed to bind server on %s: %w", r.server.Addr, err)
-	}
-
 	go func() {
 		log.Successf("Hyperterse engine listening on http://127.0.0.1:%s", r.port)
 		if err := r.server.Serve(listener); err != nil && err != http.ErrServerClosed {
@@ -140,29 +317,84 @@ func (r *Runtime) StartAsync() error {
 		}
 	}()
 
+	if err := r.startRouteGroupServers(); err != nil {
+		r.server.Close()
+		return err
+	}
+
+	go r.monitorConnectorHealth()
+
+	r.events.Publish(events.ServerStarted{Port: r.port})
 	return nil
 }
 
-// registerRoutes registers all HTTP routes
-func (r *Runtime) registerRoutes() {
+// registerRoutes registers all HTTP routes on the state's mux
+func (r *Runtime) registerRoutes(state *servingState) {
 	log := logger.New("runtime")
 
 	log.Infof("Registering routes")
 
-	// Create ConnectRPC service implementations
-	queryService := &queryServiceServer{handler: r.queryHandler}
-
 	// Track routes for logging
 	var utilityRoutes []string
 	var queryRoutes []string
 
+	// Each route group can be disabled or moved to its own port with server.routes
+	if mux := state.routeMux(routeGroupMCP); mux != nil {
+		utilityRoutes = append(utilityRoutes, r.registerMCPRoutes(state, mux)...)
+	}
+	if mux := state.routeMux(routeGroupDocs); mux != nil {
+		utilityRoutes = append(utilityRoutes, r.registerDocsRoutes(state, mux)...)
+	}
+	if mux := state.routeMux(routeGroupHealth); mux != nil {
+		utilityRoutes = append(utilityRoutes, r.registerHealthRoutes(mux)...)
+	}
+
+	if mux := state.routeMux(routeGroupAdmin); mux != nil {
+		utilityRoutes = append(utilityRoutes, r.registerAdminRoutes(state, mux)...)
+	}
+
+	// Development dashboard (dev mode only)
+	if r.devStatus != nil {
+		dashboard.Register(state.mux, state.model, state.executor, r.devStatus, r.shutdownCtx.Done())
+		utilityRoutes = append(utilityRoutes, "GET /_dev (development dashboard)")
+	}
+
+	if mux := state.routeMux(routeGroupQueries); mux != nil {
+		queryRoutes = r.registerQueryRoutes(state, mux)
+	}
+
+	// Log all registered routes
+	if basePath := state.model.GetServer().GetBasePath(); basePath != "" {
+		log.Infof("Routes mounted under %s", basePath)
+	}
+	log.Infof("Routes registered: %d utility, %d query", len(utilityRoutes), len(queryRoutes))
+	log.Debugf("Utility routes:")
+	for _, route := range utilityRoutes {
+		log.Debugf("  %s", route)
+	}
+	if len(queryRoutes) > 0 {
+		log.Debugf("Query routes:")
+		for _, route := range queryRoutes {
+			log.Debugf("  %s", route)
+		}
+	}
+}
+
+// registerMCPRoutes registers the MCP Streamable HTTP endpoint
+func (r *Runtime) registerMCPRoutes(state *servingState, mux *http.ServeMux) []string {
+	var routes []string
+
 	// Register MCP endpoint - Streamable HTTP transport (replaces deprecated SSE transport)
 	// MCP Streamable HTTP: POST for client messages, GET for server-initiated messages
-	r.mux.HandleFunc("/mcp", r.instrumentEndpoint("/mcp", func(w http.ResponseWriter, req *http.Request) {
+	mcpAllowHeaders := "Content-Type, Accept, MCP-Protocol-Version, Mcp-Session-Id, Last-Event-ID"
+	if state.model.Server != nil && len(state.model.Server.PassthroughHeaders) > 0 {
+		mcpAllowHeaders += ", " + strings.Join(state.model.Server.PassthroughHeaders, ", ")
+	}
+	mux.HandleFunc("/mcp", r.instrumentEndpoint("/mcp", r.rateLimited(func(w http.ResponseWriter, req *http.Request) {
 		// Set CORS headers for cross-origin requests
 		w.Header().Set("Access-Control-Allow-Origin", "*")
 		w.Header().Set("Access-Control-Allow-Methods", "GET, POST, DELETE, OPTIONS")
-		w.Header().Set("Access-Control-Allow-Headers", "Content-Type, Accept, MCP-Protocol-Version, Mcp-Session-Id, Last-Event-ID")
+		w.Header().Set("Access-Control-Allow-Headers", mcpAllowHeaders)
 
 		// Handle preflight OPTIONS request
 		if req.Method == http.MethodOptions {
@@ -233,14 +465,26 @@ func (r *Runtime) registerRoutes() {
 			}
 
 			// Handle initialize - generate and set session ID BEFORE processing
+			sessionID := req.Header.Get("Mcp-Session-Id")
 			if methodName == "initialize" {
 				// Generate session ID for initialize
-				sessionID := generateSessionID()
+				sessionID = generateSessionID()
+				if r.sessions != nil {
+					if err := r.sessions.Create(req.Context(), sessionID); err != nil {
+						logger.New("mcp").Warnf("Failed to store session: %v", err)
+						http.Error(w, "Failed to create session", http.StatusInternalServerError)
+						return
+					}
+				}
 				w.Header().Set("Mcp-Session-Id", sessionID)
+			} else if !r.checkSession(w, req) {
+				return
 			}
 
 			// Handle JSON-RPC request
-			responseBody, err := handlers.HandleJSONRPC(req.Context(), r.mcpHandler, body)
+			r.mcpLogs.Touch(sessionID)
+			ctx := handlers.WithMCPSession(req.Context(), sessionID)
+			responseBody, err := handlers.HandleJSONRPC(ctx, state.mcpHandler, body)
 			if err != nil {
 				// Return valid JSON-RPC error response
 				errorResponse := map[string]any{
@@ -290,6 +534,9 @@ func (r *Runtime) registerRoutes() {
 				http.Error(w, "Accept header must include 'text/event-stream'", http.StatusBadRequest)
 				return
 			}
+			if !r.checkSession(w, req) {
+				return
+			}
 
 			// Set SSE headers
 			w.Header().Set("Content-Type", "text/event-stream")
@@ -311,10 +558,19 @@ func (r *Runtime) registerRoutes() {
 			// No endpoint event needed for Streamable HTTP (that was SSE-specific)
 			// Server can send notifications/requests as needed
 
+			// Log notifications for this session are written as SSE message events
+			logMessages, unsubscribe := r.mcpLogs.Subscribe(req.Header.Get("Mcp-Session-Id"))
+			defer unsubscribe()
+
 			// Keep connection alive with periodic keep-alive messages
 			// Also listen for server shutdown to close connections gracefully
 			for {
 				select {
+				case message := <-logMessages:
+					fmt.Fprintf(w, "event: message\ndata: %s\n\n", message)
+					if flusher, ok := w.(http.Flusher); ok {
+						flusher.Flush()
+					}
 				case <-req.Context().Done():
 					// Client disconnected
 					return
@@ -337,27 +593,48 @@ func (r *Runtime) registerRoutes() {
 				http.Error(w, "Missing Mcp-Session-Id header", http.StatusBadRequest)
 				return
 			}
-			// Session termination (can be extended to actually manage sessions)
+			if r.sessions != nil {
+				if err := r.sessions.Delete(req.Context(), sessionID); err != nil {
+					logger.New("mcp").Warnf("Failed to delete session: %v", err)
+					http.Error(w, "Failed to terminate session", http.StatusInternalServerError)
+					return
+				}
+			}
+			r.mcpLogs.Forget(sessionID)
 			w.WriteHeader(http.StatusOK)
 
 		default:
 			http.Error(w, "Method not allowed. Only GET, POST, and DELETE requests are supported.", http.StatusMethodNotAllowed)
 		}
-	}))
-	utilityRoutes = append(utilityRoutes, "POST /mcp (Streamable HTTP - JSON-RPC requests)")
-	utilityRoutes = append(utilityRoutes, "GET /mcp (Streamable HTTP - server-initiated messages)")
-	utilityRoutes = append(utilityRoutes, "DELETE /mcp (Streamable HTTP - session termination)")
+	})))
+	routes = append(routes, "POST /mcp (Streamable HTTP - JSON-RPC requests)")
+	routes = append(routes, "GET /mcp (Streamable HTTP - server-initiated messages)")
+	routes = append(routes, "DELETE /mcp (Streamable HTTP - session termination)")
+	return routes
+}
+
+// registerDocsRoutes registers the LLM documentation and OpenAPI spec, which
+// point clients at the port serving queries
+func (r *Runtime) registerDocsRoutes(state *servingState, mux *http.ServeMux) []string {
+	var routes []string
+	baseURL := fmt.Sprintf("http://localhost:%s", r.routeGroupPort(state.model, routeGroupQueries))
 
 	// LLM documentation endpoint
-	r.mux.HandleFunc("/llms.txt", handlers.LLMTxtHandler(r.model, fmt.Sprintf("http://localhost:%s", r.port)))
-	utilityRoutes = append(utilityRoutes, "GET /llms.txt")
+	mux.HandleFunc("/llms.txt", handlers.LLMTxtHandler(state.model, baseURL))
+	routes = append(routes, "GET /llms.txt")
 
 	// OpenAPI/Swagger docs endpoint
-	r.mux.HandleFunc("/docs", handlers.GenerateOpenAPISpecHandler(r.model, fmt.Sprintf("http://localhost:%s", r.port)))
-	utilityRoutes = append(utilityRoutes, "GET /docs")
+	mux.HandleFunc("/docs", handlers.GenerateOpenAPISpecHandler(state.model, baseURL))
+	routes = append(routes, "GET /docs")
+	return routes
+}
+
+// registerHealthRoutes registers the liveness and readiness endpoints
+func (r *Runtime) registerHealthRoutes(mux *http.ServeMux) []string {
+	var routes []string
 
 	// Heartbeat endpoint for health checks
-	r.mux.HandleFunc("/heartbeat", r.instrumentEndpoint("/heartbeat", func(w http.ResponseWriter, req *http.Request) {
+	mux.HandleFunc("/heartbeat", r.instrumentEndpoint("/heartbeat", func(w http.ResponseWriter, req *http.Request) {
 		if req.Method != http.MethodGet {
 			http.Error(w, "Method not allowed", http.StatusMethodNotAllowed)
 			return
@@ -366,17 +643,82 @@ func (r *Runtime) registerRoutes() {
 		w.WriteHeader(http.StatusOK)
 		json.NewEncoder(w).Encode(map[string]bool{"success": true})
 	}))
-	utilityRoutes = append(utilityRoutes, "GET /heartbeat")
+	routes = append(routes, "GET /heartbeat")
+
+	// Readiness endpoint answered from the cached background health check
+	mux.HandleFunc("/health/ready", r.instrumentEndpoint("/health/ready", r.serveReadiness))
+	routes = append(routes, "GET /health/ready")
+	return routes
+}
+
+// queryErrorStatus maps a query error to an HTTP status. Invalid inputs are a
+// 400, unique constraint violations a 409 and other constraint violations a
+// 422; these are the caller's to fix. A timeout is a 504 and connector failures
+// that may clear on retry a 503. Every other failure, such as a syntax error in
+// the configured statement, a missing table or rejected credentials, is the
+// server's fault and a 500.
+func queryErrorStatus(err error) int {
+	var inputErr *executor.InputError
+	if errors.As(err, &inputErr) {
+		return http.StatusBadRequest
+	}
+	if violation, ok := connectors.AsConstraintViolation(err); ok {
+		if violation.Kind == connectors.ConstraintUnique {
+			return http.StatusConflict
+		}
+		return http.StatusUnprocessableEntity
+	}
+	switch connectors.ClassifyError(err) {
+	case connectors.ErrorClassTimeout:
+		return http.StatusGatewayTimeout
+	case connectors.ErrorClassTransient, connectors.ErrorClassUnavailable:
+		return http.StatusServiceUnavailable
+	default:
+		return http.StatusInternalServerError
+	}
+}
+
+// constraintViolationResponse is the JSON body of a query rejected by a
+// database constraint. The driver's message is left out as it can echo the
+// offending values; the constraint name is only included when exposed.
+func constraintViolationResponse(violation connectors.ConstraintViolation, exposeName bool) map[string]any {
+	response := map[string]any{
+		"success": false,
+		"error":   violation.Message(),
+		"code":    string(violation.Kind),
+		"results": []any{},
+	}
+	if exposeName && violation.Constraint != "" {
+		response["constraint"] = violation.Constraint
+	}
+	return response
+}
+
+// registerQueryRoutes registers an endpoint for each query, the handler for
+// unmatched query names and the optional query index
+func (r *Runtime) registerQueryRoutes(state *servingState, mux *http.ServeMux) []string {
+	var routes []string
+
+	exposeConstraintNames := state.model.Server != nil && state.model.Server.ExposeConstraintNames
 
 	// Register individual endpoints for each query
-	for _, query := range r.model.Queries {
+	queryHandlers := make(map[string]http.HandlerFunc, len(state.model.Queries))
+	for _, query := range state.model.Queries {
 		queryName := query.Name
 		endpointPath := "/query/" + queryName
 
-		r.mux.HandleFunc(endpointPath, r.instrumentEndpoint(endpointPath, func(q *hyperterse.Query) http.HandlerFunc {
+		sampler := newRequestSampler(state.model, query)
+		// GET is only served for queries that read, so link prefetchers,
+		// crawlers and cross-site requests cannot trigger writes
+		allowGet := executorutils.IsReadOnlyQuery(state.model, query)
+		allowed := "POST"
+		if allowGet {
+			allowed = "GET, POST"
+		}
+		handler := r.instrumentEndpoint(endpointPath, logRequests(sampler, r.rateLimited(r.refuseInMaintenance(func(q *hyperterse.Query) http.HandlerFunc {
 			return func(w http.ResponseWriter, req *http.Request) {
 				handlerLog := logger.New("handler")
-				handlerLog.InfofCtx(req.Context(), map[string]any{
+				handlerLog.DebugfCtx(req.Context(), map[string]any{
 					observability.AttrHTTPMethod: req.Method,
 					observability.AttrHTTPRoute:  req.URL.Path,
 					observability.AttrQueryName:  q.Name,
@@ -397,141 +739,171 @@ func (r *Runtime) registerRoutes() {
 					json.NewEncoder(w).Encode(responseJSON)
 				}
 
-				if req.Method != http.MethodPost {
+				var requestBody map[string]any
+				switch req.Method {
+				case http.MethodPost:
+					// Parse JSON body
+					inputs, err := executorutils.DecodeJSONInputs(req.Body)
+					if err != nil {
+						var validationErr *executorutils.ValidationError
+						if errors.As(err, &validationErr) {
+							handlerLog.Warnf("Invalid request body: %v", err)
+							writeErrorResponse(w, http.StatusBadRequest, "input validation failed: "+err.Error())
+							return
+						}
+						handlerLog.Warnf("Failed to parse JSON body: %v", err)
+						writeErrorResponse(w, http.StatusBadRequest, "Invalid JSON")
+						return
+					}
+					requestBody = inputs
+					handlerLog.Debugf("Request body parsed, %d input(s)", len(requestBody))
+				case http.MethodGet:
+					if !allowGet {
+						handlerLog.Warnf("GET refused for query '%s', which writes", q.Name)
+						w.Header().Set("Allow", allowed)
+						writeErrorResponse(w, http.StatusMethodNotAllowed, "Method not allowed: this query writes data and only accepts POST")
+						return
+					}
+					// Inputs come from inputs.* query string parameters
+					inputs, err := executorutils.InputsFromQueryParams(q, req.URL.Query())
+					if err != nil {
+						handlerLog.Warnf("Invalid query parameters: %v", err)
+						writeErrorResponse(w, http.StatusBadRequest, "input validation failed: "+err.Error())
+						return
+					}
+					if q.Cursor != nil && req.URL.Query().Has(executorutils.CursorParam) {
+						inputs[executorutils.CursorParam] = req.URL.Query().Get(executorutils.CursorParam)
+					}
+					requestBody = inputs
+					handlerLog.Debugf("Query parameters parsed, %d input(s)", len(requestBody))
+				default:
 					handlerLog.Warnf("Method not allowed: %s", req.Method)
+					w.Header().Set("Allow", allowed)
 					writeErrorResponse(w, http.StatusMethodNotAllowed, "Method not allowed")
 					return
 				}
 
-				// Parse JSON body
-				var requestBody map[string]any
-				if err := json.NewDecoder(req.Body).Decode(&requestBody); err != nil {
-					handlerLog.Warnf("Failed to parse JSON body: %v", err)
-					writeErrorResponse(w, http.StatusBadRequest, "Invalid JSON")
-					return
-				}
-				handlerLog.Debugf("Request body parsed, %d input(s)", len(requestBody))
-
-				// Convert inputs to map[string]string (JSON-encoded)
-				inputs := make(map[string]string)
-				for k, v := range requestBody {
-					jsonBytes, _ := json.Marshal(v)
-					inputs[k] = string(jsonBytes)
-				}
-
-				// Execute query
-				reqProto := &runtime.ExecuteQueryRequest{
-					QueryName: q.Name,
-					Inputs:    inputs,
-				}
-				resp, err := queryService.ExecuteQuery(req.Context(), connect.NewRequest(reqProto))
+				// Execute directly against the executor and encode rows straight into the
+				// response body, avoiding a per-value JSON round trip through proto messages.
+				// Rows are encoded in columnar form so column names are escaped once.
+				result, err := state.executor.Execute(req.Context(), q.Name, requestBody)
 				if err != nil {
-					handlerLog.Warnf("Query execution failed: %v", err)
-					writeErrorResponse(w, http.StatusInternalServerError, err.Error())
+					status := queryErrorStatus(err)
+					handlerLog.Warnf("Query returned error: %s", err.Error())
+					handlerLog.Debugf("Response: %d", status)
+					if violation, ok := connectors.AsConstraintViolation(err); ok {
+						w.Header().Set("Content-Type", "application/json")
+						w.WriteHeader(status)
+						json.NewEncoder(w).Encode(constraintViolationResponse(violation, exposeConstraintNames))
+						return
+					}
+					writeErrorResponse(w, status, handlers.ErrorMessage(state.model, err))
 					return
 				}
+				handlerLog.Debugf("Query executed successfully, %d result(s)", len(result.Rows))
+				handlerLog.Debugf("Response: %d", http.StatusOK)
 
-				// Return response
-				w.Header().Set("Content-Type", "application/json")
-				statusCode := http.StatusOK
-				if !resp.Msg.Success {
-					statusCode = http.StatusBadRequest
-					handlerLog.Warnf("Query returned error: %s", resp.Msg.Error)
-				} else {
-					handlerLog.Debugf("Query executed successfully, %d result(s)", len(resp.Msg.Results))
+				rows := result.Rows
+				// Ensure 'results' is always an array, never null
+				if rows == nil {
+					rows = []map[string]any{}
 				}
-				handlerLog.Infof("Response: %d", statusCode)
-				w.WriteHeader(statusCode)
-
-				// Manually construct response to ensure 'results' is always included
-				// (protobuf's omitempty tag would omit empty slices)
-				responseJSON := map[string]any{
-					"success": resp.Msg.Success,
-					"error":   resp.Msg.Error,
-					"results": make([]any, 0),
+				response := queryResponse{
+					Success:    true,
+					Results:    executorutils.NewRowSet(rows),
+					Truncated:  result.Truncated,
+					NextCursor: result.NextCursor,
 				}
-
-				// Convert results from proto format to regular JSON
-				if len(resp.Msg.Results) > 0 {
-					results := make([]map[string]any, len(resp.Msg.Results))
-					for i, row := range resp.Msg.Results {
-						rowMap := make(map[string]any)
-						for key, valueJSON := range row.Fields {
-							var value any
-							if err := json.Unmarshal([]byte(valueJSON), &value); err != nil {
-								// If unmarshaling fails, treat as string
-								value = valueJSON
-							}
-							rowMap[key] = value
-						}
-						results[i] = rowMap
+				if meta := handlers.BuildResponseMeta(state.model, q.Name, result); meta != nil {
+					response.Meta = &queryResponseMeta{
+						QueryName:  meta.QueryName,
+						DurationMs: meta.DurationMs,
+						RowCount:   meta.RowCount,
+						CacheHit:   meta.CacheHit,
 					}
-					responseJSON["results"] = results
 				}
 
-				json.NewEncoder(w).Encode(responseJSON)
+				w.Header().Set("Content-Type", "application/json")
+				if q.CacheControl != "" {
+					w.Header().Set("Cache-Control", q.CacheControl)
+				}
+				w.WriteHeader(http.StatusOK)
+				json.NewEncoder(w).Encode(response)
 			}
-		}(query)))
-
-		queryRoutes = append(queryRoutes, fmt.Sprintf("POST %s", endpointPath))
+		}(query)))))
+		mux.HandleFunc(endpointPath, handler)
+		queryHandlers[queryName] = handler
+
+		if allowGet {
+			routes = append(routes, fmt.Sprintf("GET|POST %s", endpointPath))
+		} else {
+			routes = append(routes, fmt.Sprintf("POST %s", endpointPath))
+		}
 	}
 
-	// Log all registered routes
-	log.Infof("Routes registered: %d utility, %d query", len(utilityRoutes), len(queryRoutes))
-	log.Debugf("Utility routes:")
-	for _, route := range utilityRoutes {
-		log.Debugf("  %s", route)
-	}
-	if len(queryRoutes) > 0 {
-		log.Debugf("Query routes:")
-		for _, route := range queryRoutes {
-			log.Debugf("  %s", route)
-		}
+	// Other /query/ paths resolve case-insensitively when enabled, or get a 404 suggesting close names
+	mux.HandleFunc("/query/", serveUnmatchedQuery(state, queryHandlers))
+
+	// Query index for client discovery, opt-in so production servers need not list their queries
+	if state.model.Server != nil && state.model.Server.QueryIndex {
+		mux.HandleFunc("/query", r.instrumentEndpoint("/query", handlers.QueryIndexHandler(state.model)))
+		routes = append(routes, "GET /query")
 	}
+	return routes
 }
 
-// ReloadModel reloads the model without restarting the HTTP server
+// ReloadModel reloads the model without restarting the HTTP server.
+// New connectors, executor and routes are built first and swapped in atomically;
+// if initialization fails the current model keeps serving. The previous
+// connectors are closed once their in-flight requests finish.
 func (r *Runtime) ReloadModel(model *hyperterse.Model) error {
+	r.reloadMu.Lock()
+	defer r.reloadMu.Unlock()
+
 	log := logger.New("engine")
 	log.Infof("Reloading model")
 
-	// Close existing connectors in parallel
-	if err := r.connectorManager.CloseAll(); err != nil {
-		log.Warnf("Errors closing connectors: %v", err)
-	}
-
-	// Initialize new connectors in parallel using a new manager
+	// Initialize new connectors in parallel using a new manager, which keeps
+	// counting adapter statistics where the current one left off
 	newManager := connectors.NewConnectorManager()
-	if err := newManager.InitializeAll(model.Adapters); err != nil {
+	newManager.InheritStats(r.state.Load().connectorManager)
+	if !r.fixtures.Replaying() {
+		if err := newManager.InitializeAll(model.Adapters); err != nil {
+			r.events.Publish(events.ConfigReloaded{Err: err})
+			return err
+		}
+	}
+	if err := r.hooks.SetHooks(model.Hooks); err != nil {
+		newManager.CloseAll()
+		err = log.Errorf("failed to configure hooks: %w", err)
+		r.events.Publish(events.ConfigReloaded{Err: err})
 		return err
 	}
 
-	// Update model
-	r.model = model
+	newState := newServingState(model, newManager, r.events, r.fixtures, r.mcpLogs, r.maintenance)
+	log.Debugf("Executor and handlers recreated")
 
-	// Create new executor with the new manager
-	r.executor = executor.NewExecutor(model, newManager)
-	log.Debugf("Executor recreated")
-
-	// Update connector manager
-	r.connectorManager = newManager
-
-	// Update handlers
-	r.queryHandler = handlers.NewQueryServiceHandler(r.executor)
-	r.mcpHandler = handlers.NewMCPServiceHandler(r.executor, r.model)
-	log.Debugf("Handlers recreated")
+	// Routes are only needed once the server is running; StartAsync registers them otherwise
+	if r.server != nil {
+		r.registerRoutes(newState)
+	}
 
-	// Re-register routes (this will update the handlers)
-	r.mux = http.NewServeMux()
-	r.registerRoutes()
+	if r.server != nil && !slices.Equal(routeGroupPorts(model), routeGroupPorts(r.state.Load().model)) {
+		log.Warnf("Route group ports changed; restart the server to listen on the new ports")
+	}
 
-	// Update server handler
-	if r.server != nil {
-		r.server.Handler = r.mux
-		log.Debugf("Server handler updated")
+	oldState := r.state.Swap(newState)
+	r.mcpLogs.SetDefaultLevel(mcpLogLevel(model))
+	// Maintenance mode switched through the admin API outlasts reloads that
+	// leave server.maintenance unchanged
+	if !proto.Equal(oldState.model.GetServer().GetMaintenance(), model.GetServer().GetMaintenance()) {
+		r.SetMaintenance(maintenanceFromConfig(model))
 	}
+	log.Debugf("Serving state swapped")
+	go oldState.retire()
 
 	log.Infof("Model reloaded successfully")
+	r.events.Publish(events.ConfigReloaded{Adapters: len(model.Adapters), Queries: len(model.Queries)})
 	return nil
 }
 
@@ -558,7 +930,7 @@ func (r *Runtime) Stop() error {
 	defer cancel()
 
 	// Close all connectors in parallel
-	if err := r.connectorManager.CloseAll(); err != nil {
+	if err := r.state.Load().connectorManager.CloseAll(); err != nil {
 		log.Warnf("Errors closing connectors: %v", err)
 	} else {
 		log.Debugf("All connectors closed")
@@ -575,6 +947,37 @@ func (r *Runtime) Stop() error {
 			return log.Errorf("failed to shutdown server gracefully: %w", err)
 		}
 		log.Debugf("Engine stopped")
+		if r.portAuto {
+			os.Remove(r.portFilePath())
+		}
+	}
+	for _, server := range r.groupServers {
+		if err := server.Shutdown(ctx); err != nil {
+			server.Close()
+		}
+	}
+
+	if r.sessions != nil {
+		if err := r.sessions.Close(); err != nil {
+			log.Warnf("Error closing session store: %v", err)
+		}
+	}
+	if r.limiter != nil {
+		if err := r.limiter.Close(); err != nil {
+			log.Warnf("Error closing rate limiter: %v", err)
+		}
+	}
+
+	if r.stopMetrics != nil {
+		r.stopMetrics()
+	}
+	if r.stopMCPLogs != nil {
+		r.stopMCPLogs()
+	}
+	if r.hooks != nil {
+		hooksCtx, hooksCancel := context.WithTimeout(context.Background(), 5*time.Second)
+		r.hooks.Close(hooksCtx)
+		hooksCancel()
 	}
 
 	log.Infof("Engine shutdown complete")
@@ -590,17 +993,86 @@ func (r *Runtime) Stop() error {
 	return nil
 }
 
-// Wrapper types for ConnectRPC compatibility
-type queryServiceServer struct {
-	handler *handlers.QueryServiceHandler
+// queryResponse is the JSON body returned by /query/<name> endpoints
+type queryResponse struct {
+	Success    bool                  `json:"success"`
+	Error      string                `json:"error"`
+	Results    *executorutils.RowSet `json:"results"`
+	Truncated  bool                  `json:"truncated,omitempty"`
+	NextCursor string                `json:"next_cursor,omitempty"`
+	Meta       *queryResponseMeta    `json:"meta,omitempty"`
 }
 
-func (s *queryServiceServer) ExecuteQuery(ctx context.Context, req *connect.Request[runtime.ExecuteQueryRequest]) (*connect.Response[runtime.ExecuteQueryResponse], error) {
-	resp, err := s.handler.ExecuteQuery(ctx, req.Msg)
+type queryResponseMeta struct {
+	QueryName  string  `json:"query_name"`
+	DurationMs float64 `json:"duration_ms"`
+	RowCount   int32   `json:"row_count"`
+	CacheHit   bool    `json:"cache_hit"`
+}
+
+// rateLimited rejects requests beyond the configured per-client rate limit with
+// 429 Too Many Requests. Clients are identified by their remote IP address.
+func (r *Runtime) rateLimited(next http.HandlerFunc) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		if r.limiter == nil || req.Method == http.MethodOptions {
+			next(w, req)
+			return
+		}
+
+		clientIP, _, err := net.SplitHostPort(req.RemoteAddr)
+		if err != nil {
+			clientIP = req.RemoteAddr
+		}
+		decision, err := r.limiter.Allow(req.Context(), clientIP)
+		if err != nil {
+			// Fail open: an unavailable limiter should not take the API down
+			logger.New("ratelimit").Warnf("Rate limit check failed: %v", err)
+			next(w, req)
+			return
+		}
+
+		w.Header().Set("X-RateLimit-Limit", strconv.Itoa(decision.Limit))
+		w.Header().Set("X-RateLimit-Remaining", strconv.Itoa(decision.Remaining))
+		if !decision.Allowed {
+			retryAfter := int(math.Ceil(decision.ResetAfter.Seconds()))
+			w.Header().Set("Retry-After", strconv.Itoa(retryAfter))
+			w.Header().Set("Content-Type", "application/json")
+			w.WriteHeader(http.StatusTooManyRequests)
+			json.NewEncoder(w).Encode(map[string]any{
+				"success": false,
+				"error":   "rate limit exceeded",
+				"results": []any{},
+			})
+			return
+		}
+		next(w, req)
+	}
+}
+
+// checkSession verifies the request's Mcp-Session-Id against the session store,
+// writing an error response and returning false when it is missing or unknown.
+// Without a session store every request is accepted.
+func (r *Runtime) checkSession(w http.ResponseWriter, req *http.Request) bool {
+	if r.sessions == nil {
+		return true
+	}
+	sessionID := req.Header.Get("Mcp-Session-Id")
+	if sessionID == "" {
+		http.Error(w, "Missing Mcp-Session-Id header", http.StatusBadRequest)
+		return false
+	}
+	ok, err := r.sessions.Touch(req.Context(), sessionID)
 	if err != nil {
-		return nil, connect.NewError(connect.CodeInternal, err)
+		logger.New("mcp").Warnf("Failed to look up session: %v", err)
+		http.Error(w, "Failed to look up session", http.StatusInternalServerError)
+		return false
+	}
+	if !ok {
+		// Per the Streamable HTTP transport, clients must re-initialize on 404
+		http.Error(w, "Session not found", http.StatusNotFound)
+		return false
 	}
-	return connect.NewResponse(resp), nil
+	return true
 }
 
 // generateSessionID generates a secure session ID for MCP sessions
@@ -0,0 +1,181 @@
+package server
+
+import (
+	"net/http"
+	"sync/atomic"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/connectors"
+	"github.com/hyperterse/hyperterse/core/runtime/events"
+	"github.com/hyperterse/hyperterse/core/runtime/executor"
+	executorutils "github.com/hyperterse/hyperterse/core/runtime/executor/utils"
+	"github.com/hyperterse/hyperterse/core/runtime/failures"
+	"github.com/hyperterse/hyperterse/core/runtime/fixtures"
+	"github.com/hyperterse/hyperterse/core/runtime/handlers"
+	"google.golang.org/protobuf/proto"
+)
+
+// drainTimeout bounds how long a replaced serving state waits for in-flight
+// requests before its connectors are closed
+const drainTimeout = 30 * time.Second
+
+// servingState holds everything derived from a single model. The runtime swaps
+// the whole state atomically on reload, so each request sees one consistent
+// model, executor and set of connectors from start to finish.
+type servingState struct {
+	model            *hyperterse.Model
+	executor         *executor.Executor
+	connectorManager *connectors.ConnectorManager
+	queryHandler     *handlers.QueryServiceHandler
+	mcpHandler       *handlers.MCPServiceHandler
+	mux              *http.ServeMux
+	portMuxes        map[string]*http.ServeMux // route groups moved to their own port, by port
+	queries          []queryState              // every query of the loaded model, including disabled ones
+	disabled         map[string]bool           // queries left out of model by their enabled field
+	failures         *failures.Store           // debug bundles of failed executions, nil when disabled
+	inflight         atomic.Int64
+}
+
+func newServingState(model *hyperterse.Model, manager *connectors.ConnectorManager, bus *events.Bus, fixtureStore *fixtures.Store, mcpLogs *handlers.MCPLogging, maintenance *handlers.Maintenance) *servingState {
+	model, queries := servedModel(model)
+	disabled := make(map[string]bool)
+	for _, query := range queries {
+		if !query.Enabled {
+			disabled[query.Name] = true
+		}
+	}
+	exec := executor.NewExecutor(model, manager)
+	exec.SetEventBus(bus)
+	exec.SetFixtures(fixtureStore)
+	failureStore := failureCaptureStore(model)
+	exec.SetFailureCapture(failureStore)
+	mcpHandler := handlers.NewMCPServiceHandler(exec, model)
+	mcpHandler.SetLogging(mcpLogs)
+	mcpHandler.SetMaintenance(maintenance)
+	return &servingState{
+		model:            model,
+		executor:         exec,
+		connectorManager: manager,
+		queryHandler:     handlers.NewQueryServiceHandler(exec, model),
+		mcpHandler:       mcpHandler,
+		mux:              http.NewServeMux(),
+		portMuxes:        make(map[string]*http.ServeMux),
+		queries:          queries,
+		disabled:         disabled,
+		failures:         failureStore,
+	}
+}
+
+// queryState is the enabled state of a query, as listed by the admin API
+type queryState struct {
+	Name    string `json:"name"`
+	Enabled bool   `json:"enabled"`
+	Error   string `json:"error,omitempty"` // Why the enabled field could not be resolved
+}
+
+// servedModel returns model without the queries its enabled fields turn off,
+// so they get no route, docs entry or MCP tool, along with the state of every
+// query. Queries whose enabled field does not resolve are turned off.
+func servedModel(model *hyperterse.Model) (*hyperterse.Model, []queryState) {
+	log := logger.New("runtime")
+	states := make([]queryState, 0, len(model.Queries))
+	served := make([]*hyperterse.Query, 0, len(model.Queries))
+	for _, query := range model.Queries {
+		enabled, err := executorutils.QueryEnabled(query)
+		state := queryState{Name: query.Name, Enabled: enabled}
+		switch {
+		case err != nil:
+			log.Warnf("Query '%s' is disabled: %v", query.Name, err)
+			state.Error = err.Error()
+		case !enabled:
+			log.Infof("Query '%s' is disabled", query.Name)
+		default:
+			served = append(served, query)
+		}
+		states = append(states, state)
+	}
+	if len(served) == len(model.Queries) {
+		return model, states
+	}
+	filtered := proto.Clone(model).(*hyperterse.Model)
+	filtered.Queries = served
+	return filtered, states
+}
+
+// serveHTTP dispatches a request on the main port to the current serving state
+func (r *Runtime) serveHTTP(w http.ResponseWriter, req *http.Request) {
+	r.serveOn("", w, req)
+}
+
+// servePort returns a handler dispatching requests received on a route group
+// port to the current serving state
+func (r *Runtime) servePort(port string) http.HandlerFunc {
+	return func(w http.ResponseWriter, req *http.Request) {
+		r.serveOn(port, w, req)
+	}
+}
+
+// serveOn dispatches a request to the mux serving port, the main mux when port is empty
+func (r *Runtime) serveOn(port string, w http.ResponseWriter, req *http.Request) {
+	state := r.acquireState()
+	defer state.inflight.Add(-1)
+	if state.model.Server != nil && len(state.model.Server.PassthroughHeaders) > 0 {
+		if headers := executor.CaptureHeaders(req.Header, state.model.Server.PassthroughHeaders); headers != nil {
+			req = req.WithContext(executor.WithRequestHeaders(req.Context(), headers))
+		}
+	}
+	req, ok := withAdapterOverride(state, w, req)
+	if !ok {
+		return
+	}
+	mux := state.mux
+	if port != "" {
+		// A reload may have moved every group off this port
+		if mux = state.portMuxes[port]; mux == nil {
+			http.NotFound(w, req)
+			return
+		}
+	}
+	// Routes are registered without server.base_path, which is removed here so
+	// requests outside it get a 404
+	if basePath := state.model.GetServer().GetBasePath(); basePath != "" {
+		http.StripPrefix(basePath, mux).ServeHTTP(w, req)
+		return
+	}
+	mux.ServeHTTP(w, req)
+}
+
+// acquireState returns the current serving state with the request counted as
+// in flight on it. The count is only kept once the state is seen to still be
+// current after it is taken, since a state swapped out before then may already
+// have been retired.
+func (r *Runtime) acquireState() *servingState {
+	for {
+		state := r.state.Load()
+		state.inflight.Add(1)
+		if r.state.Load() == state {
+			return state
+		}
+		state.inflight.Add(-1)
+	}
+}
+
+// retire closes the connectors of a replaced serving state once its in-flight
+// requests have finished, or after drainTimeout for long-lived streams
+func (s *servingState) retire() {
+	log := logger.New("engine")
+	deadline := time.Now().Add(drainTimeout)
+	for s.inflight.Load() > 0 && time.Now().Before(deadline) {
+		time.Sleep(100 * time.Millisecond)
+	}
+	if remaining := s.inflight.Load(); remaining > 0 {
+		log.Warnf("Closing previous connectors with %d request(s) still in flight", remaining)
+	}
+	if err := s.connectorManager.CloseAll(); err != nil {
+		log.Warnf("Errors closing previous connectors: %v", err)
+	} else {
+		log.Debugf("Previous connectors closed")
+	}
+}
@@ -0,0 +1,71 @@
+package sessions
+
+import (
+	"context"
+	"sync"
+	"time"
+)
+
+// MemoryStore keeps sessions in process memory. Sessions are lost on restart
+// and are not shared between replicas.
+type MemoryStore struct {
+	mu       sync.Mutex
+	ttl      time.Duration
+	sessions map[string]time.Time // session ID -> expiry
+}
+
+// NewMemoryStore creates an in-memory session store
+func NewMemoryStore(ttl time.Duration) *MemoryStore {
+	return &MemoryStore{
+		ttl:      ttl,
+		sessions: make(map[string]time.Time),
+	}
+}
+
+// Create registers a new session
+func (s *MemoryStore) Create(ctx context.Context, id string) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.evictExpired()
+	s.sessions[id] = time.Now().Add(s.ttl)
+	return nil
+}
+
+// Touch reports whether the session exists and extends its expiry
+func (s *MemoryStore) Touch(ctx context.Context, id string) (bool, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	expiry, ok := s.sessions[id]
+	if !ok {
+		return false, nil
+	}
+	if time.Now().After(expiry) {
+		delete(s.sessions, id)
+		return false, nil
+	}
+	s.sessions[id] = time.Now().Add(s.ttl)
+	return true, nil
+}
+
+// Delete terminates a session
+func (s *MemoryStore) Delete(ctx context.Context, id string) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	delete(s.sessions, id)
+	return nil
+}
+
+// Close is a no-op for the in-memory store
+func (s *MemoryStore) Close() error {
+	return nil
+}
+
+// evictExpired drops expired sessions; callers must hold s.mu
+func (s *MemoryStore) evictExpired() {
+	now := time.Now()
+	for id, expiry := range s.sessions {
+		if now.After(expiry) {
+			delete(s.sessions, id)
+		}
+	}
+}
@@ -0,0 +1,37 @@
+//go:build no_redis
+
+package sessions
+
+import (
+	"context"
+	"fmt"
+	"time"
+)
+
+// RedisStore is unavailable in builds with the no_redis tag.
+type RedisStore struct{}
+
+// NewRedisStore reports that Redis support was not compiled in
+func NewRedisStore(url string, ttl time.Duration) (*RedisStore, error) {
+	return nil, fmt.Errorf("redis session store is not available in this build: rebuild without the no_redis tag")
+}
+
+// Create is never reached since the store cannot be constructed
+func (s *RedisStore) Create(ctx context.Context, id string) error {
+	return fmt.Errorf("redis session store is not available in this build")
+}
+
+// Touch is never reached since the store cannot be constructed
+func (s *RedisStore) Touch(ctx context.Context, id string) (bool, error) {
+	return false, fmt.Errorf("redis session store is not available in this build")
+}
+
+// Delete is never reached since the store cannot be constructed
+func (s *RedisStore) Delete(ctx context.Context, id string) error {
+	return fmt.Errorf("redis session store is not available in this build")
+}
+
+// Close is a no-op
+func (s *RedisStore) Close() error {
+	return nil
+}
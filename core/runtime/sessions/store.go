@@ -0,0 +1,61 @@
+package sessions
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/hyperterse/hyperterse/core/proto/hyperterse"
+	"github.com/hyperterse/hyperterse/core/runtime/utils"
+)
+
+// Supported session store backends
+const (
+	StoreMemory = "memory"
+	StoreRedis  = "redis"
+)
+
+// DefaultTTL is how long an idle MCP session is kept when no ttl is configured
+const DefaultTTL = time.Hour
+
+// Store persists MCP session IDs. Implementations must be safe for concurrent use.
+type Store interface {
+	// Create registers a new session
+	Create(ctx context.Context, id string) error
+	// Touch reports whether the session exists and extends its expiry
+	Touch(ctx context.Context, id string) (bool, error)
+	// Delete terminates a session
+	Delete(ctx context.Context, id string) error
+	// Close releases resources held by the store
+	Close() error
+}
+
+// NewStore creates the session store described by config.
+// A nil config returns a nil store, meaning sessions are not tracked.
+func NewStore(config *hyperterse.SessionConfig) (Store, error) {
+	if config == nil {
+		return nil, nil
+	}
+
+	ttl := DefaultTTL
+	if config.Ttl > 0 {
+		ttl = time.Duration(config.Ttl) * time.Second
+	}
+
+	switch config.Store {
+	case "", StoreMemory:
+		return NewMemoryStore(ttl), nil
+	case StoreRedis:
+		url, err := utils.SubstituteEnvVars(config.Url)
+		if err != nil {
+			return nil, fmt.Errorf("server.sessions.url: %w", err)
+		}
+		store, err := NewRedisStore(url, ttl)
+		if err != nil {
+			return nil, err
+		}
+		return store, nil
+	default:
+		return nil, fmt.Errorf("unsupported session store '%s'", config.Store)
+	}
+}
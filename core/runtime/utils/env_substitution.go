@@ -14,7 +14,7 @@ var (
 
 // SubstituteEnvVars replaces {{ env.VARIABLE_NAME }} placeholders with environment variable values
 // This is called at runtime (server startup/connection time) to prevent sensitive data from being
-// baked into the final bundle. Only allowed in connection_string and statement fields.
+// baked into the final bundle. Only allowed in connection_string, connection fields and statement fields.
 func SubstituteEnvVars(value string) (string, error) {
 	result := value
 	matches := envVarPattern.FindAllStringSubmatch(value, -1)
@@ -45,3 +45,17 @@ func SubstituteEnvVars(value string) (string, error) {
 
 	return result, nil
 }
+
+// ExtractEnvVarNames returns the names of the {{ env.VARIABLE_NAME }}
+// placeholders in value, each once, in the order they first appear
+func ExtractEnvVarNames(value string) []string {
+	var names []string
+	seen := make(map[string]bool)
+	for _, match := range envVarPattern.FindAllStringSubmatch(value, -1) {
+		if !seen[match[1]] {
+			seen[match[1]] = true
+			names = append(names, match[1])
+		}
+	}
+	return names
+}
@@ -0,0 +1,99 @@
+package types
+
+import "strconv"
+
+// DefaultAuto is the default value that generates a fresh value on every
+// execution instead of a fixed one
+const DefaultAuto = "auto"
+
+// PrimitiveSchema describes how values of a primitive type are written in JSON
+type PrimitiveSchema struct {
+	Type    string // JSON Schema type
+	Format  string // JSON Schema format, empty when the type has none
+	Example any    // Example value for generated documentation
+}
+
+// primitiveSchemas describes every primitive type. OpenAPI, MCP tool schemas,
+// the query index, llms.txt and generated clients all read it, so a new
+// primitive only needs an entry here.
+var primitiveSchemas = map[PrimitiveType]PrimitiveSchema{
+	PrimitiveString:   {Type: "string", Example: "example"},
+	PrimitiveInt:      {Type: "integer", Example: 42},
+	PrimitiveFloat:    {Type: "number", Example: 3.14},
+	PrimitiveBoolean:  {Type: "boolean", Example: true},
+	PrimitiveDatetime: {Type: "string", Format: "date-time", Example: "2024-01-01T00:00:00Z"},
+	PrimitiveUuid:     {Type: "string", Format: "uuid", Example: "550e8400-e29b-41d4-a716-446655440000"},
+}
+
+// SchemaForPrimitive returns the JSON representation of a primitive type name,
+// e.g. "int". Unknown types are described as strings.
+func SchemaForPrimitive(typ string) PrimitiveSchema {
+	if schema, ok := primitiveSchemas[PrimitiveType(typ)]; ok {
+		return schema
+	}
+	return primitiveSchemas[PrimitiveString]
+}
+
+// ParsePrimitiveValue parses a value written as a string, such as an input
+// default, into the JSON value of its primitive type. Values that do not parse
+// are returned unchanged.
+func ParsePrimitiveValue(value, typ string) any {
+	switch PrimitiveType(typ) {
+	case PrimitiveInt:
+		if parsed, err := strconv.ParseInt(value, 10, 64); err == nil {
+			return parsed
+		}
+	case PrimitiveFloat:
+		if parsed, err := strconv.ParseFloat(value, 64); err == nil {
+			return parsed
+		}
+	case PrimitiveBoolean:
+		if parsed, err := strconv.ParseBool(value); err == nil {
+			return parsed
+		}
+	}
+	return value
+}
+
+// ExampleValues parses input examples, written as strings like defaults, into
+// JSON values of their primitive type
+func ExampleValues(examples []string, typ string) []any {
+	if len(examples) == 0 {
+		return nil
+	}
+	values := make([]any, len(examples))
+	for i, example := range examples {
+		values[i] = ParsePrimitiveValue(example, typ)
+	}
+	return values
+}
+
+// InputExample returns the first declared example of an input, or the example
+// of its primitive type when it declares none
+func InputExample(examples []string, typ string) any {
+	if len(examples) > 0 {
+		return ParsePrimitiveValue(examples[0], typ)
+	}
+	return SchemaForPrimitive(typ).Example
+}
+
+// InputSchema describes an input of the given primitive type as a JSON Schema
+// property, with its format, default and allowed values
+func InputSchema(typ, description, defaultValue string, enum []string) map[string]any {
+	schema := SchemaForPrimitive(typ)
+	prop := map[string]any{
+		"type":        schema.Type,
+		"description": description,
+	}
+	if schema.Format != "" {
+		prop["format"] = schema.Format
+	}
+	// Generated defaults have no fixed value to document
+	if defaultValue != "" && defaultValue != DefaultAuto {
+		prop["default"] = ParsePrimitiveValue(defaultValue, typ)
+	}
+	if len(enum) > 0 {
+		prop["enum"] = enum
+	}
+	return prop
+}
@@ -0,0 +1,55 @@
+package types
+
+// Accepted values of configuration settings. They are defined here rather than
+// in the runtime packages that act on them so the parser, which validates
+// them, does not depend on the runtime.
+
+// MCPLogLevels are the MCP log levels, the syslog severities of RFC 5424, from
+// least to most severe
+var MCPLogLevels = []string{"debug", "info", "notice", "warning", "error", "critical", "alert", "emergency"}
+
+// Values of server.error_detail
+const (
+	ErrorDetailSanitized = "sanitized"
+	ErrorDetailFull      = "full"
+)
+
+// ErrorDetails are the accepted values of server.error_detail
+var ErrorDetails = []string{ErrorDetailSanitized, ErrorDetailFull}
+
+// Values of server.log_format
+const (
+	LogFormatText = "text"
+	LogFormatJSON = "json"
+)
+
+// LogFormats are the accepted values of server.log_format
+var LogFormats = []string{LogFormatText, LogFormatJSON}
+
+// Events webhooks may subscribe to. They are derived from runtime events.
+const (
+	HookEventServerStarted          = "server.started"
+	HookEventQueryFailed            = "query.failed"
+	HookEventReloadSucceeded        = "reload.succeeded"
+	HookEventReloadFailed           = "reload.failed"
+	HookEventConnectorHealthChanged = "connector.health_changed"
+)
+
+// HookEventNames lists every webhook event
+var HookEventNames = []string{
+	HookEventServerStarted,
+	HookEventQueryFailed,
+	HookEventReloadSucceeded,
+	HookEventReloadFailed,
+	HookEventConnectorHealthChanged,
+}
+
+// IsHookEventName reports whether name is a webhook event
+func IsHookEventName(name string) bool {
+	for _, event := range HookEventNames {
+		if event == name {
+			return true
+		}
+	}
+	return false
+}
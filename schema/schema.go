@@ -0,0 +1,17 @@
+// Package schema embeds the JSON Schema for .terse configuration files.
+// The schema file is generated by scripts/generate-schema.ts from the proto definitions.
+package schema
+
+import (
+	_ "embed"
+)
+
+//go:embed terse.schema.json
+var terseSchema []byte
+
+// TerseSchema returns the JSON Schema describing the .terse configuration format
+func TerseSchema() []byte {
+	out := make([]byte, len(terseSchema))
+	copy(out, terseSchema)
+	return out
+}
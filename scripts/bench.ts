@@ -0,0 +1,155 @@
+import { $ } from "bun";
+import { resolve, dirname, join } from "node:path";
+import { mkdtemp, rm } from "node:fs/promises";
+import { tmpdir } from "node:os";
+
+// Measures binary size and startup time of the current tree. With --base, the
+// same measurements are taken for a git ref and the run fails when the current
+// tree regresses beyond the allowed growth, so CI can catch size and startup
+// regressions without a stored baseline.
+//
+// Usage:
+//   bun run scripts/bench.ts [--base <ref>] [--tags <tags>] [--runs <n>]
+//                            [--max-size-growth <pct>] [--max-startup-growth <pct>]
+
+// Ensure we're in the project root
+const scriptDir = dirname(new URL(import.meta.url).pathname);
+const rootDir = resolve(scriptDir, "..");
+$.cwd(rootDir);
+
+// Parse arguments
+const options: Record<string, string> = {
+  runs: "20",
+  "max-size-growth": "5",
+  "max-startup-growth": "20",
+};
+const args = process.argv.slice(2);
+for (let i = 0; i < args.length; i++) {
+  if (!args[i].startsWith("--") || i + 1 >= args.length) {
+    console.error(`❌ Unexpected argument: ${args[i]}`);
+    process.exit(1);
+  }
+  options[args[i].slice(2)] = args[++i];
+}
+const runs = Number(options.runs);
+const tags = options.tags ?? "";
+
+// Startup is measured on a command that loads and validates a configuration,
+// so package initialization and config parsing are both covered
+const sampleConfig = `name: bench
+adapters:
+  main_db:
+    connector: postgres
+    connection_string: 'postgresql://localhost:5432/bench'
+queries:
+  get-user:
+    use: main_db
+    description: 'Get a user by ID'
+    statement: 'SELECT id, name FROM users WHERE id = {{ inputs.id }}'
+    inputs:
+      id:
+        type: int
+`;
+
+type Result = {
+  size: number;
+  startupMs: number;
+  inits: { pkg: string; ms: number }[];
+};
+
+async function build(dir: string, output: string) {
+  await $`go build -trimpath -ldflags=${"-s -w"} -tags=${tags} -o ${output} .`
+    .cwd(dir)
+    .quiet();
+}
+
+async function measure(binary: string): Promise<Result> {
+  const size = Bun.file(binary).size;
+
+  // Warm the page cache before timing
+  await $`${binary} validate -s ${sampleConfig}`.quiet();
+
+  const samples: number[] = [];
+  for (let i = 0; i < runs; i++) {
+    const start = performance.now();
+    await $`${binary} validate -s ${sampleConfig}`.quiet();
+    samples.push(performance.now() - start);
+  }
+  samples.sort((a, b) => a - b);
+  const startupMs = samples[Math.floor(samples.length / 2)];
+
+  // GODEBUG=inittrace=1 reports the cost of each package's initialization
+  const trace = await $`${binary} --version`
+    .env({ ...process.env, GODEBUG: "inittrace=1" })
+    .quiet();
+  const inits = trace.stderr
+    .toString()
+    .split("\n")
+    .map((line) => line.match(/^init (\S+) @[\d.]+ ms, ([\d.]+) ms clock/))
+    .filter((match) => match !== null)
+    .map((match) => ({ pkg: match[1], ms: Number(match[2]) }))
+    .sort((a, b) => b.ms - a.ms)
+    .slice(0, 10);
+
+  return { size, startupMs, inits };
+}
+
+function formatSize(bytes: number) {
+  return `${(bytes / 1024 / 1024).toFixed(2)} MiB`;
+}
+
+function growth(head: number, base: number) {
+  return ((head - base) / base) * 100;
+}
+
+const workDir = await mkdtemp(join(tmpdir(), "hyperterse-bench-"));
+try {
+  console.log(`Building current tree${tags ? ` (tags: ${tags})` : ""}...`);
+  await build(rootDir, join(workDir, "hyperterse-head"));
+  const head = await measure(join(workDir, "hyperterse-head"));
+
+  console.log(`Binary size:  ${formatSize(head.size)}`);
+  console.log(`Startup:      ${head.startupMs.toFixed(1)} ms (median of ${runs})`);
+  console.log("Slowest package initializers:");
+  for (const init of head.inits) {
+    console.log(`  ${init.ms.toFixed(3).padStart(8)} ms  ${init.pkg}`);
+  }
+
+  if (options.base) {
+    const baseDir = join(workDir, "base");
+    console.log(`\nBuilding ${options.base}...`);
+    await $`git worktree add --detach ${baseDir} ${options.base}`.quiet();
+    try {
+      await $`bun run scripts/generate-proto.ts`.cwd(baseDir).quiet();
+      await build(baseDir, join(workDir, "hyperterse-base"));
+    } finally {
+      await $`git worktree remove --force ${baseDir}`.quiet();
+    }
+    const base = await measure(join(workDir, "hyperterse-base"));
+
+    const sizeGrowth = growth(head.size, base.size);
+    const startupGrowth = growth(head.startupMs, base.startupMs);
+    console.log(
+      `Binary size:  ${formatSize(base.size)} -> ${formatSize(head.size)} (${sizeGrowth.toFixed(1)}%)`
+    );
+    console.log(
+      `Startup:      ${base.startupMs.toFixed(1)} ms -> ${head.startupMs.toFixed(1)} ms (${startupGrowth.toFixed(1)}%)`
+    );
+
+    const failures: string[] = [];
+    if (sizeGrowth > Number(options["max-size-growth"])) {
+      failures.push(`binary size grew ${sizeGrowth.toFixed(1)}% (limit ${options["max-size-growth"]}%)`);
+    }
+    if (startupGrowth > Number(options["max-startup-growth"])) {
+      failures.push(`startup time grew ${startupGrowth.toFixed(1)}% (limit ${options["max-startup-growth"]}%)`);
+    }
+    if (failures.length > 0) {
+      for (const failure of failures) console.error(`❌ ${failure}`);
+      process.exitCode = 1;
+    } else {
+      console.log(`✓ No regression against ${options.base}`);
+    }
+  }
+} finally {
+  await rm(workDir, { recursive: true, force: true });
+}
@@ -54,6 +54,30 @@ const namePattern = "^[a-zA-Z][a-zA-Z0-9_-]*$";
 // Query name pattern: must start with a letter, lowercase only
 const queryNamePattern = "^[a-z][a-z0-9_-]*$";
 
+// Route group: a boolean, or a map disabling the group or moving it to another port
+const routeGroup = (description: string) => ({
+  description,
+  oneOf: [
+    { type: "boolean" as const },
+    {
+      type: "object" as const,
+      properties: {
+        enabled: {
+          type: "boolean" as const,
+          description: "Serve the group's routes (default: true)",
+        },
+        port: {
+          type: "integer" as const,
+          description: "Serve the group on this port instead of server.port",
+          minimum: 1,
+          maximum: 65535,
+        },
+      },
+      additionalProperties: false,
+    },
+  ],
+});
+
 // Generate JSON schema
 const schema = {
   $schema: "http://json-schema.org/draft-07/schema#",
@@ -73,6 +97,13 @@ const schema = {
       description: "Optional service version for observability",
       minLength: 1,
     },
+    config_version: {
+      type: "integer" as const,
+      description:
+        "Configuration layout version (current: 2). Older layouts load through compatibility shims; run hyperterse upgrade-config to rewrite them",
+      minimum: 1,
+      maximum: 2,
+    },
     export: {
       type: "object" as const,
       description: "Optional export configuration",
@@ -94,10 +125,18 @@ const schema = {
       description: "Optional server configuration",
       properties: {
         port: {
-          type: "integer" as const,
-          description: "Server port (default: 8080)",
-          minimum: 1,
-          maximum: 65535,
+          description: "Server port (default: 8080), or auto to use the next free port from 8080",
+          oneOf: [
+            {
+              type: "integer" as const,
+              minimum: 1,
+              maximum: 65535,
+            },
+            {
+              type: "string" as const,
+              enum: ["auto"],
+            },
+          ],
         },
         log_level: {
           type: "integer" as const,
@@ -105,6 +144,218 @@ const schema = {
           minimum: 1,
           maximum: 4,
         },
+        log_format: {
+          type: "string" as const,
+          enum: ["text", "json"],
+          description: "Log output format: text (default) or json, one JSON object per line",
+        },
+        max_rows: {
+          type: "integer" as const,
+          description: "Default maximum rows returned per query (0 = unlimited)",
+          minimum: 0,
+        },
+        include_meta: {
+          type: "boolean" as const,
+          description: "Include execution metadata in query responses (default: false)",
+        },
+        result_key_case: {
+          type: "string" as const,
+          enum: ["original", "camel", "snake"],
+          description: "Case applied to result keys in responses (default: original)",
+        },
+        openapi_version: {
+          type: "string" as const,
+          enum: ["3.0", "3.1"],
+          description: "OpenAPI version served at /docs (default: 3.0)",
+        },
+        health_check_interval: {
+          type: "integer" as const,
+          description: "Seconds between background adapter health checks (default: 30)",
+          minimum: 0,
+        },
+        ready_timeout: {
+          type: "integer" as const,
+          description:
+            "Seconds to wait at startup for every adapter to connect before listening (0 = fail on the first connection error)",
+          minimum: 0,
+        },
+        admin_token: {
+          type: "string" as const,
+          description: "Token authenticating admin request headers such as X-Hyperterse-Adapter; use {{ env.NAME }}",
+        },
+        case_insensitive_queries: {
+          type: "boolean" as const,
+          description: "Resolve /query/<name> ignoring case and surrounding spaces or slashes",
+        },
+        query_index: {
+          type: "boolean" as const,
+          description: "Serve GET /query and list every query name in 404 bodies",
+        },
+        error_detail: {
+          type: "string" as const,
+          enum: ["sanitized", "full"],
+          description: "Database errors returned to clients: sanitized (default) hides the database's message, full returns it for debugging",
+        },
+        expose_constraint_names: {
+          type: "boolean" as const,
+          description: "Include the violated constraint's name in 409 and 422 error responses",
+        },
+        strict_unused_inputs: {
+          type: "boolean" as const,
+          description: "Reject queries that declare an input their statement never references (default: false)",
+        },
+        base_path: {
+          type: "string" as const,
+          description: "Prefix every route is mounted under, e.g. /api/v1",
+        },
+        mcp_log_level: {
+          type: "string" as const,
+          enum: ["debug", "info", "notice", "warning", "error", "critical", "alert", "emergency"],
+          description:
+            "Minimum level of MCP log notifications for sessions that have not called logging/setLevel (default: warning)",
+        },
+        passthrough_headers: {
+          type: "array" as const,
+          description:
+            "Request headers attached to traces, query events and {{ headers.name }} statement references",
+          items: {
+            type: "string" as const,
+            pattern: "^[a-zA-Z0-9-]+$",
+          },
+        },
+        worker_threads: {
+          type: "integer" as const,
+          description: "OS threads executing Go code simultaneously (default: number of CPUs)",
+          minimum: 0,
+        },
+        max_threads: {
+          type: "integer" as const,
+          description: "Maximum OS threads, including those blocked in system calls (default: Go runtime default)",
+          minimum: 0,
+        },
+        sessions: {
+          type: "object" as const,
+          description: "Optional MCP session tracking (stateless when omitted)",
+          properties: {
+            store: {
+              type: "string" as const,
+              description: "Session store backend (default: memory)",
+              enum: ["memory", "redis"],
+            },
+            url: {
+              type: "string" as const,
+              description: "Redis connection URL, required for the redis store",
+            },
+            ttl: {
+              type: "integer" as const,
+              description: "Idle session expiry in seconds (default: 3600)",
+              minimum: 1,
+            },
+          },
+          additionalProperties: false,
+        },
+        management_port: {
+          type: "integer" as const,
+          description: "Port serving /docs, /llms.txt, health endpoints and the admin API instead of server.port",
+          minimum: 1,
+          maximum: 65535,
+        },
+        routes: {
+          type: "object" as const,
+          description: "Optional route groups to disable or serve on other ports",
+          properties: {
+            queries: routeGroup("Query endpoints and the query index"),
+            mcp: routeGroup("MCP endpoint at /mcp"),
+            docs: routeGroup("OpenAPI spec at /docs and LLM documentation at /llms.txt"),
+            health: routeGroup("Health endpoints at /heartbeat and /health/ready"),
+            admin: routeGroup("Admin API under /admin, served when server.admin_token is set"),
+          },
+          additionalProperties: false,
+        },
+        request_log: {
+          type: "object" as const,
+          description: "Optional sampling of query request logs; failed requests are always logged",
+          properties: {
+            sample: {
+              type: "integer" as const,
+              description: "Log 1 in N successful requests (default: 1, every request)",
+              minimum: 1,
+            },
+            slow_ms: {
+              type: "integer" as const,
+              description: "Always log requests taking at least this many milliseconds (0 = off)",
+              minimum: 0,
+            },
+          },
+          additionalProperties: false,
+        },
+        maintenance: {
+          type: "object" as const,
+          description: "Optional maintenance mode; query endpoints return 503 while health and docs stay up",
+          properties: {
+            enabled: {
+              type: "boolean" as const,
+              description: "Start in maintenance mode (default: false)",
+            },
+            message: {
+              type: "string" as const,
+              description: "Error returned while in maintenance mode",
+            },
+            retry_after: {
+              type: "integer" as const,
+              description: "Seconds sent in the Retry-After header of 503 responses (0 = omitted)",
+              minimum: 0,
+            },
+          },
+          additionalProperties: false,
+        },
+        failure_capture: {
+          type: "object" as const,
+          description: "Optional debug bundles of failed executions, served by the admin API",
+          properties: {
+            enabled: {
+              type: "boolean" as const,
+              description: "Save a debug bundle of each failed execution (default: false)",
+            },
+            dir: {
+              type: "string" as const,
+              description: "Directory the bundles are kept in (default: failures under the hyperterse temp directory)",
+            },
+            max: {
+              type: "integer" as const,
+              description: "Bundles kept before the oldest are removed (default: 100)",
+              minimum: 1,
+            },
+          },
+          additionalProperties: false,
+        },
+        rate_limit: {
+          type: "object" as const,
+          description: "Optional per-client request rate limit for query and MCP endpoints",
+          properties: {
+            requests: {
+              type: "integer" as const,
+              description: "Requests allowed per client within each window",
+              minimum: 1,
+            },
+            window: {
+              type: "integer" as const,
+              description: "Window length in seconds (default: 60)",
+              minimum: 1,
+            },
+            store: {
+              type: "string" as const,
+              description: "Counter store (default: memory); use redis to share limits across replicas",
+              enum: ["memory", "redis"],
+            },
+            url: {
+              type: "string" as const,
+              description: "Redis connection URL, required for the redis store",
+            },
+          },
+          required: ["requests"],
+          additionalProperties: false,
+        },
         queries: {
           type: "object" as const,
           description: "Optional query execution defaults",
@@ -147,9 +398,144 @@ const schema = {
             },
             connection_string: {
               type: "string" as const,
-              description: "Database connection string (required)",
+              description: "Database connection string (required unless host is set)",
               minLength: 1,
             },
+            host: {
+              type: "string" as const,
+              description:
+                "Database host, assembled into the connection URL with the other connection fields (postgres, mysql, redis and mongodb)",
+              minLength: 1,
+            },
+            port: {
+              description: "Database port",
+              oneOf: [{ type: "integer" as const }, { type: "string" as const }],
+            },
+            user: {
+              type: "string" as const,
+              description: "Database user",
+            },
+            password: {
+              type: "string" as const,
+              description: "Database password; any characters are allowed and percent-encoded automatically",
+            },
+            database: {
+              description: "Database name, or database number for redis",
+              oneOf: [{ type: "string" as const }, { type: "integer" as const }],
+            },
+            ssl: {
+              description: "Require TLS (true) or disable it (false); unset keeps the driver default",
+              oneOf: [{ type: "boolean" as const }, { type: "string" as const }],
+            },
+            ssl_mode: {
+              type: "string" as const,
+              enum: ["disable", "require", "verify-ca", "verify-full"],
+              description:
+                "TLS verification mode (postgres, mysql and mongodb; default: verify-full when TLS settings are given)",
+            },
+            ca_cert_file: {
+              type: "string" as const,
+              description: "PEM file with the CA certificates the server is verified against",
+            },
+            client_cert_file: {
+              type: "string" as const,
+              description: "PEM client certificate for mutual TLS (requires client_key_file)",
+            },
+            client_key_file: {
+              type: "string" as const,
+              description: "PEM client private key for mutual TLS (requires client_cert_file)",
+            },
+            auth: {
+              type: "string" as const,
+              enum: ["aws_iam", "gcp_iam"],
+              description:
+                "Authenticate with short-lived IAM tokens instead of a password (postgres and mysql only)",
+            },
+            aws_region: {
+              type: "string" as const,
+              description: "AWS region of the database for auth: aws_iam (default: AWS_REGION)",
+            },
+            credentials: {
+              type: "object" as const,
+              description:
+                "Refreshable credential re-checked while serving; the connection pool is rebuilt when it changes",
+              properties: {
+                provider: {
+                  type: "string" as const,
+                  enum: ["env", "file", "command"],
+                  description: "Where the credential is read from",
+                },
+                variable: {
+                  type: "string" as const,
+                  description: "env: variable holding the credential",
+                },
+                path: {
+                  type: "string" as const,
+                  description: "file: file holding the credential; env: env file re-read (required)",
+                },
+                command: {
+                  description: "command: program and arguments printing the credential",
+                  oneOf: [
+                    { type: "string" as const, minLength: 1 },
+                    { type: "array" as const, items: { type: "string" as const }, minItems: 1 },
+                  ],
+                },
+                target: {
+                  type: "string" as const,
+                  enum: ["password", "connection_string"],
+                  description: "What the credential replaces (default: password)",
+                },
+                refresh_interval: {
+                  type: "integer" as const,
+                  minimum: 0,
+                  description: "Seconds between checks (default: 60)",
+                },
+              },
+              required: ["provider"],
+              additionalProperties: false,
+            },
+            timezone: {
+              type: "string" as const,
+              description: "IANA time zone datetime inputs are converted to (default: UTC)",
+            },
+            max_concurrency: {
+              type: "integer" as const,
+              minimum: 0,
+              description: "Queries run on this adapter at once, shared between queries by weight (0 = unlimited)",
+            },
+            pool: {
+              type: "object" as const,
+              description: "Connection pool settings (postgres and mysql only)",
+              properties: {
+                min_connections: {
+                  type: "integer" as const,
+                  minimum: 0,
+                  description: "Idle connections kept open for reuse (default: 2)",
+                },
+                max_connections: {
+                  type: "integer" as const,
+                  minimum: 0,
+                  description: "Open connections allowed at once; queries wait beyond it (0 = unlimited)",
+                },
+                warm: {
+                  type: "boolean" as const,
+                  description: "Open min_connections at startup instead of on first use",
+                },
+              },
+              additionalProperties: false,
+            },
+            migrations: {
+              type: "string" as const,
+              minLength: 1,
+              description: "Directory of ordered SQL migration files run by hyperterse migrate (postgres, mysql and duckdb only)",
+            },
+            on_connect: {
+              description: "Session statements run on each new connection (postgres and mysql only)",
+              oneOf: [
+                { type: "string" as const, minLength: 1 },
+                { type: "array" as const, items: { type: "string" as const, minLength: 1 } },
+              ],
+            },
             options: {
               type: "object" as const,
               description: "Connector-specific options",
@@ -162,7 +548,18 @@ const schema = {
               },
             },
           },
-          required: ["connector", "connection_string"],
+          required: ["connector"],
+          anyOf: [
+            { required: ["connection_string"] },
+            { required: ["host"] },
+            { properties: { connector: { const: "static" } } },
+            {
+              required: ["credentials"],
+              properties: {
+                credentials: { required: ["target"], properties: { target: { const: "connection_string" } } },
+              },
+            },
+          ],
           additionalProperties: false,
         },
       },
@@ -214,6 +611,123 @@ const schema = {
               required: ["enabled"],
               additionalProperties: false,
             },
+            max_rows: {
+              type: "integer" as const,
+              description: "Per-query row limit override (0 = use server.max_rows)",
+              minimum: 0,
+            },
+            cache_control: {
+              type: "string" as const,
+              description: 'Cache-Control header set on successful responses (e.g. "public, max-age=60")',
+            },
+            nulls: {
+              type: "string" as const,
+              enum: ["keep", "drop", "fill"],
+              description:
+                "Null handling for result columns: keep (default), drop null columns, or fill missing and null data columns with their defaults",
+            },
+            coalesce: {
+              type: "boolean" as const,
+              description: "Execute concurrent identical requests once and share the result (default: false)",
+            },
+            allowed_adapters: {
+              type: "array" as const,
+              description: "Adapters admin requests may run this query on with the X-Hyperterse-Adapter header",
+              items: {
+                type: "string" as const,
+              },
+            },
+            log_sample: {
+              type: "integer" as const,
+              minimum: 1,
+              description: "Log 1 in N successful requests to this query, overriding server.request_log.sample",
+            },
+            weight: {
+              type: "integer" as const,
+              minimum: 1,
+              maximum: 100,
+              description: "Share of the adapter's max_concurrency slots while queries are queued (default: 1)",
+            },
+            soft_delete_filter: {
+              type: "string" as const,
+              description: "Predicate added to the WHERE clause of the SELECT statement, e.g. deleted_at IS NULL",
+            },
+            cursor: {
+              type: "object" as const,
+              description: "Cursor pagination: responses cut off at max_rows include a next_cursor for the following page",
+              properties: {
+                key: {
+                  type: "string" as const,
+                  description: "Result column whose value in the last row of a page is encoded in next_cursor",
+                },
+                input: {
+                  type: "string" as const,
+                  description: "Optional input that receives the key value of a cursor",
+                },
+              },
+              required: ["key", "input"],
+              additionalProperties: false,
+            },
+            enabled: {
+              description: "Serve this query; false hides it from routes, docs and MCP. May be an {{ env.VAR }} placeholder (default: true)",
+              oneOf: [{ type: "boolean" as const }, { type: "string" as const }],
+            },
+            federated: {
+              type: "object" as const,
+              description: "Run statements on several adapters and merge their rows, instead of use and statement",
+              properties: {
+                sources: {
+                  type: "array" as const,
+                  description: "Sub-queries, run concurrently; joins match rows onto the first",
+                  minItems: 2,
+                  items: {
+                    type: "object" as const,
+                    properties: {
+                      name: {
+                        type: "string" as const,
+                        pattern: namePattern,
+                        description: "Source name, used in join.keys",
+                      },
+                      use: {
+                        type: "string" as const,
+                        description: "Adapter the source runs on",
+                      },
+                      statement: {
+                        type: "string" as const,
+                        description: "Statement run on the source's adapter",
+                      },
+                    },
+                    required: ["name", "use", "statement"],
+                    additionalProperties: false,
+                  },
+                },
+                merge: {
+                  type: "string" as const,
+                  enum: ["join", "concat"],
+                  description: "join rows on key columns, or concat the rows of every source",
+                },
+                join: {
+                  type: "object" as const,
+                  description: "Hash join settings (merge: join)",
+                  properties: {
+                    type: {
+                      type: "string" as const,
+                      enum: ["left", "inner"],
+                      description: "Keep rows of the first source without a match (left, default) or drop them (inner)",
+                    },
+                    keys: {
+                      type: "object" as const,
+                      description: "Key column of each source, by source name",
+                      additionalProperties: { type: "string" as const },
+                    },
+                  },
+                  required: ["keys"],
+                  additionalProperties: false,
+                },
+              },
+              required: ["sources", "merge"],
+              additionalProperties: false,
+            },
             inputs: {
               type: "object" as const,
               description: "Input parameter definitions",
@@ -238,7 +752,40 @@ const schema = {
                       description:
                         "Default value (required if optional=true, type depends on input type)",
                     },
+                    coerce: {
+                      type: "boolean" as const,
+                      description:
+                        "Accept looser representations such as epoch milliseconds for datetime (default: false)",
+                    },
+                    transform: {
+                      description:
+                        "Transform applied to string values before type checking: lowercase, trim or truncate(n)",
+                      oneOf: [
+                        { type: "string" as const },
+                        { type: "array" as const, items: { type: "string" as const } },
+                      ],
+                    },
+                    enum: {
+                      type: "array" as const,
+                      description: "Allowed values of a string input; required for {{ ident.name }} references",
+                      items: { type: "string" as const },
+                      minItems: 1,
+                      uniqueItems: true,
+                    },
+                    escape_like: {
+                      type: "boolean" as const,
+                      description: "Escape LIKE wildcards (% and _) in the value substituted for {{ inputs.name }}",
+                    },
+                    example: {
+                      description: "Example value shown in OpenAPI, MCP tool schemas, llms.txt and generated clients",
+                    },
+                    examples: {
+                      type: "array" as const,
+                      description: "Several example values; use instead of example",
+                      minItems: 1,
+                    },
                   },
+                  not: { required: ["example", "examples"] },
                   required: ["type"],
                   allOf: [
                     {
@@ -252,7 +799,7 @@ const schema = {
                     {
                       if: {
                         properties: {
-                          type: { enum: ["string", "datetime"] },
+                          type: { enum: ["string", "datetime", "uuid"] },
                         },
                         required: ["type"],
                       },
@@ -313,8 +860,100 @@ const schema = {
               },
               additionalProperties: false,
             },
+            data: {
+              type: "object" as const,
+              description: "Result column definitions",
+              patternProperties: {
+                [namePattern]: {
+                  type: "object" as const,
+                  properties: {
+                    type: {
+                      type: "string" as const,
+                      description: "Column type (required)",
+                      enum: primitiveValues,
+                    },
+                    description: {
+                      type: "string" as const,
+                      description: "Column description",
+                    },
+                    map_to: {
+                      type: "string" as const,
+                      description: "Field the column maps to",
+                    },
+                    default: {
+                      description: "Value filled in for missing or null columns when nulls is fill",
+                    },
+                  },
+                  required: ["type"],
+                  additionalProperties: false,
+                },
+              },
+              additionalProperties: false,
+            },
+          },
+          required: ["description"],
+          oneOf: [{ required: ["use", "statement"] }, { required: ["federated"] }],
+          additionalProperties: false,
+        },
+      },
+      additionalProperties: false,
+    },
+    defs: {
+      type: "object" as const,
+      description: "Reusable constants and statement fragments, referenced as {{ defs.name }}",
+      patternProperties: {
+        [namePattern]: {
+          oneOf: [
+            { type: "string" as const },
+            { type: "number" as const },
+            { type: "boolean" as const },
+          ],
+        },
+      },
+      additionalProperties: false,
+    },
+    hooks: {
+      type: "object" as const,
+      description: "Optional webhooks notified of runtime events",
+      patternProperties: {
+        [namePattern]: {
+          type: "object" as const,
+          properties: {
+            url: {
+              type: "string" as const,
+              description: "Endpoint receiving JSON POSTs (required)",
+              minLength: 1,
+            },
+            events: {
+              type: "array" as const,
+              description: "Event types delivered to this hook (required)",
+              items: {
+                type: "string" as const,
+                enum: ["server.started", "query.failed", "reload.succeeded", "reload.failed", "connector.health_changed"],
+              },
+              minItems: 1,
+            },
+            secret: {
+              type: "string" as const,
+              description: "HMAC-SHA256 signing secret for the X-Hyperterse-Signature header",
+            },
+            retries: {
+              type: "integer" as const,
+              description: "Delivery retries after the first attempt (default: 3)",
+              minimum: 0,
+            },
+            timeout: {
+              type: "integer" as const,
+              description: "Per-attempt timeout in seconds (default: 10)",
+              minimum: 1,
+            },
+            failure_threshold: {
+              type: "integer" as const,
+              description: "Consecutive failures of a query before query.failed is sent (default: 1)",
+              minimum: 1,
+            },
           },
-          required: ["use", "description", "statement"],
+          required: ["url", "events"],
           additionalProperties: false,
         },
       },
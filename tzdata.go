@@ -0,0 +1,8 @@
+//go:build !no_tzdata
+
+package main
+
+// Embed the time zone database so adapter timezones resolve on hosts without
+// one. Builds with the no_tzdata tag are about 450 KB smaller and rely on the
+// host's database.
+import _ "time/tzdata"
@@ -0,0 +1,77 @@
+//go:build js && wasm
+
+// Command wasm exposes the configuration parser and validator to JavaScript, so
+// browser tools such as a config playground apply the same rules as the CLI.
+//
+// Build it with:
+//
+//	GOOS=js GOARCH=wasm go build -o hyperterse.wasm ./wasm
+//
+// and load it with the wasm_exec.js shipped in $(go env GOROOT)/lib/wasm. It
+// defines a global hyperterse object:
+//
+//	hyperterse.validate(source, format?) // => { valid, configVersion, errors }
+//
+// format is yaml, json, toml or dsl, and defaults to yaml.
+package main
+
+import (
+	"errors"
+	"syscall/js"
+
+	"github.com/hyperterse/hyperterse/core/logger"
+	"github.com/hyperterse/hyperterse/core/parser"
+)
+
+func main() {
+	// Only the returned result matters; keep the console quiet
+	logger.SetLogLevel(logger.LogLevelError)
+
+	js.Global().Set("hyperterse", js.ValueOf(map[string]any{
+		"validate": js.FuncOf(validate),
+	}))
+
+	// Keep the exported functions callable
+	select {}
+}
+
+// validate parses and validates a configuration passed as a string
+func validate(this js.Value, args []js.Value) any {
+	if len(args) == 0 || args[0].Type() != js.TypeString {
+		return validationResult(0, []string{"validate expects the configuration as a string"})
+	}
+
+	format := parser.FormatYAML
+	if len(args) > 1 && args[1].Type() == js.TypeString {
+		var err error
+		if format, err = parser.ParseFormatName(args[1].String()); err != nil {
+			return validationResult(0, []string{err.Error()})
+		}
+	}
+
+	model, err := parser.ParseWithFormat([]byte(args[0].String()), format)
+	if err != nil {
+		return validationResult(0, []string{err.Error()})
+	}
+	if err := parser.Validate(model); err != nil {
+		var validationErrs *parser.ValidationErrors
+		if errors.As(err, &validationErrs) {
+			return validationResult(int(model.ConfigVersion), validationErrs.Errors)
+		}
+		return validationResult(int(model.ConfigVersion), []string{err.Error()})
+	}
+	return validationResult(int(model.ConfigVersion), nil)
+}
+
+// validationResult converts a result to a JavaScript object
+func validationResult(configVersion int, errs []string) any {
+	jsErrors := make([]any, len(errs))
+	for i, err := range errs {
+		jsErrors[i] = err
+	}
+	return map[string]any{
+		"valid":         len(errs) == 0,
+		"configVersion": configVersion,
+		"errors":        jsErrors,
+	}
+}